@@ -0,0 +1,7153 @@
+//! Procedural macros for the `byteview` crate.
+//!
+//! These macros are re-exported from `byteview` and should be used through that crate, since the generated code refers
+//! to items defined there.
+
+use proc_macro::TokenStream;
+use quote::{ToTokens, TokenStreamExt, format_ident, quote};
+use std::str::FromStr;
+use syn::ext::IdentExt;
+use syn::parse::{Error, Parse};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, BinOp, Block, ConstParam, Expr, ExprLit, Field, FieldMutability, Fields,
+    GenericArgument, GenericParam, Generics, Ident, ImplItemConst, ItemFn, ItemImpl, Lifetime, Lit,
+    LitByteStr, LitInt, LitStr, Meta, PathArguments, RangeLimits, Stmt, Token, Type, TypeArray,
+    TypePath, Variant, Visibility, braced, parenthesized, parse, parse_macro_input, parse_quote,
+    token,
+};
+
+//////////////////////////////////////////////////////////////////////
+// Procedural Macro Wrappers
+//////////////////////////////////////////////////////////////////////
+
+// The macros are documented where `byteview` re-exports them, so that links to its items resolve.
+
+#[proc_macro]
+pub fn byteview_ref(input: TokenStream) -> TokenStream {
+    byteview_ref_impl(input)
+}
+
+#[proc_macro]
+pub fn byteview_owned(input: TokenStream) -> TokenStream {
+    byteview_owned_impl(input)
+}
+
+#[proc_macro]
+pub fn byteview(input: TokenStream) -> TokenStream {
+    byteview_impl(input)
+}
+
+#[proc_macro]
+pub fn byteview_bits(input: TokenStream) -> TokenStream {
+    byteview_bits_impl(input)
+}
+
+#[proc_macro]
+pub fn byteview_enum(input: TokenStream) -> TokenStream {
+    byteview_enum_impl(input)
+}
+
+#[proc_macro]
+pub fn assert_size(input: TokenStream) -> TokenStream {
+    assert_size_impl(input)
+}
+
+#[proc_macro]
+pub fn assert_offset(input: TokenStream) -> TokenStream {
+    assert_offset_impl(input)
+}
+
+//////////////////////////////////////////////////////////////////////
+// Constants
+//////////////////////////////////////////////////////////////////////
+
+const SUPPORTED_TYPES_MESSAGE: &str = "Supported types are u8, i8, numbers with a byte order (e.g. \
+    u16be, i32le, f64ne, or u24be), byte arrays (e.g. [u8; 4]), arrays of numbers (e.g. [u16be; 4]), packed arrays (e.g. \
+    packed<u12be, 8>), varint, shorthand types (e.g. bool or ascii<16>), and other byteview structs";
+
+// Types from the standard library that are not byteview structs, even though their names start with an uppercase
+// letter like the names of byteview structs.
+const STD_TYPE_NAMES: &[&str] = &[
+    "String", "Vec", "Box", "Option", "Result", "Rc", "Arc", "Cow", "Cell", "RefCell",
+];
+
+//////////////////////////////////////////////////////////////////////
+// Data Structures
+//////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+struct ByteViewStruct {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    // Const generic parameters, e.g. `const N: usize`, which can be used as array lengths.
+    const_params: Vec<ConstParam>,
+    fields: Vec<ByteViewField>,
+    options: StructOptions,
+}
+
+// Options set with `#[byteview(...)]` attributes on the struct.
+#[derive(Debug, Clone, Default)]
+struct StructOptions {
+    // The name of the field holding the total number of bytes in the record, making the struct variable-sized.
+    record_len: Option<Ident>,
+    // The byte written to padding fields named "_" instead of zero.
+    pad_with: Option<Expr>,
+    // The prefix of the names of the exported C functions, e.g. `entry_header` for `entry_header_index`.
+    export_c: Option<Ident>,
+    // The byte order of number fields written without one, e.g. `u32` instead of `u32be`.
+    endian: Option<Endianness>,
+    // The names of the borrowed and owned structs generated by `byteview!`, e.g. `FooRef` and `FooOwned`.
+    ref_name: Option<Ident>,
+    owned_name: Option<Ident>,
+    // Whether the bytes of the owned struct are overwritten with zeros when it is dropped.
+    zeroize_on_drop: bool,
+}
+
+#[derive(Debug, Clone)]
+struct ByteViewField {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    byteview_type: ByteViewType,
+    options: FieldOptions,
+    // The type the "getter" converts the field to, declared with `as` after the field type.
+    conversion: Option<Conversion>,
+}
+
+// A conversion from the bytes of a field to another type.
+#[derive(Debug, Clone)]
+enum Conversion {
+    // Convert each byte of a byte array with `TryFrom<u8>`, e.g. `[u8; 16] as [Mode; 16]`. The target is the array
+    // type and `element` is its element type.
+    ByteArray {
+        target: Box<Type>,
+        element: Box<Type>,
+    },
+    // Convert a number or a reference to a byte array with `byteview::FromFieldValue`, e.g. `u8 as DataInfo` or
+    // `[u8; 8] as Signature`.
+    Value {
+        target: Box<Type>,
+    },
+    // Decode an array of UTF-16 code units up to the first NUL, e.g. `utf16be<32>`. A lossy version of the "getter" is
+    // also generated.
+    Utf16,
+    // Convert a signed fixed-point number with the given number of fractional bits to an f64, e.g. `q16_16be`.
+    FixedPoint {
+        fraction_bits: u32,
+    },
+    // Decode a byte array as a packed binary-coded decimal number, e.g. `bcd<4>`.
+    Bcd,
+}
+
+// Options set with `#[byteview(...)]` attributes on a field.
+#[derive(Debug, Clone, Default)]
+struct FieldOptions {
+    // The field is an absolute offset into the buffer that contains the struct.
+    offset_into_buffer: bool,
+    // The field is the length of data following the struct (possibly including the struct itself).
+    length: Option<LengthKind>,
+    // Truncate the remaining bytes to the declared length when parsing.
+    truncate_rest: bool,
+    // The field may be missing from the end of shorter (e.g. older) records.
+    optional: bool,
+    // The value of an optional field when it is missing.
+    default: Option<Expr>,
+    // The field is the last field of the generated prefix struct.
+    prefix_boundary: bool,
+    // The field is reserved, so it is zeroed along with padding when the struct is normalized.
+    reserved: bool,
+    // The field is reserved and must be zero, which is checked by `validate()`.
+    must_be_zero: bool,
+    // The field is a byte array whose bits each hold a flag, numbered in the given order.
+    bitmap: Option<BitOrder>,
+    // The field is an array of numbers that is only accessed through an iterator or by index, not by value.
+    iter_only: bool,
+    // Named ranges of bits within an unsigned integer field, each of which gets its own "getter".
+    subfields: Vec<Subfield>,
+    // Named single bits within an unsigned integer field, each of which gets an `is_` method. Unlike the other options,
+    // these are declared after the type, e.g. `flags u8 { COMPRESSED = 0 }`.
+    flags: Vec<Flag>,
+    // The field is a signed time zone offset from UTC in the given unit, converted to a `chrono::FixedOffset` (or a
+    // `time::UtcOffset` with the `time` feature).
+    tz_offset: Option<TzOffsetUnit>,
+    // The field is an unsigned integer counter with methods to increment it in place.
+    counter: bool,
+    // The field is an unsigned integer number of units of time, each of the given number of nanoseconds, with a
+    // method that converts it to a `std::time::Duration`.
+    duration: Option<u64>,
+    // The bytes that the field must contain, checked by `split_slice_checked` and used to find the start of a struct.
+    magic: Option<LitByteStr>,
+    // The byte order of a number field written without one, overriding the default set on the struct.
+    endian: Option<Endianness>,
+    // The name of the "getter", used instead of the name of the field, e.g. for fields named `_name`.
+    getter: Option<Ident>,
+    // The name of the "getter" alone, if `getter` is not set. This is `get` for the only field of a tuple struct, whose
+    // constants and other methods are still named after the field (e.g. `OFFSET_FIELD_0`), like its layout.
+    getter_only: Option<Ident>,
+}
+
+// The unit of a time zone offset field.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum TzOffsetUnit {
+    // Stored in an i8, e.g. +05:45 is 23.
+    QuarterHours,
+    // Stored in an i16, e.g. +05:45 is 345.
+    Minutes,
+}
+
+// A named range of bits within an unsigned integer field, declared as e.g. `kind = 4..8`. Bit 0 is the least
+// significant bit.
+#[derive(Debug, Clone)]
+struct Subfield {
+    ident: Ident,
+    start: u32,
+    end: u32,
+}
+
+// A named bit within an unsigned integer field, declared as e.g. `COMPRESSED = 0` in `flags u8 { ... }`. Bit 0 is the
+// least significant bit.
+#[derive(Debug, Clone)]
+struct Flag {
+    ident: Ident,
+    bit: u32,
+}
+
+// What the value of a length field counts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum LengthKind {
+    // The number of bytes following the struct.
+    OfRest,
+    // The number of bytes in the struct plus the number of bytes following it.
+    IncludesHeader,
+}
+
+// The order of the bits within each byte of a bitmap field.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum BitOrder {
+    // Bit 0 is the least significant bit of the first byte.
+    Lsb0,
+    // Bit 0 is the most significant bit of the first byte.
+    Msb0,
+}
+
+// A newtype around an unsigned integer whose bits hold several smaller values, defined with `byteview_bits!`.
+#[derive(Debug)]
+struct BitsStruct {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    // The type of the stored integer, including its byte order (e.g. `u16be`).
+    storage: ByteViewType,
+    // The fields, from the most significant bits to the least significant bits.
+    fields: Vec<BitsField>,
+}
+
+// A named group of bits in a BitsStruct, e.g. `pub kind: 4 as DataKind`.
+#[derive(Debug)]
+struct BitsField {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    bits: u32,
+    // The type the bits are converted to with `TryFrom`, if any.
+    conversion: Option<Type>,
+}
+
+// An enum whose variants each correspond to a value of an integer type, defined with `byteview_enum!`.
+#[derive(Debug)]
+struct ByteViewEnum {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    // The integer type of the discriminants, e.g. `u8`.
+    repr: Ident,
+    // The unit variants and their discriminants.
+    variants: Vec<(Vec<Attribute>, Ident, Expr)>,
+    // The variant holding any value that does not match another variant, e.g. `Unknown(u8)`.
+    unknown: Option<(Vec<Attribute>, Ident)>,
+}
+
+// A check that a generated struct has a number of bytes, written as `assert_size!(Header, 42)`.
+#[derive(Debug)]
+struct AssertSize {
+    ty: Type,
+    size: Expr,
+}
+
+// A check that a field of a generated struct is at an offset, written as `assert_offset!(Header, log_type, 40)`.
+#[derive(Debug)]
+struct AssertOffset {
+    ty: Type,
+    field: Ident,
+    offset: Expr,
+}
+
+#[derive(Debug, Clone)]
+enum ByteViewType {
+    SingleByte(Signedness),
+    MultiByte(MultiByteType, Endianness),
+    Array(Box<Expr>),
+    // An array of `len` byte arrays of `row_len` bytes each, e.g. `[[u8; 16]; 4]`.
+    Array2D {
+        row_len: Box<Expr>,
+        len: Box<Expr>,
+    },
+    // An array of `len` numbers other than u8, e.g. `[u32be; 1024]`. The element is a SingleByte or MultiByte type.
+    NumberArray {
+        element: Box<ByteViewType>,
+        len: Box<Expr>,
+    },
+    // An array of `len` unsigned integers of `bits` bits each, packed back to back, e.g. `packed<u12be, 64>`.
+    Packed {
+        bits: u32,
+        order: BitOrder,
+        len: Box<Expr>,
+    },
+    // Another struct generated by `byteview_ref!` or `byteview_owned!`, e.g. `DeviceId<'a>` or `DeviceIdOwned`.
+    Nested(Box<Type>),
+    // An array of `len` nested structs, e.g. `[EntryHeader<'a>; 4]`, accessed as a `byteview::Table`.
+    NestedArray {
+        element: Box<Type>,
+        len: Box<Expr>,
+    },
+    // An unsigned LEB128 varint of 1 to 10 bytes, read as a u64. Varints must follow all of the fixed-size fields, so they
+    // are not counted in `NUM_BYTES`. `index` is the position of the field among the varints, which is only known once
+    // every field has been parsed.
+    Varint {
+        index: usize,
+    },
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum Signedness {
+    Unsigned,
+    Signed,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum MultiByteType {
+    U16,
+    // 24-bit and 48-bit integers are stored in 3 and 6 bytes and returned as 32-bit and 64-bit integers.
+    U24,
+    U32,
+    U48,
+    U64,
+    U128,
+    I16,
+    I24,
+    I32,
+    I48,
+    I64,
+    I128,
+    F32,
+    F64,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum Endianness {
+    Big,
+    Little,
+    Native,
+    // PDP-11 order: the more significant 16-bit half first, each half little-endian. Only for u32 and i32.
+    Middle,
+    // Chosen by the `E: byteview::Endian` type parameter of a struct declared with `#[byteview(endian = "generic")]`.
+    Generic,
+    // Chosen by the constructor of a struct declared with `#[byteview(endian = "runtime")]` and stored in the struct.
+    Runtime,
+}
+
+//////////////////////////////////////////////////////////////////////
+// Parsing Tokens
+//////////////////////////////////////////////////////////////////////
+
+// The struct declarations passed to a single macro invocation, each of which is generated independently. Structs
+// declared together can refer to each other as nested structs like any other structs in the same module.
+#[derive(Debug)]
+struct ByteViewStructs(Vec<ByteViewStruct>);
+
+impl Parse for ByteViewStructs {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let mut byteview_structs = vec![input.parse()?];
+        while !input.is_empty() {
+            byteview_structs.push(input.parse()?);
+        }
+        Ok(Self(byteview_structs))
+    }
+}
+
+impl ByteViewStructs {
+    // Generate each struct with the given kind of field, or an error for a struct that names the structs generated by
+    // `byteview!` or a borrowed struct that asks to be zeroized.
+    fn create_tagged_structs(self, field_kind: ByteViewFieldKind) -> proc_macro2::TokenStream {
+        let mut tokens = proc_macro2::TokenStream::new();
+        for byteview_struct in self.0 {
+            if let Some(error) = byteview_struct.check_no_variant_names() {
+                tokens.append_all(error.to_compile_error());
+                continue;
+            }
+            if byteview_struct.options.zeroize_on_drop && field_kind == ByteViewFieldKind::Borrowed
+            {
+                let error = Error::new(
+                    byteview_struct.ident.span(),
+                    "zeroize_on_drop is only permitted in byteview_owned! and byteview!",
+                );
+                tokens.append_all(error.to_compile_error());
+                continue;
+            }
+            ByteViewStructTagged {
+                inner: byteview_struct,
+                field_kind,
+            }
+            .to_tokens(&mut tokens);
+        }
+        tokens
+    }
+}
+
+impl Parse for ByteViewStruct {
+    // The struct is parsed by hand rather than as an ItemStruct because field types may be followed by a conversion
+    // (e.g. `[u8; 16] as [Mode; 16]`), which is not valid Rust syntax.
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let mut attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse::<Ident>()?;
+        let mut generics = input.parse::<Generics>()?;
+        generics.where_clause = input.parse()?;
+        let options = StructOptions::extract_from_attrs(&mut attrs)?;
+        let mut fields = Vec::new();
+        if input.peek(token::Paren) {
+            // The fields of a tuple struct are named by position, e.g. `field_0`. If there is only one, its "getter" is
+            // named `get` instead, but its constants and other methods keep the positional name.
+            let content;
+            parenthesized!(content in input);
+            while !content.is_empty() {
+                let ident = format_ident!("field_{}", fields.len(), span = content.span());
+                fields.push(ByteViewField::parse_unnamed(
+                    &content,
+                    options.endian,
+                    ident,
+                )?);
+                if content.is_empty() {
+                    break;
+                }
+                content.parse::<Token![,]>()?;
+            }
+            if let [field] = fields.as_mut_slice() {
+                field.options.getter_only = Some(format_ident!("get", span = field.ident.span()));
+            }
+            generics.where_clause = input.parse()?;
+            input.parse::<Token![;]>()?;
+        } else {
+            let content;
+            braced!(content in input);
+            while !content.is_empty() {
+                fields.push(ByteViewField::parse(&content, options.endian)?);
+                if content.is_empty() {
+                    break;
+                }
+                content.parse::<Token![,]>()?;
+            }
+        }
+        let mut const_params = Vec::new();
+        for param in generics.params {
+            let GenericParam::Const(param) = param else {
+                return Err(Error::new(
+                    param.span(),
+                    "Only const generic parameters are permitted on byteview struct definitions"
+                        .to_string(),
+                ));
+            };
+            const_params.push(param);
+        }
+        if let Some(where_clause) = generics.where_clause {
+            return Err(Error::new(
+                where_clause.span(),
+                "Generic types are not permitted on byteview struct definitions".to_string(),
+            ));
+        };
+        if let Some(first_varint) = fields
+            .iter()
+            .position(|field| matches!(field.byteview_type, ByteViewType::Varint { .. }))
+        {
+            if let Some(fixed_field) = fields[first_varint..]
+                .iter()
+                .find(|field| !matches!(field.byteview_type, ByteViewType::Varint { .. }))
+            {
+                return Err(Error::new(
+                    fixed_field.ident.span(),
+                    "Only trailing fields may be varints, so this field must be a varint"
+                        .to_owned(),
+                ));
+            }
+            for (index, field) in fields[first_varint..].iter_mut().enumerate() {
+                field.byteview_type = ByteViewType::Varint { index };
+            }
+        }
+        if let Some(second_length_field) = fields
+            .iter()
+            .filter(|field| field.options.length.is_some())
+            .nth(1)
+        {
+            return Err(Error::new(
+                second_length_field.ident.span(),
+                "Only one field may be annotated with len_of_rest or len_includes_header"
+                    .to_owned(),
+            ));
+        }
+        if let Some(first_optional) = fields.iter().position(|field| field.options.optional)
+            && let Some(mandatory_field) = fields[first_optional..]
+                .iter()
+                .find(|field| !field.options.optional)
+        {
+            return Err(Error::new(
+                mandatory_field.ident.span(),
+                "Only trailing fields may be optional, so this field must be annotated with #[byteview(optional)]"
+                    .to_owned(),
+            ));
+        }
+        if let Some(second_boundary) = fields
+            .iter()
+            .filter(|field| field.options.prefix_boundary)
+            .nth(1)
+        {
+            return Err(Error::new(
+                second_boundary.ident.span(),
+                "Only one field may be annotated with prefix_boundary".to_owned(),
+            ));
+        }
+        if let Some(boundary) = fields
+            .iter()
+            .find(|field| field.options.prefix_boundary && field.options.optional)
+        {
+            return Err(Error::new(
+                boundary.ident.span(),
+                "The prefix cannot contain optional fields".to_owned(),
+            ));
+        }
+        if let Some(second_magic) = fields
+            .iter()
+            .filter(|field| field.options.magic.is_some())
+            .nth(1)
+        {
+            return Err(Error::new(
+                second_magic.ident.span(),
+                "Only one field may be annotated with magic".to_owned(),
+            ));
+        }
+        if let Some(record_len) = &options.record_len {
+            let field = fields.iter().find(|field| field.ident == *record_len);
+            let Some(field) = field else {
+                return Err(Error::new(
+                    record_len.span(),
+                    format!("record_len refers to `{record_len}`, which is not a field"),
+                ));
+            };
+            if field.byteview_type.get_unsigned_integer_type().is_none() {
+                return Err(Error::new(
+                    record_len.span(),
+                    "record_len requires an unsigned integer field of at most 64 bits".to_owned(),
+                ));
+            }
+            if fields.iter().any(|field| field.options.optional) {
+                return Err(Error::new(
+                    record_len.span(),
+                    "record_len cannot be combined with optional fields".to_owned(),
+                ));
+            }
+            if let Some(varint) = fields
+                .iter()
+                .find(|field| matches!(field.byteview_type, ByteViewType::Varint { .. }))
+            {
+                return Err(Error::new(
+                    varint.ident.span(),
+                    "record_len cannot be combined with varint fields".to_owned(),
+                ));
+            }
+        }
+        // The length field is found by its index in the layout, and the exported functions have no way to leave out a
+        // field.
+        if let Some(field) = fields.iter().find(|field| field.has_cfg())
+            && (options.record_len.is_some()
+                || options.export_c.is_some()
+                || fields.iter().any(|field| field.options.length.is_some()))
+        {
+            return Err(Error::new(
+                field.ident.span(),
+                "Fields with #[cfg] cannot be combined with record_len, export_c, or a length field"
+                    .to_owned(),
+            ));
+        }
+        if let Some(endianness @ (Endianness::Generic | Endianness::Runtime)) = options.endian {
+            let name = endianness.get_attribute_value();
+            // The exported functions and the prefix struct cannot know the byte order.
+            if let Some(export_c) = &options.export_c {
+                return Err(Error::new(
+                    export_c.span(),
+                    format!("export_c cannot be combined with endian = \"{name}\""),
+                ));
+            }
+            if let Some(boundary) = fields.iter().find(|field| field.options.prefix_boundary) {
+                return Err(Error::new(
+                    boundary.ident.span(),
+                    format!("prefix_boundary cannot be combined with endian = \"{name}\""),
+                ));
+            }
+        }
+        if let Some(param) = const_params.first() {
+            // The record has exactly `NUM_BYTES` bytes, which the exported functions and the prefix struct cannot know.
+            let other_option = if options.record_len.is_some() {
+                Some("record_len")
+            } else if options.export_c.is_some() {
+                Some("export_c")
+            } else if fields.iter().any(|field| field.options.optional) {
+                Some("optional fields")
+            } else if fields
+                .iter()
+                .any(|field| matches!(field.byteview_type, ByteViewType::Varint { .. }))
+            {
+                Some("varint fields")
+            } else if fields.iter().any(|field| field.options.prefix_boundary) {
+                Some("prefix_boundary")
+            } else {
+                None
+            };
+            if let Some(other_option) = other_option {
+                return Err(Error::new(
+                    param.span(),
+                    format!("Const generic parameters cannot be combined with {other_option}"),
+                ));
+            }
+        }
+        if options.endian == Some(Endianness::Runtime) {
+            // The length of a record is needed before there is a struct to store the byte order in.
+            if let Some(record_len) = &options.record_len {
+                return Err(Error::new(
+                    record_len.span(),
+                    "record_len cannot be combined with endian = \"runtime\"".to_owned(),
+                ));
+            }
+            if let Some(field) = fields.iter().find(|field| field.options.length.is_some()) {
+                return Err(Error::new(
+                    field.ident.span(),
+                    "Length fields cannot be combined with endian = \"runtime\"".to_owned(),
+                ));
+            }
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            const_params,
+            fields,
+            options,
+        })
+    }
+}
+
+impl ByteViewStruct {
+    // Whether the number of bytes in an instance of the struct is only known at runtime. Variable-sized structs store a
+    // slice (or Vec) rather than an array, but the fixed fields are still at the same offsets.
+    fn is_variable_size(&self) -> bool {
+        self.options.record_len.is_some()
+            || self.has_optional_fields()
+            || self.count_varints() > 0
+            || self.has_const_generics()
+    }
+
+    // Whether the struct has a `swap_to_native` function. It takes an array, so it is only created for fixed-size
+    // structs, and a struct declared with `#[byteview(endian = "runtime")]` has no byte order until it is constructed.
+    fn has_swap_to_native(&self) -> bool {
+        !self.is_variable_size() && self.options.endian != Some(Endianness::Runtime)
+    }
+
+    // Whether the struct has const generic parameters. Array lengths cannot depend on them, so the bytes are stored like
+    // those of a variable-sized struct, even though every record has exactly `NUM_BYTES` bytes.
+    fn has_const_generics(&self) -> bool {
+        !self.const_params.is_empty()
+    }
+
+    // The number of trailing varint fields.
+    fn count_varints(&self) -> usize {
+        self.fields
+            .iter()
+            .filter(|field| matches!(field.byteview_type, ByteViewType::Varint { .. }))
+            .count()
+    }
+
+    // Whether any trailing fields are annotated with `#[byteview(optional)]`.
+    fn has_optional_fields(&self) -> bool {
+        self.fields.iter().any(|field| field.options.optional)
+    }
+
+    // The field annotated with `#[byteview(magic = b"...")]`, if there is one.
+    fn get_magic_field(&self) -> Option<&ByteViewField> {
+        self.fields
+            .iter()
+            .find(|field| field.options.magic.is_some())
+    }
+
+    // Whether the struct is declared with `#[byteview(endian = "generic")]`, which gives it an `E: byteview::Endian` type
+    // parameter.
+    fn has_generic_endianness(&self) -> bool {
+        self.options.endian == Some(Endianness::Generic)
+    }
+
+    // The path used to refer to the struct inside its own impl blocks. The type parameter of a struct with a generic byte
+    // order and const generic parameters cannot be inferred from its name alone, so `Self` is used instead.
+    fn create_self_path(&self) -> Type {
+        let ident = &self.ident;
+        if self.has_generic_endianness() || self.has_const_generics() {
+            parse_quote! { Self }
+        } else {
+            parse_quote! { #ident }
+        }
+    }
+
+    // Create the number of bytes for use as an array length. Array lengths cannot depend on a type parameter, so a struct
+    // with a generic byte order is given a concrete one, which does not change its size.
+    fn create_num_bytes_len_expr(&self, field_kind: ByteViewFieldKind) -> Expr {
+        let ident = &self.ident;
+        match (self.has_generic_endianness(), field_kind) {
+            (false, _) => parse_quote! { #ident::NUM_BYTES },
+            (true, ByteViewFieldKind::Borrowed) => {
+                parse_quote! { #ident::<'static, ::byteview::BigEndian>::NUM_BYTES }
+            }
+            (true, ByteViewFieldKind::Owned) => {
+                parse_quote! { #ident::<::byteview::BigEndian>::NUM_BYTES }
+            }
+        }
+    }
+
+    // Create the field holding the type parameter of a struct with a generic byte order, or the byte order of a struct
+    // declared with `#[byteview(endian = "runtime")]`. Other structs have no such field.
+    fn create_endian_field(&self) -> Option<proc_macro2::TokenStream> {
+        match self.options.endian {
+            Some(Endianness::Generic) => Some(quote! { endian: ::std::marker::PhantomData<E>, }),
+            Some(Endianness::Runtime) => Some(quote! { little_endian: ::std::primitive::bool, }),
+            _ => None,
+        }
+    }
+
+    // Create the initializer of the field created by `create_endian_field`, which is added to every struct expression.
+    // The constructors that do not take a byte order read big-endian numbers.
+    fn create_endian_field_init(&self) -> Option<proc_macro2::TokenStream> {
+        match self.options.endian {
+            Some(Endianness::Generic) => Some(quote! { endian: ::std::marker::PhantomData, }),
+            Some(Endianness::Runtime) => Some(quote! { little_endian: false, }),
+            _ => None,
+        }
+    }
+
+    // Return an error if the struct names the structs generated by `byteview!`, since only that macro generates both.
+    fn check_no_variant_names(&self) -> Option<Error> {
+        let name = self
+            .options
+            .ref_name
+            .as_ref()
+            .or(self.options.owned_name.as_ref())?;
+        Some(Error::new(
+            name.span(),
+            "ref_name and owned_name are only permitted in byteview!",
+        ))
+    }
+
+    // Split a struct declared with `byteview!` into the borrowed and owned structs it generates, e.g. `FooRef` and
+    // `FooOwned`. Only the borrowed struct exports C functions, since both would export functions with the same names.
+    fn split_into_variants(self) -> (Self, Self) {
+        let mut ref_struct = self.clone();
+        ref_struct.ident = self
+            .options
+            .ref_name
+            .clone()
+            .unwrap_or_else(|| format_ident!("{}Ref", self.ident));
+        let mut owned_struct = self;
+        owned_struct.ident = owned_struct
+            .options
+            .owned_name
+            .clone()
+            .unwrap_or_else(|| format_ident!("{}Owned", owned_struct.ident));
+        owned_struct.options.export_c = None;
+        (ref_struct, owned_struct)
+    }
+
+    // The name of the generated prefix struct, e.g. `FooPrefix`.
+    fn get_prefix_ident(&self) -> Ident {
+        format_ident!("{}Prefix", self.ident)
+    }
+
+    // If a field is annotated with `#[byteview(prefix_boundary)]`, create the struct containing only the fields up to and
+    // including that field. The prefix struct is generated from the same fields, so the offsets are the same. Length
+    // fields are only meaningful for the full struct, so they become plain fields in the prefix.
+    fn create_prefix_struct(&self) -> Option<ByteViewStruct> {
+        let boundary = self
+            .fields
+            .iter()
+            .position(|field| field.options.prefix_boundary)?;
+        let ident = &self.ident;
+        let prefix_ident = self.get_prefix_ident();
+        let doc = format!(
+            "The fields at the start of a [`{ident}`], up to and including the prefix boundary."
+        );
+        let attrs = std::iter::once(parse_quote! { #[doc = #doc] })
+            .chain(
+                self.attrs
+                    .iter()
+                    .filter(|attr| !attr.path().is_ident("doc"))
+                    .cloned(),
+            )
+            .collect();
+        let fields = self.fields[..=boundary]
+            .iter()
+            .cloned()
+            .map(|mut field| {
+                field.options = FieldOptions {
+                    offset_into_buffer: field.options.offset_into_buffer,
+                    getter: field.options.getter.take(),
+                    ..FieldOptions::default()
+                };
+                field
+            })
+            .collect();
+        Some(ByteViewStruct {
+            attrs,
+            vis: self.vis.clone(),
+            ident: prefix_ident,
+            const_params: Vec::new(),
+            fields,
+            options: StructOptions {
+                pad_with: self.options.pad_with.clone(),
+                ..StructOptions::default()
+            },
+        })
+    }
+}
+
+impl StructOptions {
+    // Remove any `#[byteview(...)]` attributes from the list of attributes and parse them into StructOptions. All other
+    // attributes are left in place so they can be passed through to the generated struct.
+    fn extract_from_attrs(attrs: &mut Vec<Attribute>) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let mut other_attrs = Vec::new();
+        for attr in attrs.drain(..) {
+            if !attr.path().is_ident("byteview") {
+                other_attrs.push(attr);
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("record_len") {
+                    let name = meta.value()?.parse::<LitStr>()?;
+                    options.record_len = Some(name.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("pad_with") {
+                    options.pad_with = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("export_c") {
+                    let prefix = meta.value()?.parse::<LitStr>()?;
+                    options.export_c = Some(prefix.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("endian") {
+                    options.endian = Some(Endianness::parse_attribute_value(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("ref_name") {
+                    let name = meta.value()?.parse::<LitStr>()?;
+                    options.ref_name = Some(name.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("owned_name") {
+                    let name = meta.value()?.parse::<LitStr>()?;
+                    options.owned_name = Some(name.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("zeroize_on_drop") {
+                    options.zeroize_on_drop = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported byteview struct attribute"))
+                }
+            })?;
+        }
+        *attrs = other_attrs;
+        Ok(options)
+    }
+}
+
+impl ByteViewField {
+    // Parse a field, which is not a Parse implementation because number types without a byte order (e.g. `u32`) use the
+    // byte order set on the struct, if any.
+    fn parse(input: parse::ParseStream, default_endian: Option<Endianness>) -> syn::Result<Self> {
+        reject_stray_comma(input)?;
+        // Report a missing type (e.g. `a: ,`) with the name of the field instead of every token that could start a
+        // type.
+        let fork = input.fork();
+        if fork.call(Attribute::parse_outer).is_ok()
+            && fork.parse::<Visibility>().is_ok()
+            && let Ok(ident) = fork.call(Ident::parse_any)
+            && fork.parse::<Token![:]>().is_ok()
+            && (fork.is_empty() || fork.peek(Token![,]))
+        {
+            return Err(Error::new(
+                ident.span(),
+                format!("Missing type for field `{ident}`"),
+            ));
+        }
+        let field = Field::parse_named(input)?;
+        Self::parse_after_type(input, field, default_endian)
+    }
+
+    // Parse a field of a tuple struct, which is given the name `ident`.
+    fn parse_unnamed(
+        input: parse::ParseStream,
+        default_endian: Option<Endianness>,
+        ident: Ident,
+    ) -> syn::Result<Self> {
+        reject_stray_comma(input)?;
+        let mut field = Field::parse_unnamed(input)?;
+        field.ident = Some(ident);
+        Self::parse_after_type(input, field, default_endian)
+    }
+
+    // Finish parsing a field whose type has been parsed, which may be followed by a list of flags or a conversion.
+    fn parse_after_type(
+        input: parse::ParseStream,
+        mut field: Field,
+        default_endian: Option<Endianness>,
+    ) -> syn::Result<Self> {
+        let flags = if is_flags_keyword(&field.ty) && !input.is_empty() && !input.peek(Token![,]) {
+            let flags_span = field.ty.span();
+            field.ty = input.parse()?;
+            Some((flags_span, parse_flags(input)?))
+        } else {
+            None
+        };
+        // A field with a value, e.g. `magic: [u8; 4] = *b"LOG1"`, is shorthand for `#[byteview(magic = b"LOG1")]`.
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            input.parse::<Option<Token![*]>>()?;
+            let magic = input.parse::<LitByteStr>()?;
+            field
+                .attrs
+                .push(parse_quote! { #[byteview(magic = #magic)] });
+        }
+        let mut byteview_field = Self::from_field(field, default_endian)?;
+        if let Some((flags_span, flags)) = flags {
+            byteview_field.set_flags(flags_span, flags)?;
+        }
+        if input.peek(Token![as]) {
+            let as_token = input.parse::<Token![as]>()?;
+            if byteview_field.conversion.is_some() {
+                return Err(Error::new(
+                    as_token.span(),
+                    "Fields with a shorthand type (e.g. bool, uuid, or q16_16be) are already converted"
+                        .to_owned(),
+                ));
+            }
+            let target = input.parse::<Type>()?;
+            byteview_field.conversion = Some(Conversion::new(&byteview_field, target)?);
+        }
+        Ok(byteview_field)
+    }
+}
+
+// Report an extra comma where a field should start, e.g. in `a: u8,, b: u8`, instead of the token that was expected.
+fn reject_stray_comma(input: parse::ParseStream) -> syn::Result<()> {
+    if input.peek(Token![,]) {
+        let comma = input.parse::<Token![,]>()?;
+        return Err(Error::new(comma.span(), "Unexpected `,`, expected a field"));
+    }
+    Ok(())
+}
+
+// Whether a field type is the `flags` in e.g. `flags u8 { COMPRESSED = 0 }`.
+fn is_flags_keyword(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("flags"))
+}
+
+// Parse the braced list of named bits that follows the type of a flags field, e.g. `{ COMPRESSED = 0, ENCRYPTED = 1 }`.
+fn parse_flags(input: parse::ParseStream) -> syn::Result<Vec<Flag>> {
+    let content;
+    braced!(content in input);
+    let mut flags = Vec::new();
+    while !content.is_empty() {
+        let ident = content.parse::<Ident>()?;
+        content.parse::<Token![=]>()?;
+        let bit = content.parse::<LitInt>()?.base10_parse()?;
+        flags.push(Flag { ident, bit });
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+    Ok(flags)
+}
+
+impl ByteViewField {
+    fn from_field(field: Field, default_endian: Option<Endianness>) -> syn::Result<Self> {
+        let field_span = field.span();
+        let Field {
+            mut attrs,
+            vis,
+            mutability,
+            ident,
+            colon_token: _,
+            ty,
+        } = field;
+        // Future proof
+        let FieldMutability::None = mutability else {
+            return Err(Error::new(
+                field_span,
+                "Field mutability restrictions are not supported".to_owned(),
+            ));
+        };
+        let ident = ident.expect("Tuple struct fields are named by position when parsed");
+        let ty_span = ty.span();
+        let options = FieldOptions::extract_from_attrs(&mut attrs)?;
+        // A byte order set on the field wins over the default set on the struct.
+        let with_endianness = match options.endian.or(default_endian) {
+            Some(endianness) => match with_endianness(&ty, endianness) {
+                Some(byteview_type) => Some(byteview_type),
+                None if options.endian.is_some() => {
+                    return Err(Error::new(
+                        ty_span,
+                        "endian requires a number type without a byte order, e.g. u32 or [u16; 4]"
+                            .to_owned(),
+                    ));
+                }
+                None => None,
+            },
+            None => None,
+        };
+        let shorthand = parse_shorthand_type(&ty);
+        let byteview_type = match (with_endianness, &shorthand) {
+            (Some(byteview_type), _) => byteview_type,
+            (None, Some((byteview_type, _))) => byteview_type.clone(),
+            (None, None) => ByteViewType::try_from(ty).map_err(|error| {
+                Error::new(
+                    error.span(),
+                    format!("Invalid type for field `{ident}`: {error}"),
+                )
+            })?,
+        };
+        if options.offset_into_buffer && byteview_type.get_unsigned_integer_type().is_none() {
+            return Err(Error::new(
+                field_span,
+                "offset_into_buffer requires an unsigned integer field of at most 64 bits"
+                    .to_owned(),
+            ));
+        }
+        if options.length.is_some() && byteview_type.get_unsigned_integer_type().is_none() {
+            return Err(Error::new(
+                field_span,
+                "Length fields require an unsigned integer field of at most 64 bits".to_owned(),
+            ));
+        }
+        if options.truncate_rest && options.length.is_none() {
+            return Err(Error::new(
+                field_span,
+                "truncate_rest requires len_of_rest or len_includes_header".to_owned(),
+            ));
+        }
+        if options.optional && (options.offset_into_buffer || options.length.is_some()) {
+            return Err(Error::new(
+                field_span,
+                "Optional fields cannot be offsets or lengths".to_owned(),
+            ));
+        }
+        if options.reserved && (options.offset_into_buffer || options.length.is_some()) {
+            return Err(Error::new(
+                field_span,
+                "Reserved fields cannot be offsets or lengths".to_owned(),
+            ));
+        }
+        if options.must_be_zero && options.magic.is_some() {
+            return Err(Error::new(
+                field_span,
+                "Magic fields cannot be annotated with must_be_zero".to_owned(),
+            ));
+        }
+        if options.bitmap.is_some() && !matches!(byteview_type, ByteViewType::Array(_)) {
+            return Err(Error::new(
+                field_span,
+                "bitmap requires a byte array field".to_owned(),
+            ));
+        }
+        match (options.tz_offset, &byteview_type) {
+            (None, _)
+            | (Some(TzOffsetUnit::QuarterHours), ByteViewType::SingleByte(Signedness::Signed))
+            | (Some(TzOffsetUnit::Minutes), ByteViewType::MultiByte(MultiByteType::I16, _)) => {}
+            (Some(TzOffsetUnit::QuarterHours), _) => {
+                return Err(Error::new(
+                    field_span,
+                    "tz_offset_quarter_hours requires an i8 field".to_owned(),
+                ));
+            }
+            (Some(TzOffsetUnit::Minutes), _) => {
+                return Err(Error::new(
+                    field_span,
+                    "tz_offset_minutes requires an i16 field, e.g. i16be".to_owned(),
+                ));
+            }
+        }
+        if options.duration.is_some() && byteview_type.get_unsigned_integer_type().is_none() {
+            return Err(Error::new(
+                field_span,
+                "duration requires an unsigned integer field of at most 64 bits".to_owned(),
+            ));
+        }
+        if options.duration.is_some() && options.optional {
+            return Err(Error::new(
+                field_span,
+                "Duration fields cannot be optional".to_owned(),
+            ));
+        }
+        if options.tz_offset.is_some() && options.optional {
+            return Err(Error::new(
+                field_span,
+                "Time zone offset fields cannot be optional".to_owned(),
+            ));
+        }
+        if options.counter && byteview_type.get_unsigned_integer_type().is_none() {
+            return Err(Error::new(
+                field_span,
+                "counter requires an unsigned integer field of at most 64 bits".to_owned(),
+            ));
+        }
+        if let Some(magic) = &options.magic {
+            let len = match &byteview_type {
+                ByteViewType::Array(len) => evaluate_usize_expr(len),
+                _ => None,
+            };
+            if len != Some(magic.value().len()) || len == Some(0) {
+                return Err(Error::new(
+                    magic.span(),
+                    "magic requires a byte array field with a literal length equal to the number of magic bytes"
+                        .to_owned(),
+                ));
+            }
+            if options.optional {
+                return Err(Error::new(
+                    field_span,
+                    "Magic fields cannot be optional".to_owned(),
+                ));
+            }
+        }
+        if options.counter && options.optional {
+            return Err(Error::new(
+                field_span,
+                "Counter fields cannot be optional".to_owned(),
+            ));
+        }
+        if options.bitmap.is_some() && options.optional {
+            return Err(Error::new(
+                field_span,
+                "Bitmap fields cannot be optional".to_owned(),
+            ));
+        }
+        if options.default.is_some()
+            && matches!(
+                byteview_type,
+                ByteViewType::Array2D { .. }
+                    | ByteViewType::NumberArray { .. }
+                    | ByteViewType::Packed { .. }
+                    | ByteViewType::Nested(_)
+                    | ByteViewType::NestedArray { .. }
+            )
+        {
+            return Err(Error::new(
+                field_span,
+                "Two-dimensional arrays, number arrays, packed fields, and nested structs cannot have default \
+                values"
+                    .to_owned(),
+            ));
+        }
+        if !options.subfields.is_empty() {
+            let Some(bits) = byteview_type.get_unsigned_integer_bits() else {
+                return Err(Error::new(
+                    field_span,
+                    "subfields requires an unsigned integer field of at most 64 bits".to_owned(),
+                ));
+            };
+            if let Some(subfield) = options
+                .subfields
+                .iter()
+                .find(|subfield| subfield.start >= subfield.end || subfield.end > bits)
+            {
+                return Err(Error::new(
+                    subfield.ident.span(),
+                    format!("The bits of a subfield must be a non-empty range within 0..{bits}"),
+                ));
+            }
+            if options.optional || ident == "_" {
+                return Err(Error::new(
+                    field_span,
+                    "Optional and padding fields cannot have subfields".to_owned(),
+                ));
+            }
+        }
+        if matches!(byteview_type, ByteViewType::Varint { .. })
+            && (options.optional || options.reserved || options.prefix_boundary)
+        {
+            return Err(Error::new(
+                field_span,
+                "Varint fields cannot be optional, reserved, or a prefix boundary".to_owned(),
+            ));
+        }
+        if options.optional && matches!(byteview_type, ByteViewType::NumberArray { .. }) {
+            return Err(Error::new(
+                field_span,
+                "Number arrays cannot be optional".to_owned(),
+            ));
+        }
+        if options.iter_only && !matches!(byteview_type, ByteViewType::NumberArray { .. }) {
+            return Err(Error::new(
+                field_span,
+                "iter_only requires an array of numbers other than u8".to_owned(),
+            ));
+        }
+        let has_cfg = attrs.iter().any(|attr| attr.path().is_ident("cfg"));
+        if has_cfg
+            && (options.optional
+                || options.length.is_some()
+                || options.magic.is_some()
+                || options.prefix_boundary
+                || matches!(byteview_type, ByteViewType::Varint { .. }))
+        {
+            return Err(Error::new(
+                field_span,
+                "Optional, varint, length, magic, and prefix boundary fields cannot have #[cfg]"
+                    .to_owned(),
+            ));
+        }
+        if options.getter.is_some() && ident == "_" {
+            return Err(Error::new(
+                field_span,
+                "Padding fields cannot have a getter".to_owned(),
+            ));
+        }
+        if options.default.is_some() && !options.optional {
+            return Err(Error::new(
+                field_span,
+                "default requires optional".to_owned(),
+            ));
+        }
+        let mut byteview_field = Self {
+            attrs,
+            vis,
+            ident,
+            byteview_type,
+            options,
+            conversion: None,
+        };
+        if let Some((_, conversion)) = shorthand {
+            Conversion::check_options(&byteview_field, ty_span)?;
+            byteview_field.conversion = Some(conversion);
+        }
+        Ok(byteview_field)
+    }
+}
+
+impl ByteViewField {
+    // The name of the "getter" of the field, which is also used in the names of the other methods created for it. This
+    // is the name of the field unless another is given with `#[byteview(getter = "...")]`.
+    fn get_getter_ident(&self) -> &Ident {
+        self.options
+            .getter
+            .as_ref()
+            .or(self.options.getter_only.as_ref())
+            .unwrap_or(&self.ident)
+    }
+
+    // The name that the constants and methods of the field other than its "getter" are derived from, e.g. `OFFSET_KIND`
+    // or `kind_raw`. This is the name of the "getter", except for the only field of a tuple struct.
+    fn get_name_ident(&self) -> &Ident {
+        self.options.getter.as_ref().unwrap_or(&self.ident)
+    }
+
+    // Whether the field has `#[cfg(...)]` attributes, so it is only present in some builds.
+    fn has_cfg(&self) -> bool {
+        self.attrs.iter().any(|attr| attr.path().is_ident("cfg"))
+    }
+
+    // The `#[cfg(...)]` attributes of the field, which are also applied to the methods, constants, and statements created
+    // for it.
+    fn get_cfg_attrs(&self) -> Vec<Attribute> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .cloned()
+            .collect()
+    }
+
+    // Create an expression for the number of bytes in the field, which is zero in builds where its `#[cfg(...)]`
+    // attributes are not satisfied.
+    fn create_cfg_size_expr(&self) -> Expr {
+        let size = self.byteview_type.get_size_expr();
+        let predicates = self
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .filter_map(|attr| match &attr.meta {
+                Meta::List(list) => Some(&list.tokens),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            return size;
+        }
+        parse_quote! {
+            (if ::std::cfg!(all(#(#predicates),*)) { #size } else { 0 })
+        }
+    }
+
+    // Add the named bits of a flags field, checking that they fit in the field.
+    fn set_flags(&mut self, span: proc_macro2::Span, flags: Vec<Flag>) -> syn::Result<()> {
+        let bits = match self.byteview_type.get_unsigned_integer_bits() {
+            Some(bits) if self.conversion.is_none() => bits,
+            _ => {
+                return Err(Error::new(
+                    span,
+                    "flags requires an unsigned integer type of at most 64 bits, e.g. flags u8 { ... }"
+                        .to_owned(),
+                ));
+            }
+        };
+        if self.options.optional || self.ident == "_" {
+            return Err(Error::new(
+                span,
+                "Optional and padding fields cannot have flags".to_owned(),
+            ));
+        }
+        if let Some(flag) = flags.iter().find(|flag| flag.bit >= bits) {
+            return Err(Error::new(
+                flag.ident.span(),
+                format!("The bit of a flag must be less than {bits}"),
+            ));
+        }
+        self.options.flags = flags;
+        Ok(())
+    }
+}
+
+// Give a number type written without a byte order (e.g. `u32`) the byte order `endianness`, including the elements of an
+// array (e.g. `[u16; 4]`). Return None if the type is not a number type without a byte order.
+fn with_endianness(ty: &Type, endianness: Endianness) -> Option<ByteViewType> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let multi_byte_type = type_path.path.get_ident()?.to_string().parse().ok()?;
+            Some(ByteViewType::MultiByte(multi_byte_type, endianness))
+        }
+        Type::Array(array) => {
+            let element = with_endianness(&array.elem, endianness)?;
+            Some(ByteViewType::NumberArray {
+                element: Box::new(element),
+                len: Box::new(array.len.clone()),
+            })
+        }
+        _ => None,
+    }
+}
+
+// Some types are shorthand for a conversion that cannot be written with `as`: `ascii<16>` is a `[u8; 16]` converted to
+// a `str`, `utf16be<32>` is a `[u16be; 32]` decoded as UTF-16 (likewise for `utf16le`), `bcd<4>` is a `[u8; 4]` decoded
+// as binary-coded decimal, and `q16_16be` is an `i32be` holding a fixed-point number with 16 fractional bits. Return the
+// type of the stored value and the conversion.
+fn parse_shorthand_type(ty: &Type) -> Option<(ByteViewType, Conversion)> {
+    if let Type::Path(type_path) = ty
+        && let Some(ident) = type_path.path.get_ident()
+        && let Some((byteview_type, fraction_bits)) = parse_fixed_point_type(&ident.to_string())
+    {
+        return Some((byteview_type, Conversion::FixedPoint { fraction_bits }));
+    }
+    if let Type::Path(type_path) = ty
+        && let [segment] = type_path.path.segments.iter().collect::<Vec<_>>()[..]
+        && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+    {
+        let [argument] = arguments.args.iter().collect::<Vec<_>>()[..] else {
+            return None;
+        };
+        let len = Box::new(get_length_argument(argument)?);
+        let name = segment.ident.to_string();
+        if name == "bcd" {
+            return Some((ByteViewType::Array(len), Conversion::Bcd));
+        }
+        if name == "ascii" {
+            let target = parse_quote! { ::std::primitive::str };
+            return Some((
+                ByteViewType::Array(len),
+                Conversion::Value {
+                    target: Box::new(target),
+                },
+            ));
+        }
+        let endianness = match name.strip_prefix("utf16")? {
+            "be" => Endianness::Big,
+            "le" => Endianness::Little,
+            _ => return None,
+        };
+        let element = ByteViewType::MultiByte(MultiByteType::U16, endianness);
+        let byteview_type = ByteViewType::NumberArray {
+            element: Box::new(element),
+            len,
+        };
+        return Some((byteview_type, Conversion::Utf16));
+    }
+    let (byteview_type, target) = parse_value_shorthand_type(ty)?;
+    let conversion = Conversion::Value {
+        target: Box::new(target),
+    };
+    Some((byteview_type, conversion))
+}
+
+// Parse a signed fixed-point type in Q format, e.g. `q16_16be` (16 integer bits and 16 fractional bits, stored as an
+// `i32be`) or `q4_4` (stored as an `i8`). Return the type of the stored value and the number of fractional bits.
+fn parse_fixed_point_type(name: &str) -> Option<(ByteViewType, u32)> {
+    let (integer_bits, rest) = name.strip_prefix('q')?.split_once('_')?;
+    let (fraction_bits, endianness) = split_type_endianness_strings(rest);
+    let integer_bits = integer_bits.parse::<u32>().ok()?;
+    let fraction_bits = fraction_bits.parse::<u32>().ok()?;
+    let total_bits = integer_bits.checked_add(fraction_bits)?;
+    if fraction_bits == 0 || !matches!(total_bits, 8 | 16 | 32) {
+        return None;
+    }
+    let integer_type = syn::parse_str::<TypePath>(&format!("i{total_bits}{endianness}")).ok()?;
+    let byteview_type = ByteViewType::try_from(integer_type).ok()?;
+    Some((byteview_type, fraction_bits))
+}
+
+// Other shorthand types are converted with `byteview::FromFieldValue`: `bool` is `u8 as bool`, `charbe` is
+// `u32be as char` (likewise for `charle` and `charne`), `nonzero_u32be` is `u32be as NonZero<u32>` (likewise for other
+// integer types), `uuid` and `mac` are a `[u8; 16]` and a `[u8; 6]` converted to a `byteview::Uuid` and a
+// `byteview::MacAddr`, `ipv4` and `ipv6` are a `[u8; 4]` and a `[u8; 16]` converted to `Ipv4Addr` and `Ipv6Addr`, and
+// `timestamp32be` and `timestamp64be` are a `u32be` and an `i64be` converted to a `DateTime<Utc>` (or an
+// `OffsetDateTime` with the `time` feature), and `timestamp_ms64be` is a `u64be` converted to a `SystemTime` (likewise
+// for other byte orders). Return the type of the stored value and the target type.
+fn parse_value_shorthand_type(ty: &Type) -> Option<(ByteViewType, Type)> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let ident = type_path.path.get_ident()?;
+    let name = ident.to_string();
+    if let Some(integer_name) = name.strip_prefix("nonzero_") {
+        let integer_type = syn::parse_str::<TypePath>(integer_name).ok()?;
+        let byteview_type = ByteViewType::try_from(integer_type).ok()?;
+        let is_integer = match &byteview_type {
+            ByteViewType::SingleByte(_) => true,
+            ByteViewType::MultiByte(multi_byte_type, _) => {
+                !matches!(multi_byte_type, MultiByteType::F32 | MultiByteType::F64)
+            }
+            _ => false,
+        };
+        if !is_integer {
+            return None;
+        }
+        let value_type = byteview_type.get_type();
+        let target = parse_quote! { ::std::num::NonZero<#value_type> };
+        return Some((byteview_type, target));
+    }
+    if let Some(suffix) = name.strip_prefix("timestamp_ms64") {
+        let integer_type = syn::parse_str::<TypePath>(&format!("u64{suffix}")).ok()?;
+        let byteview_type = ByteViewType::try_from(integer_type).ok()?;
+        if !matches!(byteview_type, ByteViewType::MultiByte(_, _)) {
+            return None;
+        }
+        let target = parse_quote! { ::std::time::SystemTime };
+        return Some((byteview_type, target));
+    }
+    if let Some(rest) = name.strip_prefix("timestamp") {
+        // 32-bit timestamps are unsigned, as in many older formats, while 64-bit timestamps are signed like `time_t`.
+        let integer_name = if let Some(suffix) = rest.strip_prefix("32") {
+            format!("u32{suffix}")
+        } else {
+            format!("i64{}", rest.strip_prefix("64")?)
+        };
+        let integer_type = syn::parse_str::<TypePath>(&integer_name).ok()?;
+        let byteview_type = ByteViewType::try_from(integer_type).ok()?;
+        if !matches!(byteview_type, ByteViewType::MultiByte(_, _)) {
+            return None;
+        }
+        let target = if cfg!(feature = "time") {
+            parse_quote! { ::byteview::time::OffsetDateTime }
+        } else {
+            parse_quote! { ::byteview::chrono::DateTime<::byteview::chrono::Utc> }
+        };
+        return Some((byteview_type, target));
+    }
+    if name == "bool" {
+        let target = parse_quote! { ::std::primitive::bool };
+        return Some((ByteViewType::SingleByte(Signedness::Unsigned), target));
+    }
+    if name == "uuid" {
+        let target = parse_quote! { ::byteview::Uuid };
+        return Some((ByteViewType::Array(parse_quote! { 16 }), target));
+    }
+    if name == "mac" {
+        let target = parse_quote! { ::byteview::MacAddr };
+        return Some((ByteViewType::Array(parse_quote! { 6 }), target));
+    }
+    if name == "ipv4" {
+        let target = parse_quote! { ::std::net::Ipv4Addr };
+        return Some((ByteViewType::Array(parse_quote! { 4 }), target));
+    }
+    if name == "ipv6" {
+        let target = parse_quote! { ::std::net::Ipv6Addr };
+        return Some((ByteViewType::Array(parse_quote! { 16 }), target));
+    }
+    let endianness = match name.strip_prefix("char")? {
+        "be" => Endianness::Big,
+        "le" => Endianness::Little,
+        "ne" => Endianness::Native,
+        _ => return None,
+    };
+    let target = parse_quote! { ::std::primitive::char };
+    Some((
+        ByteViewType::MultiByte(MultiByteType::U32, endianness),
+        target,
+    ))
+}
+
+impl Conversion {
+    // Check that a field can be converted to `target` and create the conversion.
+    fn new(field: &ByteViewField, target: Type) -> syn::Result<Self> {
+        let span = target.span();
+        Self::check_options(field, span)?;
+        match (&field.byteview_type, &target) {
+            (ByteViewType::Array(_), Type::Array(array)) => Ok(Conversion::ByteArray {
+                element: array.elem.clone(),
+                target: Box::new(target),
+            }),
+            (
+                ByteViewType::SingleByte(_) | ByteViewType::MultiByte(_, _) | ByteViewType::Array(_),
+                _,
+            ) => {
+                Ok(Conversion::Value {
+                    target: Box::new(target),
+                })
+            }
+            _ => Err(Error::new(
+                span,
+                "Only numbers and byte arrays can be converted, e.g. `u8 as DataInfo`, `[u8; 8] as Signature`, or \
+                `[u8; 16] as [Mode; 16]`"
+                    .to_owned(),
+            )),
+        }
+    }
+
+    fn check_options(field: &ByteViewField, span: proc_macro2::Span) -> syn::Result<()> {
+        if field.options.optional
+            || field.options.offset_into_buffer
+            || field.options.length.is_some()
+            || field.options.bitmap.is_some()
+            || field.options.tz_offset.is_some()
+        {
+            return Err(Error::new(
+                span,
+                "Optional, offset, length, bitmap, and time zone offset fields cannot be converted"
+                    .to_owned(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl FieldOptions {
+    // Remove any `#[byteview(...)]` attributes from the list of attributes and parse them into FieldOptions. All other
+    // attributes are left in place so they can be passed through to the "getter" method.
+    fn extract_from_attrs(attrs: &mut Vec<Attribute>) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let mut other_attrs = Vec::new();
+        for attr in attrs.drain(..) {
+            if !attr.path().is_ident("byteview") {
+                other_attrs.push(attr);
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("offset_into_buffer") {
+                    options.offset_into_buffer = true;
+                    Ok(())
+                } else if meta.path.is_ident("len_of_rest") {
+                    options.length = Some(LengthKind::OfRest);
+                    Ok(())
+                } else if meta.path.is_ident("len_includes_header") {
+                    options.length = Some(LengthKind::IncludesHeader);
+                    Ok(())
+                } else if meta.path.is_ident("truncate_rest") {
+                    options.truncate_rest = true;
+                    Ok(())
+                } else if meta.path.is_ident("optional") {
+                    options.optional = true;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    options.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("prefix_boundary") {
+                    options.prefix_boundary = true;
+                    Ok(())
+                } else if meta.path.is_ident("reserved") {
+                    options.reserved = true;
+                    Ok(())
+                } else if meta.path.is_ident("must_be_zero") {
+                    options.reserved = true;
+                    options.must_be_zero = true;
+                    Ok(())
+                } else if meta.path.is_ident("subfields") {
+                    meta.parse_nested_meta(|subfield| {
+                        let ident = subfield.path.require_ident()?.clone();
+                        let expr = subfield.value()?.parse::<Expr>()?;
+                        let error =
+                            || Error::new(expr.span(), "Expected a range of bits, e.g. 4..8");
+                        let Expr::Range(range) = &expr else {
+                            return Err(error());
+                        };
+                        let bound = |bound: Option<&Expr>| {
+                            bound
+                                .and_then(evaluate_usize_expr)
+                                .and_then(|bound| u32::try_from(bound).ok())
+                                .ok_or_else(error)
+                        };
+                        let start = bound(range.start.as_deref())?;
+                        let mut end = bound(range.end.as_deref())?;
+                        if let RangeLimits::Closed(_) = range.limits {
+                            end += 1;
+                        }
+                        options.subfields.push(Subfield { ident, start, end });
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("tz_offset_quarter_hours") {
+                    options.tz_offset = Some(TzOffsetUnit::QuarterHours);
+                    Ok(())
+                } else if meta.path.is_ident("tz_offset_minutes") {
+                    options.tz_offset = Some(TzOffsetUnit::Minutes);
+                    Ok(())
+                } else if meta.path.is_ident("magic") {
+                    if options.magic.is_some() {
+                        return Err(meta.error("A field can only have one magic value"));
+                    }
+                    options.magic = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("counter") {
+                    options.counter = true;
+                    Ok(())
+                } else if meta.path.is_ident("duration") {
+                    meta.parse_nested_meta(|duration| {
+                        if !duration.path.is_ident("unit") {
+                            return Err(duration.error("Expected unit, e.g. duration(unit = \"10ms\")"));
+                        }
+                        let unit = duration.value()?.parse::<LitStr>()?;
+                        let nanos = parse_duration_unit(&unit.value()).ok_or_else(|| {
+                            Error::new(
+                                unit.span(),
+                                "Expected a unit of s, ms, us, or ns, optionally preceded by a multiplier, e.g. \"10ms\"",
+                            )
+                        })?;
+                        options.duration = Some(nanos);
+                        Ok(())
+                    })?;
+                    if options.duration.is_none() {
+                        return Err(meta.error("Expected duration(unit = \"...\")"));
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("endian") {
+                    let endianness = Endianness::parse_attribute_value(&meta)?;
+                    if let Endianness::Generic | Endianness::Runtime = endianness {
+                        return Err(meta.error(format!(
+                            "endian = \"{}\" is only permitted on the struct",
+                            endianness.get_attribute_value()
+                        )));
+                    }
+                    options.endian = Some(endianness);
+                    Ok(())
+                } else if meta.path.is_ident("getter") {
+                    let getter = meta.value()?.parse::<LitStr>()?;
+                    options.getter = Some(getter.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("iter_only") {
+                    options.iter_only = true;
+                    Ok(())
+                } else if meta.path.is_ident("bitmap") {
+                    options.bitmap.get_or_insert(BitOrder::Lsb0);
+                    Ok(())
+                } else if meta.path.is_ident("bit_order") {
+                    let bit_order = meta.value()?.parse::<LitStr>()?;
+                    options.bitmap = Some(match bit_order.value().as_str() {
+                        "lsb0" => BitOrder::Lsb0,
+                        "msb0" => BitOrder::Msb0,
+                        _ => {
+                            return Err(Error::new(
+                                bit_order.span(),
+                                "Expected \"lsb0\" or \"msb0\"",
+                            ));
+                        }
+                    });
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported byteview field attribute"))
+                }
+            })?;
+        }
+        *attrs = other_attrs;
+        Ok(options)
+    }
+}
+
+impl TryFrom<Type> for ByteViewType {
+    type Error = syn::Error;
+
+    fn try_from(ty: Type) -> Result<Self, Self::Error> {
+        match ty {
+            Type::Path(type_path) => Self::try_from(type_path),
+            Type::Array(type_array) => Self::try_from(type_array),
+            Type::Paren(type_paren) => {
+                let span = type_paren.span();
+                Self::try_from(*type_paren.elem).map_err(|e| Error::new(span, e.to_string()))
+            }
+            Type::Slice(type_slice) => Err(Error::new(
+                type_slice.span(),
+                "Length must be provided at compile time".to_owned(),
+            )),
+            value => Err(Error::new(
+                value.span(),
+                format!("Unsupported type. {SUPPORTED_TYPES_MESSAGE}"),
+            )),
+        }
+    }
+}
+
+impl TryFrom<TypeArray> for ByteViewType {
+    type Error = syn::Error;
+
+    fn try_from(array: TypeArray) -> Result<Self, Self::Error> {
+        let TypeArray {
+            bracket_token: _,
+            elem,
+            semi_token: _,
+            len,
+        } = array;
+        let span = elem.span();
+        let err = || {
+            Err(Error::new(
+                span,
+                "Must be an array of u8 or another numeric type".to_owned(),
+            ))
+        };
+        if let Type::Array(row) = *elem {
+            let is_u8 =
+                matches!(&*row.elem, Type::Path(type_path) if type_path.path.is_ident("u8"));
+            if !is_u8 {
+                return Err(Error::new(
+                    span,
+                    "Arrays of arrays must have u8 elements".to_owned(),
+                ));
+            }
+            return Ok(Self::Array2D {
+                row_len: Box::new(row.len),
+                len: Box::new(len),
+            });
+        }
+        let Type::Path(type_path) = *elem else {
+            return err();
+        };
+        if type_path.path.is_ident("u8") {
+            return Ok(Self::Array(Box::new(len)));
+        }
+        let element = Self::try_from(type_path)?;
+        if let Self::Nested(element) = element {
+            return Ok(Self::NestedArray {
+                element,
+                len: Box::new(len),
+            });
+        }
+        if !matches!(element, Self::SingleByte(_) | Self::MultiByte(_, _)) {
+            return err();
+        }
+        Ok(Self::NumberArray {
+            element: Box::new(element),
+            len: Box::new(len),
+        })
+    }
+}
+
+impl TryFrom<TypePath> for ByteViewType {
+    type Error = syn::Error;
+
+    fn try_from(type_path: TypePath) -> Result<Self, Self::Error> {
+        let span = type_path.span();
+        let error = || Error::new(span, format!("Unsupported type. {SUPPORTED_TYPES_MESSAGE}"));
+        if let Some(segment) = type_path.path.segments.last()
+            && segment.ident == "packed"
+        {
+            return Self::parse_packed(&segment.arguments, span);
+        }
+        // Like other types, byteview structs have names that start with an uppercase letter.
+        if let Some(segment) = type_path.path.segments.last()
+            && segment
+                .ident
+                .to_string()
+                .starts_with(|c: char| c.is_ascii_uppercase())
+        {
+            if STD_TYPE_NAMES.contains(&segment.ident.to_string().as_str()) {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "`{}` is not a byteview struct. {SUPPORTED_TYPES_MESSAGE}",
+                        segment.ident
+                    ),
+                ));
+            }
+            return Ok(Self::Nested(Box::new(Type::Path(type_path))));
+        }
+        let ident_str = type_path.path.require_ident()?.to_string();
+        match ident_str.as_str() {
+            "u8" => return Ok(Self::SingleByte(Signedness::Unsigned)),
+            "varint" => return Ok(Self::Varint { index: 0 }),
+            "u8be" | "u8le" | "u8ne" => {
+                return Err(Error::new(
+                    span,
+                    "u8 does not need endianness specifier".to_string(),
+                ));
+            }
+            "i8" => return Ok(Self::SingleByte(Signedness::Signed)),
+            "i8be" | "i8le" | "i8ne" => {
+                return Err(Error::new(
+                    span,
+                    "i8 does not need endianness specifier".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        let (type_string, endianness_string) = split_type_endianness_strings(&ident_str);
+
+        let multi_byte_type = type_string
+            .as_str()
+            .parse::<MultiByteType>()
+            .map_err(|_| error())?;
+
+        let endianness = match endianness_string.as_str() {
+            "be" => Endianness::Big,
+            "le" => Endianness::Little,
+            "ne" => Endianness::Native,
+            "me" if matches!(multi_byte_type, MultiByteType::U32 | MultiByteType::I32) => {
+                Endianness::Middle
+            }
+            "me" => {
+                return Err(Error::new(
+                    span,
+                    "Middle endian is only supported for u32me and i32me".to_string(),
+                ));
+            }
+            "" => {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "Endianness required, use {0}be, {0}le, or {0}ne, or set #[byteview(endian = \"...\")] on the \
+                        field or struct",
+                        type_string
+                    ),
+                ));
+            }
+            _ => {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "Unknown byte order `{endianness_string}` in `{ident_str}`, use {0}be, {0}le, or {0}ne",
+                        type_string
+                    ),
+                ));
+            }
+        };
+
+        Ok(Self::MultiByte(multi_byte_type, endianness))
+    }
+}
+
+impl ByteViewType {
+    // Parse the generic arguments of a packed array, e.g. `<u12be, 64>`. The element type is an unsigned integer of 1 to
+    // 64 bits followed by "be" (packed starting with the most significant bit) or "le" (packed starting with the least
+    // significant bit). The length may be any constant expression.
+    fn parse_packed(arguments: &PathArguments, span: proc_macro2::Span) -> syn::Result<Self> {
+        let error = || Error::new(span, "Expected packed<uNbe, LEN> or packed<uNle, LEN>");
+        let PathArguments::AngleBracketed(arguments) = arguments else {
+            return Err(error());
+        };
+        let mut arguments = arguments.args.iter();
+        let (Some(GenericArgument::Type(Type::Path(element))), Some(len), None) =
+            (arguments.next(), arguments.next(), arguments.next())
+        else {
+            return Err(error());
+        };
+        let len = get_length_argument(len).ok_or_else(error)?;
+        let element = element.path.require_ident()?.to_string();
+        let (type_string, endianness_string) = split_type_endianness_strings(&element);
+        let bits = type_string
+            .strip_prefix('u')
+            .and_then(|bits| bits.parse::<u32>().ok())
+            .filter(|bits| (1..=64).contains(bits))
+            .ok_or_else(|| {
+                Error::new(
+                    span,
+                    "Packed elements must be unsigned integers of 1 to 64 bits",
+                )
+            })?;
+        let order = match endianness_string.as_str() {
+            "be" => BitOrder::Msb0,
+            "le" => BitOrder::Lsb0,
+            _ => return Err(error()),
+        };
+        Ok(Self::Packed {
+            bits,
+            order,
+            len: Box::new(len),
+        })
+    }
+}
+
+// Split a type + endianness string. This is expected to be the name of a support multi-byte type name (see
+// MultiByteType) followed by an endianness string (see Endianness). This function naively splits the string into two
+// pieces: 1) the string before and including any digits and 2) the string after the first set of consecutive digits.
+fn split_type_endianness_strings(ident_str: &str) -> (String, String) {
+    // This is slightly unwieldly, but my ideas for how to make it more succinct all involve the regex crate or the
+    // itertools crate. I would like to avoid taking on such large dependencies just for this one thing.
+    let mut chars = ident_str.chars();
+    let mut type_chars = Vec::new();
+    let mut endianness_chars = Vec::new();
+    let mut seen_digit = false;
+    for c in chars.by_ref() {
+        if c.is_ascii_digit() {
+            seen_digit = true;
+            type_chars.push(c);
+        } else if seen_digit {
+            endianness_chars.push(c);
+            break;
+        } else {
+            type_chars.push(c);
+        }
+    }
+    endianness_chars.extend(chars);
+
+    (
+        type_chars.into_iter().collect(),
+        endianness_chars.into_iter().collect(),
+    )
+}
+
+impl Parse for BitsStruct {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let storage_path = input.parse::<TypePath>()?;
+        let storage_span = storage_path.span();
+        let storage = ByteViewType::try_from(storage_path)?;
+        let Some(storage_bits) = storage.get_unsigned_integer_bits() else {
+            return Err(Error::new(
+                storage_span,
+                "The bits must be stored in an unsigned integer of at most 64 bits, e.g. u8 or u16be".to_owned(),
+            ));
+        };
+        let content;
+        braced!(content in input);
+        let fields: Vec<BitsField> = content
+            .parse_terminated(BitsField::parse, Token![,])?
+            .into_iter()
+            .collect();
+        let total_bits: u32 = fields.iter().map(|field| field.bits).sum();
+        if total_bits != storage_bits {
+            return Err(Error::new(
+                storage_span,
+                format!(
+                    "The fields have a total of {total_bits} bits but the storage type has {storage_bits} bits (use \
+                    fields named `_` for unused bits)"
+                ),
+            ));
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            storage,
+            fields,
+        })
+    }
+}
+
+impl Parse for ByteViewEnum {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        input.parse::<Token![enum]>()?;
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let repr = input.parse::<Ident>()?;
+        if !["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"]
+            .contains(&repr.to_string().as_str())
+        {
+            return Err(Error::new(
+                repr.span(),
+                "The discriminant type must be a primitive integer type, e.g. u8 or u16".to_owned(),
+            ));
+        }
+        let content;
+        braced!(content in input);
+        let mut variants = Vec::new();
+        let mut unknown = None;
+        for variant in content.parse_terminated(Variant::parse, Token![,])? {
+            let span = variant.span();
+            match (variant.fields, variant.discriminant) {
+                (Fields::Unit, Some((_, discriminant))) => {
+                    variants.push((variant.attrs, variant.ident, discriminant));
+                }
+                (Fields::Unnamed(fields), None)
+                    if fields.unnamed.len() == 1
+                        && matches!(&fields.unnamed[0].ty, Type::Path(path) if path.path.is_ident(&repr)) =>
+                {
+                    if unknown.is_some() {
+                        return Err(Error::new(
+                            span,
+                            "Only one variant may hold unknown values".to_owned(),
+                        ));
+                    }
+                    unknown = Some((variant.attrs, variant.ident));
+                }
+                _ => {
+                    return Err(Error::new(
+                        span,
+                        format!(
+                            "Variants must have a discriminant (e.g. `Foo = 1`) or hold unknown values (e.g. \
+                            `Unknown({repr})`)"
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            repr,
+            variants,
+            unknown,
+        })
+    }
+}
+
+impl BitsField {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse::<Visibility>()?;
+        let ident = input.call(Ident::parse_any)?;
+        input.parse::<Token![:]>()?;
+        let bits_lit = input.parse::<LitInt>()?;
+        let bits = bits_lit.base10_parse::<u32>()?;
+        if bits == 0 {
+            return Err(Error::new(
+                bits_lit.span(),
+                "A field must have at least one bit".to_owned(),
+            ));
+        }
+        let conversion = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            Some(input.parse::<Type>()?)
+        } else {
+            None
+        };
+        if ident == "_" && conversion.is_some() {
+            return Err(Error::new(
+                ident.span(),
+                "Unused bits cannot be converted".to_owned(),
+            ));
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            bits,
+            conversion,
+        })
+    }
+}
+
+impl Parse for AssertSize {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let size = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, size })
+    }
+}
+
+impl Parse for AssertOffset {
+    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let field = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let offset = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { ty, field, offset })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////
+// Constructing Tokens
+//////////////////////////////////////////////////////////////////////
+
+// Whether the inner bytes are borrowed or owned.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum ByteViewFieldKind {
+    Borrowed,
+    Owned,
+}
+
+// The ByteViewStruct + FieldKind. This custom struct makes it easy to implement the ToTokens trait, which in turn
+// makes it easy to implement the proc_macro(s).
+#[derive(Debug)]
+struct ByteViewStructTagged {
+    inner: ByteViewStruct,
+    field_kind: ByteViewFieldKind,
+}
+
+impl ToTokens for ByteViewStructTagged {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { inner, field_kind } = self;
+        let ByteViewStruct {
+            attrs,
+            vis,
+            ident,
+            const_params: _,
+            fields,
+            options: _,
+        } = inner;
+
+        let (offset_exprs, total_size_expr) = create_offset_exprs_and_total_size_expr(fields);
+        let getters = create_getters(fields, &offset_exprs);
+        let default_consts = fields
+            .iter()
+            .filter_map(ByteViewField::create_default_const);
+        let subfield_consts = fields
+            .iter()
+            .flat_map(ByteViewField::create_subfield_consts);
+        let flag_consts = fields.iter().flat_map(ByteViewField::create_flag_consts);
+        let offset_consts = fields
+            .iter()
+            .zip(&offset_exprs)
+            .flat_map(|(field, offset_expr)| field.create_offset_consts(offset_expr));
+        let magic_items = create_magic_items(inner, &offset_exprs);
+        let validate_method = create_validate_method(inner, &offset_exprs);
+        let pad_byte_const = inner.options.pad_with.as_ref().map(|pad_with| {
+            let doc =
+                format!("The byte written to padding fields when a [`{ident}`] is normalized.");
+            quote! {
+                #[doc = #doc]
+                pub const PAD_BYTE: ::std::primitive::u8 = #pad_with;
+            }
+        });
+        let export_c_fns = create_export_c_fns(inner, &offset_exprs);
+        let field_infos = create_field_infos(fields, &offset_exprs);
+        let layout_doc_lines = create_layout_doc_lines(fields, &offset_exprs);
+        let bytes_type = field_kind.create_bytes_type(inner);
+        let constructors = field_kind.create_constructors(inner);
+        let swap_to_native = field_kind.create_swap_to_native(inner, &offset_exprs);
+        let byteview_impl = field_kind.create_byteview_impl(inner);
+        let zeroize_impls = field_kind.create_zeroize_impls(inner);
+        let prefix_method = field_kind.create_prefix_method(inner);
+        let normalize_methods = field_kind.create_normalize_methods(inner, &offset_exprs);
+        let mutators = field_kind.create_mutators(inner, &offset_exprs);
+        let (fields_mut_struct, split_fields_mut_method) =
+            field_kind.create_split_fields_mut(inner).unzip();
+        let prefix_struct =
+            inner
+                .create_prefix_struct()
+                .map(|prefix_struct| ByteViewStructTagged {
+                    inner: prefix_struct,
+                    field_kind: *field_kind,
+                });
+        let generics = field_kind.create_generics(inner);
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+        let endian_field = inner.create_endian_field();
+        let num_bytes_doc = format!("The total number of bytes contained in a [`{ident}`].");
+        let (min_num_bytes_expr, max_num_bytes_expr) =
+            create_num_bytes_bounds(inner, &offset_exprs);
+        let min_num_bytes_doc = format!("The smallest number of bytes in a valid [`{ident}`].");
+        let max_num_bytes_doc = format!(
+            "The largest number of bytes in a valid [`{ident}`], including any bytes counted by its length field."
+        );
+        let layout_doc = format!("The [`Layout`](::byteview::Layout) of a [`{ident}`].");
+        let fields_doc = format!(
+            "The [`FieldInfo`](::byteview::FieldInfo) of every field of a [`{ident}`], including padding fields named `_`."
+        );
+        let as_bytes_doc = format!("The bytes of this [`{ident}`].");
+        let name = ident.to_string();
+
+        let token_stream = quote! {
+            #(#attrs)*
+            #(#[doc = #layout_doc_lines])*
+            #vis struct #ident #generics {
+                bytes: #bytes_type,
+                #endian_field
+            }
+
+            impl #impl_generics #ident #ty_generics {
+                #[doc = #num_bytes_doc]
+                pub const NUM_BYTES: usize = #total_size_expr;
+
+                #[doc = #min_num_bytes_doc]
+                pub const MIN_NUM_BYTES: usize = #min_num_bytes_expr;
+
+                #[doc = #max_num_bytes_doc]
+                pub const MAX_NUM_BYTES: usize = #max_num_bytes_expr;
+
+                #[doc = #layout_doc]
+                pub const LAYOUT: ::byteview::Layout = ::byteview::Layout {
+                    name: #name,
+                    num_bytes: Self::NUM_BYTES,
+                    fields: &[#(#field_infos),*],
+                };
+
+                #[doc = #layout_doc]
+                pub const fn layout() -> ::byteview::Layout {
+                    Self::LAYOUT
+                }
+
+                #[doc = #fields_doc]
+                pub const FIELDS: &'static [::byteview::FieldInfo] = Self::LAYOUT.fields;
+
+                #[doc = #as_bytes_doc]
+                pub fn as_bytes(&self) -> &[::std::primitive::u8] {
+                    &self.bytes[..]
+                }
+
+                #pad_byte_const
+
+                #magic_items
+
+                #(#default_consts)*
+
+                #(#subfield_consts)*
+
+                #(#flag_consts)*
+
+                #(#offset_consts)*
+
+                #(#constructors)*
+
+                #swap_to_native
+
+                #prefix_method
+
+                #(#normalize_methods)*
+
+                #validate_method
+
+                #(#getters)*
+
+                #(#mutators)*
+
+                #split_fields_mut_method
+            }
+
+            #fields_mut_struct
+
+            #byteview_impl
+
+            #zeroize_impls
+
+            #(#export_c_fns)*
+
+            #prefix_struct
+        };
+
+        tokens.append_all(token_stream);
+    }
+}
+
+// Create an offset expression for each ByteViewField, which requires keeping a running tally of the cumulative length
+// so far. Return the offsets along with the final cumulative length, which is the total size required for the struct.
+fn create_offset_exprs_and_total_size_expr(fields: &[ByteViewField]) -> (Vec<Expr>, Expr) {
+    let mut cur_offset_expr: Expr = parse_quote! { 0 };
+    let mut offset_exprs = Vec::new();
+    for field in fields {
+        let size = field.create_cfg_size_expr();
+        let next_offset_expr = parse_quote! { #cur_offset_expr + #size };
+        offset_exprs.push(cur_offset_expr);
+        cur_offset_expr = next_offset_expr;
+    }
+    let total_size_expr = cur_offset_expr;
+    (offset_exprs, total_size_expr)
+}
+
+// Create a list of "getter" functions for each ByteViewField, along with any additional methods requested with
+// `#[byteview(...)]` attributes. The "getter" will extract the bytes at the corresponding offset.
+fn create_getters(fields: &[ByteViewField], offset_exprs: &[Expr]) -> Vec<ItemFn> {
+    fields
+        .iter()
+        .zip(offset_exprs)
+        .flat_map(|(field, offset_expr)| {
+            // The "getter" already has every attribute of the field.
+            let cfg_attrs = field.get_cfg_attrs();
+            field.create_getter(offset_expr).into_iter().chain(
+                field
+                    .create_option_methods(offset_expr)
+                    .into_iter()
+                    .map(move |mut method| {
+                        method.attrs.extend(cfg_attrs.clone());
+                        method
+                    }),
+            )
+        })
+        .collect()
+}
+
+// Create the lines of a Markdown table showing the byte range, name, type, and size of each field (including "padding"
+// fields named "_"). This is appended to the doc comment of the generated struct. Offsets are evaluated from the same
+// expressions used by the "getter" methods when possible. If an array length is not an integer literal (e.g. a named
+// constant) the offset expression is shown instead.
+fn create_layout_doc_lines(fields: &[ByteViewField], offset_exprs: &[Expr]) -> Vec<String> {
+    let mut lines = vec![
+        String::new(),
+        "# Layout".to_owned(),
+        String::new(),
+        "| Bytes | Field | Type | Size |".to_owned(),
+        "| ----- | ----- | ---- | ---- |".to_owned(),
+    ];
+    // The offsets of fields after a field with `#[cfg(...)]` depend on the build, so they are left out.
+    let mut after_cfg = false;
+    for (field, offset_expr) in fields.iter().zip(offset_exprs) {
+        let size_expr = field.byteview_type.get_size_expr();
+        let end_expr: Expr = parse_quote! { #offset_expr + #size_expr };
+        let range = if after_cfg {
+            String::new()
+        } else {
+            let range = format!(
+                "{}..{}",
+                display_usize_expr(offset_expr),
+                display_usize_expr(&end_expr)
+            );
+            match (
+                evaluate_usize_expr(offset_expr),
+                evaluate_usize_expr(&end_expr),
+            ) {
+                (Some(_), Some(_)) => range,
+                _ => format!("`{range}`"),
+            }
+        };
+        after_cfg |= field.has_cfg();
+        let name = if field.ident == "_" {
+            "_(padding)_".to_owned()
+        } else {
+            format!("`{}`", field.ident)
+        };
+        let name = if field.options.optional {
+            format!("{name} (optional)")
+        } else {
+            name
+        };
+        let name = if field.options.must_be_zero {
+            format!("{name} (must be zero)")
+        } else if field.options.reserved {
+            format!("{name} (reserved)")
+        } else {
+            name
+        };
+        let cfgs = field
+            .get_cfg_attrs()
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                Meta::List(list) => Some(format!("`{}`", list.tokens)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let name = if cfgs.is_empty() {
+            name
+        } else {
+            format!("{name} (if {})", cfgs.join(" and "))
+        };
+        let ty = field.byteview_type.get_type_string();
+        if let ByteViewType::Varint { .. } = field.byteview_type {
+            lines.push(format!("| | {name} | `{ty}` | 1 to 10 |"));
+            continue;
+        }
+        let size = match evaluate_usize_expr(&size_expr) {
+            Some(size) => size.to_string(),
+            None => format!("`{}`", display_usize_expr(&size_expr)),
+        };
+        lines.push(format!("| {range} | {name} | `{ty}` | {size} |"));
+    }
+    lines
+}
+
+// Display a constant expression in documentation, e.g. `22 + OTHER_LEN` for `0 + 4 + 1 + 1 + 16 + OTHER_LEN`. The
+// integer terms of a sum are added together and other terms, such as named constants, are kept as written.
+fn display_usize_expr(expr: &Expr) -> String {
+    if let Some(value) = evaluate_usize_expr(expr) {
+        return value.to_string();
+    }
+    let mut terms = Vec::new();
+    collect_sum_terms(expr, &mut terms);
+    let mut total = 0;
+    let mut named_terms = Vec::new();
+    for term in terms {
+        match evaluate_usize_expr(term) {
+            Some(value) => total += value,
+            None => named_terms.push(term.to_token_stream().to_string()),
+        }
+    }
+    if total != 0 {
+        named_terms.insert(0, total.to_string());
+    }
+    named_terms.join(" + ")
+}
+
+// Collect the terms of a sum, looking through parentheses, e.g. `a`, `b`, and `c` for `a + (b + c)`.
+fn collect_sum_terms<'a>(expr: &'a Expr, terms: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::Binary(binary) if matches!(binary.op, BinOp::Add(_)) => {
+            collect_sum_terms(&binary.left, terms);
+            collect_sum_terms(&binary.right, terms);
+        }
+        Expr::Paren(paren) => collect_sum_terms(&paren.expr, terms),
+        Expr::Group(group) => collect_sum_terms(&group.expr, terms),
+        _ => terms.push(expr),
+    }
+}
+
+// Evaluate a constant expression made up of integer literals, addition, multiplication, and `usize::div_ceil`. This is
+// used to display offsets and sizes in documentation. Return None for anything else, such as named constants.
+fn evaluate_usize_expr(expr: &Expr) -> Option<usize> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse().ok(),
+        Expr::Paren(paren) => evaluate_usize_expr(&paren.expr),
+        Expr::Group(group) => evaluate_usize_expr(&group.expr),
+        Expr::Call(call)
+            if matches!(&*call.func, Expr::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "div_ceil"))
+                && call.args.len() == 2 =>
+        {
+            let left = evaluate_usize_expr(&call.args[0])?;
+            let right = evaluate_usize_expr(&call.args[1])?;
+            left.checked_div(right)
+                .map(|quotient| quotient + usize::from(left % right != 0))
+        }
+        Expr::Binary(binary) => {
+            let left = evaluate_usize_expr(&binary.left)?;
+            let right = evaluate_usize_expr(&binary.right)?;
+            match binary.op {
+                BinOp::Add(_) => left.checked_add(right),
+                BinOp::Mul(_) => left.checked_mul(right),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Create a `byteview::FieldInfo` expression for each ByteViewField, including "padding" fields named "_".
+fn create_field_infos(fields: &[ByteViewField], offset_exprs: &[Expr]) -> Vec<Expr> {
+    // Varints have no fixed offset or size, so they are not part of the layout.
+    fields
+        .iter()
+        .zip(offset_exprs)
+        .filter(|(field, _)| !matches!(field.byteview_type, ByteViewType::Varint { .. }))
+        .map(|(field, offset_expr)| {
+            let name = field.ident.to_string();
+            let size = field.byteview_type.get_size_expr();
+            let ty = field.byteview_type.get_type_string();
+            let kind = field.byteview_type.create_field_kind_expr();
+            let cfg_attrs = field.get_cfg_attrs();
+            parse_quote! {
+                #(#cfg_attrs)*
+                ::byteview::FieldInfo {
+                    name: #name,
+                    offset: #offset_expr,
+                    size: #size,
+                    ty: #ty,
+                    kind: #kind,
+                }
+            }
+        })
+        .collect()
+}
+
+impl ByteViewField {
+    // Create a "getter" function for the field. The method should extract the appropriate number of bytes at the given
+    // offset from the inner `self.bytes` field. As a special case, if the field name is "_" then do not create a
+    // function, since "_" is not a valid identifier. Users can use the "_" pattern to create "padding" bytes that have
+    // no meaning but need to be there so later bytes are at the correct offsets.
+    fn create_getter(&self, offset_expr: &Expr) -> Option<ItemFn> {
+        let ByteViewField {
+            attrs,
+            vis,
+            ident: _,
+            byteview_type: ty,
+            options,
+            conversion,
+        } = self;
+        let ident = self.get_getter_ident();
+
+        if *ident == "_" {
+            // A single underscore is not an identifier (see
+            // https://doc.rust-lang.org/reference/identifiers.html#r-ident.profile). Allow users to use them as
+            // placeholders for "padding", but do not create getter methods.
+            return None;
+        }
+        if options.iter_only {
+            // Large arrays are only accessed through the methods created by `create_option_methods`.
+            return None;
+        }
+        let mut body: Expr = ty.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+        let mut return_type = ty.get_type();
+        match conversion {
+            Some(Conversion::ByteArray { target, .. }) => {
+                // Return the index and value of the first byte that cannot be converted.
+                body = parse_quote! { ::byteview::__private::convert_byte_array(#body) };
+                return_type = parse_quote! {
+                    ::std::result::Result<#target, (::std::primitive::usize, ::std::primitive::u8)>
+                };
+            }
+            Some(Conversion::Value { target }) => {
+                body = parse_quote! {
+                    <#target as ::byteview::FromFieldValue<#return_type>>::from_field_value(#body)
+                };
+                return_type =
+                    parse_quote! { <#target as ::byteview::FromFieldValue<#return_type>>::Output };
+            }
+            Some(Conversion::Utf16) => {
+                body = parse_quote! {{
+                    let units: #return_type = #body;
+                    ::byteview::__private::decode_utf16(&units)
+                }};
+                return_type = parse_quote! { ::std::option::Option<::std::string::String> };
+            }
+            Some(Conversion::Bcd) => {
+                body = parse_quote! { ::byteview::__private::decode_bcd(#body) };
+                return_type = parse_quote! {
+                    ::std::result::Result<::std::primitive::u64, ::byteview::BcdError>
+                };
+            }
+            Some(Conversion::FixedPoint { fraction_bits }) => {
+                // Dividing by a power of two is exact, and every value of at most 32 bits is exactly representable.
+                let scale = proc_macro2::Literal::f64_unsuffixed((1u64 << fraction_bits) as f64);
+                body = parse_quote! { ::std::primitive::f64::from(#body) / #scale };
+                return_type = parse_quote! { ::std::primitive::f64 };
+            }
+            None => {}
+        }
+        if let Some(unit) = options.tz_offset {
+            let seconds_per_unit = unit.get_seconds();
+            let seconds: Expr =
+                parse_quote! { ::std::primitive::i32::from(#body) * #seconds_per_unit };
+            if cfg!(feature = "time") {
+                body = parse_quote! { ::byteview::__private::time_utc_offset(#seconds) };
+                return_type = parse_quote! { ::std::option::Option<::byteview::time::UtcOffset> };
+            } else {
+                body = parse_quote! { ::byteview::__private::chrono_fixed_offset(#seconds) };
+                return_type =
+                    parse_quote! { ::std::option::Option<::byteview::chrono::FixedOffset> };
+            }
+        }
+        if options.optional {
+            // Optional trailing fields may be missing from shorter records. Return the default value if there is one.
+            let size = ty.get_size_expr();
+            body = match self.create_default_expr() {
+                Some(mut default) => {
+                    if let ByteViewType::Array(_) = ty {
+                        default = parse_quote! { &#default };
+                    }
+                    parse_quote! {
+                        if self.bytes.len() < #offset_expr + #size {
+                            #default
+                        } else {
+                            #body
+                        }
+                    }
+                }
+                None => {
+                    return_type = parse_quote! { ::std::option::Option<#return_type> };
+                    parse_quote! {
+                        if self.bytes.len() < #offset_expr + #size {
+                            ::std::option::Option::None
+                        } else {
+                            ::std::option::Option::Some(#body)
+                        }
+                    }
+                }
+            };
+        }
+        // Document where the field is stored after the field's own doc comments.
+        let has_doc = attrs.iter().any(|attr| attr.path().is_ident("doc"));
+        let separator = has_doc.then(|| quote! { #[doc = ""] });
+        let position_doc = self.create_position_doc(offset_expr);
+        Some(parse_quote! {
+            #(#attrs)*
+            #separator
+            #[doc = #position_doc]
+            #vis fn #ident(&self) -> #return_type {
+                #body
+            }
+        })
+    }
+
+    // Describe the bytes a field is stored in, e.g. "Bytes 4..8 (4 bytes).". The range is left out if the offset is not a
+    // constant, e.g. after a varint or a field with `#[cfg(...)]`.
+    fn create_position_doc(&self, offset_expr: &Expr) -> String {
+        let size_expr = self.byteview_type.get_size_expr();
+        let size = match (&self.byteview_type, evaluate_usize_expr(&size_expr)) {
+            (ByteViewType::Varint { .. }, _) => return "1 to 10 bytes.".to_owned(),
+            (_, Some(1)) => "1 byte".to_owned(),
+            (_, Some(size)) => format!("{size} bytes"),
+            (_, None) => format!("`{}` bytes", size_expr.to_token_stream()),
+        };
+        let end_expr: Expr = parse_quote! { #offset_expr + #size_expr };
+        match (
+            evaluate_usize_expr(offset_expr),
+            evaluate_usize_expr(&end_expr),
+        ) {
+            (Some(start), Some(end)) => format!("Bytes {start}..{end} ({size})."),
+            _ => format!("{size}."),
+        }
+    }
+}
+
+impl ByteViewField {
+    // The name of the associated constant holding the default value of an optional field, e.g. `TIMESTAMP_DEFAULT`.
+    fn get_default_const_ident(&self) -> Ident {
+        format_ident!("{}_DEFAULT", self.ident.to_string().to_uppercase())
+    }
+
+    // Create the associated constant holding the default value of an optional field, if it has one. Putting the default
+    // in a constant ensures it is const-evaluable and has the same type as the field. Padding fields named "_" use an
+    // inline constant instead (see `create_default_expr`).
+    fn create_default_const(&self) -> Option<ImplItemConst> {
+        let default = self.options.default.as_ref()?;
+        if self.ident == "_" {
+            return None;
+        }
+        let vis = &self.vis;
+        let ident = self.get_getter_ident();
+        let const_ident = self.get_default_const_ident();
+        let ty = self.byteview_type.get_value_type();
+        let doc = format!(
+            "The value of [`{ident}`](Self::{ident}) when it is missing from a shorter record."
+        );
+        let cfg_attrs = self.get_cfg_attrs();
+        Some(parse_quote! {
+            #[doc = #doc]
+            #(#cfg_attrs)*
+            #vis const #const_ident: #ty = #default;
+        })
+    }
+
+    // Create the associated constants holding the offset and size of a field in bytes, e.g. `OFFSET_KIND` and `SIZE_KIND`,
+    // so that individual bytes can be found without counting by hand. Padding fields named "_" and varints, which have
+    // no fixed size, have none.
+    fn create_offset_consts(&self, offset_expr: &Expr) -> Vec<ImplItemConst> {
+        if self.ident == "_" || matches!(self.byteview_type, ByteViewType::Varint { .. }) {
+            return Vec::new();
+        }
+        let vis = &self.vis;
+        let ident = self.get_name_ident();
+        let name = ident.to_string().to_uppercase();
+        let offset_ident = format_ident!("OFFSET_{name}");
+        let size_ident = format_ident!("SIZE_{name}");
+        let size = self.byteview_type.get_size_expr();
+        let offset_doc = format!("The offset in bytes of the `{ident}` field.");
+        let size_doc = format!("The number of bytes in the `{ident}` field.");
+        let cfg_attrs = self.get_cfg_attrs();
+        vec![
+            parse_quote! {
+                #[doc = #offset_doc]
+                #(#cfg_attrs)*
+                #vis const #offset_ident: ::std::primitive::usize = #offset_expr;
+            },
+            parse_quote! {
+                #[doc = #size_doc]
+                #(#cfg_attrs)*
+                #vis const #size_ident: ::std::primitive::usize = #size;
+            },
+        ]
+    }
+
+    // The names of the associated constants holding the mask and shift of a subfield, e.g. `KIND_MASK` and `KIND_SHIFT`.
+    fn get_subfield_const_idents(subfield: &Subfield) -> (Ident, Ident) {
+        let name = subfield.ident.to_string().to_uppercase();
+        (format_ident!("{name}_MASK"), format_ident!("{name}_SHIFT"))
+    }
+
+    // Create the associated constants holding the mask and shift of each subfield. The "getter" of each subfield uses
+    // these constants, so they cannot disagree.
+    fn create_subfield_consts(&self) -> Vec<ImplItemConst> {
+        let Some(ty) = self.byteview_type.get_unsigned_integer_type() else {
+            return Vec::new();
+        };
+        let vis = &self.vis;
+        let field_ident = self.get_getter_ident();
+        let cfg_attrs = self.get_cfg_attrs();
+        self.options
+            .subfields
+            .iter()
+            .flat_map(|subfield| {
+                let Subfield { ident, start, end } = subfield;
+                let (mask_ident, shift_ident) = Self::get_subfield_const_idents(subfield);
+                let mask = ((1u128 << (end - start)) - 1) << start;
+                let mask = proc_macro2::Literal::from_str(&format!("{mask:#X}"))
+                    .expect("a hexadecimal integer should be a valid literal");
+                let mask_doc = format!(
+                    "The mask of the bits of [`{field_ident}`](Self::{field_ident}) that hold [`{ident}`](Self::{ident})."
+                );
+                let shift_doc = format!(
+                    "The position of the lowest bit of [`{ident}`](Self::{ident}) within [`{field_ident}`](Self::{field_ident})."
+                );
+                [
+                    parse_quote! {
+                        #[doc = #mask_doc]
+                        #(#cfg_attrs)*
+                        #vis const #mask_ident: #ty = #mask;
+                    },
+                    parse_quote! {
+                        #[doc = #shift_doc]
+                        #(#cfg_attrs)*
+                        #vis const #shift_ident: ::std::primitive::u32 = #start;
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    // The names of the associated constant and methods of a flag, e.g. `COMPRESSED`, `is_compressed`, and
+    // `set_compressed`.
+    fn get_flag_idents(flag: &Flag) -> (Ident, Ident, Ident) {
+        let name = flag.ident.to_string();
+        let lower = name.to_lowercase();
+        (
+            format_ident!("{}", name.to_uppercase()),
+            format_ident!("is_{lower}"),
+            format_ident!("set_{lower}"),
+        )
+    }
+
+    // Create the associated constant holding the bit of each flag, so that flags can also be tested or combined with the
+    // raw value of the field.
+    fn create_flag_consts(&self) -> Vec<ImplItemConst> {
+        let Some(ty) = self.byteview_type.get_unsigned_integer_type() else {
+            return Vec::new();
+        };
+        let vis = &self.vis;
+        let field_ident = self.get_getter_ident();
+        let cfg_attrs = self.get_cfg_attrs();
+        self.options
+            .flags
+            .iter()
+            .map(|flag| {
+                let (const_ident, is_ident, _) = Self::get_flag_idents(flag);
+                let mask = proc_macro2::Literal::from_str(&format!("{:#X}", 1u128 << flag.bit))
+                    .expect("a hexadecimal integer should be a valid literal");
+                let doc = format!(
+                    "The bit of [`{field_ident}`](Self::{field_ident}) that is set if [`{is_ident}`](Self::{is_ident})."
+                );
+                parse_quote! {
+                    #[doc = #doc]
+                    #(#cfg_attrs)*
+                    #vis const #const_ident: #ty = #mask;
+                }
+            })
+            .collect()
+    }
+
+    // Create an expression for the default value of an optional field, if it has one.
+    fn create_default_expr(&self) -> Option<Expr> {
+        let default = self.options.default.as_ref()?;
+        if self.ident == "_" {
+            let ty = self.byteview_type.get_value_type();
+            Some(parse_quote! {{
+                const DEFAULT: #ty = #default;
+                DEFAULT
+            }})
+        } else {
+            let const_ident = self.get_default_const_ident();
+            Some(parse_quote! { Self::#const_ident })
+        }
+    }
+}
+
+impl ByteViewField {
+    // Create the additional methods requested with `#[byteview(...)]` attributes. These call the "getter" method rather
+    // than reading the bytes directly.
+    fn create_option_methods(&self, offset_expr: &Expr) -> Vec<ItemFn> {
+        let ByteViewField {
+            attrs: _,
+            vis,
+            ident: _,
+            byteview_type,
+            options,
+            conversion,
+        } = self;
+        let ident = self.get_getter_ident();
+        let name = self.get_name_ident();
+        let mut methods = Vec::new();
+        if let Some(ty) = byteview_type.get_unsigned_integer_type() {
+            for subfield in &options.subfields {
+                let Subfield {
+                    ident: subfield_ident,
+                    start,
+                    end,
+                } = subfield;
+                let (mask_ident, shift_ident) = Self::get_subfield_const_idents(subfield);
+                let doc = format!(
+                    "Bits {start}..{end} of [`{ident}`](Self::{ident}), shifted down to start at bit 0."
+                );
+                let value =
+                    byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+                methods.push(parse_quote! {
+                    #[doc = #doc]
+                    #vis fn #subfield_ident(&self) -> #ty {
+                        (#value & Self::#mask_ident) >> Self::#shift_ident
+                    }
+                });
+            }
+        }
+        for flag in &options.flags {
+            let (const_ident, is_ident, _) = Self::get_flag_idents(flag);
+            let bit = flag.bit;
+            let doc = format!("Whether bit {bit} of [`{ident}`](Self::{ident}) is set.");
+            let value = byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+            methods.push(parse_quote! {
+                #[doc = #doc]
+                #vis fn #is_ident(&self) -> ::std::primitive::bool {
+                    #value & Self::#const_ident != 0
+                }
+            });
+        }
+        if let ByteViewType::NumberArray { element, len } = byteview_type
+            && *ident != "_"
+        {
+            let element_type = element.get_type();
+            let element_size = element.get_size_expr();
+            let size = byteview_type.get_size_expr();
+            let iter_ident = format_ident!("{name}_iter");
+            let iter_doc = format!(
+                "An iterator over the elements of `{ident}`, which are decoded as they are needed."
+            );
+            let chunk_getter =
+                element.create_getter_expr(&parse_quote! { chunk }, &parse_quote! { 0 });
+            methods.push(parse_quote! {
+                #[doc = #iter_doc]
+                #vis fn #iter_ident(&self) -> impl ::std::iter::ExactSizeIterator<Item = #element_type> + '_ {
+                    self.bytes[#offset_expr..#offset_expr + #size]
+                        .chunks_exact(#element_size)
+                        .map(|chunk| #chunk_getter)
+                }
+            });
+            let at_ident = format_ident!("{name}_at");
+            let at_doc = format!("Element `i` of `{ident}`, or [`None`] if `i` is out of range.");
+            let element_getter = element.create_getter_expr(
+                &parse_quote! { self.bytes },
+                &parse_quote! { #offset_expr + i * (#element_size) },
+            );
+            methods.push(parse_quote! {
+                #[doc = #at_doc]
+                #vis fn #at_ident(&self, i: ::std::primitive::usize) -> ::std::option::Option<#element_type> {
+                    let len: ::std::primitive::usize = #len;
+                    (i < len).then(|| #element_getter)
+                }
+            });
+        }
+        if let Some(nanos) = options.duration
+            && *ident != "_"
+        {
+            let duration_ident = format_ident!("{name}_duration");
+            let duration_doc = format!(
+                "The value of `{ident}` as a `Duration`, where each unit is {nanos} nanoseconds. Values too large for a \
+                `Duration` saturate at `Duration::MAX`."
+            );
+            let value = byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+            methods.push(parse_quote! {
+                #[doc = #duration_doc]
+                #vis fn #duration_ident(&self) -> ::std::time::Duration {
+                    ::byteview::__private::duration_from_units(::std::primitive::u64::from(#value), #nanos)
+                }
+            });
+        }
+        if (conversion.is_some() || options.tz_offset.is_some()) && *ident != "_" {
+            let raw_ident = format_ident!("{name}_raw");
+            let raw_doc = format!(
+                "The value of [`{ident}`](Self::{ident}) as it is stored in the bytes, before it is converted."
+            );
+            let raw_type = byteview_type.get_type();
+            let raw_getter =
+                byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+            methods.push(parse_quote! {
+                #[doc = #raw_doc]
+                #vis fn #raw_ident(&self) -> #raw_type {
+                    #raw_getter
+                }
+            });
+        }
+        if let Some(Conversion::Utf16) = conversion
+            && *ident != "_"
+        {
+            let lossy_ident = format_ident!("{name}_lossy");
+            let lossy_doc = format!(
+                "The value of [`{ident}`](Self::{ident}), with invalid UTF-16 (e.g. unpaired surrogates) replaced by \
+                U+FFFD."
+            );
+            let units_type = byteview_type.get_type();
+            let units = byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+            methods.push(parse_quote! {
+                #[doc = #lossy_doc]
+                #vis fn #lossy_ident(&self) -> ::std::string::String {
+                    let units: #units_type = #units;
+                    ::byteview::__private::decode_utf16_lossy(&units)
+                }
+            });
+        }
+        if let Some(Conversion::ByteArray { element, .. }) = conversion
+            && *ident != "_"
+        {
+            let at_ident = format_ident!("{name}_at");
+            let at_doc = format!(
+                "Element `i` of [`{ident}`](Self::{ident}), or [`None`] if `i` is out of range. If the byte cannot be \
+                converted, return it as an error."
+            );
+            let bytes = byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+            methods.push(parse_quote! {
+                #[doc = #at_doc]
+                #vis fn #at_ident(
+                    &self,
+                    i: ::std::primitive::usize,
+                ) -> ::std::option::Option<::std::result::Result<#element, ::std::primitive::u8>> {
+                    let bytes = #bytes;
+                    bytes.get(i).map(|byte| {
+                        <#element as ::std::convert::TryFrom<::std::primitive::u8>>::try_from(*byte).map_err(|_| *byte)
+                    })
+                }
+            });
+        }
+        if options.optional && *ident != "_" {
+            let present_ident = format_ident!("{name}_present");
+            let present_doc =
+                format!("Whether [`{ident}`](Self::{ident}) is present in this record.");
+            let size = byteview_type.get_size_expr();
+            methods.push(parse_quote! {
+                #[doc = #present_doc]
+                #vis fn #present_ident(&self) -> ::std::primitive::bool {
+                    let end = #offset_expr + #size;
+                    self.bytes.len() >= end
+                }
+            });
+        }
+        if let Some(bit_order) = options.bitmap
+            && *ident != "_"
+        {
+            let bit_order = bit_order.get_variant_name();
+            let bitmap_ident = format_ident!("{name}_bitmap");
+            let bitmap_doc = format!(
+                "The bits of [`{ident}`](Self::{ident}) as a [`Bitmap`](::byteview::Bitmap)."
+            );
+            methods.push(parse_quote! {
+                #[doc = #bitmap_doc]
+                #vis fn #bitmap_ident(&self) -> ::byteview::Bitmap<'_> {
+                    ::byteview::Bitmap::new(self.#ident(), ::byteview::BitOrder::#bit_order)
+                }
+            });
+            let bit_ident = format_ident!("{name}_bit");
+            let bit_doc = format!(
+                "Whether bit `i` of [`{ident}`](Self::{ident}) is set, or [`None`] if `i` is out of range."
+            );
+            methods.push(parse_quote! {
+                #[doc = #bit_doc]
+                #vis fn #bit_ident(&self, i: ::std::primitive::usize) -> ::std::option::Option<::std::primitive::bool> {
+                    self.#bitmap_ident().bit(i)
+                }
+            });
+            let count_ones_ident = format_ident!("{name}_count_ones");
+            let count_ones_doc =
+                format!("The number of bits of [`{ident}`](Self::{ident}) that are set.");
+            methods.push(parse_quote! {
+                #[doc = #count_ones_doc]
+                #vis fn #count_ones_ident(&self) -> ::std::primitive::u32 {
+                    self.#bitmap_ident().count_ones()
+                }
+            });
+            let iter_ones_ident = format_ident!("{name}_iter_ones");
+            let iter_ones_doc = format!(
+                "An iterator over the indices of the bits of [`{ident}`](Self::{ident}) that are set."
+            );
+            methods.push(parse_quote! {
+                #[doc = #iter_ones_doc]
+                #vis fn #iter_ones_ident(&self) -> ::byteview::BitmapOnes<'_> {
+                    self.#bitmap_ident().iter_ones()
+                }
+            });
+        }
+        if options.offset_into_buffer {
+            let target_ident = format_ident!("{name}_target");
+            let target_doc = format!(
+                "The bytes of `buffer` starting at the offset stored in [`{ident}`](Self::{ident}).\n\
+                \n\
+                Return an error if the offset is past the end of `buffer`."
+            );
+            methods.push(parse_quote! {
+                #[doc = #target_doc]
+                #vis fn #target_ident<'b>(
+                    &self,
+                    buffer: &'b [::std::primitive::u8],
+                ) -> ::std::result::Result<&'b [::std::primitive::u8], ::byteview::OffsetError> {
+                    ::byteview::slice_from_offset(buffer, ::std::primitive::u64::from(self.#ident()))
+                }
+            });
+            let resolve_ident = format_ident!("{name}_resolve");
+            let resolve_doc = format!(
+                "Create a `T` from the bytes of `buffer` starting at the offset stored in [`{ident}`](Self::{ident}).\n\
+                \n\
+                Return an error if the offset is past the end of `buffer` or there are not enough bytes for a `T`."
+            );
+            methods.push(parse_quote! {
+                #[doc = #resolve_doc]
+                #vis fn #resolve_ident<'b, T: ::byteview::ByteView<'b>>(
+                    &self,
+                    buffer: &'b [::std::primitive::u8],
+                ) -> ::std::result::Result<T, ::byteview::OffsetError> {
+                    ::byteview::view_from_offset(buffer, ::std::primitive::u64::from(self.#ident()))
+                }
+            });
+        }
+        // The `_in` helpers only have the raw bytes of the record, so they are skipped for fields of a struct declared
+        // with `#[byteview(endian = "runtime")]`, whose byte order cannot be known from the bytes alone.
+        if options.counter
+            && *ident != "_"
+            && !matches!(
+                byteview_type,
+                ByteViewType::MultiByte(_, Endianness::Runtime)
+            )
+        {
+            let ty = byteview_type.get_type();
+            let size = byteview_type.get_size_expr();
+            for overflow in ["wrapping", "saturating"] {
+                let add_in_ident = format_ident!("add_{name}_{overflow}_in");
+                let add_in_doc = format!(
+                    "Add `n` to [`{ident}`](Self::{ident}) in the bytes of a record that starts at the beginning of \
+                    `bytes`, {overflow} on overflow, and return the new value. Return [`None`] without changing anything \
+                    if `bytes` is too short to hold the field."
+                );
+                let add = self.create_counter_add_expr(
+                    &parse_quote! { bytes },
+                    offset_expr,
+                    &format_ident!("{overflow}_add"),
+                );
+                methods.push(parse_quote! {
+                    #[doc = #add_in_doc]
+                    #vis fn #add_in_ident(
+                        bytes: &mut [::std::primitive::u8],
+                        n: #ty,
+                    ) -> ::std::option::Option<#ty> {
+                        if bytes.len() < #offset_expr + #size {
+                            return ::std::option::Option::None;
+                        }
+                        ::std::option::Option::Some(#add)
+                    }
+                });
+            }
+        }
+        methods
+    }
+
+    // Create an expression that adds `n` to a counter field in `bytes_expr` with the given method (e.g. `wrapping_add`),
+    // stores the result, and evaluates to it.
+    fn create_counter_add_expr(
+        &self,
+        bytes_expr: &Expr,
+        offset_expr: &Expr,
+        add_ident: &Ident,
+    ) -> Expr {
+        let ty = self.byteview_type.get_type();
+        let size = self.byteview_type.get_size_expr();
+        let getter = self
+            .byteview_type
+            .create_getter_expr(bytes_expr, offset_expr);
+        let to_bytes = self
+            .byteview_type
+            .create_to_bytes_expr(&parse_quote! { value });
+        parse_quote! {
+            {
+                let value: #ty = #getter;
+                let value = value.#add_ident(n);
+                #bytes_expr[#offset_expr..#offset_expr + #size].copy_from_slice(&#to_bytes);
+                value
+            }
+        }
+    }
+}
+
+impl ByteViewField {
+    // Create the methods that modify the field, which are only generated for owned structs.
+    fn create_mutators(&self, offset_expr: &Expr) -> Vec<ItemFn> {
+        let ByteViewField {
+            attrs: _,
+            vis,
+            ident: _,
+            byteview_type,
+            options,
+            conversion: _,
+        } = self;
+        let ident = self.get_getter_ident();
+        let name = self.get_name_ident();
+        let mut methods = Vec::new();
+        if let Some(bit_order) = options.bitmap
+            && *ident != "_"
+        {
+            let bit_order = bit_order.get_variant_name();
+            let size = byteview_type.get_size_expr();
+            let set_bit_ident = format_ident!("set_{name}_bit");
+            let set_bit_doc = format!(
+                "Set or clear bit `i` of [`{ident}`](Self::{ident}) and return its previous value, or return [`None`] \
+                without changing anything if `i` is out of range."
+            );
+            methods.push(parse_quote! {
+                #[doc = #set_bit_doc]
+                #vis fn #set_bit_ident(
+                    &mut self,
+                    i: ::std::primitive::usize,
+                    value: ::std::primitive::bool,
+                ) -> ::std::option::Option<::std::primitive::bool> {
+                    ::byteview::BitOrder::#bit_order.set_bit(&mut self.bytes[#offset_expr..#offset_expr + #size], i, value)
+                }
+            });
+        }
+        for flag in &options.flags {
+            let (const_ident, _, set_ident) = Self::get_flag_idents(flag);
+            let ty = byteview_type.get_type();
+            let size = byteview_type.get_size_expr();
+            let bit = flag.bit;
+            let doc = format!("Set or clear bit {bit} of [`{ident}`](Self::{ident}).");
+            let getter =
+                byteview_type.create_getter_expr(&parse_quote! { self.bytes }, offset_expr);
+            let to_bytes = byteview_type.create_to_bytes_expr(&parse_quote! { value });
+            methods.push(parse_quote! {
+                #[doc = #doc]
+                #vis fn #set_ident(&mut self, set: ::std::primitive::bool) {
+                    let value: #ty = #getter;
+                    let value = if set {
+                        value | Self::#const_ident
+                    } else {
+                        value & !Self::#const_ident
+                    };
+                    self.bytes[#offset_expr..#offset_expr + #size].copy_from_slice(&#to_bytes);
+                }
+            });
+        }
+        if options.counter && *ident != "_" {
+            let ty = byteview_type.get_type();
+            for overflow in ["wrapping", "saturating"] {
+                let add_ident = format_ident!("{overflow}_add");
+                let add = self.create_counter_add_expr(
+                    &parse_quote! { self.bytes },
+                    offset_expr,
+                    &add_ident,
+                );
+                let add_method_ident = format_ident!("add_{name}_{overflow}");
+                let add_doc = format!(
+                    "Add `n` to [`{ident}`](Self::{ident}), {overflow} on overflow, and return the new value."
+                );
+                let increment_ident = format_ident!("increment_{name}_{overflow}");
+                let increment_doc = format!(
+                    "Add one to [`{ident}`](Self::{ident}), {overflow} on overflow, and return the new value."
+                );
+                methods.push(parse_quote! {
+                    #[doc = #add_doc]
+                    #vis fn #add_method_ident(&mut self, n: #ty) -> #ty {
+                        #add
+                    }
+                });
+                methods.push(parse_quote! {
+                    #[doc = #increment_doc]
+                    #vis fn #increment_ident(&mut self) -> #ty {
+                        self.#add_method_ident(1)
+                    }
+                });
+            }
+        }
+        methods
+    }
+}
+
+impl BitOrder {
+    fn get_variant_name(self) -> Ident {
+        match self {
+            BitOrder::Lsb0 => parse_quote! { Lsb0 },
+            BitOrder::Msb0 => parse_quote! { Msb0 },
+        }
+    }
+}
+
+impl TzOffsetUnit {
+    // The number of seconds in one unit.
+    fn get_seconds(self) -> i32 {
+        match self {
+            TzOffsetUnit::QuarterHours => 15 * 60,
+            TzOffsetUnit::Minutes => 60,
+        }
+    }
+}
+
+impl ByteViewType {
+    // If this is an unsigned integer type of at most 64 bits, return the type. These are the types that can be
+    // losslessly converted to a u64 (and therefore used as offsets or lengths).
+    fn get_unsigned_integer_type(&self) -> Option<Type> {
+        match self {
+            ByteViewType::SingleByte(Signedness::Unsigned) => Some(parse_quote! { u8 }),
+            ByteViewType::MultiByte(
+                multi_byte_type @ (MultiByteType::U16 | MultiByteType::U32 | MultiByteType::U64),
+                _,
+            ) => Some(multi_byte_type.get_type()),
+            _ => None,
+        }
+    }
+
+    // If this is an unsigned integer type of at most 64 bits, return the number of bits in the type.
+    fn get_unsigned_integer_bits(&self) -> Option<u32> {
+        match self {
+            ByteViewType::SingleByte(Signedness::Unsigned) => Some(8),
+            ByteViewType::MultiByte(
+                multi_byte_type @ (MultiByteType::U16 | MultiByteType::U32 | MultiByteType::U64),
+                _,
+            ) => Some(multi_byte_type.get_size() as u32 * 8),
+            _ => None,
+        }
+    }
+
+    fn get_type(&self) -> Type {
+        match self {
+            ByteViewType::SingleByte(Signedness::Unsigned) => parse_quote! { u8 },
+            ByteViewType::SingleByte(Signedness::Signed) => parse_quote! { i8 },
+            ByteViewType::MultiByte(multi_byte_type, _) => multi_byte_type.get_type(),
+            ByteViewType::Array(expr) => parse_quote! { &[u8; #expr] },
+            ByteViewType::Array2D { row_len, len } => parse_quote! { &[[u8; #row_len]; #len] },
+            ByteViewType::NumberArray { element, len } => {
+                let element = element.get_type();
+                parse_quote! { [#element; #len] }
+            }
+            ByteViewType::Packed { bits, .. } => {
+                let element = get_packed_element_type(*bits);
+                parse_quote! { ::byteview::Packed<'_, #element> }
+            }
+            ByteViewType::Nested(ty) => (**ty).clone(),
+            ByteViewType::NestedArray { element, .. } => {
+                let lifetime = get_first_lifetime(element);
+                parse_quote! { ::byteview::Table<#lifetime, #element> }
+            }
+            ByteViewType::Varint { .. } => parse_quote! { u64 },
+        }
+    }
+
+    // The type of an owned value of this type. This is the same as `get_type` except that arrays are not references.
+    fn get_value_type(&self) -> Type {
+        match self {
+            ByteViewType::Array(expr) => parse_quote! { [u8; #expr] },
+            ByteViewType::Array2D { row_len, len } => parse_quote! { [[u8; #row_len]; #len] },
+            _ => self.get_type(),
+        }
+    }
+
+    // Create an expression that converts `value_expr` (of the type returned by `get_value_type`) to an array of bytes.
+    fn create_to_bytes_expr(&self, value_expr: &Expr) -> Expr {
+        match self {
+            ByteViewType::SingleByte(Signedness::Unsigned) => parse_quote! { [#value_expr] },
+            ByteViewType::SingleByte(Signedness::Signed) => parse_quote! { [#value_expr as u8] },
+            ByteViewType::MultiByte(multi_byte_type, endianness)
+                if multi_byte_type.is_truncated() =>
+            {
+                let size = multi_byte_type.get_size();
+                let to_bytes = endianness.create_to_bytes_expr(value_expr);
+                let little_endian = endianness.create_is_little_endian_expr();
+                parse_quote! { ::byteview::__private::truncate_bytes::<_, #size>(#to_bytes, #little_endian) }
+            }
+            ByteViewType::MultiByte(_, endianness) => endianness.create_to_bytes_expr(value_expr),
+            ByteViewType::Array(_) => value_expr.clone(),
+            ByteViewType::Array2D { .. }
+            | ByteViewType::NumberArray { .. }
+            | ByteViewType::Packed { .. }
+            | ByteViewType::Nested(_)
+            | ByteViewType::NestedArray { .. }
+            | ByteViewType::Varint { .. } => {
+                unreachable!(
+                    "two-dimensional arrays, number arrays, packed fields, nested structs, and varints cannot have \
+                    default values"
+                )
+            }
+        }
+    }
+
+    fn get_size_expr(&self) -> Expr {
+        match self {
+            Self::SingleByte(_) => parse_quote! { 1 },
+            Self::Array(expr) => (**expr).clone(),
+            Self::Array2D { row_len, len } => parse_quote! { (#len) * (#row_len) },
+            Self::MultiByte(multi_byte_type, _) => {
+                let size = proc_macro2::Literal::usize_unsuffixed(multi_byte_type.get_size());
+                parse_quote! { #size }
+            }
+            Self::NumberArray { element, len } => {
+                let element_size = element.get_size_expr();
+                parse_quote! { (#len) * (#element_size) }
+            }
+            Self::Packed { bits, len, .. } => {
+                let bits = proc_macro2::Literal::usize_unsuffixed(*bits as usize);
+                parse_quote! { ::std::primitive::usize::div_ceil((#len) * #bits, 8) }
+            }
+            Self::Nested(ty) => {
+                // The size may be needed outside the impl block where the lifetime of the struct is declared.
+                let ty = with_static_lifetimes(ty);
+                parse_quote! { ::byteview::__private::nested_num_bytes::<#ty>() }
+            }
+            Self::NestedArray { element, len } => {
+                let element = with_static_lifetimes(element);
+                parse_quote! { (#len) * ::byteview::__private::nested_num_bytes::<#element>() }
+            }
+            // The size of a varint is only known at runtime.
+            Self::Varint { .. } => parse_quote! { 0 },
+        }
+    }
+
+    // Create a `byteview::FieldKind` expression for this type.
+    fn create_field_kind_expr(&self) -> Expr {
+        match self {
+            Self::SingleByte(Signedness::Unsigned) => parse_quote! { ::byteview::FieldKind::U8 },
+            Self::SingleByte(Signedness::Signed) => parse_quote! { ::byteview::FieldKind::I8 },
+            // The byte order is not known until the struct is constructed.
+            Self::MultiByte(multi_byte_type, Endianness::Runtime) => {
+                let number_type = multi_byte_type.get_variant_name();
+                parse_quote! { ::byteview::FieldKind::RuntimeNumber(::byteview::NumberType::#number_type) }
+            }
+            Self::MultiByte(multi_byte_type, endianness) => {
+                let number_type = multi_byte_type.get_variant_name();
+                let endianness = endianness.create_runtime_expr();
+                parse_quote! {
+                    ::byteview::FieldKind::Number(::byteview::NumberType::#number_type, #endianness)
+                }
+            }
+            Self::Array(_)
+            | Self::Array2D { .. }
+            | Self::NumberArray { .. }
+            | Self::Packed { .. }
+            | Self::Nested(_)
+            | Self::NestedArray { .. } => {
+                parse_quote! { ::byteview::FieldKind::Bytes }
+            }
+            Self::Varint { .. } => unreachable!("varints are not part of the layout"),
+        }
+    }
+
+    // The type as the user would have written it in the struct declaration, e.g. "u32be" or "[u8; 16]".
+    fn get_type_string(&self) -> String {
+        match self {
+            Self::SingleByte(Signedness::Unsigned) => "u8".to_owned(),
+            Self::SingleByte(Signedness::Signed) => "i8".to_owned(),
+            Self::MultiByte(multi_byte_type, endianness) => {
+                format!("{}{}", multi_byte_type.get_name(), endianness.get_suffix())
+            }
+            Self::Array(expr) => format!("[u8; {}]", expr.to_token_stream()),
+            Self::Array2D { row_len, len } => format!(
+                "[[u8; {}]; {}]",
+                row_len.to_token_stream(),
+                len.to_token_stream()
+            ),
+            Self::NumberArray { element, len } => {
+                format!("[{}; {}]", element.get_type_string(), len.to_token_stream())
+            }
+            Self::Packed { bits, order, len } => {
+                let suffix = match order {
+                    BitOrder::Msb0 => "be",
+                    BitOrder::Lsb0 => "le",
+                };
+                format!("packed<u{bits}{suffix}, {}>", len.to_token_stream())
+            }
+            Self::Nested(ty) => ty.to_token_stream().to_string().replace(' ', ""),
+            Self::NestedArray { element, len } => format!(
+                "[{}; {}]",
+                element.to_token_stream().to_string().replace(' ', ""),
+                len.to_token_stream()
+            ),
+            Self::Varint { .. } => "varint".to_owned(),
+        }
+    }
+
+    // Create an expression that gets the appropriate bytes from `bytes_expr`, which is usually the inner `self.bytes`
+    // field.
+    fn create_getter_expr(&self, bytes_expr: &Expr, offset_expr: &Expr) -> Expr {
+        match self {
+            ByteViewType::SingleByte(Signedness::Unsigned) => {
+                parse_quote! { #bytes_expr[#offset_expr] }
+            }
+            ByteViewType::SingleByte(Signedness::Signed) => {
+                parse_quote! { #bytes_expr[#offset_expr] as i8 }
+            }
+            ByteViewType::MultiByte(multi_byte_type, endianness) => {
+                let type_name = multi_byte_type.get_type_name();
+                let size = multi_byte_type.get_size();
+                let start = offset_expr;
+                let end: Expr = parse_quote! { #start + #size };
+                let mut bytes: Expr = parse_quote! {
+                    ::std::result::Result::expect(::core::convert::TryInto::<[u8; #size]>::try_into(&#bytes_expr[#start..#end]), "invalid indices")
+                };
+                // 24-bit and 48-bit integers are extended to the size of their type in the same byte order before they
+                // are converted.
+                if multi_byte_type.is_truncated() {
+                    let little_endian = endianness.create_is_little_endian_expr();
+                    let signed = matches!(multi_byte_type, MultiByteType::I24 | MultiByteType::I48);
+                    bytes = parse_quote! { ::byteview::__private::extend_bytes(#bytes, #little_endian, #signed) };
+                }
+                endianness
+                    .create_from_bytes_expr(&parse_quote! { ::std::primitive::#type_name }, &bytes)
+            }
+            ByteViewType::Array(size) => {
+                let start = offset_expr;
+                let end: Expr = parse_quote! { #offset_expr + #size };
+                parse_quote! {
+                    ::std::result::Result::expect(::core::convert::TryInto::<&[u8; #size]>::try_into(&#bytes_expr[#start..#end]), "invalid indices")
+                }
+            }
+            ByteViewType::Array2D { row_len, len } => {
+                let size = self.get_size_expr();
+                let start = offset_expr;
+                let end: Expr = parse_quote! { #offset_expr + #size };
+                parse_quote! {
+                    ::std::result::Result::expect(
+                        ::core::convert::TryInto::<&[[u8; #row_len]; #len]>::try_into(
+                            <[u8]>::as_chunks::<{ #row_len }>(&#bytes_expr[#start..#end]).0,
+                        ),
+                        "invalid indices",
+                    )
+                }
+            }
+            ByteViewType::NumberArray { element, .. } => {
+                let element_size = element.get_size_expr();
+                let element_getter = element.create_getter_expr(
+                    bytes_expr,
+                    &parse_quote! { #offset_expr + i * (#element_size) },
+                );
+                parse_quote! { ::core::array::from_fn(|i| #element_getter) }
+            }
+            ByteViewType::Packed { bits, order, len } => {
+                let size = self.get_size_expr();
+                let start = offset_expr;
+                let end: Expr = parse_quote! { #offset_expr + #size };
+                let order = order.get_variant_name();
+                parse_quote! {
+                    ::byteview::Packed::new(&#bytes_expr[#start..#end], #bits, #len, ::byteview::BitOrder::#order)
+                }
+            }
+            ByteViewType::Nested(ty) => {
+                let size = self.get_size_expr();
+                let start = offset_expr;
+                let end: Expr = parse_quote! { #offset_expr + #size };
+                parse_quote! {
+                    ::std::option::Option::expect(<#ty as ::byteview::ByteView>::split_slice(&#bytes_expr[#start..#end]), "invalid indices").0
+                }
+            }
+            ByteViewType::NestedArray { element, .. } => {
+                let size = self.get_size_expr();
+                let start = offset_expr;
+                let end: Expr = parse_quote! { #offset_expr + #size };
+                parse_quote! {
+                    ::std::option::Option::expect(::byteview::Table::<#element>::new(&#bytes_expr[#start..#end]), "invalid indices")
+                }
+            }
+            ByteViewType::Varint { index } => {
+                // Every varint starts at the end of the fixed fields, so skip the earlier varints.
+                parse_quote! { ::byteview::__private::nth_varint(&#bytes_expr[#offset_expr..], #index) }
+            }
+        }
+    }
+}
+
+// The number of nanoseconds in a unit of time such as "s", "ms", "us", or "ns", optionally preceded by a multiplier, e.g.
+// "10ms". Return None if the unit is invalid, zero, or more than `u64::MAX` nanoseconds.
+fn parse_duration_unit(unit: &str) -> Option<u64> {
+    let suffix_start = unit
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unit.len());
+    let (multiplier, suffix) = unit.split_at(suffix_start);
+    let multiplier = match multiplier {
+        "" => 1,
+        multiplier => multiplier.parse::<u64>().ok()?,
+    };
+    let nanos_per_unit: u64 = match suffix {
+        "s" => 1_000_000_000,
+        "ms" => 1_000_000,
+        "us" => 1_000,
+        "ns" => 1,
+        _ => return None,
+    };
+    multiplier
+        .checked_mul(nanos_per_unit)
+        .filter(|nanos| *nanos != 0)
+}
+
+// The length given as a generic argument, e.g. the 64 in `packed<u12be, 64>` or the `NAME_LEN` in `ascii<NAME_LEN>`.
+fn get_length_argument(argument: &GenericArgument) -> Option<Expr> {
+    match argument {
+        GenericArgument::Const(expr) => Some(expr.clone()),
+        GenericArgument::Type(Type::Path(path)) => Some(parse_quote! { #path }),
+        _ => None,
+    }
+}
+
+// Replace every lifetime in the generic arguments of a type with 'static, e.g. `DeviceId<'a>` with
+// `DeviceId<'static>`.
+fn with_static_lifetimes(ty: &Type) -> Type {
+    let mut ty = ty.clone();
+    if let Type::Path(type_path) = &mut ty {
+        for segment in &mut type_path.path.segments {
+            if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                for argument in &mut arguments.args {
+                    if let GenericArgument::Lifetime(lifetime) = argument {
+                        *lifetime = parse_quote! { 'static };
+                    }
+                }
+            }
+        }
+    }
+    ty
+}
+
+// The first lifetime in the generic arguments of a type, e.g. the 'a in `DeviceId<'a>`, or '_ if there is none.
+fn get_first_lifetime(ty: &Type) -> Lifetime {
+    let Type::Path(type_path) = ty else {
+        return parse_quote! { '_ };
+    };
+    type_path
+        .path
+        .segments
+        .iter()
+        .filter_map(|segment| match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => Some(&arguments.args),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|argument| match argument {
+            GenericArgument::Lifetime(lifetime) => Some(lifetime.clone()),
+            _ => None,
+        })
+        .unwrap_or_else(|| parse_quote! { '_ })
+}
+
+// The smallest unsigned integer type that can hold an element of a packed array.
+fn get_packed_element_type(bits: u32) -> Type {
+    match bits {
+        ..=8 => parse_quote! { u8 },
+        9..=16 => parse_quote! { u16 },
+        17..=32 => parse_quote! { u32 },
+        _ => parse_quote! { u64 },
+    }
+}
+
+impl MultiByteType {
+    fn get_size(self) -> usize {
+        match self {
+            MultiByteType::U16 => 2,
+            MultiByteType::U24 => 3,
+            MultiByteType::U32 => 4,
+            MultiByteType::U48 => 6,
+            MultiByteType::U64 => 8,
+            MultiByteType::U128 => 16,
+            MultiByteType::I16 => 2,
+            MultiByteType::I24 => 3,
+            MultiByteType::I32 => 4,
+            MultiByteType::I48 => 6,
+            MultiByteType::I64 => 8,
+            MultiByteType::I128 => 16,
+            MultiByteType::F32 => 4,
+            MultiByteType::F64 => 8,
+        }
+    }
+
+    fn get_type(self) -> Type {
+        self.parse_type_name()
+    }
+
+    // The name of the corresponding `byteview::NumberType` variant.
+    fn get_variant_name(self) -> Ident {
+        match self {
+            MultiByteType::U16 => parse_quote! { U16 },
+            MultiByteType::U24 => parse_quote! { U24 },
+            MultiByteType::U32 => parse_quote! { U32 },
+            MultiByteType::U48 => parse_quote! { U48 },
+            MultiByteType::U64 => parse_quote! { U64 },
+            MultiByteType::U128 => parse_quote! { U128 },
+            MultiByteType::I16 => parse_quote! { I16 },
+            MultiByteType::I24 => parse_quote! { I24 },
+            MultiByteType::I32 => parse_quote! { I32 },
+            MultiByteType::I48 => parse_quote! { I48 },
+            MultiByteType::I64 => parse_quote! { I64 },
+            MultiByteType::I128 => parse_quote! { I128 },
+            MultiByteType::F32 => parse_quote! { F32 },
+            MultiByteType::F64 => parse_quote! { F64 },
+        }
+    }
+
+    fn get_type_name(self) -> Ident {
+        self.parse_type_name()
+    }
+
+    // The name used in field types, e.g. the "u24" in "u24be". This is the name of the type except for 24-bit and
+    // 48-bit integers.
+    fn get_name(self) -> String {
+        match self {
+            MultiByteType::U24 => "u24".to_owned(),
+            MultiByteType::U48 => "u48".to_owned(),
+            MultiByteType::I24 => "i24".to_owned(),
+            MultiByteType::I48 => "i48".to_owned(),
+            _ => self.get_type_name().to_string(),
+        }
+    }
+
+    // Whether this type is stored in fewer bytes than the type returned by its "getter".
+    fn is_truncated(self) -> bool {
+        matches!(
+            self,
+            MultiByteType::U24 | MultiByteType::U48 | MultiByteType::I24 | MultiByteType::I48
+        )
+    }
+
+    // This is a helper function that is generic over the output T. It can be used to parse any kind of syn item by
+    // using the type name. This could be, for example, a syn::Ident or a syn::Type.
+    fn parse_type_name<T>(self) -> T
+    where
+        T: Parse,
+    {
+        match self {
+            MultiByteType::U16 => parse_quote! { u16 },
+            MultiByteType::U24 | MultiByteType::U32 => parse_quote! { u32 },
+            MultiByteType::U48 | MultiByteType::U64 => parse_quote! { u64 },
+            MultiByteType::U128 => parse_quote! { u128 },
+            MultiByteType::I16 => parse_quote! { i16 },
+            MultiByteType::I24 | MultiByteType::I32 => parse_quote! { i32 },
+            MultiByteType::I48 | MultiByteType::I64 => parse_quote! { i64 },
+            MultiByteType::I128 => parse_quote! { i128 },
+            MultiByteType::F32 => parse_quote! { f32 },
+            MultiByteType::F64 => parse_quote! { f64 },
+        }
+    }
+}
+
+impl FromStr for MultiByteType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "u16" => MultiByteType::U16,
+            "u24" => MultiByteType::U24,
+            "u32" => MultiByteType::U32,
+            "u48" => MultiByteType::U48,
+            "u64" => MultiByteType::U64,
+            "u128" => MultiByteType::U128,
+            "i16" => MultiByteType::I16,
+            "i24" => MultiByteType::I24,
+            "i32" => MultiByteType::I32,
+            "i48" => MultiByteType::I48,
+            "i64" => MultiByteType::I64,
+            "i128" => MultiByteType::I128,
+            "f32" => MultiByteType::F32,
+            "f64" => MultiByteType::F64,
+            _ => return Err(s.to_owned()),
+        })
+    }
+}
+
+impl Endianness {
+    // Create the corresponding `byteview::Endianness` value.
+    fn create_runtime_expr(self) -> Expr {
+        match self {
+            Endianness::Big => parse_quote! { ::byteview::Endianness::Big },
+            Endianness::Little => parse_quote! { ::byteview::Endianness::Little },
+            Endianness::Native => parse_quote! { ::byteview::Endianness::Native },
+            Endianness::Middle => parse_quote! { ::byteview::Endianness::Middle },
+            Endianness::Generic => parse_quote! { <E as ::byteview::Endian>::ENDIANNESS },
+            Endianness::Runtime => unreachable!("the layout does not depend on the constructor"),
+        }
+    }
+
+    // Parse the value of an `endian = "..."` attribute on a struct or field.
+    fn parse_attribute_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        let endian = meta.value()?.parse::<LitStr>()?;
+        match endian.value().as_str() {
+            "big" => Ok(Endianness::Big),
+            "little" => Ok(Endianness::Little),
+            "native" => Ok(Endianness::Native),
+            "generic" => Ok(Endianness::Generic),
+            "runtime" => Ok(Endianness::Runtime),
+            _ => Err(Error::new(
+                endian.span(),
+                "Expected \"big\", \"little\", \"native\", \"generic\", or \"runtime\"",
+            )),
+        }
+    }
+
+    // The inverse of `parse_attribute_value`.
+    fn get_attribute_value(self) -> &'static str {
+        match self {
+            Endianness::Big => "big",
+            Endianness::Little => "little",
+            Endianness::Native => "native",
+            Endianness::Middle => unreachable!("middle endian cannot be set with an attribute"),
+            Endianness::Generic => "generic",
+            Endianness::Runtime => "runtime",
+        }
+    }
+
+    // The suffix used in field types with this endianness, e.g. the "be" in "u32be".
+    fn get_suffix(self) -> &'static str {
+        match self {
+            Endianness::Big => "be",
+            Endianness::Little => "le",
+            Endianness::Native => "ne",
+            Endianness::Middle => "me",
+            Endianness::Generic | Endianness::Runtime => "",
+        }
+    }
+
+    // Create an expression that converts the byte array `bytes_expr` to a number of type `ty` with this endianness. This
+    // takes advantage of the fact that all of the supported multi-byte types have the same name for the functions that
+    // convert byte arrays to their corresponding type. These are not trait methods, so there is no requirement for them
+    // to be the same, but since they _are_ the same, we can use the same function name for all of the supported types.
+    // There is no function for middle endian, so the halves are swapped into big-endian order first. A byte order that is
+    // not known until the struct is compiled or constructed is checked with an `if`, which is parenthesized so that the
+    // expression can start a statement. The expression is valid in a const context.
+    fn create_from_bytes_expr(self, ty: &Type, bytes_expr: &Expr) -> Expr {
+        match self {
+            Endianness::Big => parse_quote! { #ty::from_be_bytes(#bytes_expr) },
+            Endianness::Little => parse_quote! { #ty::from_le_bytes(#bytes_expr) },
+            Endianness::Native => parse_quote! { #ty::from_ne_bytes(#bytes_expr) },
+            Endianness::Middle => parse_quote! {
+                #ty::from_be_bytes(::byteview::__private::swap_middle_endian(#bytes_expr))
+            },
+            Endianness::Generic | Endianness::Runtime => {
+                let little_endian = self.create_is_little_endian_expr();
+                parse_quote! {
+                    (if #little_endian { #ty::from_le_bytes(#bytes_expr) } else { #ty::from_be_bytes(#bytes_expr) })
+                }
+            }
+        }
+    }
+
+    // Create an expression that is true if the least significant byte comes first.
+    fn create_is_little_endian_expr(self) -> Expr {
+        match self {
+            Endianness::Big => parse_quote! { false },
+            Endianness::Little => parse_quote! { true },
+            Endianness::Native => parse_quote! { ::std::cfg!(target_endian = "little") },
+            Endianness::Middle => unreachable!("only u32 and i32 can be middle endian"),
+            Endianness::Generic => parse_quote! {
+                ::std::matches!(<E as ::byteview::Endian>::ENDIANNESS, ::byteview::Endianness::Little)
+            },
+            Endianness::Runtime => parse_quote! { self.little_endian },
+        }
+    }
+
+    // The inverse of `create_from_bytes_expr`.
+    fn create_to_bytes_expr(self, value_expr: &Expr) -> Expr {
+        match self {
+            Endianness::Big => parse_quote! { #value_expr.to_be_bytes() },
+            Endianness::Little => parse_quote! { #value_expr.to_le_bytes() },
+            Endianness::Native => parse_quote! { #value_expr.to_ne_bytes() },
+            Endianness::Middle => parse_quote! {
+                ::byteview::__private::swap_middle_endian(#value_expr.to_be_bytes())
+            },
+            Endianness::Generic | Endianness::Runtime => {
+                let little_endian = self.create_is_little_endian_expr();
+                parse_quote! {
+                    (if #little_endian { #value_expr.to_le_bytes() } else { #value_expr.to_be_bytes() })
+                }
+            }
+        }
+    }
+}
+
+impl ByteViewFieldKind {
+    // Create the type of the inner `bytes` field. Variable-sized structs store a slice or Vec instead of an array.
+    fn create_bytes_type(self, inner: &ByteViewStruct) -> Type {
+        let num_bytes = inner.create_num_bytes_len_expr(self);
+        match (self, inner.is_variable_size()) {
+            (ByteViewFieldKind::Borrowed, false) => {
+                parse_quote! { &'a [::std::primitive::u8; #num_bytes] }
+            }
+            (ByteViewFieldKind::Borrowed, true) => parse_quote! { &'a [::std::primitive::u8] },
+            (ByteViewFieldKind::Owned, false) => {
+                parse_quote! { [::std::primitive::u8; #num_bytes] }
+            }
+            (ByteViewFieldKind::Owned, true) => {
+                parse_quote! { ::std::vec::Vec<::std::primitive::u8> }
+            }
+        }
+    }
+
+    // Create the various constructors for this kind of field. For FieldKind::Borrowed, the input will be a reference to
+    // a byte array/slice. For FieldKind::Owned, the input will be an owned byte array/Vec.
+    fn create_constructors(self, inner: &ByteViewStruct) -> Vec<ItemFn> {
+        let mut constructors = match (self, inner.is_variable_size()) {
+            (ByteViewFieldKind::Borrowed, false) => create_constructors_for_borrowed(inner),
+            (ByteViewFieldKind::Borrowed, true) => {
+                create_constructors_for_borrowed_variable_size(inner)
+            }
+            (ByteViewFieldKind::Owned, false) => create_constructors_for_owned(inner),
+            (ByteViewFieldKind::Owned, true) => create_constructors_for_owned_variable_size(inner),
+        };
+        if inner.has_const_generics() {
+            constructors.push(self.create_const_generic_from_array(inner));
+        }
+        if inner.options.endian == Some(Endianness::Runtime) {
+            constructors.extend(self.create_runtime_endian_constructors(inner));
+        }
+        constructors
+    }
+
+    // Create the `from_array` constructor of a struct with const generic parameters. The length of the array cannot be
+    // written in terms of the parameters, so it is a separate parameter that is checked when the function is compiled.
+    fn create_const_generic_from_array(self, inner: &ByteViewStruct) -> ItemFn {
+        let ident = &inner.ident;
+        let endian = inner.create_endian_field_init();
+        let doc = format!(
+            "Create a [`{ident}`] from {} array of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes. Using an array of any \
+            other length is a compile-time error.",
+            match self {
+                ByteViewFieldKind::Borrowed => "a reference to an",
+                ByteViewFieldKind::Owned => "an",
+            }
+        );
+        let check: Stmt = parse_quote! {
+            const {
+                ::std::assert!(__BYTES == Self::NUM_BYTES, "the array must have NUM_BYTES bytes");
+            };
+        };
+        match self {
+            ByteViewFieldKind::Borrowed => parse_quote! {
+                #[doc = #doc]
+                pub fn from_array<const __BYTES: ::std::primitive::usize>(bytes: &'a [::std::primitive::u8; __BYTES]) -> Self {
+                    #check
+                    Self { #endian bytes }
+                }
+            },
+            ByteViewFieldKind::Owned => parse_quote! {
+                #[doc = #doc]
+                pub fn from_array<const __BYTES: ::std::primitive::usize>(bytes: [::std::primitive::u8; __BYTES]) -> Self {
+                    #check
+                    Self { #endian bytes: bytes.to_vec() }
+                }
+            },
+        }
+    }
+
+    // Create the constructors of a struct declared with `#[byteview(endian = "runtime")]` that take the byte order of its
+    // number fields, e.g. `split_slice_with`, along with the `byte_order` method. Each constructor calls the one without
+    // the `_with`, which reads big-endian numbers, and then replaces the byte order.
+    //
+    // The byte order is set on the constructed struct rather than with struct update syntax, which cannot move the
+    // bytes out of an owned struct declared with `#[byteview(zeroize_on_drop)]`.
+    fn create_runtime_endian_constructors(self, inner: &ByteViewStruct) -> Vec<ItemFn> {
+        let ident = &inner.ident;
+        let num_bytes = inner.create_num_bytes_len_expr(self);
+        let doc = |name: &str| {
+            format!(
+                "Like [`{name}`][{ident}::{name}], but numbers without a byte order are read in the byte order \
+                `byte_order` instead of big-endian."
+            )
+        };
+        let little_endian: Stmt = parse_quote! {
+            let little_endian = byte_order == ::byteview::ByteOrder::Little;
+        };
+        let mut methods = Vec::new();
+        if !inner.is_variable_size() {
+            let from_array_doc = doc("from_array");
+            let bytes_type: Type = match self {
+                ByteViewFieldKind::Borrowed => {
+                    parse_quote! { &'a [::std::primitive::u8; #num_bytes] }
+                }
+                ByteViewFieldKind::Owned => parse_quote! { [::std::primitive::u8; #num_bytes] },
+            };
+            methods.push(parse_quote! {
+                #[doc = #from_array_doc]
+                pub fn from_array_with(bytes: #bytes_type, byte_order: ::byteview::ByteOrder) -> Self {
+                    #little_endian
+                    let mut view = Self::from_array(bytes);
+                    view.little_endian = little_endian;
+                    view
+                }
+            });
+        }
+        match self {
+            ByteViewFieldKind::Borrowed => {
+                let from_exact_slice_doc = doc("from_exact_slice");
+                let split_slice_doc = doc("split_slice");
+                let split_slice_checked_doc = doc("split_slice_checked");
+                methods.push(parse_quote! {
+                    #[doc = #from_exact_slice_doc]
+                    pub fn from_exact_slice_with(
+                        bytes: &'a [::std::primitive::u8],
+                        byte_order: ::byteview::ByteOrder,
+                    ) -> ::std::option::Option<Self> {
+                        #little_endian
+                        Self::from_exact_slice(bytes).map(|mut view| {
+                            view.little_endian = little_endian;
+                            view
+                        })
+                    }
+                });
+                methods.push(parse_quote! {
+                    #[doc = #split_slice_doc]
+                    pub fn split_slice_with(
+                        bytes: &'a [::std::primitive::u8],
+                        byte_order: ::byteview::ByteOrder,
+                    ) -> ::std::option::Option<(Self, &'a [::std::primitive::u8])> {
+                        #little_endian
+                        Self::split_slice(bytes).map(|(mut view, rest)| {
+                            view.little_endian = little_endian;
+                            (view, rest)
+                        })
+                    }
+                });
+                methods.push(parse_quote! {
+                    #[doc = #split_slice_checked_doc]
+                    pub fn split_slice_checked_with(
+                        bytes: &'a [::std::primitive::u8],
+                        byte_order: ::byteview::ByteOrder,
+                    ) -> ::std::result::Result<(Self, &'a [::std::primitive::u8]), ::byteview::ParseError> {
+                        #little_endian
+                        Self::split_slice_checked(bytes).map(|(mut view, rest)| {
+                            view.little_endian = little_endian;
+                            (view, rest)
+                        })
+                    }
+                });
+            }
+            ByteViewFieldKind::Owned => {
+                let from_exact_vec_doc = doc("from_exact_vec");
+                let split_vec_doc = doc("split_vec");
+                methods.push(parse_quote! {
+                    #[doc = #from_exact_vec_doc]
+                    pub fn from_exact_vec_with(bytes: Vec<u8>, byte_order: ::byteview::ByteOrder) -> Option<Self> {
+                        #little_endian
+                        Self::from_exact_vec(bytes).map(|mut view| {
+                            view.little_endian = little_endian;
+                            view
+                        })
+                    }
+                });
+                methods.push(parse_quote! {
+                    #[doc = #split_vec_doc]
+                    pub fn split_vec_with(
+                        bytes: Vec<u8>,
+                        byte_order: ::byteview::ByteOrder,
+                    ) -> Option<(Self, Vec<u8>)> {
+                        #little_endian
+                        Self::split_vec(bytes).map(|(mut view, rest)| {
+                            view.little_endian = little_endian;
+                            (view, rest)
+                        })
+                    }
+                });
+            }
+        }
+        let byte_order_doc = format!(
+            "The byte order of the numbers in this [`{ident}`] that were declared without one, which was chosen when it \
+            was constructed."
+        );
+        methods.push(parse_quote! {
+            #[doc = #byte_order_doc]
+            pub fn byte_order(&self) -> ::byteview::ByteOrder {
+                if self.little_endian {
+                    ::byteview::ByteOrder::Little
+                } else {
+                    ::byteview::ByteOrder::Big
+                }
+            }
+        });
+        methods
+    }
+
+    // Create the `swap_to_native` function, which reorders the bytes of each number field into the byte order of the
+    // target platform.
+    fn create_swap_to_native(
+        self,
+        inner: &ByteViewStruct,
+        offset_exprs: &[Expr],
+    ) -> Option<ItemFn> {
+        if !inner.has_swap_to_native() {
+            return None;
+        }
+        let ident = &inner.ident;
+        let num_bytes = inner.create_num_bytes_len_expr(self);
+        let swaps = inner
+            .fields
+            .iter()
+            .zip(offset_exprs)
+            .filter(|(field, _)| field.ident != "_")
+            .filter_map(|(field, offset_expr)| {
+                let byteview_type = &field.byteview_type;
+                let size = byteview_type.get_size_expr();
+                let range = quote! { #offset_expr..#offset_expr + #size };
+                let cfg_attrs = field.get_cfg_attrs();
+                match byteview_type {
+                    ByteViewType::MultiByte(_, endianness) => {
+                        let endianness = endianness.create_runtime_expr();
+                        Some(quote! {
+                            #(#cfg_attrs)*
+                            ::byteview::__private::swap_to_native(&mut bytes[#range], #endianness);
+                        })
+                    }
+                    ByteViewType::NumberArray { element, .. } => {
+                        let ByteViewType::MultiByte(_, endianness) = **element else {
+                            return None;
+                        };
+                        let endianness = endianness.create_runtime_expr();
+                        let element_size = element.get_size_expr();
+                        Some(quote! {
+                            #(#cfg_attrs)*
+                            for element in bytes[#range].chunks_exact_mut(#element_size) {
+                                ::byteview::__private::swap_to_native(element, #endianness);
+                            }
+                        })
+                    }
+                    // Nested structs reorder their own fields.
+                    ByteViewType::Nested(ty) => Some(quote! {
+                        #(#cfg_attrs)*
+                        <#ty as ::byteview::ByteView>::__swap_to_native(&mut bytes[#range]);
+                    }),
+                    ByteViewType::NestedArray { element, .. } => Some(quote! {
+                        #(#cfg_attrs)*
+                        for element in bytes[#range].chunks_exact_mut(::byteview::__private::nested_num_bytes::<#element>()) {
+                            <#element as ::byteview::ByteView>::__swap_to_native(element);
+                        }
+                    }),
+                    _ => None,
+                }
+            });
+        let doc = format!(
+            "Reorder the bytes of each number field in `bytes`, which hold a [`{ident}`], from its declared byte order \
+            into the byte order of the target platform, e.g. before handing them to code that reads them as a packed \
+            native struct.\n\
+            \n\
+            The fields of nested structs are reordered too, unless their byte order is chosen at runtime. Byte arrays, packed arrays, and padding are left unchanged. \
+            Calling this again restores the original bytes."
+        );
+        Some(parse_quote! {
+            #[doc = #doc]
+            pub fn swap_to_native(bytes: &mut [::std::primitive::u8; #num_bytes]) {
+                #(#swaps)*
+            }
+        })
+    }
+
+    // Implement `zeroize::Zeroize` for an owned struct if the `zeroize` feature is enabled, and `Drop` if it is declared
+    // with `#[byteview(zeroize_on_drop)]`. Both overwrite the bytes with zeros, including any spare capacity of a
+    // variable-sized struct's `Vec`, without changing its length. Dropping does not need the `zeroize` crate.
+    fn create_zeroize_impls(self, inner: &ByteViewStruct) -> proc_macro2::TokenStream {
+        if self == ByteViewFieldKind::Borrowed {
+            return proc_macro2::TokenStream::new();
+        }
+        let ident = &inner.ident;
+        let generics = self.create_generics(inner);
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let wipe: Stmt = if inner.is_variable_size() {
+            parse_quote! { ::byteview::__private::wipe_vec(&mut self.bytes); }
+        } else {
+            parse_quote! { ::byteview::__private::wipe(&mut self.bytes); }
+        };
+        let zeroize_impl = cfg!(feature = "zeroize").then(|| {
+            quote! {
+                impl #impl_generics ::byteview::zeroize::Zeroize for #ident #ty_generics #where_clause {
+                    fn zeroize(&mut self) {
+                        #wipe
+                    }
+                }
+            }
+        });
+        let drop_impl = inner.options.zeroize_on_drop.then(|| {
+            let zeroize_on_drop_impl = cfg!(feature = "zeroize").then(|| {
+                quote! {
+                    impl #impl_generics ::byteview::zeroize::ZeroizeOnDrop for #ident #ty_generics #where_clause {}
+                }
+            });
+            quote! {
+                impl #impl_generics ::std::ops::Drop for #ident #ty_generics #where_clause {
+                    fn drop(&mut self) {
+                        #wipe
+                    }
+                }
+
+                #zeroize_on_drop_impl
+            }
+        });
+        quote! {
+            #zeroize_impl
+            #drop_impl
+        }
+    }
+
+    // Implement the `byteview::ByteView` trait. Borrowed structs implement it for the lifetime of their bytes, while
+    // owned structs copy the bytes and implement it for any lifetime.
+    fn create_byteview_impl(self, inner: &ByteViewStruct) -> ItemImpl {
+        let ident = &inner.ident;
+        let path = inner.create_self_path();
+        let endian = inner.create_endian_field_init();
+        let owned_split_slice_body: Block = if inner.is_variable_size() {
+            parse_quote! {{
+                let record_len = #path::__record_len(bytes).ok()?;
+                let (record_bytes, rest_bytes) = bytes.split_at(record_len);
+                Some((#path { #endian bytes: record_bytes.to_vec() }, rest_bytes))
+            }}
+        } else {
+            parse_quote! {{
+                let (exact_bytes, rest_bytes) = bytes.split_at_checked(#path::NUM_BYTES)?;
+                Some((#path::from_array(::std::convert::TryInto::try_into(exact_bytes).ok()?), rest_bytes))
+            }}
+        };
+        let length_field = create_length_field_expr(inner);
+        let magic: Expr = if inner.get_magic_field().is_some() {
+            parse_quote! { ::std::option::Option::Some(#path::MAGIC) }
+        } else {
+            parse_quote! { ::std::option::Option::None }
+        };
+        // Let structs containing this one reorder its fields with the trait.
+        let swap_to_native = inner.has_swap_to_native().then(|| {
+            quote! {
+                fn __swap_to_native(bytes: &mut [::std::primitive::u8]) {
+                    #path::swap_to_native(::std::result::Result::expect(
+                        ::std::convert::TryInto::try_into(bytes),
+                        "invalid indices",
+                    ));
+                }
+            }
+        });
+        let write_defaults = create_write_defaults(inner);
+        let generics = self.create_generics(inner);
+        let (_, ty_generics, _) = generics.split_for_impl();
+        match self {
+            ByteViewFieldKind::Borrowed => parse_quote! {
+                impl #generics ::byteview::ByteView<'a> for #ident #ty_generics {
+                    const NUM_BYTES: usize = #path::NUM_BYTES;
+                    const MIN_NUM_BYTES: usize = #path::MIN_NUM_BYTES;
+                    const MAX_NUM_BYTES: usize = #path::MAX_NUM_BYTES;
+                    const LAYOUT: ::byteview::Layout = #path::LAYOUT;
+                    const LENGTH_FIELD: ::std::option::Option<::byteview::LengthField> = #length_field;
+                    const MAGIC: ::std::option::Option<::byteview::Magic> = #magic;
+
+                    fn as_bytes(&self) -> &[::std::primitive::u8] {
+                        #path::as_bytes(self)
+                    }
+
+                    fn split_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<(Self, &'a [::std::primitive::u8])> {
+                        #path::split_slice(bytes)
+                    }
+
+                    fn split_slice_checked(bytes: &'a [::std::primitive::u8]) -> ::std::result::Result<(Self, &'a [::std::primitive::u8]), ::byteview::ParseError> {
+                        #path::split_slice_checked(bytes)
+                    }
+
+                    #swap_to_native
+                    #write_defaults
+                }
+            },
+            ByteViewFieldKind::Owned => {
+                let split_slice_checked_body = create_split_slice_checked_body(
+                    inner,
+                    parse_quote! { <Self as ::byteview::ByteView<'a>>::split_slice(bytes) },
+                );
+                // The trait has a lifetime even though the struct does not.
+                let mut impl_generics = generics.clone();
+                impl_generics.params.insert(0, parse_quote! { 'a });
+                parse_quote! {
+                    impl #impl_generics ::byteview::ByteView<'a> for #ident #ty_generics {
+                        const NUM_BYTES: usize = #path::NUM_BYTES;
+                        const MIN_NUM_BYTES: usize = #path::MIN_NUM_BYTES;
+                        const MAX_NUM_BYTES: usize = #path::MAX_NUM_BYTES;
+                        const LAYOUT: ::byteview::Layout = #path::LAYOUT;
+                        const LENGTH_FIELD: ::std::option::Option<::byteview::LengthField> = #length_field;
+                        const MAGIC: ::std::option::Option<::byteview::Magic> = #magic;
+
+                        fn as_bytes(&self) -> &[::std::primitive::u8] {
+                            #path::as_bytes(self)
+                        }
+
+                        fn split_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<(Self, &'a [::std::primitive::u8])> {
+                            #owned_split_slice_body
+                        }
+
+                        fn split_slice_checked(bytes: &'a [::std::primitive::u8]) -> ::std::result::Result<(Self, &'a [::std::primitive::u8]), ::byteview::ParseError> {
+                            #split_slice_checked_body
+                        }
+
+                        #swap_to_native
+                        #write_defaults
+                    }
+                }
+            }
+        }
+    }
+
+    // Create the `prefix` method, which returns the prefix struct created from the first bytes of this struct.
+    fn create_prefix_method(self, inner: &ByteViewStruct) -> Option<ItemFn> {
+        inner.create_prefix_struct()?;
+        let ident = &inner.ident;
+        let prefix_ident = inner.get_prefix_ident();
+        let doc = format!("The [`{prefix_ident}`] at the start of this [`{ident}`].");
+        Some(match self {
+            ByteViewFieldKind::Borrowed => parse_quote! {
+                #[doc = #doc]
+                pub fn prefix(&self) -> #prefix_ident<'a> {
+                    let bytes: &'a [::std::primitive::u8] = self.bytes;
+                    #prefix_ident::from_array(::std::result::Result::expect(
+                        ::std::convert::TryInto::try_into(&bytes[..#prefix_ident::NUM_BYTES]),
+                        "invalid indices",
+                    ))
+                }
+            },
+            ByteViewFieldKind::Owned => parse_quote! {
+                #[doc = #doc]
+                pub fn prefix(&self) -> #prefix_ident {
+                    #prefix_ident::from_array(::std::result::Result::expect(
+                        ::std::convert::TryInto::try_into(&self.bytes[..#prefix_ident::NUM_BYTES]),
+                        "invalid indices",
+                    ))
+                }
+            },
+        })
+    }
+
+    // Create the methods that zero padding fields named "_" and fields annotated with `#[byteview(reserved)]`, so that
+    // records that differ only in those bytes have identical bytes. If the struct is annotated with
+    // `#[byteview(pad_with = ...)]`, padding fields are filled with that byte instead. Borrowed structs cannot modify
+    // their bytes, so they only return a normalized copy.
+    fn create_normalize_methods(
+        self,
+        inner: &ByteViewStruct,
+        offset_exprs: &[Expr],
+    ) -> Vec<ItemFn> {
+        let ident = &inner.ident;
+        // The copy keeps the byte order of a struct declared with `#[byteview(endian = "runtime")]`.
+        let copy_endian = inner.create_endian_field().map(|_| quote! { ..*self });
+        let fill_fields = inner
+            .fields
+            .iter()
+            .zip(offset_exprs)
+            .filter(|(field, _)| field.ident == "_" || field.options.reserved)
+            .map(|(field, offset_expr)| {
+                let size = field.byteview_type.get_size_expr();
+                let fill: Expr = if field.ident == "_" && inner.options.pad_with.is_some() {
+                    parse_quote! { Self::PAD_BYTE }
+                } else {
+                    parse_quote! { 0 }
+                };
+                let cfg_attrs = field.get_cfg_attrs();
+                // Optional fields may be missing, so only fill the bytes that are present.
+                quote! {
+                    #(#cfg_attrs)*
+                    if let ::std::option::Option::Some(field) = bytes.get_mut(#offset_expr..#offset_expr + #size) {
+                        field.fill(#fill);
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        // Avoid an unused variable warning if there is nothing to fill.
+        let bind_bytes = |bytes_expr: Expr| {
+            (!fill_fields.is_empty()).then(|| quote! { let bytes = &mut #bytes_expr[..]; })
+        };
+        let filled = if inner.options.pad_with.is_some() {
+            "padding fields set to [`PAD_BYTE`](Self::PAD_BYTE) and all reserved fields set to zero"
+        } else {
+            "padding and reserved fields set to zero"
+        };
+        match self {
+            ByteViewFieldKind::Borrowed => {
+                let bind_bytes = bind_bytes(parse_quote! { normalized });
+                let doc = format!(
+                    "The bytes of this [`{ident}`] with all {filled}, so that records that differ only in those \
+                    fields have identical bytes."
+                );
+                vec![parse_quote! {
+                    #[doc = #doc]
+                    pub fn normalized(&self) -> ::std::vec::Vec<::std::primitive::u8> {
+                        let mut normalized = self.bytes.to_vec();
+                        #bind_bytes
+                        #(#fill_fields)*
+                        normalized
+                    }
+                }]
+            }
+            ByteViewFieldKind::Owned => {
+                let bind_bytes = bind_bytes(parse_quote! { self.bytes });
+                let normalize_doc = format!(
+                    "Modify this [`{ident}`] so it has all {filled}, so that records that differ only in those \
+                    fields have identical bytes."
+                );
+                let normalized_doc = format!("A copy of this [`{ident}`] with all {filled}.");
+                vec![
+                    parse_quote! {
+                        #[doc = #normalize_doc]
+                        pub fn normalize(&mut self) {
+                            #bind_bytes
+                            #(#fill_fields)*
+                        }
+                    },
+                    parse_quote! {
+                        #[doc = #normalized_doc]
+                        pub fn normalized(&self) -> Self {
+                            let mut normalized = Self { bytes: self.bytes.clone(), #copy_endian };
+                            normalized.normalize();
+                            normalized
+                        }
+                    },
+                ]
+            }
+        }
+    }
+
+    // Create the methods that modify fields. Borrowed structs cannot modify their bytes, so these are only generated for
+    // owned structs.
+    fn create_mutators(self, inner: &ByteViewStruct, offset_exprs: &[Expr]) -> Vec<ItemFn> {
+        match self {
+            ByteViewFieldKind::Borrowed => Vec::new(),
+            ByteViewFieldKind::Owned => inner
+                .fields
+                .iter()
+                .zip(offset_exprs)
+                .flat_map(|(field, offset_expr)| {
+                    let cfg_attrs = field.get_cfg_attrs();
+                    field
+                        .create_mutators(offset_expr)
+                        .into_iter()
+                        .map(move |mut method| {
+                            method.attrs.extend(cfg_attrs.clone());
+                            method
+                        })
+                })
+                .collect(),
+        }
+    }
+
+    // Create a struct holding a mutable reference to the bytes of each named field, along with the `split_fields_mut`
+    // method that creates it. Borrowed structs cannot modify their bytes and the fields of structs with optional fields
+    // may be missing, so these are only generated for other owned structs.
+    fn create_split_fields_mut(
+        self,
+        inner: &ByteViewStruct,
+    ) -> Option<(proc_macro2::TokenStream, ItemFn)> {
+        // The struct holding the references would also need the const generic parameters of a struct that has them.
+        if self == ByteViewFieldKind::Borrowed
+            || inner.has_optional_fields()
+            || inner.has_const_generics()
+        {
+            return None;
+        }
+        let ByteViewStruct {
+            vis, ident, fields, ..
+        } = inner;
+        let fields_mut_ident = format_ident!("{ident}FieldsMut");
+        let mut struct_fields = Vec::new();
+        let mut splits = Vec::new();
+        let mut field_values = Vec::new();
+        for field in fields {
+            if let ByteViewType::Varint { .. } = field.byteview_type {
+                // Varints follow the fixed fields and have no fixed size.
+                continue;
+            }
+            let size = field.byteview_type.get_size_expr();
+            let field_ident = &field.ident;
+            let cfg_attrs = field.get_cfg_attrs();
+            if *field_ident == "_" {
+                splits
+                    .push(quote! { #(#cfg_attrs)* let (_, __rest) = __rest.split_at_mut(#size); });
+                continue;
+            }
+            let field_vis = &field.vis;
+            let field_doc = format!("The bytes of the `{field_ident}` field.");
+            struct_fields.push(quote! {
+                #[doc = #field_doc]
+                #(#cfg_attrs)*
+                #field_vis #field_ident: &'a mut [::std::primitive::u8; #size]
+            });
+            splits.push(
+                quote! { #(#cfg_attrs)* let (#field_ident, __rest) = __rest.split_at_mut(#size); },
+            );
+            field_values.push(quote! {
+                #(#cfg_attrs)*
+                #field_ident: ::std::result::Result::expect(::core::convert::TryInto::try_into(#field_ident), "invalid indices")
+            });
+        }
+        if struct_fields.is_empty() {
+            return None;
+        }
+        let struct_doc = format!(
+            "Mutable references to the bytes of each named field of a [`{ident}`], which can be held at the same time.\n\
+            \n\
+            This is created by [`{ident}::split_fields_mut`]."
+        );
+        let fields_mut_struct = quote! {
+            #[doc = #struct_doc]
+            #[derive(Debug)]
+            #vis struct #fields_mut_ident<'a> {
+                #(#struct_fields,)*
+            }
+        };
+        let method_doc = format!(
+            "Borrow the bytes of each named field mutably at the same time, e.g. to fill several fields from different \
+            sources. Destructure the returned [`{fields_mut_ident}`] to use the fields independently."
+        );
+        let method = parse_quote! {
+            #[doc = #method_doc]
+            pub fn split_fields_mut(&mut self) -> #fields_mut_ident<'_> {
+                let __rest: &mut [::std::primitive::u8] = &mut self.bytes[..Self::NUM_BYTES];
+                #(#splits)*
+                let _ = __rest;
+                #fields_mut_ident {
+                    #(#field_values,)*
+                }
+            }
+        };
+        Some((fields_mut_struct, method))
+    }
+
+    // Create the generic parameters of the struct. This will be a <'a> if the bytes field is borrowed and nothing if the
+    // bytes field is owned, plus an `E: byteview::Endian` type parameter if the struct has a generic byte order and any
+    // const generic parameters declared on the struct.
+    fn create_generics(self, inner: &ByteViewStruct) -> Generics {
+        let mut generics: Generics = match (self, inner.has_generic_endianness()) {
+            (ByteViewFieldKind::Borrowed, false) => parse_quote! { <'a> },
+            (ByteViewFieldKind::Borrowed, true) => parse_quote! { <'a, E: ::byteview::Endian> },
+            (ByteViewFieldKind::Owned, false) => parse_quote! {},
+            (ByteViewFieldKind::Owned, true) => parse_quote! { <E: ::byteview::Endian> },
+        };
+        generics
+            .params
+            .extend(inner.const_params.iter().cloned().map(GenericParam::Const));
+        generics
+    }
+}
+
+// Create the methods that convert between the borrowed and owned structs generated by `byteview!`, which have the same
+// fields: `to_owned` copies the bytes of the borrowed struct and `as_ref` borrows the bytes of the owned struct.
+fn create_variant_conversions(
+    ref_struct: &ByteViewStruct,
+    owned_struct: &ByteViewStruct,
+) -> proc_macro2::TokenStream {
+    let ref_ident = &ref_struct.ident;
+    let owned_ident = &owned_struct.ident;
+    let ref_generics = ByteViewFieldKind::Borrowed.create_generics(ref_struct);
+    let (ref_impl_generics, ref_ty_generics, _) = ref_generics.split_for_impl();
+    let owned_generics = ByteViewFieldKind::Owned.create_generics(owned_struct);
+    let (owned_impl_generics, owned_ty_generics, _) = owned_generics.split_for_impl();
+    // The lifetime of the borrowed struct is elided in the return type of `as_ref`.
+    let ref_args = ref_generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(_) => quote! { '_ },
+        GenericParam::Type(param) => param.ident.to_token_stream(),
+        GenericParam::Const(param) => param.ident.to_token_stream(),
+    });
+    let borrowed_ref_type: Type = parse_quote! { #ref_ident<#(#ref_args),*> };
+    let copy_endian = match ref_struct.options.endian {
+        Some(Endianness::Generic) => Some(quote! { endian: ::std::marker::PhantomData, }),
+        Some(Endianness::Runtime) => Some(quote! { little_endian: self.little_endian, }),
+        _ => None,
+    };
+    // Both variants are compared by their bytes (including any padding bytes), plus their byte order if it is chosen at
+    // runtime.
+    let endian_eq = (ref_struct.options.endian == Some(Endianness::Runtime))
+        .then(|| quote! { && self.little_endian == other.little_endian });
+    let to_owned_doc = format!("Copy the bytes of this [`{ref_ident}`] into an [`{owned_ident}`].");
+    let as_ref_doc = format!("Borrow the bytes of this [`{owned_ident}`] as a [`{ref_ident}`].");
+    quote! {
+        impl #ref_impl_generics #ref_ident #ref_ty_generics {
+            #[doc = #to_owned_doc]
+            pub fn to_owned(&self) -> #owned_ident #owned_ty_generics {
+                #owned_ident {
+                    bytes: ::std::borrow::ToOwned::to_owned(self.bytes),
+                    #copy_endian
+                }
+            }
+        }
+
+        impl #owned_impl_generics #owned_ident #owned_ty_generics {
+            #[doc = #as_ref_doc]
+            pub fn as_ref(&self) -> #borrowed_ref_type {
+                #ref_ident {
+                    bytes: &self.bytes,
+                    #copy_endian
+                }
+            }
+        }
+
+        impl #ref_impl_generics ::std::cmp::PartialEq<#owned_ident #owned_ty_generics> for #ref_ident #ref_ty_generics {
+            fn eq(&self, other: &#owned_ident #owned_ty_generics) -> ::std::primitive::bool {
+                self.bytes[..] == other.bytes[..] #endian_eq
+            }
+        }
+
+        impl #ref_impl_generics ::std::cmp::PartialEq<#ref_ident #ref_ty_generics> for #owned_ident #owned_ty_generics {
+            fn eq(&self, other: &#ref_ident #ref_ty_generics) -> ::std::primitive::bool {
+                self.bytes[..] == other.bytes[..] #endian_eq
+            }
+        }
+    }
+}
+
+// Create the `MAGIC` constant and `find_sync` function for a struct with a field annotated with
+// `#[byteview(magic = b"...")]`.
+fn create_magic_items(
+    inner: &ByteViewStruct,
+    offset_exprs: &[Expr],
+) -> Option<proc_macro2::TokenStream> {
+    let ident = &inner.ident;
+    let (magic, offset_expr) = inner
+        .fields
+        .iter()
+        .zip(offset_exprs)
+        .find_map(|(field, offset_expr)| Some((field.options.magic.as_ref()?, offset_expr)))?;
+    let magic_doc = format!(
+        "The magic bytes that every valid [`{ident}`] contains. Checked constructors such as \
+        [`split_slice_checked`]({ident}::split_slice_checked) return an error if they do not match."
+    );
+    let find_sync_doc = format!(
+        "Return the offset of the first [`{ident}`] at or after `from` in `bytes` whose magic bytes match, or [`None`] \
+        if there is none. Only the magic bytes are checked, so parse the struct to make sure it is valid."
+    );
+    Some(quote! {
+        #[doc = #magic_doc]
+        pub const MAGIC: ::byteview::Magic = ::byteview::Magic {
+            offset: #offset_expr,
+            bytes: #magic,
+        };
+
+        #[doc = #find_sync_doc]
+        pub fn find_sync(bytes: &[::std::primitive::u8], from: ::std::primitive::usize) -> ::std::option::Option<::std::primitive::usize> {
+            Self::MAGIC.find(bytes, from)
+        }
+    })
+}
+
+// Create the `validate` method for a struct with fields annotated with `#[byteview(must_be_zero)]`, which checks that each
+// of them contains only zero bytes.
+fn create_validate_method(inner: &ByteViewStruct, offset_exprs: &[Expr]) -> Option<ItemFn> {
+    let checks = inner
+        .fields
+        .iter()
+        .zip(offset_exprs)
+        .filter(|(field, _)| field.options.must_be_zero)
+        .map(|(field, offset_expr)| {
+            let name = field.ident.to_string();
+            let size = field.byteview_type.get_size_expr();
+            let cfg_attrs = field.get_cfg_attrs();
+            quote! {
+                #(#cfg_attrs)*
+                ::byteview::__private::check_zero(&self.bytes[..], #name, #offset_expr, #size)?;
+            }
+        })
+        .collect::<Vec<_>>();
+    if checks.is_empty() {
+        return None;
+    }
+    let ident = &inner.ident;
+    let doc = format!(
+        "Check that every field of this [`{ident}`] that must be zero contains only zero bytes, and return the first \
+        one that does not. The constructors do not check this, so nonconforming records can still be inspected."
+    );
+    Some(parse_quote! {
+        #[doc = #doc]
+        pub fn validate(&self) -> ::std::result::Result<(), ::byteview::ValidateError> {
+            #(#checks)*
+            ::std::result::Result::Ok(())
+        }
+    })
+}
+
+// If the struct is annotated with `#[byteview(export_c = "prefix")]`, create an `extern "C"` function for each field
+// that copies the stored value into an out pointer, plus a function returning the number of bytes. The functions return
+// a `byteview::ExportStatus` code instead of panicking. 128-bit numbers have no stable C ABI, arrays of numbers and
+// packed arrays have no obvious C representation, and varints have no fixed offset, so they are skipped.
+fn create_export_c_fns(inner: &ByteViewStruct, offset_exprs: &[Expr]) -> Vec<ItemFn> {
+    let Some(prefix) = &inner.options.export_c else {
+        return Vec::new();
+    };
+    let ident = &inner.ident;
+    let size_ident = format_ident!("{prefix}_size");
+    let size_doc = format!("The number of bytes in a [`{ident}`] (its `NUM_BYTES`).");
+    let mut fns = vec![parse_quote! {
+        #[doc = #size_doc]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn #size_ident() -> ::std::primitive::usize {
+            #ident::NUM_BYTES
+        }
+    }];
+    for (field, offset_expr) in inner.fields.iter().zip(offset_exprs) {
+        let field_ident = &field.ident;
+        if field_ident == "_" {
+            continue;
+        }
+        let byteview_type = &field.byteview_type;
+        let (out_type, write_stmt): (Type, Stmt) = match byteview_type {
+            ByteViewType::MultiByte(MultiByteType::U128 | MultiByteType::I128, _)
+            | ByteViewType::NumberArray { .. }
+            | ByteViewType::Packed { .. }
+            | ByteViewType::Nested(_)
+            | ByteViewType::NestedArray { .. }
+            | ByteViewType::Varint { .. } => continue,
+            ByteViewType::SingleByte(_) | ByteViewType::MultiByte(..) => {
+                let ty = byteview_type.get_type();
+                let getter_expr =
+                    byteview_type.create_getter_expr(&parse_quote! { bytes }, offset_expr);
+                (
+                    ty,
+                    parse_quote! { unsafe { out.write_unaligned(#getter_expr) }; },
+                )
+            }
+            ByteViewType::Array(_) | ByteViewType::Array2D { .. } => {
+                let size = byteview_type.get_size_expr();
+                (
+                    parse_quote! { ::std::primitive::u8 },
+                    parse_quote! {
+                        unsafe { ::std::ptr::copy_nonoverlapping(bytes[#offset_expr..].as_ptr(), out, #size) };
+                    },
+                )
+            }
+        };
+        let size_expr = byteview_type.get_size_expr();
+        let fn_ident = format_ident!("{prefix}_{field_ident}");
+        let doc = format!(
+            "Read the `{field_ident}` field of the [`{ident}`] at the start of the `len` bytes at `buf` and write it to \
+            `out`, as stored.\n\
+            \n\
+            Return [`ExportStatus::Ok`](::byteview::ExportStatus::Ok) as an `int` on success, or a negative \
+            [`ExportStatus`](::byteview::ExportStatus) if a pointer is null or there are too few bytes.\n\
+            \n\
+            # Safety\n\
+            \n\
+            If `buf` is not null, it must be valid for reads of `len` bytes. If `out` is not null, it must be valid for \
+            writes of {size} bytes.",
+            size = size_expr.to_token_stream()
+        );
+        fns.push(parse_quote! {
+            #[doc = #doc]
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn #fn_ident(
+                buf: *const ::std::primitive::u8,
+                len: ::std::primitive::usize,
+                out: *mut #out_type,
+            ) -> ::std::ffi::c_int {
+                if buf.is_null() || out.is_null() {
+                    return ::byteview::ExportStatus::NullPointer.code();
+                }
+                let bytes = unsafe { ::std::slice::from_raw_parts(buf, len) };
+                if bytes.len() < #offset_expr + #size_expr {
+                    return ::byteview::ExportStatus::TooShort.code();
+                }
+                #write_stmt
+                ::byteview::ExportStatus::Ok.code()
+            }
+        });
+    }
+    fns
+}
+
+// Create the values of the `MIN_NUM_BYTES` and `MAX_NUM_BYTES` constants. Optional fields may be missing from the end
+// of the struct, and a length field may declare more bytes than the fixed fields.
+fn create_num_bytes_bounds(inner: &ByteViewStruct, offset_exprs: &[Expr]) -> (Expr, Expr) {
+    let min = inner
+        .fields
+        .iter()
+        .zip(offset_exprs)
+        .find(|(field, _)| field.options.optional)
+        .map_or_else(
+            || parse_quote! { Self::NUM_BYTES },
+            |(_, offset_expr)| offset_expr.clone(),
+        );
+    let length_field = inner.fields.iter().find_map(|field| {
+        if inner.options.record_len.as_ref() == Some(&field.ident) {
+            return Some((field, true));
+        }
+        match field.options.length? {
+            LengthKind::OfRest => Some((field, false)),
+            LengthKind::IncludesHeader => Some((field, true)),
+        }
+    });
+    let num_varints = inner.count_varints();
+    if num_varints > 0 {
+        // Each varint has 1 to 10 bytes.
+        let max_varint_len = 10 * num_varints;
+        return (
+            parse_quote! { Self::NUM_BYTES + #num_varints },
+            parse_quote! { Self::NUM_BYTES + #max_varint_len },
+        );
+    }
+    let max = match length_field {
+        Some((field, includes_header)) => {
+            let ty = field
+                .byteview_type
+                .get_unsigned_integer_type()
+                .expect("length fields should have been validated when parsing");
+            parse_quote! {
+                ::byteview::__private::max_declared_len(Self::NUM_BYTES, #ty::MAX as ::std::primitive::u64, #includes_header)
+            }
+        }
+        None => parse_quote! { Self::NUM_BYTES },
+    };
+    (min, max)
+}
+
+// Create the value of the `LENGTH_FIELD` constant of the `byteview::ByteView` trait, which describes the field named by
+// `#[byteview(record_len = "...")]` or annotated with `#[byteview(len_of_rest)]` or `#[byteview(len_includes_header)]`.
+fn create_length_field_expr(inner: &ByteViewStruct) -> Expr {
+    let path = inner.create_self_path();
+    let length_field = inner.fields.iter().enumerate().find_map(|(index, field)| {
+        if inner.options.record_len.as_ref() == Some(&field.ident) {
+            return Some((index, true));
+        }
+        match field.options.length? {
+            LengthKind::OfRest => Some((index, false)),
+            LengthKind::IncludesHeader => Some((index, true)),
+        }
+    });
+    match length_field {
+        Some((index, includes_header)) => parse_quote! {
+            ::std::option::Option::Some(::byteview::LengthField {
+                field: #path::LAYOUT.fields[#index],
+                includes_header: #includes_header,
+            })
+        },
+        None => parse_quote! { ::std::option::Option::None },
+    }
+}
+
+// Create the body of a `split_slice_checked` function, which splits the bytes using `split_expr` and then checks the
+// value of the length field (if any) against the remaining bytes. For variable-sized structs, `split_expr` is only
+// evaluated once the size of the record has been checked, so any errors are reported before it is called.
+fn create_split_slice_checked_body(inner: &ByteViewStruct, split_expr: Expr) -> Block {
+    let path = inner.create_self_path();
+    let fields = &inner.fields;
+    let record_len_check: Option<Stmt> = inner.is_variable_size().then(|| {
+        parse_quote! {
+            #path::__record_len(bytes)?;
+        }
+    });
+    let length_check: Option<Stmt> = fields.iter().find_map(|field| {
+        let length = field.options.length?;
+        let getter = field.get_getter_ident();
+        let header_len: Expr = match length {
+            LengthKind::OfRest => parse_quote! { 0 },
+            LengthKind::IncludesHeader => parse_quote! { #path::NUM_BYTES },
+        };
+        let truncate = field.options.truncate_rest;
+        Some(parse_quote! {
+            let rest_bytes = ::byteview::__private::check_declared_len(
+                rest_bytes,
+                ::std::primitive::u64::from(view.#getter()),
+                #header_len,
+                #truncate,
+            )?;
+        })
+    });
+    let magic_check: Option<Stmt> = inner.get_magic_field().map(|_| {
+        parse_quote! {
+            ::byteview::__private::check_magic(bytes, #path::MAGIC.offset, #path::MAGIC.bytes)?;
+        }
+    });
+    parse_quote! {{
+        #magic_check
+        #record_len_check
+        let (view, rest_bytes) = match #split_expr {
+            ::std::option::Option::Some(split) => split,
+            ::std::option::Option::None => {
+                return ::std::result::Result::Err(::byteview::ParseError::TooShort {
+                    needed: #path::NUM_BYTES,
+                    available: bytes.len(),
+                });
+            }
+        };
+        #length_check
+        ::std::result::Result::Ok((view, rest_bytes))
+    }}
+}
+
+// Create the appropriate constructors if the bytes field is borrowed.
+fn create_constructors_for_borrowed(inner: &ByteViewStruct) -> Vec<ItemFn> {
+    let ident = &inner.ident;
+    let path = inner.create_self_path();
+    let num_bytes = inner.create_num_bytes_len_expr(ByteViewFieldKind::Borrowed);
+    let endian = inner.create_endian_field_init();
+    let from_array_doc = format!(
+        "Create a [`{ident}`] from a reference to an array of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes."
+    );
+    let from_array = parse_quote! {
+        #[doc = #from_array_doc]
+        pub fn from_array(bytes: &'a [::std::primitive::u8; #num_bytes]) -> Self {
+            Self { #endian bytes }
+        }
+    };
+
+    let from_exact_slice_doc = format!(
+        "Create a [`{ident}`] from a slice of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.\n\
+        \n\
+        If the slice does not contain exactly [`NUM_BYTES`][{ident}::NUM_BYTES], return [`Option::None`]."
+    );
+    let from_exact_slice = parse_quote! {
+        #[doc = #from_exact_slice_doc]
+        pub fn from_exact_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<Self> {
+            Some(Self { #endian bytes: ::std::convert::TryInto::try_into(bytes).ok()? })
+        }
+
+    };
+
+    let split_slice_doc = format!(
+        "Create a [`{ident}`] from the first [`NUM_BYTES`][{ident}::NUM_BYTES] bytes of a slice and return it along with the remaining bytes.\n\
+        \n\
+        If the slice does not contain at least [`NUM_BYTES`][{ident}::NUM_BYTES] bytes, return [`Option::None`]."
+    );
+    let split_slice = parse_quote! {
+        #[doc = #split_slice_doc]
+        pub fn split_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<(Self, &'a [::std::primitive::u8])> {
+            let (exact_bytes, rest_bytes) = bytes.split_at_checked(#path::NUM_BYTES)?;
+            Some((Self { #endian bytes: ::std::convert::TryInto::try_into(exact_bytes).ok()? }, rest_bytes))
+        }
+    };
+
+    let split_slice_checked_doc = format!(
+        "Create a [`{ident}`] from the first [`NUM_BYTES`][{ident}::NUM_BYTES] bytes of a slice and return it along with the remaining bytes.\n\
+        \n\
+        Unlike [`split_slice`][{ident}::split_slice], this returns a [`ParseError`](::byteview::ParseError) describing \
+        why the bytes are invalid. If a field is annotated with `#[byteview(len_of_rest)]` or \
+        `#[byteview(len_includes_header)]`, its value is checked against the number of remaining bytes."
+    );
+    let split_slice_checked_body =
+        create_split_slice_checked_body(inner, parse_quote! { #path::split_slice(bytes) });
+    let split_slice_checked = parse_quote! {
+        #[doc = #split_slice_checked_doc]
+        pub fn split_slice_checked(bytes: &'a [::std::primitive::u8]) -> ::std::result::Result<(Self, &'a [::std::primitive::u8]), ::byteview::ParseError> {
+            #split_slice_checked_body
+        }
+    };
+
+    vec![
+        from_array,
+        from_exact_slice,
+        split_slice,
+        split_slice_checked,
+    ]
+}
+
+// Create the appropriate constructors if the bytes field is owned.
+fn create_constructors_for_owned(inner: &ByteViewStruct) -> Vec<ItemFn> {
+    let ident = &inner.ident;
+    let num_bytes = inner.create_num_bytes_len_expr(ByteViewFieldKind::Owned);
+    let endian = inner.create_endian_field_init();
+    let from_array_doc =
+        format!("Create a [`{ident}`] from an array of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.");
+    let from_array = parse_quote! {
+        #[doc = #from_array_doc]
+        pub fn from_array(bytes: [::std::primitive::u8; #num_bytes]) -> Self {
+            Self { #endian bytes }
+        }
+    };
+
+    let from_exact_vec_doc = format!(
+        "Create a [`{ident}`] from a [`Vec`] of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.\n\
+        \n\
+        If the `Vec` does not contain exactly [`NUM_BYTES`][{ident}::NUM_BYTES], return [`Option::None`]."
+    );
+    let from_exact_vec = parse_quote! {
+        #[doc = #from_exact_vec_doc]
+        pub fn from_exact_vec(bytes: Vec<u8>) -> Option<Self> {
+            Some(Self { #endian bytes: bytes.try_into().ok()? })
+        }
+    };
+
+    let split_vec_doc = format!(
+        "Create a [`{ident}`] from the first [`NUM_BYTES`][{ident}::NUM_BYTES] bytes of a [`Vec`] and return it along with the remaining bytes.\n\
+        \n\
+        If the [`Vec`] does not contain at least [`NUM_BYTES`][{ident}::NUM_BYTES] bytes, return [`Option::None`]."
+    );
+    let split_vec = parse_quote! {
+        #[doc = #split_vec_doc]
+        pub fn split_vec(mut bytes: Vec<u8>) -> Option<(Self, Vec<u8>)> {
+            if bytes.len() < Self::NUM_BYTES {
+                return None;
+            }
+            let rest = bytes.split_off(Self::NUM_BYTES);
+            Some((Self { #endian bytes: bytes.try_into().ok()? }, rest))
+        }
+    };
+
+    vec![from_array, from_exact_vec, split_vec]
+}
+
+// Create the private `__record_len` function for a variable-sized struct, which returns the number of bytes in the
+// record at the start of the bytes. If the struct has a `record_len` field, its value is read and checked against the
+// number of bytes available. Otherwise, the record contains every optional trailing field that fits in the bytes.
+fn create_record_len_fn(inner: &ByteViewStruct) -> ItemFn {
+    let path = inner.create_self_path();
+    let (offset_exprs, total_size_expr) = create_offset_exprs_and_total_size_expr(&inner.fields);
+    let num_varints = inner.count_varints();
+    if num_varints > 0 {
+        return parse_quote! {
+            fn __record_len(bytes: &[::std::primitive::u8]) -> ::std::result::Result<::std::primitive::usize, ::byteview::ParseError> {
+                if bytes.len() < #path::NUM_BYTES {
+                    return ::std::result::Result::Err(::byteview::ParseError::TooShort {
+                        needed: #path::NUM_BYTES + #num_varints,
+                        available: bytes.len(),
+                    });
+                }
+                ::byteview::__private::skip_varints(bytes, #path::NUM_BYTES, #num_varints)
+            }
+        };
+    }
+    if inner.has_const_generics() {
+        return parse_quote! {
+            fn __record_len(bytes: &[::std::primitive::u8]) -> ::std::result::Result<::std::primitive::usize, ::byteview::ParseError> {
+                if bytes.len() < #path::NUM_BYTES {
+                    return ::std::result::Result::Err(::byteview::ParseError::TooShort {
+                        needed: #path::NUM_BYTES,
+                        available: bytes.len(),
+                    });
+                }
+                ::std::result::Result::Ok(#path::NUM_BYTES)
+            }
+        };
+    }
+    let Some(record_len) = &inner.options.record_len else {
+        let first_optional = inner
+            .fields
+            .iter()
+            .position(|field| field.options.optional)
+            .expect("variable-sized structs without a record_len field have optional fields");
+        let mandatory_len = &offset_exprs[first_optional];
+        // Check the longest records first.
+        let optional_lens = offset_exprs[first_optional + 1..]
+            .iter()
+            .chain([&total_size_expr])
+            .rev();
+        return parse_quote! {
+            fn __record_len(bytes: &[::std::primitive::u8]) -> ::std::result::Result<::std::primitive::usize, ::byteview::ParseError> {
+                if bytes.len() < #mandatory_len {
+                    return ::std::result::Result::Err(::byteview::ParseError::TooShort {
+                        needed: #mandatory_len,
+                        available: bytes.len(),
+                    });
+                }
+                for record_len in [#(#optional_lens),*] {
+                    if bytes.len() >= record_len {
+                        return ::std::result::Result::Ok(record_len);
+                    }
+                }
+                ::std::result::Result::Ok(#mandatory_len)
+            }
+        };
+    };
+    let (field, offset_expr) = inner
+        .fields
+        .iter()
+        .zip(&offset_exprs)
+        .find(|(field, _)| field.ident == *record_len)
+        .expect("record_len field should have been validated when parsing");
+    let getter_expr = field
+        .byteview_type
+        .create_getter_expr(&parse_quote! { bytes }, offset_expr);
+    parse_quote! {
+        fn __record_len(bytes: &[::std::primitive::u8]) -> ::std::result::Result<::std::primitive::usize, ::byteview::ParseError> {
+            if bytes.len() < #path::NUM_BYTES {
+                return ::std::result::Result::Err(::byteview::ParseError::TooShort {
+                    needed: #path::NUM_BYTES,
+                    available: bytes.len(),
+                });
+            }
+            let declared = ::std::primitive::u64::from(#getter_expr);
+            if declared < #path::NUM_BYTES as ::std::primitive::u64 {
+                return ::std::result::Result::Err(::byteview::ParseError::LengthTooSmall {
+                    declared,
+                    minimum: #path::NUM_BYTES,
+                });
+            }
+            match ::std::primitive::usize::try_from(declared) {
+                ::std::result::Result::Ok(record_len) if record_len <= bytes.len() => {
+                    ::std::result::Result::Ok(record_len)
+                }
+                _ => ::std::result::Result::Err(::byteview::ParseError::LengthMismatch {
+                    declared,
+                    available: bytes.len(),
+                }),
+            }
+        }
+    }
+}
+
+// Describe how the number of bytes in a record of a variable-sized struct is determined, for use in documentation.
+fn create_record_size_doc(inner: &ByteViewStruct) -> String {
+    let ident = &inner.ident;
+    match &inner.options.record_len {
+        Some(record_len) => {
+            let getter = inner
+                .fields
+                .iter()
+                .find(|field| field.ident == *record_len)
+                .map_or(record_len, ByteViewField::get_getter_ident);
+            format!(
+                "The number of bytes in the record is read from [`{getter}`][{ident}::{getter}]."
+            )
+        }
+        None if inner.count_varints() > 0 => {
+            "The record ends after the last varint field, whose length is read from its bytes."
+                .to_owned()
+        }
+        None if inner.has_const_generics() => {
+            format!("Every record has exactly [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.")
+        }
+        None => "The record includes each optional trailing field that is complete.".to_owned(),
+    }
+}
+
+// Create the methods that are specific to variable-sized structs but do not depend on whether the bytes are borrowed.
+fn create_variable_size_methods(inner: &ByteViewStruct, body_type: Type) -> Vec<ItemFn> {
+    let ident = &inner.ident;
+    let path = inner.create_self_path();
+    let mut methods = Vec::new();
+
+    let byte_len_doc = format!(
+        "The number of bytes in this record. This may differ from [`NUM_BYTES`][{ident}::NUM_BYTES]."
+    );
+    methods.push(parse_quote! {
+        #[doc = #byte_len_doc]
+        pub fn byte_len(&self) -> ::std::primitive::usize {
+            self.bytes.len()
+        }
+    });
+
+    if inner.options.record_len.is_some() {
+        let body_doc = format!(
+            "The bytes of the record following the first [`NUM_BYTES`][{ident}::NUM_BYTES] bytes."
+        );
+        methods.push(parse_quote! {
+            #[doc = #body_doc]
+            pub fn body(&self) -> #body_type {
+                &self.bytes[#path::NUM_BYTES..]
+            }
+        });
+    }
+
+    methods.push(create_record_len_fn(inner));
+    methods
+}
+
+// Create the appropriate constructors if the bytes field is borrowed and the struct is variable-sized. The number of
+// bytes in each record is only known at runtime, so there is no `from_array` constructor.
+fn create_constructors_for_borrowed_variable_size(inner: &ByteViewStruct) -> Vec<ItemFn> {
+    let ident = &inner.ident;
+    let path = inner.create_self_path();
+    let endian = inner.create_endian_field_init();
+    let record_size_doc = create_record_size_doc(inner);
+
+    let from_exact_slice_doc = format!(
+        "Create a [`{ident}`] from a slice containing exactly one record.\n\
+        \n\
+        {record_size_doc} If the slice contains any other bytes, return [`Option::None`]."
+    );
+    let from_exact_slice = parse_quote! {
+        #[doc = #from_exact_slice_doc]
+        pub fn from_exact_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<Self> {
+            let record_len = #path::__record_len(bytes).ok()?;
+            (record_len == bytes.len()).then_some(Self { #endian bytes })
+        }
+    };
+
+    let split_slice_doc = format!(
+        "Create a [`{ident}`] from the first record of a slice and return it along with the remaining bytes.\n\
+        \n\
+        {record_size_doc} If the slice does not contain a complete record, return [`Option::None`]."
+    );
+    let split_slice = parse_quote! {
+        #[doc = #split_slice_doc]
+        pub fn split_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<(Self, &'a [::std::primitive::u8])> {
+            let record_len = #path::__record_len(bytes).ok()?;
+            let (record_bytes, rest_bytes) = bytes.split_at(record_len);
+            Some((Self { #endian bytes: record_bytes }, rest_bytes))
+        }
+    };
+
+    let split_slice_checked_doc = format!(
+        "Create a [`{ident}`] from the first record of a slice and return it along with the remaining bytes.\n\
+        \n\
+        Unlike [`split_slice`][{ident}::split_slice], this returns a [`ParseError`](::byteview::ParseError) describing \
+        why the bytes are invalid."
+    );
+    let split_slice_checked_body =
+        create_split_slice_checked_body(inner, parse_quote! { #path::split_slice(bytes) });
+    let split_slice_checked = parse_quote! {
+        #[doc = #split_slice_checked_doc]
+        pub fn split_slice_checked(bytes: &'a [::std::primitive::u8]) -> ::std::result::Result<(Self, &'a [::std::primitive::u8]), ::byteview::ParseError> {
+            #split_slice_checked_body
+        }
+    };
+
+    let mut constructors = vec![from_exact_slice, split_slice, split_slice_checked];
+    constructors.extend(create_variable_size_methods(
+        inner,
+        parse_quote! { &'a [::std::primitive::u8] },
+    ));
+    constructors
+}
+
+// Create the `into_long_form` method for an owned struct, which appends the default value of every missing optional
+// field. This is only possible if every optional field has a default value.
+fn create_into_long_form(inner: &ByteViewStruct) -> Option<ItemFn> {
+    let ident = &inner.ident;
+    let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&inner.fields);
+    let optional_fields = inner
+        .fields
+        .iter()
+        .zip(&offset_exprs)
+        .filter(|(field, _)| field.options.optional)
+        .collect::<Vec<_>>();
+    if optional_fields.is_empty() {
+        return None;
+    }
+    let appends = optional_fields
+        .into_iter()
+        .map(|(field, offset_expr)| {
+            let default = field.create_default_expr()?;
+            let to_bytes = field.byteview_type.create_to_bytes_expr(&default);
+            Some(quote! {
+                if self.bytes.len() == #offset_expr {
+                    self.bytes.extend_from_slice(&#to_bytes);
+                }
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let doc = format!(
+        "Convert this [`{ident}`] to the longest form of the record by appending the default value of each missing \
+        optional field. The result contains [`NUM_BYTES`][{ident}::NUM_BYTES] bytes."
+    );
+    Some(parse_quote! {
+        #[doc = #doc]
+        pub fn into_long_form(mut self) -> Self {
+            #(#appends)*
+            self
+        }
+    })
+}
+
+// Create the `ByteView::__write_defaults` method, which lets `byteview::migrate` write the default value of every
+// optional field with one into the bytes of a new struct.
+fn create_write_defaults(inner: &ByteViewStruct) -> Option<proc_macro2::TokenStream> {
+    let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&inner.fields);
+    let writes = inner
+        .fields
+        .iter()
+        .zip(&offset_exprs)
+        .filter(|(field, _)| field.options.optional)
+        .filter_map(|(field, offset_expr)| {
+            let default = field.create_default_expr()?;
+            let to_bytes = field.byteview_type.create_to_bytes_expr(&default);
+            let cfg_attrs = field.get_cfg_attrs();
+            Some(quote! {
+                #(#cfg_attrs)*
+                {
+                    let default = #to_bytes;
+                    bytes[#offset_expr..#offset_expr + default.len()].copy_from_slice(&default);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    if writes.is_empty() {
+        return None;
+    }
+    Some(quote! {
+        fn __write_defaults(bytes: &mut [::std::primitive::u8]) {
+            #(#writes)*
+        }
+    })
+}
+
+// Create the appropriate constructors if the bytes field is owned and the struct is variable-sized.
+fn create_constructors_for_owned_variable_size(inner: &ByteViewStruct) -> Vec<ItemFn> {
+    let ident = &inner.ident;
+    let path = inner.create_self_path();
+    let endian = inner.create_endian_field_init();
+    let record_size_doc = create_record_size_doc(inner);
+
+    let from_exact_vec_doc = format!(
+        "Create a [`{ident}`] from a [`Vec`] containing exactly one record.\n\
+        \n\
+        {record_size_doc} If the `Vec` contains any other bytes, return [`Option::None`]."
+    );
+    let from_exact_vec = parse_quote! {
+        #[doc = #from_exact_vec_doc]
+        pub fn from_exact_vec(bytes: Vec<u8>) -> Option<Self> {
+            let record_len = #path::__record_len(&bytes).ok()?;
+            (record_len == bytes.len()).then_some(Self { #endian bytes })
+        }
+    };
+
+    let split_vec_doc = format!(
+        "Create a [`{ident}`] from the first record of a [`Vec`] and return it along with the remaining bytes.\n\
+        \n\
+        {record_size_doc} If the [`Vec`] does not contain a complete record, return [`Option::None`]."
+    );
+    let split_vec = parse_quote! {
+        #[doc = #split_vec_doc]
+        pub fn split_vec(mut bytes: Vec<u8>) -> Option<(Self, Vec<u8>)> {
+            let record_len = #path::__record_len(&bytes).ok()?;
+            let rest = bytes.split_off(record_len);
+            Some((Self { #endian bytes }, rest))
+        }
+    };
+
+    let mut constructors = vec![from_exact_vec, split_vec];
+    constructors.extend(create_into_long_form(inner));
+    constructors.extend(create_variable_size_methods(
+        inner,
+        parse_quote! { &[::std::primitive::u8] },
+    ));
+    constructors
+}
+
+impl ToTokens for BitsStruct {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let BitsStruct {
+            attrs,
+            vis,
+            ident,
+            storage,
+            fields,
+        } = self;
+        let ty = storage
+            .get_unsigned_integer_type()
+            .expect("storage type should have been validated when parsing");
+        let num_bytes = storage.get_size_expr();
+        let endianness = match storage {
+            ByteViewType::MultiByte(_, endianness) => *endianness,
+            _ => Endianness::Big,
+        };
+        let from_bytes = endianness.create_from_bytes_expr(&ty, &parse_quote! { bytes });
+        let to_bytes = endianness.create_to_bytes_expr(&parse_quote! { self.0 });
+        let storage_name = storage.get_type_string();
+        let from_bytes_doc = format!("Create a value from the bytes of a `{storage_name}`.");
+        let to_bytes_doc = format!("The bytes of the value as a `{storage_name}`.");
+        let mut consts = Vec::new();
+        let mut methods = Vec::new();
+        let mut shift = fields.iter().map(|field| field.bits).sum::<u32>();
+        for field in fields {
+            shift -= field.bits;
+            if field.ident == "_" {
+                continue;
+            }
+            let (field_consts, field_methods) = field.create_items(&ty, shift);
+            consts.extend(field_consts);
+            methods.extend(field_methods);
+        }
+        tokens.append_all(quote! {
+            #(#attrs)*
+            #vis struct #ident(#ty);
+
+            impl #ident {
+                /// The number of bytes in the stored value.
+                #vis const NUM_BYTES: ::std::primitive::usize = #num_bytes;
+
+                #(#consts)*
+
+                /// Create a value from its raw bits.
+                #vis const fn from_raw(raw: #ty) -> Self {
+                    Self(raw)
+                }
+
+                /// The raw bits of the value.
+                #vis const fn raw(self) -> #ty {
+                    self.0
+                }
+
+                #[doc = #from_bytes_doc]
+                #vis const fn from_bytes(bytes: [::std::primitive::u8; #num_bytes]) -> Self {
+                    Self(#from_bytes)
+                }
+
+                #[doc = #to_bytes_doc]
+                #vis const fn to_bytes(self) -> [::std::primitive::u8; #num_bytes] {
+                    #to_bytes
+                }
+
+                #(#methods)*
+            }
+
+            impl ::std::convert::From<#ty> for #ident {
+                fn from(raw: #ty) -> Self {
+                    Self(raw)
+                }
+            }
+
+            impl ::std::convert::From<#ident> for #ty {
+                fn from(value: #ident) -> Self {
+                    value.0
+                }
+            }
+
+            impl ::byteview::FromFieldValue<#ty> for #ident {
+                type Output = Self;
+
+                fn from_field_value(value: #ty) -> Self {
+                    Self(value)
+                }
+            }
+        });
+    }
+}
+
+impl ToTokens for ByteViewEnum {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let ByteViewEnum {
+            attrs,
+            vis,
+            ident,
+            repr,
+            variants,
+            unknown,
+        } = self;
+        let variant_attrs = variants.iter().map(|(attrs, _, _)| attrs);
+        let variant_idents = variants
+            .iter()
+            .map(|(_, ident, _)| ident)
+            .collect::<Vec<_>>();
+        let discriminants = variants
+            .iter()
+            .map(|(_, _, discriminant)| discriminant)
+            .collect::<Vec<_>>();
+        let (enum_tokens, from_value, from_value_type) = match unknown {
+            Some((unknown_attrs, unknown_ident)) => {
+                // The discriminants cannot be declared on the enum without also giving the unknown variant a
+                // discriminant, which could collide with one of the others.
+                let enum_tokens = quote! {
+                    #(#attrs)*
+                    #vis enum #ident {
+                        #(
+                            #(#variant_attrs)*
+                            #variant_idents,
+                        )*
+                        #(#unknown_attrs)*
+                        #unknown_ident(#repr),
+                    }
+
+                    impl ::std::convert::From<#repr> for #ident {
+                        fn from(value: #repr) -> Self {
+                            #(
+                                if value == #discriminants {
+                                    return Self::#variant_idents;
+                                }
+                            )*
+                            Self::#unknown_ident(value)
+                        }
+                    }
+
+                    impl ::std::convert::From<#ident> for #repr {
+                        fn from(value: #ident) -> Self {
+                            match value {
+                                #(#ident::#variant_idents => #discriminants,)*
+                                #ident::#unknown_ident(value) => value,
+                            }
+                        }
+                    }
+                };
+                let from_value: Expr =
+                    parse_quote! { <Self as ::std::convert::From<#repr>>::from(value) };
+                (enum_tokens, from_value, parse_quote! { Self })
+            }
+            None => {
+                let enum_tokens = quote! {
+                    #(#attrs)*
+                    #[repr(#repr)]
+                    #vis enum #ident {
+                        #(
+                            #(#variant_attrs)*
+                            #variant_idents = #discriminants,
+                        )*
+                    }
+
+                    impl ::std::convert::TryFrom<#repr> for #ident {
+                        type Error = #repr;
+
+                        fn try_from(value: #repr) -> ::std::result::Result<Self, #repr> {
+                            #(
+                                if value == #discriminants {
+                                    return ::std::result::Result::Ok(Self::#variant_idents);
+                                }
+                            )*
+                            ::std::result::Result::Err(value)
+                        }
+                    }
+
+                    impl ::std::convert::From<#ident> for #repr {
+                        fn from(value: #ident) -> Self {
+                            value as #repr
+                        }
+                    }
+                };
+                let from_value: Expr =
+                    parse_quote! { <Self as ::std::convert::TryFrom<#repr>>::try_from(value) };
+                (
+                    enum_tokens,
+                    from_value,
+                    parse_quote! { ::std::result::Result<Self, #repr> },
+                )
+            }
+        };
+        let from_value_type: Type = from_value_type;
+        tokens.append_all(quote! {
+            #enum_tokens
+
+            impl ::byteview::FromFieldValue<#repr> for #ident {
+                type Output = #from_value_type;
+
+                fn from_field_value(value: #repr) -> Self::Output {
+                    #from_value
+                }
+            }
+        });
+    }
+}
+
+impl BitsField {
+    // Create the mask and shift constants and the "getter" and "setter" methods for the field, which starts `shift`
+    // bits from the least significant bit of a value of type `ty`.
+    fn create_items(&self, ty: &Type, shift: u32) -> (Vec<ImplItemConst>, Vec<ItemFn>) {
+        let BitsField {
+            attrs,
+            vis,
+            ident,
+            bits,
+            conversion,
+        } = self;
+        let name = ident.to_string().to_uppercase();
+        let mask_ident = format_ident!("{name}_MASK");
+        let shift_ident = format_ident!("{name}_SHIFT");
+        let mask = ((1u128 << bits) - 1) << shift;
+        let mask = proc_macro2::Literal::from_str(&format!("{mask:#X}"))
+            .expect("a hexadecimal integer should be a valid literal");
+        let end = shift + bits;
+        let mask_doc = format!("The mask of the bits that hold [`{ident}`](Self::{ident}).");
+        let shift_doc = format!("The position of the lowest bit of [`{ident}`](Self::{ident}).");
+        let consts = vec![
+            parse_quote! {
+                #[doc = #mask_doc]
+                #vis const #mask_ident: #ty = #mask;
+            },
+            parse_quote! {
+                #[doc = #shift_doc]
+                #vis const #shift_ident: ::std::primitive::u32 = #shift;
+            },
+        ];
+        let bits_expr: Expr = parse_quote! { (self.0 & Self::#mask_ident) >> Self::#shift_ident };
+        let setter_ident = format_ident!("set_{ident}");
+        let setter_doc = format!(
+            "Replace bits {shift}..{end} with [`{ident}`](Self::{ident}). Bits of the new value that do not fit are \
+            ignored."
+        );
+        let (getter, setter): (ItemFn, ItemFn) = match conversion {
+            Some(target) => (
+                parse_quote! {
+                    #(#attrs)*
+                    ///
+                    /// If the bits cannot be converted, return them as an error.
+                    #vis fn #ident(self) -> ::std::result::Result<#target, #ty> {
+                        let bits = #bits_expr;
+                        <#target as ::std::convert::TryFrom<#ty>>::try_from(bits).map_err(|_| bits)
+                    }
+                },
+                parse_quote! {
+                    #[doc = #setter_doc]
+                    #vis fn #setter_ident(&mut self, value: impl ::std::convert::Into<#ty>) {
+                        let value: #ty = value.into();
+                        self.0 = (self.0 & !Self::#mask_ident) | ((value << Self::#shift_ident) & Self::#mask_ident);
+                    }
+                },
+            ),
+            None => (
+                parse_quote! {
+                    #(#attrs)*
+                    #vis fn #ident(self) -> #ty {
+                        #bits_expr
+                    }
+                },
+                parse_quote! {
+                    #[doc = #setter_doc]
+                    #vis fn #setter_ident(&mut self, value: #ty) {
+                        self.0 = (self.0 & !Self::#mask_ident) | ((value << Self::#shift_ident) & Self::#mask_ident);
+                    }
+                },
+            ),
+        };
+        (consts, vec![getter, setter])
+    }
+}
+
+// The assertions are constant items, so a mismatch is reported when the crate is compiled. The message is a string
+// literal because constant panics cannot format values.
+impl ToTokens for AssertSize {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let AssertSize { ty, size } = self;
+        let message = format!(
+            "`{}` must have {} bytes",
+            ty.to_token_stream().to_string().replace(' ', ""),
+            size.to_token_stream()
+        );
+        tokens.append_all(quote! {
+            const _: () = ::std::assert!(<#ty>::NUM_BYTES == #size, #message);
+        });
+    }
+}
+
+impl ToTokens for AssertOffset {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let AssertOffset { ty, field, offset } = self;
+        // Use the span of the field so that an unknown field is reported there.
+        let offset_ident = format_ident!(
+            "OFFSET_{}",
+            field.to_string().to_uppercase(),
+            span = field.span()
+        );
+        let message = format!(
+            "`{field}` must be at offset {} in `{}`",
+            offset.to_token_stream(),
+            ty.to_token_stream().to_string().replace(' ', "")
+        );
+        tokens.append_all(quote! {
+            const _: () = ::std::assert!(<#ty>::#offset_ident == #offset, #message);
+        });
+    }
+}
+
+//////////////////////////////////////////////////////////////////////
+// Procedural Macro Implementations
+//////////////////////////////////////////////////////////////////////
+
+fn byteview_ref_impl(input: TokenStream) -> TokenStream {
+    let byteview_structs = parse_macro_input!(input as ByteViewStructs);
+    byteview_structs
+        .create_tagged_structs(ByteViewFieldKind::Borrowed)
+        .into()
+}
+
+fn byteview_bits_impl(input: TokenStream) -> TokenStream {
+    let bits_struct = parse_macro_input!(input as BitsStruct);
+    quote! {
+        #bits_struct
+    }
+    .into()
+}
+
+fn byteview_enum_impl(input: TokenStream) -> TokenStream {
+    let byteview_enum = parse_macro_input!(input as ByteViewEnum);
+    quote! {
+        #byteview_enum
+    }
+    .into()
+}
+
+fn assert_size_impl(input: TokenStream) -> TokenStream {
+    let assert_size = parse_macro_input!(input as AssertSize);
+    assert_size.into_token_stream().into()
+}
+
+fn assert_offset_impl(input: TokenStream) -> TokenStream {
+    let assert_offset = parse_macro_input!(input as AssertOffset);
+    assert_offset.into_token_stream().into()
+}
+
+fn byteview_owned_impl(input: TokenStream) -> TokenStream {
+    let byteview_structs = parse_macro_input!(input as ByteViewStructs);
+    byteview_structs
+        .create_tagged_structs(ByteViewFieldKind::Owned)
+        .into()
+}
+
+fn byteview_impl(input: TokenStream) -> TokenStream {
+    let ByteViewStructs(byteview_structs) = parse_macro_input!(input as ByteViewStructs);
+    let mut tokens = proc_macro2::TokenStream::new();
+    for byteview_struct in byteview_structs {
+        let (ref_struct, owned_struct) = byteview_struct.split_into_variants();
+        let conversions = create_variant_conversions(&ref_struct, &owned_struct);
+        let ref_struct = ByteViewStructTagged {
+            inner: ref_struct,
+            field_kind: ByteViewFieldKind::Borrowed,
+        };
+        let owned_struct = ByteViewStructTagged {
+            inner: owned_struct,
+            field_kind: ByteViewFieldKind::Owned,
+        };
+        tokens.append_all(quote! {
+            #ref_struct
+            #owned_struct
+            #conversions
+        });
+    }
+    tokens.into()
+}
+
+//////////////////////////////////////////////////////////////////////
+// Tests
+//////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn single_underscore_field_name() {
+        let tokens = quote! {
+            struct Foo {
+                x: u8,
+                y: u16be,
+                z: [u8; 3],
+                _: u8,
+                _: [u8; 13],
+                _: u32be,
+            }
+        };
+
+        let byteview_struct: Result<ByteViewStruct, _> = syn::parse2(tokens);
+        assert!(byteview_struct.is_ok());
+        let byteview_struct = byteview_struct.unwrap();
+        let (offset_exprs, total_size_expr) =
+            create_offset_exprs_and_total_size_expr(&byteview_struct.fields);
+        assert_eq!(Some(7), evaluate_usize_expr(&offset_exprs[4]));
+        assert_eq!(Some(24), evaluate_usize_expr(&total_size_expr));
+        let getters = create_getters(&byteview_struct.fields, &offset_exprs);
+        assert_eq!(3, getters.len());
+        let tagged = ByteViewStructTagged {
+            inner: byteview_struct,
+            field_kind: ByteViewFieldKind::Borrowed,
+        };
+
+        quote! { #tagged };
+    }
+
+    #[test]
+    pub fn layout_doc() {
+        let tokens = quote! {
+            struct EntryHeader {
+                index: u32be,
+                _kind: u8,
+                _: u8,
+                _name: [u8; 16],
+                other: [u8; OTHER_LEN],
+                last: u8,
+            }
+        };
+
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&fields);
+        let lines = create_layout_doc_lines(&fields, &offset_exprs);
+        assert_eq!(
+            vec![
+                "",
+                "# Layout",
+                "",
+                "| Bytes | Field | Type | Size |",
+                "| ----- | ----- | ---- | ---- |",
+                "| 0..4 | `index` | `u32be` | 4 |",
+                "| 4..5 | `_kind` | `u8` | 1 |",
+                "| 5..6 | _(padding)_ | `u8` | 1 |",
+                "| 6..22 | `_name` | `[u8; 16]` | 16 |",
+                "| `22..22 + OTHER_LEN` | `other` | `[u8; OTHER_LEN]` | `OTHER_LEN` |",
+                "| `22 + OTHER_LEN..23 + OTHER_LEN` | `last` | `u8` | 1 |",
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    pub fn layout_doc_on_struct() {
+        // The header from the `entry` integration test.
+        let tokens = quote! {
+            /// The header for an entry (reference version).
+            #[derive(Debug)]
+            pub struct EntryHeaderRef {
+                /// The index of the entry.
+                pub index: u32be,
+                _kind: u8,
+                _: u8,
+                _name: [u8; 16],
+            }
+        };
+
+        let byteview_structs: ByteViewStructs = syn::parse2(tokens).unwrap();
+        let tokens = byteview_structs.create_tagged_structs(ByteViewFieldKind::Borrowed);
+        let file: syn::File = syn::parse2(tokens).unwrap();
+        let item_struct = file
+            .items
+            .iter()
+            .find_map(|item| match item {
+                syn::Item::Struct(item_struct) if item_struct.ident == "EntryHeaderRef" => {
+                    Some(item_struct)
+                }
+                _ => None,
+            })
+            .unwrap();
+        let doc_lines = item_struct
+            .attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                Meta::NameValue(name_value) if name_value.path.is_ident("doc") => {
+                    match &name_value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(lit_str),
+                            ..
+                        }) => Some(lit_str.value()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                " The header for an entry (reference version).",
+                "",
+                "# Layout",
+                "",
+                "| Bytes | Field | Type | Size |",
+                "| ----- | ----- | ---- | ---- |",
+                "| 0..4 | `index` | `u32be` | 4 |",
+                "| 4..5 | `_kind` | `u8` | 1 |",
+                "| 5..6 | _(padding)_ | `u8` | 1 |",
+                "| 6..22 | `_name` | `[u8; 16]` | 16 |",
+            ],
+            doc_lines
+        );
+    }
+
+    #[test]
+    pub fn getter_docs() {
+        let tokens = quote! {
+            struct EntryHeader {
+                /// The index of the entry.
+                #[allow(dead_code)]
+                index: u32be,
+                kind: u8,
+                other: [u8; OTHER_LEN],
+                count: varint,
+            }
+        };
+
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&fields);
+        let getters = fields
+            .iter()
+            .zip(&offset_exprs)
+            .map(|(field, offset_expr)| field.create_getter(offset_expr).unwrap())
+            .collect::<Vec<_>>();
+        let attrs = getters[0]
+            .attrs
+            .iter()
+            .map(|attr| attr.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                r#"# [doc = r" The index of the entry."]"#,
+                "# [allow (dead_code)]",
+                r#"# [doc = ""]"#,
+                r#"# [doc = "Bytes 0..4 (4 bytes)."]"#,
+            ],
+            attrs
+        );
+        let position_docs = fields
+            .iter()
+            .zip(&offset_exprs)
+            .map(|(field, offset_expr)| field.create_position_doc(offset_expr))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "Bytes 0..4 (4 bytes).",
+                "Bytes 4..5 (1 byte).",
+                "`OTHER_LEN` bytes.",
+                "1 to 10 bytes.",
+            ],
+            position_docs
+        );
+        assert_eq!(1, getters[1].attrs.len());
+    }
+
+    #[test]
+    pub fn offset_consts() {
+        let tokens = quote! {
+            struct Foo {
+                _: u8,
+                pub log_type: u8,
+                #[byteview(getter = "raw_name")]
+                _name: [u8; 4],
+                count: varint,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&fields);
+        let names = fields
+            .iter()
+            .zip(&offset_exprs)
+            .flat_map(|(field, offset_expr)| field.create_offset_consts(offset_expr))
+            .map(|item| item.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "OFFSET_LOG_TYPE",
+                "SIZE_LOG_TYPE",
+                "OFFSET_RAW_NAME",
+                "SIZE_RAW_NAME"
+            ],
+            names
+        );
+    }
+
+    #[test]
+    pub fn layout_assertions() {
+        let assert_size: AssertSize = syn::parse2(quote! { Header<BigEndian>, 40 + 2 }).unwrap();
+        let tokens = assert_size.into_token_stream().to_string();
+        assert!(tokens.contains("\"`Header<BigEndian>` must have 40 + 2 bytes\""));
+
+        let assert_offset: AssertOffset = syn::parse2(quote! { Header, log_type, 40, }).unwrap();
+        let tokens = assert_offset.into_token_stream().to_string();
+        assert!(tokens.contains("OFFSET_LOG_TYPE == 40"));
+        assert!(tokens.contains("\"`log_type` must be at offset 40 in `Header`\""));
+
+        assert!(syn::parse2::<AssertSize>(quote! { Header }).is_err());
+        assert!(syn::parse2::<AssertSize>(quote! { Header, 42, 43 }).is_err());
+        assert!(syn::parse2::<AssertOffset>(quote! { Header, 40 }).is_err());
+        assert!(syn::parse2::<AssertOffset>(quote! { Header, "log_type", 40 }).is_err());
+    }
+
+    #[test]
+    pub fn type_error_messages() {
+        let error = |tokens| {
+            syn::parse2::<ByteViewStruct>(tokens)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            "Invalid type for field `a`: Unknown byte order `eb` in `u32eb`, use u32be, u32le, or u32ne",
+            error(quote! { struct Foo { a: u32eb, b: u8, } })
+        );
+        assert_eq!(
+            format!("Invalid type for field `b`: Unsupported type. {SUPPORTED_TYPES_MESSAGE}"),
+            error(quote! { struct Foo { a: u8, b: usize, } })
+        );
+        assert_eq!(
+            format!(
+                "Invalid type for field `a`: `String` is not a byteview struct. {SUPPORTED_TYPES_MESSAGE}"
+            ),
+            error(quote! { struct Foo { a: String, } })
+        );
+        assert!(
+            error(quote! { struct Foo { a: [u16; 4], } })
+                .starts_with("Invalid type for field `a`: Endianness required")
+        );
+        assert_eq!(
+            "Missing type for field `a`",
+            error(quote! { struct Foo { pub a: , b: u8, } })
+        );
+        assert_eq!(
+            "Missing type for field `b`",
+            error(quote! { struct Foo { a: u8, #[byteview(optional)] b: } })
+        );
+        assert_eq!(
+            "Unexpected `,`, expected a field",
+            error(quote! { struct Foo { a: u8,, b: u8, } })
+        );
+        assert_eq!(
+            "Unexpected `,`, expected a field",
+            error(quote! { struct Foo(u8,, u8); })
+        );
+    }
+
+    #[test]
+    pub fn byteview_field_attributes() {
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(offset_into_buffer)]
+                #[doc = "The offset."]
+                offset: u32be,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let field = &byteview_struct.fields[0];
+        assert!(field.options.offset_into_buffer);
+        assert_eq!(1, field.attrs.len());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(offset_into_buffer)]
+                offset: [u8; 4],
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(not_an_option)]
+                offset: u32be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(reserved)]
+                flags: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.fields[0].options.reserved);
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(reserved, len_of_rest)]
+                len: u8,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(must_be_zero)]
+                _: [u8; 3],
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.fields[0].options.must_be_zero);
+        assert!(byteview_struct.fields[0].options.reserved);
+
+        let invalid_fields = [
+            quote! { #[byteview(must_be_zero, len_of_rest)] len: u8 },
+            quote! { #[byteview(must_be_zero, magic = b"A")] a: [u8; 1] },
+            quote! { #[byteview(must_be_zero)] a: varint },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(bitmap)]
+                a: [u8; 4],
+                #[byteview(bitmap, bit_order = "msb0")]
+                b: [u8; 4],
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            Some(BitOrder::Lsb0),
+            byteview_struct.fields[0].options.bitmap
+        );
+        assert_eq!(
+            Some(BitOrder::Msb0),
+            byteview_struct.fields[1].options.bitmap
+        );
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(bitmap)]
+                a: u32be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(bitmap, bit_order = "backwards")]
+                a: [u8; 4],
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+    }
+
+    #[test]
+    pub fn subfields() {
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(subfields(kind = 4..8, length = 0..=3))]
+                a: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let subfields = &byteview_struct.fields[0].options.subfields;
+        assert_eq!(2, subfields.len());
+        assert_eq!("kind", subfields[0].ident.to_string());
+        assert_eq!((4, 8), (subfields[0].start, subfields[0].end));
+        assert_eq!("length", subfields[1].ident.to_string());
+        assert_eq!((0, 4), (subfields[1].start, subfields[1].end));
+
+        let invalid_fields = [
+            quote! { #[byteview(subfields(kind = 4..9))] a: u8 },
+            quote! { #[byteview(subfields(kind = 4..4))] a: u16be },
+            quote! { #[byteview(subfields(kind = 4))] a: u8 },
+            quote! { #[byteview(subfields(kind = 0..4))] a: i8 },
+            quote! { #[byteview(subfields(kind = 0..4))] a: [u8; 2] },
+            quote! { #[byteview(optional, subfields(kind = 0..4))] a: u8 },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn endian_attributes() {
+        let tokens = quote! {
+            #[byteview(endian = "little")]
+            struct Foo {
+                a: u32,
+                #[byteview(endian = "big")]
+                b: i16,
+                c: [f32; 2],
+                d: u16be,
+                e: u8,
+                #[byteview(endian = "native")]
+                f: [u64; 4],
+            }
+        };
+        let ByteViewStruct {
+            fields, options, ..
+        } = syn::parse2(tokens).unwrap();
+        assert_eq!(Some(Endianness::Little), options.endian);
+        let types = fields
+            .iter()
+            .map(|field| field.byteview_type.get_type_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ["u32le", "i16be", "[f32le; 2]", "u16be", "u8", "[u64ne; 4]"],
+            types.as_slice()
+        );
+
+        let invalid_structs = [
+            quote! { struct Foo { a: u32, } },
+            quote! { #[byteview(endian = "middle")] struct Foo { a: u32, } },
+            quote! { struct Foo { #[byteview(endian = "big")] a: u32le, } },
+            quote! { struct Foo { #[byteview(endian = "big")] a: u8, } },
+            quote! { struct Foo { #[byteview(endian = "big")] a: [u8; 4], } },
+            quote! { struct Foo { #[byteview(endian = big)] a: u32, } },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn generic_endianness() {
+        let tokens = quote! {
+            #[byteview(endian = "generic")]
+            struct Foo {
+                a: u32,
+                b: [i16; 2],
+                c: u16be,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.has_generic_endianness());
+        assert!(matches!(
+            byteview_struct.fields[0].byteview_type,
+            ByteViewType::MultiByte(MultiByteType::U32, Endianness::Generic)
+        ));
+        let types = byteview_struct
+            .fields
+            .iter()
+            .map(|field| field.byteview_type.get_type_string())
+            .collect::<Vec<_>>();
+        assert_eq!(["u32", "[i16; 2]", "u16be"], types.as_slice());
+
+        let invalid_structs = [
+            quote! { struct Foo { #[byteview(endian = "generic")] a: u32, } },
+            quote! { #[byteview(endian = "generic", export_c = "foo")] struct Foo { a: u32, } },
+            quote! {
+                #[byteview(endian = "generic")]
+                struct Foo { #[byteview(prefix_boundary)] a: u32, b: u8, }
+            },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn runtime_endianness() {
+        let tokens = quote! {
+            #[byteview(endian = "runtime")]
+            struct Foo {
+                a: u32,
+                #[byteview(endian = "little")]
+                b: u16,
+                #[byteview(optional)]
+                c: i16,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(matches!(
+            byteview_struct.fields[0].byteview_type,
+            ByteViewType::MultiByte(MultiByteType::U32, Endianness::Runtime)
+        ));
+        assert!(matches!(
+            byteview_struct.fields[1].byteview_type,
+            ByteViewType::MultiByte(MultiByteType::U16, Endianness::Little)
+        ));
+        assert!(byteview_struct.create_endian_field().is_some());
+
+        let invalid_structs = [
+            quote! { struct Foo { #[byteview(endian = "runtime")] a: u32, } },
+            quote! { #[byteview(endian = "runtime", record_len = "a")] struct Foo { a: u32, } },
+            quote! { #[byteview(endian = "runtime")] struct Foo { #[byteview(len_of_rest)] a: u32, } },
+            quote! { #[byteview(endian = "runtime", export_c = "foo")] struct Foo { a: u32, } },
+            quote! {
+                #[byteview(endian = "runtime")]
+                struct Foo { #[byteview(prefix_boundary)] a: u32, b: u8, }
+            },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn const_generics() {
+        let tokens = quote! {
+            pub struct Batch<const N: usize> {
+                header: u16be,
+                slots: [u8; N],
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.has_const_generics());
+        assert!(byteview_struct.is_variable_size());
+        let generics = ByteViewFieldKind::Borrowed.create_generics(&byteview_struct);
+        assert_eq!(2, generics.params.len());
+
+        let invalid_structs = [
+            quote! { struct Foo<T> { a: u8, } },
+            quote! { struct Foo<'b> { a: u8, } },
+            quote! { struct Foo<const N: usize> where [u8; N]: Sized { a: [u8; N], } },
+            quote! { #[byteview(record_len = "a")] struct Foo<const N: usize> { a: u8, b: [u8; N], } },
+            quote! { #[byteview(export_c = "foo")] struct Foo<const N: usize> { a: [u8; N], } },
+            quote! { struct Foo<const N: usize> { a: [u8; N], #[byteview(optional)] b: u8, } },
+            quote! { struct Foo<const N: usize> { a: [u8; N], b: varint, } },
+            quote! { struct Foo<const N: usize> { #[byteview(prefix_boundary)] a: u8, b: [u8; N], } },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn tuple_structs() {
+        let tokens = quote! { pub struct Crc(pub u32be); };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(1, byteview_struct.fields.len());
+        assert_eq!("field_0", byteview_struct.fields[0].ident.to_string());
+        assert_eq!(
+            "get",
+            byteview_struct.fields[0].get_getter_ident().to_string()
+        );
+        assert_eq!(
+            "field_0",
+            byteview_struct.fields[0].get_name_ident().to_string()
+        );
+
+        let tokens = quote! { struct Pair(u16le, #[byteview(getter = "second")] u8,); };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            "field_0",
+            byteview_struct.fields[0].get_getter_ident().to_string()
+        );
+        assert_eq!(
+            "second",
+            byteview_struct.fields[1].get_getter_ident().to_string()
+        );
+
+        let invalid_structs = [
+            quote! { struct Foo(u8) },
+            quote! { struct Foo(a: u8); },
+            quote! { struct Foo(u33); },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn multiple_structs() {
+        let tokens = quote! {
+            struct Foo { a: u8, }
+            #[byteview(endian = "little")]
+            pub struct Bar { b: u16, c: Foo<'a>, }
+        };
+        let ByteViewStructs(byteview_structs) = syn::parse2(tokens).unwrap();
+        assert_eq!(2, byteview_structs.len());
+        assert_eq!("Foo", byteview_structs[0].ident.to_string());
+        assert_eq!("Bar", byteview_structs[1].ident.to_string());
+        assert!(byteview_structs[0].options.endian.is_none());
+
+        let invalid_structs = [
+            quote! {},
+            quote! { struct Foo { a: u8, } struct Bar { b: u33, } },
+            quote! { struct Foo { a: u8, }; },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStructs>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn variant_names() {
+        let tokens = quote! {
+            #[byteview(export_c = "foo")]
+            struct Foo { a: u8, }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.check_no_variant_names().is_none());
+        let (ref_struct, owned_struct) = byteview_struct.split_into_variants();
+        assert_eq!("FooRef", ref_struct.ident.to_string());
+        assert_eq!("FooOwned", owned_struct.ident.to_string());
+        assert!(ref_struct.options.export_c.is_some());
+        assert!(owned_struct.options.export_c.is_none());
+
+        let tokens = quote! {
+            #[byteview(ref_name = "FooView", owned_name = "Foo")]
+            struct Foo { a: u8, }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.check_no_variant_names().is_some());
+        let (ref_struct, owned_struct) = byteview_struct.split_into_variants();
+        assert_eq!("FooView", ref_struct.ident.to_string());
+        assert_eq!("Foo", owned_struct.ident.to_string());
+    }
+
+    #[test]
+    pub fn zeroize_on_drop() {
+        let tokens = quote! {
+            #[byteview(zeroize_on_drop)]
+            struct Key { id: u8, material: [u8; 16], }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens.clone()).unwrap();
+        assert!(byteview_struct.options.zeroize_on_drop);
+        let impls = ByteViewFieldKind::Owned
+            .create_zeroize_impls(&byteview_struct)
+            .to_string();
+        assert!(impls.contains("Drop for Key"));
+        assert!(impls.contains("wipe (& mut self . bytes)"));
+        assert!(
+            ByteViewFieldKind::Borrowed
+                .create_zeroize_impls(&byteview_struct)
+                .is_empty()
+        );
+
+        let byteview_structs: ByteViewStructs = syn::parse2(tokens).unwrap();
+        let tokens = byteview_structs
+            .create_tagged_structs(ByteViewFieldKind::Borrowed)
+            .to_string();
+        assert!(tokens.contains("zeroize_on_drop is only permitted"));
+
+        let tokens = quote! { struct Key { id: u8, } };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let impls = ByteViewFieldKind::Owned
+            .create_zeroize_impls(&byteview_struct)
+            .to_string();
+        assert!(!impls.contains("Drop"));
+    }
+
+    #[test]
+    pub fn cfg_fields() {
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                #[cfg(feature = "rev_b")]
+                b: u16be,
+                c: u8,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert!(!fields[0].has_cfg());
+        assert!(fields[1].has_cfg());
+        let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&fields);
+        let lines = create_layout_doc_lines(&fields, &offset_exprs);
+        assert_eq!(
+            vec![
+                "| 0..1 | `a` | `u8` | 1 |",
+                "| 1..3 | `b` (if `feature = \"rev_b\"`) | `u16be` | 2 |",
+                "|  | `c` | `u8` | 1 |",
+            ],
+            lines[5..]
+        );
+
+        let invalid_structs = [
+            quote! { struct Foo { a: u8, #[cfg(x)] #[byteview(optional)] b: u8, } },
+            quote! { struct Foo { #[cfg(x)] #[byteview(len_of_rest)] a: u8, } },
+            quote! { struct Foo { #[cfg(x)] #[byteview(magic = b"A")] a: [u8; 1], } },
+            quote! { struct Foo { #[cfg(x)] a: varint, } },
+            quote! { #[byteview(record_len = "a")] struct Foo { a: u8, #[cfg(x)] b: u8, } },
+            quote! { #[byteview(export_c = "foo")] struct Foo { #[cfg(x)] a: u8, } },
+            quote! { struct Foo { #[cfg(x)] a: u8, #[byteview(len_of_rest)] b: u8, } },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn getter_name() {
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(getter = "raw_name")]
+                _name: [u8; 4],
+                count: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            "raw_name",
+            byteview_struct.fields[0].get_getter_ident().to_string()
+        );
+        assert_eq!(
+            "count",
+            byteview_struct.fields[1].get_getter_ident().to_string()
+        );
+
+        let invalid_structs = [
+            quote! { struct Foo { #[byteview(getter = "a")] _: u32, } },
+            quote! { struct Foo { #[byteview(getter = "not a name")] a: u32, } },
+            quote! { struct Foo { #[byteview(getter)] a: u32, } },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn varint_fields() {
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                b: varint,
+                c: varint,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.is_variable_size());
+        assert_eq!(2, byteview_struct.count_varints());
+        assert!(matches!(
+            byteview_struct.fields[1].byteview_type,
+            ByteViewType::Varint { index: 0 }
+        ));
+        assert!(matches!(
+            byteview_struct.fields[2].byteview_type,
+            ByteViewType::Varint { index: 1 }
+        ));
+
+        let invalid_structs = [
+            quote! { struct Foo { a: varint, b: u8, } },
+            quote! { struct Foo { a: u8, #[byteview(optional)] b: varint, } },
+            quote! { struct Foo { a: varint, #[byteview(optional)] b: u8, } },
+            quote! { struct Foo { #[byteview(reserved)] a: varint, } },
+            quote! { struct Foo { #[byteview(counter)] a: varint, } },
+            quote! { struct Foo { a: varint as Count, } },
+            quote! { #[byteview(record_len = "a")] struct Foo { a: u8, b: varint, } },
+        ];
+        for tokens in invalid_structs {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn flags_fields() {
+        let tokens = quote! {
+            struct Foo {
+                a: flags u8 { COMPRESSED = 0, ENCRYPTED = 1, TRUNCATED = 7 },
+                b: flags u16be { READY = 15 },
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            "u8",
+            byteview_struct.fields[0].byteview_type.get_type_string()
+        );
+        let flags = &byteview_struct.fields[0].options.flags;
+        assert_eq!(3, flags.len());
+        assert_eq!("COMPRESSED", flags[0].ident.to_string());
+        assert_eq!(0, flags[0].bit);
+        assert_eq!("TRUNCATED", flags[2].ident.to_string());
+        assert_eq!(7, flags[2].bit);
+        assert_eq!(
+            "u16be",
+            byteview_struct.fields[1].byteview_type.get_type_string()
+        );
+        assert_eq!(15, byteview_struct.fields[1].options.flags[0].bit);
+
+        let invalid_fields = [
+            quote! { a: flags u8 { COMPRESSED = 8 } },
+            quote! { a: flags i8 { COMPRESSED = 0 } },
+            quote! { a: flags [u8; 2] { COMPRESSED = 0 } },
+            quote! { a: flags bool { COMPRESSED = 0 } },
+            quote! { a: flags u8 { COMPRESSED } },
+            quote! { a: flags u8 },
+            quote! { #[byteview(optional)] a: flags u8 { COMPRESSED = 0 } },
+            quote! { _: flags u8 { COMPRESSED = 0 } },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn tz_offset_fields() {
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(tz_offset_quarter_hours)]
+                a: i8,
+                #[byteview(tz_offset_minutes)]
+                b: i16le,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            Some(TzOffsetUnit::QuarterHours),
+            byteview_struct.fields[0].options.tz_offset
+        );
+        assert_eq!(
+            Some(TzOffsetUnit::Minutes),
+            byteview_struct.fields[1].options.tz_offset
+        );
+
+        let invalid_fields = [
+            quote! { #[byteview(tz_offset_quarter_hours)] a: u8 },
+            quote! { #[byteview(tz_offset_quarter_hours)] a: i16be },
+            quote! { #[byteview(tz_offset_minutes)] a: i8 },
+            quote! { #[byteview(tz_offset_minutes)] a: u16be },
+            quote! { #[byteview(optional, tz_offset_minutes)] a: i16be },
+            quote! { #[byteview(tz_offset_quarter_hours)] a: i8 as Offset },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn duration_fields() {
+        assert_eq!(Some(1_000_000_000), parse_duration_unit("s"));
+        assert_eq!(Some(10_000_000), parse_duration_unit("10ms"));
+        assert_eq!(Some(1_000), parse_duration_unit("us"));
+        assert_eq!(Some(100), parse_duration_unit("100ns"));
+        for unit in ["", "10", "0ms", "1min", "ms10", "-1s", "99999999999s"] {
+            assert_eq!(None, parse_duration_unit(unit));
+        }
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(duration(unit = "10ms"))]
+                a: u32be,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(Some(10_000_000), byteview_struct.fields[0].options.duration);
+
+        let invalid_fields = [
+            quote! { #[byteview(duration(unit = "10ms"))] a: i32be },
+            quote! { #[byteview(duration(unit = "10ms"))] a: [u8; 4] },
+            quote! { #[byteview(duration(unit = "minutes"))] a: u32be },
+            quote! { #[byteview(duration(scale = "ms"))] a: u32be },
+            quote! { #[byteview(duration)] a: u32be },
+            quote! { #[byteview(optional, duration(unit = "s"))] a: u32be },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn counter_fields() {
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(counter)]
+                a: u16be,
+                b: u16be,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.fields[0].options.counter);
+        assert!(!byteview_struct.fields[1].options.counter);
+
+        let invalid_fields = [
+            quote! { #[byteview(counter)] a: i16be },
+            quote! { #[byteview(counter)] a: u128be },
+            quote! { #[byteview(counter)] a: [u8; 2] },
+            quote! { #[byteview(optional, counter)] a: u32le },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn magic_fields() {
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                #[byteview(magic = b"LOG1")]
+                b: [u8; 4],
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let magic = byteview_struct.fields[1].options.magic.as_ref().unwrap();
+        assert_eq!(b"LOG1", magic.value().as_slice());
+
+        let tokens = quote! {
+            struct Foo {
+                a: [u8; 4] = *b"LOG1",
+                b: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let magic = byteview_struct.fields[0].options.magic.as_ref().unwrap();
+        assert_eq!(b"LOG1", magic.value().as_slice());
+        assert!(byteview_struct.fields[0].attrs.is_empty());
+        let tokens = quote! { struct Foo { a: [u8; 2] = b"\xCA\xFE", } };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let magic = byteview_struct.fields[0].options.magic.as_ref().unwrap();
+        assert_eq!(b"\xCA\xFE", magic.value().as_slice());
+
+        let invalid_fields = [
+            quote! { #[byteview(magic = b"LOG1")] a: [u8; 3] },
+            quote! { #[byteview(magic = b"LOG1")] a: u32be },
+            quote! { #[byteview(magic = b"")] a: [u8; 0] },
+            quote! { #[byteview(magic = "LOG1")] a: [u8; 4] },
+            quote! { #[byteview(optional, magic = b"LOG1")] a: [u8; 4] },
+            quote! { a: [u8; 3] = *b"LOG1" },
+            quote! { a: [u8; 4] = "LOG1" },
+            quote! { a: [u8; 4] = [0; 4] },
+            quote! { #[byteview(magic = b"LOG1")] a: [u8; 4] = *b"LOG1" },
+        ];
+        for field in invalid_fields {
+            let tokens = quote! { struct Foo { #field, } };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(magic = b"A")]
+                a: [u8; 1],
+                #[byteview(magic = b"B")]
+                b: [u8; 1],
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+    }
+
+    #[test]
+    pub fn optional_fields() {
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                #[byteview(optional)]
+                b: u16be,
+                #[byteview(optional)]
+                c: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.has_optional_fields());
+        assert!(byteview_struct.is_variable_size());
+
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                #[byteview(optional)]
+                b: u16be,
+                c: u8,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            #[byteview(record_len = "a")]
+            struct Foo {
+                a: u8,
+                #[byteview(optional)]
+                b: u16be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                #[byteview(optional, default = 0xFFFF)]
+                b: u16be,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            Some("B_DEFAULT".to_owned()),
+            byteview_struct.fields[1]
+                .create_default_const()
+                .map(|item| item.ident.to_string())
+        );
+
+        let tokens = quote! {
+            struct Foo {
+                a: u8,
+                #[byteview(default = 0xFFFF)]
+                b: u16be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+    }
+
+    #[test]
+    pub fn prefix_boundary() {
+        let tokens = quote! {
+            #[doc = "A record."]
+            #[derive(Debug)]
+            struct Foo {
+                a: u8,
+                #[byteview(prefix_boundary)]
+                b: u16be,
+                c: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let prefix_struct = byteview_struct.create_prefix_struct().unwrap();
+        assert_eq!("FooPrefix", prefix_struct.ident.to_string());
+        assert_eq!(2, prefix_struct.fields.len());
+        assert_eq!(2, prefix_struct.attrs.len());
+        assert!(prefix_struct.create_prefix_struct().is_none());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(prefix_boundary)]
+                a: u8,
+                #[byteview(prefix_boundary)]
+                b: u16be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+    }
+
+    #[test]
+    pub fn byteview_struct_attributes() {
+        let tokens = quote! {
+            #[byteview(record_len = "len")]
+            #[derive(Debug)]
+            struct Foo {
+                len: u16be,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            Some("len".to_owned()),
+            byteview_struct
+                .options
+                .record_len
+                .map(|ident| ident.to_string())
+        );
+        assert_eq!(1, byteview_struct.attrs.len());
+
+        let tokens = quote! {
+            #[byteview(record_len = "missing")]
+            struct Foo {
+                len: u16be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            #[byteview(record_len = "len")]
+            struct Foo {
+                len: i16be,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            #[byteview(pad_with = 0xFF)]
+            struct Foo {
+                a: u8,
+                _: u8,
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        assert!(byteview_struct.options.pad_with.is_some());
+        assert!(byteview_struct.options.record_len.is_none());
+
+        let tokens = quote! {
+            #[byteview(export_c = "foo")]
+            struct Foo {
+                a: u8,
+                b: [u32be; 2],
+            }
+        };
+        let byteview_struct: ByteViewStruct = syn::parse2(tokens).unwrap();
+        let (offset_exprs, _) = create_offset_exprs_and_total_size_expr(&byteview_struct.fields);
+        let names = create_export_c_fns(&byteview_struct, &offset_exprs)
+            .into_iter()
+            .map(|item_fn| item_fn.sig.ident.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["foo_size", "foo_a"], names);
+
+        let tokens = quote! {
+            #[byteview(export_c = "not an identifier")]
+            struct Foo {
+                a: u8,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+    }
+
+    #[test]
+    pub fn packed_type() {
+        let tokens = quote! {
+            struct Foo {
+                a: packed<u12be, 64>,
+                b: packed<u3le, LEN>,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert!(matches!(
+            fields[0].byteview_type,
+            ByteViewType::Packed {
+                bits: 12,
+                order: BitOrder::Msb0,
+                ..
+            }
+        ));
+        assert_eq!(
+            Some(96),
+            evaluate_usize_expr(&fields[0].byteview_type.get_size_expr())
+        );
+        assert_eq!(
+            "packed<u3le, LEN>",
+            fields[1].byteview_type.get_type_string()
+        );
+
+        for ty in [
+            quote! { packed<u12, 4> },
+            quote! { packed<u0be, 4> },
+            quote! { packed<u65be, 4> },
+            quote! { packed<i12be, 4> },
+            quote! { packed<u12be> },
+        ] {
+            let tokens = quote! {
+                struct Foo {
+                    a: #ty,
+                }
+            };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn middle_endian_type() {
+        let tokens = quote! {
+            struct Foo {
+                a: u32me,
+                b: i32me,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert!(matches!(
+            fields[0].byteview_type,
+            ByteViewType::MultiByte(MultiByteType::U32, Endianness::Middle)
+        ));
+        assert_eq!("i32me", fields[1].byteview_type.get_type_string());
+
+        for ty in [quote! { u16me }, quote! { u64me }, quote! { f32me }] {
+            let tokens = quote! {
+                struct Foo {
+                    a: #ty,
+                }
+            };
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn twenty_four_bit_types() {
+        let tokens = quote! {
+            struct Foo {
+                a: u24be,
+                b: i24le,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert!(matches!(
+            fields[0].byteview_type,
+            ByteViewType::MultiByte(MultiByteType::U24, Endianness::Big)
+        ));
+        assert_eq!(
+            Some(3),
+            evaluate_usize_expr(&fields[0].byteview_type.get_size_expr())
+        );
+        assert_eq!("i24le", fields[1].byteview_type.get_type_string());
+        let ty = fields[1].byteview_type.get_type();
+        assert_eq!("i32", ty.to_token_stream().to_string());
+    }
+
+    #[test]
+    pub fn number_arrays() {
+        let tokens = quote! {
+            struct Foo {
+                a: [u32be; 4],
+                #[byteview(iter_only)]
+                b: [i16le; LEN],
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!(
+            Some(16),
+            evaluate_usize_expr(&fields[0].byteview_type.get_size_expr())
+        );
+        assert_eq!("[i16le; LEN]", fields[1].byteview_type.get_type_string());
+        assert!(fields[1].options.iter_only);
+
+        for tokens in [
+            quote! { struct Foo { a: [u32; 4], } },
+            quote! { struct Foo { #[byteview(iter_only)] a: [u8; 4], } },
+            quote! { struct Foo { #[byteview(optional)] a: [u32be; 4], } },
+        ] {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn conversions() {
+        let tokens = quote! {
+            struct Foo {
+                a: [u8; 16] as [Mode; 16],
+                b: u8,
+                c: u16be as Flags,
+                d: [u8; 8] as Signature,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert!(matches!(
+            fields[0].conversion,
+            Some(Conversion::ByteArray { .. })
+        ));
+        assert!(fields[1].conversion.is_none());
+        assert!(matches!(
+            fields[2].conversion,
+            Some(Conversion::Value { .. })
+        ));
+        assert!(matches!(
+            fields[3].conversion,
+            Some(Conversion::Value { .. })
+        ));
+
+        let tokens = quote! {
+            struct Foo {
+                a: [u16be; 16] as Mode,
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+
+        let tokens = quote! {
+            struct Foo {
+                #[byteview(bitmap)]
+                a: [u8; 16] as [Mode; 16],
+            }
+        };
+        assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+    }
+
+    #[test]
+    pub fn nested_types() {
+        let tokens = quote! {
+            struct Foo {
+                a: DeviceId<'a>,
+                b: types::VersionOwned,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert!(matches!(fields[0].byteview_type, ByteViewType::Nested(_)));
+        assert_eq!("DeviceId<'a>", fields[0].byteview_type.get_type_string());
+        let size = fields[0].byteview_type.get_size_expr();
+        assert!(size.to_token_stream().to_string().contains("'static"));
+        assert_eq!(
+            "types::VersionOwned",
+            fields[1].byteview_type.get_type_string()
+        );
+
+        let tokens = quote! {
+            struct Foo {
+                a: [DeviceId<'a>; 4],
+                b: [VersionOwned; 2],
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        let types: Vec<_> = fields
+            .iter()
+            .map(|field| {
+                let ty = field.byteview_type.get_type();
+                ty.to_token_stream().to_string().replace(' ', "")
+            })
+            .collect();
+        assert_eq!("::byteview::Table<'a,DeviceId<'a>>", types[0]);
+        assert_eq!("::byteview::Table<'_,VersionOwned>", types[1]);
+
+        for tokens in [
+            quote! { struct Foo { a: device_id, } },
+            quote! { struct Foo { #[byteview(optional, default = 0)] a: DeviceIdOwned, } },
+        ] {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn two_dimensional_arrays() {
+        let tokens = quote! {
+            struct Foo {
+                a: [[u8; 16]; 4],
+                b: [[u8; ROW_LEN]; N + 1],
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("[[u8; 16]; 4]", fields[0].byteview_type.get_type_string());
+        assert_eq!(
+            Some(64),
+            evaluate_usize_expr(&fields[0].byteview_type.get_size_expr())
+        );
+        let size = fields[1].byteview_type.get_size_expr();
+        assert_eq!("(N + 1) * (ROW_LEN)", size.to_token_stream().to_string());
+
+        for tokens in [
+            quote! { struct Foo { a: [[u16be; 16]; 4], } },
+            quote! { struct Foo { a: [[[u8; 2]; 16]; 4], } },
+            quote! { struct Foo { #[byteview(optional, default = [[0; 2]; 2])] a: [[u8; 2]; 2], } },
+        ] {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn shorthand_types() {
+        let tokens = quote! {
+            struct Foo {
+                a: bool,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("u8", fields[0].byteview_type.get_type_string());
+        assert!(matches!(
+            fields[0].conversion,
+            Some(Conversion::Value { .. })
+        ));
+
+        let tokens = quote! {
+            struct Foo {
+                a: charle,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("u32le", fields[0].byteview_type.get_type_string());
+
+        let tokens = quote! {
+            struct Foo {
+                a: nonzero_u24be,
+                b: nonzero_i8,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("u24be", fields[0].byteview_type.get_type_string());
+        assert_eq!("i8", fields[1].byteview_type.get_type_string());
+
+        let tokens = quote! {
+            struct Foo {
+                a: ascii<16>,
+                b: ascii<NAME_LEN>,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("[u8; 16]", fields[0].byteview_type.get_type_string());
+        assert_eq!("[u8; NAME_LEN]", fields[1].byteview_type.get_type_string());
+
+        let tokens = quote! {
+            struct Foo {
+                a: timestamp32be,
+                b: timestamp64le,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("u32be", fields[0].byteview_type.get_type_string());
+        assert_eq!("i64le", fields[1].byteview_type.get_type_string());
+
+        let tokens = quote! {
+            struct Foo {
+                a: timestamp_ms64be,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("u64be", fields[0].byteview_type.get_type_string());
+
+        let tokens = quote! {
+            struct Foo {
+                a: q16_16be,
+                b: q1_7,
+                c: q0_16le,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        let types: Vec<_> = fields
+            .iter()
+            .map(|field| field.byteview_type.get_type_string())
+            .collect();
+        assert_eq!(["i32be", "i8", "i16le"], types[..]);
+        assert!(matches!(
+            fields[0].conversion,
+            Some(Conversion::FixedPoint { fraction_bits: 16 })
+        ));
+
+        let tokens = quote! {
+            struct Foo {
+                a: bcd<4>,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("[u8; 4]", fields[0].byteview_type.get_type_string());
+        assert!(matches!(fields[0].conversion, Some(Conversion::Bcd)));
+
+        let tokens = quote! {
+            struct Foo {
+                a: utf16be<32>,
+            }
+        };
+        let ByteViewStruct { fields, .. } = syn::parse2(tokens).unwrap();
+        assert_eq!("[u16be; 32]", fields[0].byteview_type.get_type_string());
+        assert!(matches!(fields[0].conversion, Some(Conversion::Utf16)));
+
+        for tokens in [
+            quote! { struct Foo { a: bool as Flag, } },
+            quote! { struct Foo { a: charbe as Letter, } },
+            quote! { struct Foo { a: charme, } },
+            quote! { struct Foo { a: nonzero_f32be, } },
+            quote! { struct Foo { a: nonzero_u32, } },
+            quote! { struct Foo { a: nonzero_, } },
+            quote! { struct Foo { a: ascii, } },
+            quote! { struct Foo { a: ascii<16, 2>, } },
+            quote! { struct Foo { a: ascii<16> as Name, } },
+            quote! { struct Foo { a: utf16ne<16>, } },
+            quote! { struct Foo { a: uuid<16>, } },
+            quote! { struct Foo { a: uuid as Id, } },
+            quote! { struct Foo { a: ipv4 as Address, } },
+            quote! { struct Foo { a: mac<6>, } },
+            quote! { struct Foo { a: timestamp32, } },
+            quote! { struct Foo { a: timestamp16be, } },
+            quote! { struct Foo { a: timestamp, } },
+            quote! { struct Foo { a: timestamp_ms32be, } },
+            quote! { struct Foo { a: timestamp_ms64, } },
+            quote! { struct Foo { a: q16_16, } },
+            quote! { struct Foo { a: q8_8me, } },
+            quote! { struct Foo { a: q4_4be, } },
+            quote! { struct Foo { a: q16_0be, } },
+            quote! { struct Foo { a: q32_32be, } },
+            quote! { struct Foo { a: q12_12be, } },
+            quote! { struct Foo { a: q16_16be as Fixed, } },
+            quote! { struct Foo { a: bcd, } },
+            quote! { struct Foo { a: bcd<4> as Counter, } },
+            quote! { struct Foo { a: utf16be<16> as Name, } },
+            quote! { struct Foo { #[byteview(optional)] a: bool, } },
+        ] {
+            assert!(syn::parse2::<ByteViewStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn bits_struct() {
+        let tokens = quote! {
+            #[derive(Debug)]
+            pub struct DataInfo: u16be {
+                pub kind: 4 as DataKind,
+                _: 4,
+                /// The length.
+                pub length: 8,
+            }
+        };
+        let bits_struct: BitsStruct = syn::parse2(tokens).unwrap();
+        assert_eq!(1, bits_struct.attrs.len());
+        assert_eq!(Some(16), bits_struct.storage.get_unsigned_integer_bits());
+        let bits = bits_struct
+            .fields
+            .iter()
+            .map(|field| {
+                (
+                    field.ident.to_string(),
+                    field.bits,
+                    field.conversion.is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("kind".to_owned(), 4, true),
+                ("_".to_owned(), 4, false),
+                ("length".to_owned(), 8, false),
+            ],
+            bits
+        );
+        assert_eq!(1, bits_struct.fields[2].attrs.len());
+
+        let invalid = [
+            quote! { struct A: u8 { a: 4, b: 3 } },
+            quote! { struct A: u8 { a: 4, b: 5 } },
+            quote! { struct A: u8 { a: 0, b: 8 } },
+            quote! { struct A: i8 { a: 8 } },
+            quote! { struct A: u128be { a: 128 } },
+            quote! { struct A: u8 { _: 8 as Kind } },
+        ];
+        for tokens in invalid {
+            assert!(syn::parse2::<BitsStruct>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn byteview_enum() {
+        let tokens = quote! {
+            #[derive(Debug)]
+            pub enum LogType: u16 {
+                System = 0,
+                /// Communication.
+                Comm = 1,
+                Unknown(u16),
+            }
+        };
+        let byteview_enum: ByteViewEnum = syn::parse2(tokens).unwrap();
+        assert_eq!("u16", byteview_enum.repr.to_string());
+        assert_eq!(2, byteview_enum.variants.len());
+        assert_eq!(1, byteview_enum.variants[1].0.len());
+        assert_eq!(
+            Some("Unknown".to_owned()),
+            byteview_enum.unknown.map(|(_, ident)| ident.to_string())
+        );
+
+        let invalid = [
+            quote! { enum A: u8 { B } },
+            quote! { enum A: u8 { B(u16) } },
+            quote! { enum A: u8 { B { value: u8 } } },
+            quote! { enum A: u8 { B(u8), C(u8) } },
+            quote! { enum A: u8 { B(u8) = 1 } },
+            quote! { enum A: f32 { B = 1 } },
+            quote! { enum A: u16be { B = 1 } },
+        ];
+        for tokens in invalid {
+            assert!(syn::parse2::<ByteViewEnum>(tokens).is_err());
+        }
+    }
+
+    #[test]
+    pub fn ref_field() {
+        // Use a raw string here so we can look at the span byte offsets if needed
+        let s = "struct Foo { x: &[u8; 32], }";
+        let tokens: proc_macro2::TokenStream = s.parse().unwrap();
+
+        let byteview_struct: Result<ByteViewStruct, _> = syn::parse2(tokens);
+        assert!(byteview_struct.is_err());
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local};
 use std::borrow::Cow;
 
 pub struct Header<'a> {
@@ -26,9 +26,12 @@ impl Header<'_> {
 byteview::byteview_ref! {
     pub struct HeaderStart {
         _file_name: [u8; 32],
-        _earliest_date_epoch: u32be,
-        _latest_date_epoch: u32be,
-        _log_type: u8,
+        /// The earliest date in Utc.
+        pub earliest_date_utc: timestamp32be,
+        /// The latest date in Utc.
+        pub latest_date_utc: timestamp32be,
+        /// The [`LogType`] for this header.
+        pub log_type: u8 as LogType,
         pub num_fields: u8,
     }
 }
@@ -40,39 +43,19 @@ impl HeaderStart<'_> {
     }
 
     /// The name of the file this header is associated with (lossily converted).
-    pub fn file_name_lossy(&self) -> Cow<str> {
+    pub fn file_name_lossy(&self) -> Cow<'_, str> {
         null_terminated_string_lossy(self._file_name())
     }
 
-    /// The earliest date in Utc.
-    pub fn earliest_date_utc(&self) -> Option<DateTime<Utc>> {
-        from_unix_epoch(self._earliest_date_epoch())
-    }
-
     /// The earliest date in the Local time zone.
     pub fn earliest_date_local(&self) -> Option<DateTime<Local>> {
         self.earliest_date_utc().map(|dt| dt.with_timezone(&Local))
     }
 
-    /// The latest date in Utc.
-    pub fn latest_date_utc(&self) -> Option<DateTime<Utc>> {
-        from_unix_epoch(self._latest_date_epoch())
-    }
-
     /// The latest date in the Local time zone.
     pub fn latest_date_local(&self) -> Option<DateTime<Local>> {
         self.latest_date_utc().map(|dt| dt.with_timezone(&Local))
     }
-
-    /// The [`LogType`] for this header.
-    pub fn log_type(&self) -> Result<LogType, u8> {
-        Ok(match self._log_type() {
-            0 => LogType::System,
-            1 => LogType::Comm,
-            2 => LogType::Debug,
-            byte => return Err(byte),
-        })
-    }
 }
 
 byteview::byteview_ref! {
@@ -92,7 +75,7 @@ impl FieldDefinition<'_> {
     }
 
     /// The name of the field (lossily converted).
-    pub fn name_lossy(&self) -> Cow<str> {
+    pub fn name_lossy(&self) -> Cow<'_, str> {
         null_terminated_string_lossy(self._name())
     }
 
@@ -102,11 +85,13 @@ impl FieldDefinition<'_> {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
-pub enum LogType {
-    System,
-    Comm,
-    Debug,
+byteview::byteview_enum! {
+    #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+    pub enum LogType: u8 {
+        System = 0,
+        Comm = 1,
+        Debug = 2,
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
@@ -152,17 +137,10 @@ fn null_terminated_string(bytes: &[u8]) -> Option<String> {
     String::from_utf8(null_terminated_bytes(bytes).to_vec()).ok()
 }
 
-fn null_terminated_string_lossy(bytes: &[u8]) -> Cow<str> {
+fn null_terminated_string_lossy(bytes: &[u8]) -> Cow<'_, str> {
     String::from_utf8_lossy(null_terminated_bytes(bytes))
 }
 
-fn from_unix_epoch(num_seconds: u32) -> Option<DateTime<Utc>> {
-    if num_seconds == 0 {
-        return None;
-    }
-    DateTime::<Utc>::from_timestamp(num_seconds.into(), 0)
-}
-
 // pub fn main() {
 //     // Read all fields
 //     let contents = std::fs::read("sample.log").unwrap();
@@ -41,7 +41,7 @@ impl HeaderStart {
         null_terminated_string(&self.file_name)
     }
 
-    pub fn file_name_lossy(&self) -> Cow<str> {
+    pub fn file_name_lossy(&self) -> Cow<'_, str> {
         null_terminated_string_lossy(&self.file_name)
     }
 
@@ -76,7 +76,7 @@ impl FieldDefinition {
     }
 
     /// The name of the field (lossily converted).
-    pub fn name_lossy(&self) -> Cow<str> {
+    pub fn name_lossy(&self) -> Cow<'_, str> {
         null_terminated_string_lossy(&self.name)
     }
 }
@@ -190,6 +190,6 @@ fn null_terminated_string(bytes: &[u8]) -> Option<String> {
     String::from_utf8(null_terminated_bytes(bytes).to_vec()).ok()
 }
 
-fn null_terminated_string_lossy(bytes: &[u8]) -> Cow<str> {
+fn null_terminated_string_lossy(bytes: &[u8]) -> Cow<'_, str> {
     String::from_utf8_lossy(null_terminated_bytes(bytes))
 }
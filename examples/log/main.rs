@@ -51,7 +51,7 @@ pub fn main() {
 
     println!("=== Try with bad log_type value ===");
     let mut contents = contents.to_vec();
-    contents[40] = 7; // Index of log_type = 40, 7 is NOT a valid LogType value
+    contents[log_byteview::HeaderStart::OFFSET_LOG_TYPE] = 7; // 7 is NOT a valid LogType value
 
     println!("= byteview =");
     let (header, _rest_bytes) = log_byteview::Header::split_slice(&contents).unwrap();
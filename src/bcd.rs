@@ -0,0 +1,39 @@
+//! Packed binary-coded decimal (BCD) numbers, the type of `bcd<N>` fields.
+
+use std::fmt;
+
+/// A byte of a `bcd<N>` field holds a nibble that is not a decimal digit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct BcdError {
+    /// The index of the byte within the field.
+    pub index: usize,
+    /// The value of the byte.
+    pub byte: u8,
+}
+
+impl fmt::Display for BcdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "byte {} of a BCD number is {:#04x}, which is not two decimal digits",
+            self.index, self.byte
+        )
+    }
+}
+
+impl std::error::Error for BcdError {}
+
+// Decode packed BCD with the most significant digit first, two digits per byte. At most 9 bytes (18 digits) are allowed
+// so that every value fits in a u64.
+pub fn decode_bcd<const N: usize>(bytes: &[u8; N]) -> Result<u64, BcdError> {
+    const { assert!(N <= 9, "a BCD field can have at most 9 bytes") };
+    let mut value = 0;
+    for (index, &byte) in bytes.iter().enumerate() {
+        let (high, low) = (byte >> 4, byte & 0x0F);
+        if high > 9 || low > 9 {
+            return Err(BcdError { index, byte });
+        }
+        value = value * 100 + u64::from(high) * 10 + u64::from(low);
+    }
+    Ok(value)
+}
@@ -0,0 +1,128 @@
+//! Byte arrays whose bits each hold a flag, e.g. whether a block is in use.
+
+use std::iter::FusedIterator;
+
+/// The order of the bits within each byte of a [`Bitmap`]. Bytes are always in order, so bit 8 is in the second byte.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub enum BitOrder {
+    /// Bit 0 is the least significant bit of the first byte.
+    #[default]
+    Lsb0,
+    /// Bit 0 is the most significant bit of the first byte.
+    Msb0,
+}
+
+impl BitOrder {
+    // The index of the byte containing bit `i` and the mask of the bit within that byte.
+    fn locate(self, i: usize) -> (usize, u8) {
+        let shift = match self {
+            BitOrder::Lsb0 => i % 8,
+            BitOrder::Msb0 => 7 - i % 8,
+        };
+        (i / 8, 1 << shift)
+    }
+
+    /// Set or clear bit `i` of `bytes` and return its previous value, or return `None` without changing anything if `i`
+    /// is out of range.
+    pub fn set_bit(self, bytes: &mut [u8], i: usize, value: bool) -> Option<bool> {
+        let (index, mask) = self.locate(i);
+        let byte = bytes.get_mut(index)?;
+        let previous = *byte & mask != 0;
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+        Some(previous)
+    }
+}
+
+/// A read-only view of the bits of a byte array.
+///
+/// ```
+/// use byteview::{BitOrder, Bitmap};
+///
+/// let bitmap = Bitmap::new(&[0b0000_0101, 0b1000_0000], BitOrder::Lsb0);
+/// assert_eq!(Some(true), bitmap.bit(2));
+/// assert_eq!(Some(true), bitmap.bit(15));
+/// assert_eq!(None, bitmap.bit(16));
+/// assert_eq!(3, bitmap.count_ones());
+/// assert_eq!(vec![0, 2, 15], bitmap.iter_ones().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bitmap<'a> {
+    bytes: &'a [u8],
+    order: BitOrder,
+}
+
+impl<'a> Bitmap<'a> {
+    /// Create a view of the bits of `bytes`.
+    pub fn new(bytes: &'a [u8], order: BitOrder) -> Self {
+        Self { bytes, order }
+    }
+
+    /// The number of bits.
+    pub fn len(&self) -> usize {
+        self.bytes.len() * 8
+    }
+
+    /// Whether there are no bits.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The bytes containing the bits.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The order of the bits within each byte.
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Whether bit `i` is set, or `None` if `i` is out of range.
+    pub fn bit(&self, i: usize) -> Option<bool> {
+        let (index, mask) = self.order.locate(i);
+        self.bytes.get(index).map(|byte| byte & mask != 0)
+    }
+
+    /// The number of bits that are set.
+    pub fn count_ones(&self) -> u32 {
+        self.bytes.iter().map(|byte| byte.count_ones()).sum()
+    }
+
+    /// An iterator over the indices of the bits that are set, in increasing order.
+    pub fn iter_ones(&self) -> BitmapOnes<'a> {
+        BitmapOnes {
+            bitmap: *self,
+            next: 0,
+        }
+    }
+}
+
+/// An iterator over the indices of the bits that are set in a [`Bitmap`].
+///
+/// See [`Bitmap::iter_ones`].
+#[derive(Debug, Clone)]
+pub struct BitmapOnes<'a> {
+    bitmap: Bitmap<'a>,
+    next: usize,
+}
+
+impl Iterator for BitmapOnes<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(bit) = self.bitmap.bit(self.next) {
+            let i = self.next;
+            self.next += 1;
+            if bit {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for BitmapOnes<'_> {}
@@ -0,0 +1,148 @@
+//! Writing records whose header contains the length of the record.
+
+use crate::{ByteView, FieldKind, LengthField};
+use std::fmt;
+
+/// The reason a [`LengthPrefixedBuilder`] could not write a record.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BuildError {
+    /// The header does not have a length field.
+    NoLengthField,
+    /// The length field is not an unsigned integer of at most 64 bits.
+    UnsupportedLengthField { kind: FieldKind },
+    /// The length of the record does not fit in the length field.
+    LengthOverflow { len: usize, size: usize },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::NoLengthField => write!(f, "the header does not have a length field"),
+            BuildError::UnsupportedLengthField { kind } => {
+                write!(f, "a field of kind {kind:?} cannot hold a length")
+            }
+            BuildError::LengthOverflow { len, size } => {
+                write!(f, "a length of {len} does not fit in {size} bytes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Writes a record whose header contains the length of the record, patching the length once the body is complete.
+///
+/// The header is written with its length field zeroed. Body bytes (or nested records) are then appended, and
+/// [`finish`](LengthPrefixedBuilder::finish) writes the final length into the length field. If the builder is dropped
+/// without calling `finish`, the length field is left as zero.
+///
+/// ```
+/// use byteview::{LengthPrefixedBuilder, byteview_owned};
+///
+/// byteview_owned! {
+///     pub struct Header {
+///         pub kind: u8,
+///         #[byteview(len_of_rest)]
+///         pub body_len: u16be,
+///     }
+/// }
+///
+/// let mut buffer = Vec::new();
+/// let mut builder = LengthPrefixedBuilder::new(&mut buffer, &Header::from_array([7, 0xFF, 0xFF])).unwrap();
+/// builder.extend_from_slice(b"body");
+/// builder.finish().unwrap();
+/// assert_eq!(b"\x07\x00\x04body", &buffer[..]);
+/// ```
+#[derive(Debug)]
+pub struct LengthPrefixedBuilder<'b> {
+    buffer: &'b mut Vec<u8>,
+    start: usize,
+    header_len: usize,
+    length: LengthField,
+}
+
+impl<'b> LengthPrefixedBuilder<'b> {
+    /// Start a record by appending `header` to `buffer`, using the header's [`LENGTH_FIELD`](ByteView::LENGTH_FIELD).
+    pub fn new<'a, T: ByteView<'a>>(
+        buffer: &'b mut Vec<u8>,
+        header: &T,
+    ) -> Result<Self, BuildError> {
+        let length = T::LENGTH_FIELD.ok_or(BuildError::NoLengthField)?;
+        Self::with_length_field(buffer, header.as_bytes(), length)
+    }
+
+    /// Start a record by appending the bytes of a header to `buffer`, using `length` as the length field.
+    pub fn with_length_field(
+        buffer: &'b mut Vec<u8>,
+        header: &[u8],
+        length: LengthField,
+    ) -> Result<Self, BuildError> {
+        let kind = length.field.kind;
+        if !kind.is_unsigned_integer() {
+            return Err(BuildError::UnsupportedLengthField { kind });
+        }
+        let start = buffer.len();
+        buffer.extend_from_slice(header);
+        let range = length.field.range();
+        buffer[start + range.start..start + range.end].fill(0);
+        Ok(Self {
+            buffer,
+            start,
+            header_len: header.len(),
+            length,
+        })
+    }
+
+    /// Append bytes to the body of the record.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) -> &mut Self {
+        self.buffer.extend_from_slice(bytes);
+        self
+    }
+
+    /// Append a single byte to the body of the record.
+    pub fn push(&mut self, byte: u8) -> &mut Self {
+        self.buffer.push(byte);
+        self
+    }
+
+    /// Append the bytes of a struct to the body of the record.
+    pub fn append<'a, T: ByteView<'a>>(&mut self, view: &T) -> &mut Self {
+        self.extend_from_slice(view.as_bytes())
+    }
+
+    /// Start a nested record in the body of this record.
+    ///
+    /// The nested record must be finished before more bytes can be appended to this record.
+    pub fn nested<'a, T: ByteView<'a>>(
+        &mut self,
+        header: &T,
+    ) -> Result<LengthPrefixedBuilder<'_>, BuildError> {
+        LengthPrefixedBuilder::new(self.buffer, header)
+    }
+
+    /// The number of bytes written to the record so far, including the header.
+    pub fn record_len(&self) -> usize {
+        self.buffer.len() - self.start
+    }
+
+    /// Write the length of the record into the length field and return the number of bytes in the record.
+    pub fn finish(self) -> Result<usize, BuildError> {
+        let record_len = self.record_len();
+        let len = if self.length.includes_header {
+            record_len
+        } else {
+            record_len - self.header_len
+        };
+        let field = self.length.field;
+        let bytes = field
+            .kind
+            .encode_unsigned(len as u64)
+            .ok_or(BuildError::LengthOverflow {
+                len,
+                size: field.size,
+            })?;
+        let start = self.start + field.offset;
+        self.buffer[start..start + field.size].copy_from_slice(&bytes);
+        Ok(record_len)
+    }
+}
@@ -0,0 +1,149 @@
+//! Collecting structs stored back to back into a buffer of bytes.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::ByteView;
+
+/// The bytes of structs stored back to back, collected from an iterator.
+///
+/// Each struct is appended with [`ByteView::as_bytes`], so variable-sized records include their bodies. Collecting is
+/// the inverse of iterating with [`Records`](crate::Records) or [`Table`](crate::Table).
+///
+/// ```
+/// use byteview::{RecordBytes, byteview_owned};
+///
+/// byteview_owned! {
+///     pub struct Sample {
+///         pub sensor: u8,
+///         pub value: u16be,
+///     }
+/// }
+///
+/// let bytes = [(1, 0x0102), (2, 0x0304)]
+///     .into_iter()
+///     .map(|(sensor, value): (u8, u16)| {
+///         let [high, low] = value.to_be_bytes();
+///         Sample::from_array([sensor, high, low])
+///     })
+///     .collect::<RecordBytes<Sample>>()
+///     .into_vec();
+/// assert_eq!(vec![1, 1, 2, 2, 3, 4], bytes);
+/// ```
+pub struct RecordBytes<T> {
+    bytes: Vec<u8>,
+    len: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> RecordBytes<T> {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The number of structs in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the buffer contains no structs.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bytes of the structs.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Return the bytes of the structs.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl<'a, T: ByteView<'a>> Extend<T> for RecordBytes<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let len = &mut self.len;
+        self.bytes
+            .extend_records(iter.into_iter().inspect(|_| *len += 1));
+    }
+}
+
+impl<'a, T: ByteView<'a>> FromIterator<T> for RecordBytes<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl<T> Default for RecordBytes<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for RecordBytes<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            len: self.len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for RecordBytes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordBytes")
+            .field("bytes", &self.bytes)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T> From<RecordBytes<T>> for Vec<u8> {
+    fn from(records: RecordBytes<T>) -> Self {
+        records.into_vec()
+    }
+}
+
+/// Appending structs to a buffer of bytes.
+pub trait ExtendRecords {
+    /// Append the bytes of each struct, reserving space for them first based on the iterator's
+    /// [`size_hint`](Iterator::size_hint) and [`MIN_NUM_BYTES`](ByteView::MIN_NUM_BYTES).
+    ///
+    /// ```
+    /// use byteview::{ExtendRecords, byteview_ref};
+    ///
+    /// byteview_ref! {
+    ///     pub struct Pair {
+    ///         pub a: u8,
+    ///         pub b: u8,
+    ///     }
+    /// }
+    ///
+    /// let source = [1, 2, 3, 4, 5, 6];
+    /// let mut buf = vec![0xFF];
+    /// buf.extend_records(source.chunks(2).rev().map(|chunk| Pair::from_exact_slice(chunk).unwrap()));
+    /// assert_eq!(vec![0xFF, 5, 6, 3, 4, 1, 2], buf);
+    /// ```
+    fn extend_records<'a, T: ByteView<'a>>(&mut self, records: impl IntoIterator<Item = T>);
+}
+
+impl ExtendRecords for Vec<u8> {
+    fn extend_records<'a, T: ByteView<'a>>(&mut self, records: impl IntoIterator<Item = T>) {
+        let records = records.into_iter();
+        let (lower, _) = records.size_hint();
+        self.reserve(lower.saturating_mul(T::MIN_NUM_BYTES));
+        for record in records {
+            self.extend_from_slice(record.as_bytes());
+        }
+    }
+}
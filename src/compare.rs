@@ -0,0 +1,172 @@
+//! Comparing the bytes of two views field by field, for tests.
+
+use crate::{ByteView, FieldInfo, Layout, Value};
+use std::fmt::{self, Write};
+
+/// Assert that two views contain the same bytes, like [`assert_eq!`].
+///
+/// The views must have the same layout but can be different types, e.g. a struct generated by
+/// [`byteview_ref!`](crate::byteview_ref) and one generated by [`byteview_owned!`](crate::byteview_owned) from the
+/// same fields. If the bytes differ, the panic message includes a table of the fields that differ with their offsets,
+/// bytes, and decoded values. A custom message can be added with format arguments, as with `assert_eq!`.
+///
+/// ```
+/// byteview::byteview_ref! {
+///     pub struct HeaderRef {
+///         pub magic: [u8; 2],
+///         pub count: u16be,
+///     }
+/// }
+///
+/// byteview::byteview_owned! {
+///     pub struct HeaderOwned {
+///         pub magic: [u8; 2],
+///         pub count: u16be,
+///     }
+/// }
+///
+/// let bytes = *b"HD\x00\x07";
+/// byteview::assert_views_eq!(HeaderRef::from_array(&bytes), HeaderOwned::from_array(bytes));
+///
+/// let result = std::panic::catch_unwind(|| {
+///     byteview::assert_views_eq!(HeaderRef::from_array(b"HD\x00\x07"), HeaderRef::from_array(b"HD\x00\x08"));
+/// });
+/// assert!(result.is_err());
+/// ```
+#[macro_export]
+macro_rules! assert_views_eq {
+    ($expected:expr, $actual:expr $(,)?) => {
+        $crate::__private::assert_views_eq(&$expected, &$actual, ::std::option::Option::None)
+    };
+    ($expected:expr, $actual:expr, $($arg:tt)+) => {
+        $crate::__private::assert_views_eq(
+            &$expected,
+            &$actual,
+            ::std::option::Option::Some(::std::format_args!($($arg)+)),
+        )
+    };
+}
+
+// The implementation of `assert_views_eq!`.
+#[track_caller]
+pub fn assert_views_eq<'a, 'b, E: ByteView<'a>, A: ByteView<'b>>(
+    expected: &E,
+    actual: &A,
+    message: Option<fmt::Arguments<'_>>,
+) {
+    let (layout, other_layout) = (E::LAYOUT, A::LAYOUT);
+    if layout.num_bytes != other_layout.num_bytes || layout.fields != other_layout.fields {
+        panic!(
+            "assertion `expected == actual` failed: `{}` and `{}` have different layouts",
+            layout.name, other_layout.name
+        );
+    }
+    let (expected, actual) = (expected.as_bytes(), actual.as_bytes());
+    if expected == actual {
+        return;
+    }
+    let rows = diff_rows(&layout, expected, actual);
+    let summary = format!(
+        "`{}` views differ in {} field{}",
+        layout.name,
+        rows.len() - 1,
+        if rows.len() == 2 { "" } else { "s" }
+    );
+    let mut report = String::from("assertion `expected == actual` failed: ");
+    match message {
+        Some(message) => write!(report, "{message}\n{summary}"),
+        None => write!(report, "{summary}"),
+    }
+    .expect("writing to a String cannot fail");
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        write!(report, "\n  {}", line.trim_end()).expect("writing to a String cannot fail");
+    }
+    panic!("{report}");
+}
+
+// The header and one row for each field that differs, plus a row for any differing bytes after the fields (e.g. the
+// body of a variable-sized record).
+fn diff_rows<'a>(layout: &Layout, expected: &'a [u8], actual: &'a [u8]) -> Vec<[String; 6]> {
+    let mut rows = vec![
+        [
+            "field",
+            "bytes",
+            "expected",
+            "actual",
+            "expected value",
+            "actual value",
+        ]
+        .map(String::from),
+    ];
+    for field in layout.fields {
+        let range = field.range();
+        // Optional fields may be missing from shorter records.
+        let (expected_field, actual_field) =
+            (expected.get(range.clone()), actual.get(range.clone()));
+        if expected_field == actual_field {
+            continue;
+        }
+        let describe = |bytes: Option<&[u8]>| match bytes {
+            Some(bytes) => (hex(bytes), decode(field, bytes)),
+            None => ("(missing)".to_owned(), "-".to_owned()),
+        };
+        let (expected_hex, expected_value) = describe(expected_field);
+        let (actual_hex, actual_value) = describe(actual_field);
+        rows.push([
+            field.name.to_owned(),
+            format!("{}..{}", range.start, range.end),
+            expected_hex,
+            actual_hex,
+            expected_value,
+            actual_value,
+        ]);
+    }
+    let rest = |bytes: &'a [u8]| bytes.get(layout.num_bytes..).unwrap_or_default();
+    let (expected_rest, actual_rest) = (rest(expected), rest(actual));
+    if expected_rest != actual_rest {
+        let end = layout.num_bytes + expected_rest.len().max(actual_rest.len());
+        rows.push([
+            "(rest)".to_owned(),
+            format!("{}..{end}", layout.num_bytes),
+            hex(expected_rest),
+            hex(actual_rest),
+            format!("{} bytes", expected_rest.len()),
+            format!("{} bytes", actual_rest.len()),
+        ]);
+    }
+    rows
+}
+
+// Format bytes as space-separated hexadecimal, truncating long fields.
+fn hex(bytes: &[u8]) -> String {
+    const MAX_BYTES: usize = 16;
+    let mut hex = bytes
+        .iter()
+        .take(MAX_BYTES)
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    if bytes.len() > MAX_BYTES {
+        hex.push_str(" ..");
+    }
+    hex
+}
+
+// Decode the bytes of a numeric field, or return "-" for byte arrays.
+fn decode(field: &FieldInfo, bytes: &[u8]) -> String {
+    match Value::decode(field.kind, bytes) {
+        Value::Unsigned(unsigned) => unsigned.to_string(),
+        Value::Signed(signed) => signed.to_string(),
+        Value::Float(float) => float.to_string(),
+        Value::Bytes(_) | Value::Bit { .. } => "-".to_owned(),
+    }
+}
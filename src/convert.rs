@@ -0,0 +1,149 @@
+//! Conversions used by the "getter" methods of fields declared with `as`.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::num::NonZero;
+
+/// A type that a numeric field can be converted to with `as`, e.g. `pub info: u8 as DataInfo`. The "getter" of the
+/// field returns [`Output`](FromFieldValue::Output), which is usually `Self` or a `Result` for fallible conversions.
+/// Byte array fields can also be converted, e.g. `pub signature: [u8; 8] as Signature`, in which case `T` is a reference
+/// to the array.
+///
+/// This is implemented by types defined with [`byteview_bits!`](crate::byteview_bits).
+///
+/// ```
+/// use byteview::FromFieldValue;
+///
+/// pub struct Percent(u8);
+///
+/// impl FromFieldValue<u8> for Percent {
+///     type Output = Result<Percent, u8>;
+///
+///     fn from_field_value(value: u8) -> Self::Output {
+///         if value <= 100 { Ok(Percent(value)) } else { Err(value) }
+///     }
+/// }
+///
+/// byteview::byteview_ref! {
+///     pub struct Battery {
+///         pub charge: u8 as Percent,
+///     }
+/// }
+///
+/// assert_eq!(Some(50), Battery::from_array(&[50]).charge().ok().map(|percent| percent.0));
+/// assert_eq!(Err(200), Battery::from_array(&[200]).charge().map(|percent| percent.0));
+/// ```
+pub trait FromFieldValue<T> {
+    /// The type returned by the "getter" of the field.
+    type Output;
+
+    /// Convert the value of the field as it is stored in the bytes.
+    fn from_field_value(value: T) -> Self::Output;
+}
+
+/// A `bool` field is a byte that must be 0 (`false`) or 1 (`true`). Any other byte is returned as an error.
+impl FromFieldValue<u8> for bool {
+    type Output = Result<bool, u8>;
+
+    fn from_field_value(value: u8) -> Self::Output {
+        match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(value),
+        }
+    }
+}
+
+/// A `charbe` field (or `charle` or `charne`) is a 32-bit Unicode scalar value. Surrogates and values above
+/// `0x10FFFF` are [`None`].
+impl FromFieldValue<u32> for char {
+    type Output = Option<char>;
+
+    fn from_field_value(value: u32) -> Self::Output {
+        char::from_u32(value)
+    }
+}
+
+macro_rules! impl_from_field_value_for_non_zero {
+    ($($value:ty),*) => {
+        $(
+            /// A `nonzero_` field (e.g. `nonzero_u32be`) is an integer that must not be zero. Zero is [`None`].
+            impl FromFieldValue<$value> for NonZero<$value> {
+                type Output = Option<NonZero<$value>>;
+
+                fn from_field_value(value: $value) -> Self::Output {
+                    NonZero::new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field_value_for_non_zero!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// An `ipv4` field is a `[u8; 4]` converted to an [`Ipv4Addr`], in network byte order.
+impl FromFieldValue<&[u8; 4]> for Ipv4Addr {
+    type Output = Ipv4Addr;
+
+    fn from_field_value(value: &[u8; 4]) -> Self::Output {
+        Ipv4Addr::from(*value)
+    }
+}
+
+/// An `ipv6` field is a `[u8; 16]` converted to an [`Ipv6Addr`], in network byte order.
+impl FromFieldValue<&[u8; 16]> for Ipv6Addr {
+    type Output = Ipv6Addr;
+
+    fn from_field_value(value: &[u8; 16]) -> Self::Output {
+        Ipv6Addr::from(*value)
+    }
+}
+
+/// An `ascii<N>` field is printable ASCII text padded with trailing spaces or NUL bytes. The padding is trimmed, and
+/// text containing any other byte (including a NUL byte before the padding) is [`None`].
+impl<'a, const N: usize> FromFieldValue<&'a [u8; N]> for str {
+    type Output = Option<&'a str>;
+
+    fn from_field_value(value: &'a [u8; N]) -> Self::Output {
+        let len = value
+            .iter()
+            .rposition(|byte| !matches!(byte, b' ' | 0))
+            .map_or(0, |i| i + 1);
+        let text = &value[..len];
+        if !text.iter().all(|byte| (b' '..=b'~').contains(byte)) {
+            return None;
+        }
+        std::str::from_utf8(text).ok()
+    }
+}
+
+/// Convert each byte of an array with `TryFrom<u8>`. If a byte cannot be converted, return its index and value.
+pub fn convert_byte_array<T: TryFrom<u8>, const N: usize>(
+    bytes: &[u8; N],
+) -> Result<[T; N], (usize, u8)> {
+    let mut values = Vec::with_capacity(N);
+    for (i, byte) in bytes.iter().enumerate() {
+        values.push(T::try_from(*byte).map_err(|_| (i, *byte))?);
+    }
+    match values.try_into() {
+        Ok(values) => Ok(values),
+        Err(_) => unreachable!("there is one value for each byte"),
+    }
+}
+
+// Decode UTF-16 code units up to the first NUL, for `utf16be<N>` and `utf16le<N>` fields. Invalid UTF-16 is `None`.
+pub fn decode_utf16(units: &[u16]) -> Option<String> {
+    String::from_utf16(until_nul(units)).ok()
+}
+
+// Decode UTF-16 code units up to the first NUL, replacing invalid UTF-16 with U+FFFD.
+pub fn decode_utf16_lossy(units: &[u16]) -> String {
+    String::from_utf16_lossy(until_nul(units))
+}
+
+fn until_nul(units: &[u16]) -> &[u16] {
+    let len = units
+        .iter()
+        .position(|unit| *unit == 0)
+        .unwrap_or(units.len());
+    &units[..len]
+}
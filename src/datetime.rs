@@ -0,0 +1,100 @@
+//! Conversions to date and time types used by the "getter" methods of date and time fields.
+
+use crate::FromFieldValue;
+use std::time::{Duration, SystemTime};
+
+// The largest time zone offset accepted, in seconds. No time zone is more than 18 hours from UTC.
+#[cfg(any(feature = "chrono", feature = "time"))]
+const MAX_TZ_OFFSET_SECONDS: i32 = 18 * 60 * 60;
+
+// Create a time zone offset east of UTC, or return None if it is more than 18 hours from UTC.
+#[cfg(feature = "chrono")]
+pub fn chrono_fixed_offset(seconds: i32) -> Option<chrono::FixedOffset> {
+    if seconds.abs() > MAX_TZ_OFFSET_SECONDS {
+        return None;
+    }
+    chrono::FixedOffset::east_opt(seconds)
+}
+
+// Create a time zone offset east of UTC, or return None if it is more than 18 hours from UTC.
+#[cfg(feature = "time")]
+pub fn time_utc_offset(seconds: i32) -> Option<time::UtcOffset> {
+    if seconds.abs() > MAX_TZ_OFFSET_SECONDS {
+        return None;
+    }
+    time::UtcOffset::from_whole_seconds(seconds).ok()
+}
+
+// Convert a number of units of time, each of `nanos_per_unit` nanoseconds, to a `Duration`, saturating at
+// `Duration::MAX`. This is used by fields annotated with `#[byteview(duration(unit = "..."))]`.
+pub fn duration_from_units(units: u64, nanos_per_unit: u64) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    let nanos = u128::from(units) * u128::from(nanos_per_unit);
+    match u64::try_from(nanos / NANOS_PER_SEC) {
+        Ok(secs) => Duration::new(secs, (nanos % NANOS_PER_SEC) as u32),
+        Err(_) => Duration::MAX,
+    }
+}
+
+/// A `timestamp_ms64be` field (or `timestamp_ms64le` or `timestamp_ms64ne`) holds the number of milliseconds since the
+/// Unix epoch. Zero means that the time is not set and is [`None`], as are times that `SystemTime` cannot represent.
+impl FromFieldValue<u64> for SystemTime {
+    type Output = Option<SystemTime>;
+
+    fn from_field_value(value: u64) -> Self::Output {
+        if value == 0 {
+            return None;
+        }
+        SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(value))
+    }
+}
+
+/// A `timestamp32be` field (or `timestamp32le` or `timestamp32ne`) holds the number of seconds since the Unix epoch. Zero
+/// means that the time is not set and is [`None`].
+#[cfg(feature = "chrono")]
+impl FromFieldValue<u32> for chrono::DateTime<chrono::Utc> {
+    type Output = Option<chrono::DateTime<chrono::Utc>>;
+
+    fn from_field_value(value: u32) -> Self::Output {
+        Self::from_field_value(i64::from(value))
+    }
+}
+
+/// A `timestamp64be` field (or `timestamp64le` or `timestamp64ne`) holds the signed number of seconds since the Unix
+/// epoch. Zero means that the time is not set and is [`None`], as are times that `DateTime` cannot represent.
+#[cfg(feature = "chrono")]
+impl FromFieldValue<i64> for chrono::DateTime<chrono::Utc> {
+    type Output = Option<chrono::DateTime<chrono::Utc>>;
+
+    fn from_field_value(value: i64) -> Self::Output {
+        if value == 0 {
+            return None;
+        }
+        chrono::DateTime::from_timestamp(value, 0)
+    }
+}
+
+/// A `timestamp32be` field (or `timestamp32le` or `timestamp32ne`) holds the number of seconds since the Unix epoch. Zero
+/// means that the time is not set and is [`None`].
+#[cfg(feature = "time")]
+impl FromFieldValue<u32> for time::OffsetDateTime {
+    type Output = Option<time::OffsetDateTime>;
+
+    fn from_field_value(value: u32) -> Self::Output {
+        Self::from_field_value(i64::from(value))
+    }
+}
+
+/// A `timestamp64be` field (or `timestamp64le` or `timestamp64ne`) holds the signed number of seconds since the Unix
+/// epoch. Zero means that the time is not set and is [`None`], as are times that `OffsetDateTime` cannot represent.
+#[cfg(feature = "time")]
+impl FromFieldValue<i64> for time::OffsetDateTime {
+    type Output = Option<time::OffsetDateTime>;
+
+    fn from_field_value(value: i64) -> Self::Output {
+        if value == 0 {
+            return None;
+        }
+        time::OffsetDateTime::from_unix_timestamp(value).ok()
+    }
+}
@@ -0,0 +1,232 @@
+//! Parsing a stream of different record types distinguished by a tag byte.
+
+use crate::{ByteView, ParseError};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// The reason a record could not be parsed by a [`Dispatcher`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DispatchError {
+    /// There are no more records.
+    Empty,
+    /// No parser is registered for the tag and the record cannot be skipped.
+    UnknownTag(u8),
+    /// The parser registered for the tag failed, or an unknown record declares an invalid length.
+    Parse { tag: u8, error: ParseError },
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DispatchError::Empty => write!(f, "there are no more records"),
+            DispatchError::UnknownTag(tag) => write!(f, "unknown tag {tag:#04x}"),
+            DispatchError::Parse { tag, error } => {
+                write!(f, "invalid record with tag {tag:#04x}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DispatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DispatchError::Parse { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+type Parser<'a, R> = Box<dyn Fn(&'a [u8]) -> Result<(R, &'a [u8]), ParseError> + 'a>;
+type RecordLen<'a> = Box<dyn Fn(&'a [u8]) -> Option<usize> + 'a>;
+
+/// Parses records whose first byte is a tag identifying the type of the record.
+///
+/// Each tag is registered with a parser that creates a caller-defined record type `R` (usually an enum with one
+/// variant per record type). Records with unknown tags are skipped if [`skip_unknown`](Dispatcher::skip_unknown) has
+/// been called with a way to find the length of a record.
+///
+/// ```
+/// use byteview::{Dispatcher, byteview_ref};
+///
+/// byteview_ref! {
+///     pub struct Ping {
+///         pub tag: u8,
+///         pub record_len: u8,
+///         pub id: u16be,
+///     }
+/// }
+///
+/// enum Record<'a> {
+///     Ping(Ping<'a>),
+/// }
+///
+/// let bytes = [9, 3, 0xFF, 1, 4, 0x12, 0x34];
+///
+/// let mut dispatcher = Dispatcher::new();
+/// dispatcher.register(1, Record::Ping);
+/// dispatcher.skip_unknown(|bytes| bytes.get(1).map(|len| usize::from(*len)));
+///
+/// let (record, rest) = dispatcher.next(&bytes).unwrap();
+/// assert!(matches!(record, Record::Ping(ping) if ping.id() == 0x1234));
+/// assert!(rest.is_empty());
+/// ```
+pub struct Dispatcher<'a, R> {
+    parsers: BTreeMap<u8, Parser<'a, R>>,
+    record_len: Option<RecordLen<'a>>,
+}
+
+impl<'a, R> Dispatcher<'a, R> {
+    /// Create a dispatcher with no registered tags.
+    pub fn new() -> Self {
+        Self {
+            parsers: BTreeMap::new(),
+            record_len: None,
+        }
+    }
+
+    /// Parse records with `tag` as a `T` and convert them to a record with `convert`.
+    ///
+    /// The bytes passed to `T` include the tag byte. If `tag` is already registered, the previous parser is replaced.
+    pub fn register<T: ByteView<'a>>(
+        &mut self,
+        tag: u8,
+        convert: impl Fn(T) -> R + 'a,
+    ) -> &mut Self {
+        self.register_fn(tag, move |bytes| {
+            let (view, rest) = T::split_slice_checked(bytes)?;
+            Ok((convert(view), rest))
+        })
+    }
+
+    /// Parse records with `tag` using `parser`, which returns the record along with the remaining bytes.
+    ///
+    /// The bytes passed to `parser` include the tag byte. If `tag` is already registered, the previous parser is
+    /// replaced.
+    pub fn register_fn(
+        &mut self,
+        tag: u8,
+        parser: impl Fn(&'a [u8]) -> Result<(R, &'a [u8]), ParseError> + 'a,
+    ) -> &mut Self {
+        self.parsers.insert(tag, Box::new(parser));
+        self
+    }
+
+    /// Skip records with unknown tags instead of returning [`DispatchError::UnknownTag`].
+    ///
+    /// `record_len` is passed the bytes of an unknown record (including the tag byte) and returns the total number of
+    /// bytes in the record, or `None` if the length cannot be determined.
+    pub fn skip_unknown(
+        &mut self,
+        record_len: impl Fn(&'a [u8]) -> Option<usize> + 'a,
+    ) -> &mut Self {
+        self.record_len = Some(Box::new(record_len));
+        self
+    }
+
+    /// Parse the first record with a registered tag and return it along with the remaining bytes.
+    ///
+    /// Records with unknown tags before it are skipped if possible. Return [`DispatchError::Empty`] if there are no
+    /// more records.
+    pub fn next(&self, mut bytes: &'a [u8]) -> Result<(R, &'a [u8]), DispatchError> {
+        loop {
+            let tag = *bytes.first().ok_or(DispatchError::Empty)?;
+            if let Some(parser) = self.parsers.get(&tag) {
+                return parser(bytes).map_err(|error| DispatchError::Parse { tag, error });
+            }
+            bytes = self.skip(tag, bytes)?;
+        }
+    }
+
+    /// An iterator over the records in `bytes`.
+    ///
+    /// The iterator yields an error if a record cannot be parsed and then stops.
+    pub fn iter<'d>(&'d self, bytes: &'a [u8]) -> DispatchIter<'d, 'a, R> {
+        DispatchIter {
+            dispatcher: self,
+            bytes,
+            failed: false,
+        }
+    }
+
+    // Skip a record with an unknown tag and return the bytes after it.
+    fn skip(&self, tag: u8, bytes: &'a [u8]) -> Result<&'a [u8], DispatchError> {
+        let record_len = self
+            .record_len
+            .as_ref()
+            .ok_or(DispatchError::UnknownTag(tag))?;
+        let len = record_len(bytes).ok_or(DispatchError::UnknownTag(tag))?;
+        let error = if len == 0 {
+            ParseError::LengthTooSmall {
+                declared: 0,
+                minimum: 1,
+            }
+        } else if let Some(rest) = bytes.get(len..) {
+            return Ok(rest);
+        } else {
+            ParseError::LengthMismatch {
+                declared: len as u64,
+                available: bytes.len(),
+            }
+        };
+        Err(DispatchError::Parse { tag, error })
+    }
+}
+
+impl<R> Default for Dispatcher<'_, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> fmt::Debug for Dispatcher<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("tags", &self.parsers.keys().collect::<Vec<_>>())
+            .field("skip_unknown", &self.record_len.is_some())
+            .finish()
+    }
+}
+
+/// An iterator over the records parsed by a [`Dispatcher`].
+///
+/// See [`Dispatcher::iter`].
+#[derive(Debug)]
+pub struct DispatchIter<'d, 'a, R> {
+    dispatcher: &'d Dispatcher<'a, R>,
+    bytes: &'a [u8],
+    failed: bool,
+}
+
+impl<'a, R> DispatchIter<'_, 'a, R> {
+    /// The bytes that have not been consumed by the iterator.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<R> Iterator for DispatchIter<'_, '_, R> {
+    type Item = Result<R, DispatchError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.dispatcher.next(self.bytes) {
+            Ok((record, rest)) => {
+                self.bytes = rest;
+                Some(Ok(record))
+            }
+            Err(DispatchError::Empty) => {
+                self.bytes = &[];
+                None
+            }
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl<R> FusedIterator for DispatchIter<'_, '_, R> {}
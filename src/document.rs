@@ -0,0 +1,413 @@
+//! Whole files made of a header, a section of records, and a footer.
+
+use std::fmt;
+
+use crate::{ByteView, FieldInfo, Layout, ParseError, Value};
+
+/// The shape of a complete file: a header, a section of records, and a footer, each an owned struct generated by
+/// [`byteview_owned!`](crate::byteview_owned). See [`Document`].
+///
+/// The records are either counted by a field of the header ([`COUNT_FIELD`](DocumentFormat::COUNT_FIELD)) or fill
+/// every byte between the header and the footer. Files without a footer use [`NoFooter`].
+pub trait DocumentFormat {
+    /// The struct at the start of the file.
+    type Header: for<'a> ByteView<'a>;
+    /// The structs stored back to back after the header. These may be variable-sized.
+    type Record: for<'a> ByteView<'a>;
+    /// The struct at the end of the file. This must not be variable-sized, or [`Document::parse`] fails to compile.
+    type Footer: for<'a> ByteView<'a>;
+
+    /// The name of the unsigned integer field of the header holding the number of records, if there is one.
+    ///
+    /// If this is [`None`], the records fill every byte between the header and the footer, so they must not be empty.
+    ///
+    /// The field is looked up in the [`LAYOUT`](ByteView::LAYOUT) of the header when [`Document::parse`] or
+    /// [`Document::write`] is compiled, so a field that does not exist, is not an unsigned integer, or is optional fails
+    /// to compile:
+    ///
+    /// ```compile_fail
+    /// use byteview::{Document, DocumentFormat, NoFooter, byteview_owned};
+    ///
+    /// byteview_owned! {
+    ///     pub struct Header {
+    ///         pub count: u16be,
+    ///     }
+    /// }
+    ///
+    /// byteview_owned! {
+    ///     pub struct Sample {
+    ///         pub value: u16be,
+    ///     }
+    /// }
+    ///
+    /// struct SampleFile;
+    ///
+    /// impl DocumentFormat for SampleFile {
+    ///     type Header = Header;
+    ///     type Record = Sample;
+    ///     type Footer = NoFooter;
+    ///
+    ///     const COUNT_FIELD: Option<&'static str> = Some("num_samples");
+    /// }
+    ///
+    /// let _ = Document::<SampleFile>::parse(&[0, 0]);
+    /// ```
+    const COUNT_FIELD: Option<&'static str> = None;
+
+    /// The checksum stored in the footer, if there is one.
+    const CHECKSUM: Option<Checksum> = None;
+}
+
+/// A checksum of every byte before the footer of a [`Document`], stored in an unsigned integer field of the footer.
+///
+/// The computed checksum is truncated to the size of the field, e.g. to the low 16 bits for a `u16be` field.
+#[derive(Debug, Copy, Clone)]
+pub struct Checksum {
+    /// The name of the field of the footer holding the checksum.
+    ///
+    /// Like [`DocumentFormat::COUNT_FIELD`], this is checked when [`Document::parse`] or [`Document::write`] is
+    /// compiled.
+    pub field: &'static str,
+    /// Compute the checksum of the bytes before the footer.
+    pub compute: fn(&[u8]) -> u64,
+}
+
+/// The footer of a [`DocumentFormat`] without a footer. It contains no bytes.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct NoFooter;
+
+impl<'a> ByteView<'a> for NoFooter {
+    const NUM_BYTES: usize = 0;
+    const LAYOUT: Layout = Layout {
+        name: "NoFooter",
+        num_bytes: 0,
+        fields: &[],
+    };
+
+    fn as_bytes(&self) -> &[u8] {
+        &[]
+    }
+
+    fn split_slice(bytes: &'a [u8]) -> Option<(Self, &'a [u8])> {
+        Some((NoFooter, bytes))
+    }
+}
+
+/// The reason a [`Document`] could not be parsed or written.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DocumentError {
+    /// The header could not be parsed.
+    Header(ParseError),
+    /// The record at `index` could not be parsed.
+    Record { index: usize, error: ParseError },
+    /// The footer could not be parsed from the end of the bytes.
+    Footer(ParseError),
+    /// There are bytes between the last counted record and the footer.
+    TrailingBytes { offset: usize, len: usize },
+    /// The checksum stored in the footer does not match the bytes before it.
+    ChecksumMismatch { stored: u64, computed: u64 },
+    /// There are more records than the count field of the header can hold.
+    TooManyRecords { count: usize },
+}
+
+impl fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentError::Header(error) => write!(f, "invalid header: {error}"),
+            DocumentError::Record { index, error } => write!(f, "invalid record {index}: {error}"),
+            DocumentError::Footer(error) => write!(f, "invalid footer: {error}"),
+            DocumentError::TrailingBytes { offset, len } => {
+                write!(f, "{len} bytes at offset {offset} follow the last record")
+            }
+            DocumentError::ChecksumMismatch { stored, computed } => write!(
+                f,
+                "the stored checksum {stored:#x} does not match the computed checksum {computed:#x}"
+            ),
+            DocumentError::TooManyRecords { count } => {
+                write!(f, "{count} records do not fit in the count field")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DocumentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DocumentError::Header(error)
+            | DocumentError::Record { error, .. }
+            | DocumentError::Footer(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A complete file in the shape described by a [`DocumentFormat`], with owned access to every part.
+///
+/// [`parse`](Document::parse) checks the header, every record, and the footer with
+/// [`split_slice_checked`](ByteView::split_slice_checked), along with the checksum if there is one.
+/// [`write`](Document::write) writes the parts back, updating the count field and the checksum, so parsing and writing
+/// an unmodified document reproduces the original bytes.
+///
+/// ```
+/// use byteview::{Document, DocumentFormat, NoFooter, byteview_owned};
+///
+/// byteview_owned! {
+///     pub struct Header {
+///         pub kind: u8,
+///         pub count: u8,
+///     }
+/// }
+///
+/// byteview_owned! {
+///     pub struct Sample {
+///         pub value: u16be,
+///     }
+/// }
+///
+/// struct SampleFile;
+///
+/// impl DocumentFormat for SampleFile {
+///     type Header = Header;
+///     type Record = Sample;
+///     type Footer = NoFooter;
+///
+///     const COUNT_FIELD: Option<&'static str> = Some("count");
+/// }
+///
+/// let bytes = [7, 2, 0x00, 0x01, 0x00, 0x02];
+/// let mut document = Document::<SampleFile>::parse(&bytes).unwrap();
+/// assert_eq!(2, document.records[1].value());
+///
+/// document.records.pop();
+/// let mut written = Vec::new();
+/// document.write(&mut written).unwrap();
+/// assert_eq!([7, 1, 0x00, 0x01], *written);
+/// ```
+pub struct Document<D: DocumentFormat> {
+    /// The header.
+    pub header: D::Header,
+    /// The records, in order.
+    pub records: Vec<D::Record>,
+    /// The footer.
+    pub footer: D::Footer,
+}
+
+impl<D: DocumentFormat> Document<D> {
+    // The count field of the header, if there is one. Records that are not counted must contain bytes, or parsing
+    // would never reach the footer.
+    const COUNT: Option<FieldInfo> = match D::COUNT_FIELD {
+        Some(name) => Some(unsigned_field(
+            <D::Header as ByteView>::LAYOUT,
+            <D::Header as ByteView>::MIN_NUM_BYTES,
+            name,
+            "the count field must be a required unsigned integer field of the header",
+        )),
+        None => {
+            assert!(
+                <D::Record as ByteView>::NUM_BYTES != 0,
+                "records without a count field must not be empty"
+            );
+            None
+        }
+    };
+
+    // The checksum field of the footer, if there is one.
+    const CHECKSUM_FIELD: Option<FieldInfo> = match D::CHECKSUM {
+        Some(checksum) => Some(unsigned_field(
+            <D::Footer as ByteView>::LAYOUT,
+            <D::Footer as ByteView>::MIN_NUM_BYTES,
+            checksum.field,
+            "the checksum field must be a required unsigned integer field of the footer",
+        )),
+        None => None,
+    };
+
+    // The number of bytes in the footer. Fail to compile if the size of the footer can vary.
+    const FOOTER_BYTES: usize = {
+        assert!(
+            <D::Footer as ByteView>::MIN_NUM_BYTES == <D::Footer as ByteView>::NUM_BYTES
+                && <D::Footer as ByteView>::MAX_NUM_BYTES == <D::Footer as ByteView>::NUM_BYTES,
+            "the footer must not have optional fields, varint fields, or length fields"
+        );
+        <D::Footer as ByteView>::NUM_BYTES
+    };
+
+    /// Parse a complete file.
+    ///
+    /// Return an error if any part is invalid, the checksum does not match, or there are bytes after the counted
+    /// records.
+    pub fn parse(bytes: &[u8]) -> Result<Self, DocumentError> {
+        let (header, rest) =
+            D::Header::split_slice_checked(bytes).map_err(DocumentError::Header)?;
+        let header_len = bytes.len() - rest.len();
+        let Some(footer_start) = bytes
+            .len()
+            .checked_sub(Self::FOOTER_BYTES)
+            .filter(|&start| start >= header_len)
+        else {
+            return Err(DocumentError::Footer(ParseError::TooShort {
+                needed: Self::FOOTER_BYTES,
+                available: rest.len(),
+            }));
+        };
+        let (footer, _) = D::Footer::split_slice_checked(&bytes[footer_start..])
+            .map_err(DocumentError::Footer)?;
+        if let (Some(checksum), Some(field)) = (D::CHECKSUM, Self::CHECKSUM_FIELD) {
+            let stored = read_unsigned(field, footer.as_bytes());
+            let computed = truncate(field, (checksum.compute)(&bytes[..footer_start]));
+            if stored != computed {
+                return Err(DocumentError::ChecksumMismatch { stored, computed });
+            }
+        }
+
+        let count = Self::COUNT.map(|field| read_unsigned(field, header.as_bytes()));
+        let mut section = &bytes[header_len..footer_start];
+        let mut records = Vec::new();
+        while count.map_or(!section.is_empty(), |count| (records.len() as u64) < count) {
+            let (record, rest) =
+                D::Record::split_slice_checked(section).map_err(|error| DocumentError::Record {
+                    index: records.len(),
+                    error,
+                })?;
+            records.push(record);
+            section = rest;
+        }
+        if !section.is_empty() {
+            return Err(DocumentError::TrailingBytes {
+                offset: footer_start - section.len(),
+                len: section.len(),
+            });
+        }
+        Ok(Self {
+            header,
+            records,
+            footer,
+        })
+    }
+
+    /// Append the bytes of the file to `out`.
+    ///
+    /// The count field of the header is set to the number of records and the checksum in the footer is recomputed, so
+    /// the written file can be parsed again. The parts themselves are not modified. Return an error without modifying
+    /// `out` if there are too many records for the count field.
+    pub fn write(&self, out: &mut Vec<u8>) -> Result<(), DocumentError> {
+        let count = match Self::COUNT {
+            Some(field) => {
+                let count = self.records.len();
+                let bytes = field
+                    .kind
+                    .encode_unsigned(count as u64)
+                    .ok_or(DocumentError::TooManyRecords { count })?;
+                Some((field, bytes))
+            }
+            None => None,
+        };
+        let start = out.len();
+        out.extend_from_slice(self.header.as_bytes());
+        if let Some((field, bytes)) = count {
+            let range = field.range();
+            out[start + range.start..start + range.end].copy_from_slice(&bytes);
+        }
+        for record in &self.records {
+            out.extend_from_slice(record.as_bytes());
+        }
+        let footer_start = out.len();
+        out.extend_from_slice(self.footer.as_bytes());
+        if let (Some(checksum), Some(field)) = (D::CHECKSUM, Self::CHECKSUM_FIELD) {
+            let computed = truncate(field, (checksum.compute)(&out[start..footer_start]));
+            let bytes = field
+                .kind
+                .encode_unsigned(computed)
+                .expect("the checksum was truncated to the size of the field");
+            let range = field.range();
+            out[footer_start + range.start..footer_start + range.end].copy_from_slice(&bytes);
+        }
+        Ok(())
+    }
+}
+
+impl<D: DocumentFormat> fmt::Debug for Document<D>
+where
+    D::Header: fmt::Debug,
+    D::Record: fmt::Debug,
+    D::Footer: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Document")
+            .field("header", &self.header)
+            .field("records", &self.records)
+            .field("footer", &self.footer)
+            .finish()
+    }
+}
+
+impl<D: DocumentFormat> Clone for Document<D>
+where
+    D::Header: Clone,
+    D::Record: Clone,
+    D::Footer: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            records: self.records.clone(),
+            footer: self.footer.clone(),
+        }
+    }
+}
+
+// The unsigned integer field with the given name in the first `min_num_bytes` bytes of a struct. Fail to compile with
+// `message` if there is no such field.
+const fn unsigned_field(
+    layout: Layout,
+    min_num_bytes: usize,
+    name: &str,
+    message: &str,
+) -> FieldInfo {
+    let mut i = 0;
+    while i < layout.fields.len() {
+        let field = layout.fields[i];
+        // Anonymous padding fields cannot be found by name.
+        if str_eq(field.name, name) && !str_eq(name, "_") {
+            assert!(
+                field.kind.is_unsigned_integer() && field.offset + field.size <= min_num_bytes,
+                "{}",
+                message
+            );
+            return field;
+        }
+        i += 1;
+    }
+    panic!("{}", message)
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+fn read_unsigned(field: FieldInfo, bytes: &[u8]) -> u64 {
+    match Value::decode(field.kind, &bytes[field.range()]) {
+        // Unsigned integer fields are at most 64 bits.
+        Value::Unsigned(value) => value as u64,
+        value => unreachable!("unsigned integer fields decode to unsigned values, not {value:?}"),
+    }
+}
+
+// Truncate a value to the size of a field.
+fn truncate(field: FieldInfo, value: u64) -> u64 {
+    match field.size {
+        8.. => value,
+        size => value & ((1 << (size * 8)) - 1),
+    }
+}
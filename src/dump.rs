@@ -0,0 +1,131 @@
+//! Rendering the fields of a struct as a table, without allocating.
+
+use std::fmt::{self, Write};
+
+use crate::{FieldKind, Layout, NumberType};
+
+// Write a table of the fields of a struct with the given layout to `out`, followed by a row for any bytes after the
+// fields (e.g. the body of a variable-sized record). Nothing is allocated, so this can be used with a fixed-capacity
+// writer.
+pub fn dump_to(layout: &Layout, bytes: &[u8], out: &mut dyn Write) -> fmt::Result {
+    let rest = bytes.get(layout.num_bytes..).unwrap_or_default();
+    let rest_range = (layout.num_bytes, layout.num_bytes + rest.len());
+    let rows = layout.fields.iter().map(|field| {
+        (
+            field.offset,
+            field.offset + field.size,
+            field.name,
+            field.ty,
+        )
+    });
+    let widths = rows.clone().fold(
+        (0, 0, 0),
+        |(range, name, ty), (start, end, field_name, field_ty)| {
+            (
+                range.max(range_width(start, end)),
+                name.max(field_name.len()),
+                ty.max(field_ty.len()),
+            )
+        },
+    );
+    let widths = if rest.is_empty() {
+        widths
+    } else {
+        (
+            widths.0.max(range_width(rest_range.0, rest_range.1)),
+            widths.1.max(REST_NAME.len()),
+            widths.2.max(bytes_type_width(rest.len())),
+        )
+    };
+    let (range_width_max, name_width, ty_width) = widths;
+
+    write!(out, "{} ({} bytes)", layout.name, bytes.len())?;
+    for field in layout.fields {
+        let (start, end) = (field.offset, field.offset + field.size);
+        write!(out, "\n  {start}..{end}")?;
+        pad(out, range_width_max - range_width(start, end) + 2)?;
+        write!(out, "{:name_width$}  {:ty_width$}  ", field.name, field.ty)?;
+        match bytes.get(start..end) {
+            Some(field_bytes) => write_value(out, field.kind, field_bytes)?,
+            // Optional fields may be missing from shorter records.
+            None => out.write_str("(missing)")?,
+        }
+    }
+    if !rest.is_empty() {
+        let (start, end) = rest_range;
+        write!(out, "\n  {start}..{end}")?;
+        pad(out, range_width_max - range_width(start, end) + 2)?;
+        write!(out, "{REST_NAME:name_width$}  [u8; {}]", rest.len())?;
+        pad(out, ty_width - bytes_type_width(rest.len()) + 2)?;
+        write_hex(out, rest)?;
+    }
+    Ok(())
+}
+
+// The name shown for the bytes after the fields.
+const REST_NAME: &str = "(rest)";
+
+// Write the value of a field, decoding numbers and writing byte arrays as hexadecimal. Numbers whose byte order is chosen
+// when the struct is constructed cannot be decoded from the layout, so they are also written as hexadecimal.
+fn write_value(out: &mut dyn Write, kind: FieldKind, bytes: &[u8]) -> fmt::Result {
+    let (number_type, endianness) = match kind {
+        FieldKind::U8 => return write!(out, "{}", bytes[0]),
+        FieldKind::I8 => return write!(out, "{}", bytes[0] as i8),
+        FieldKind::Bytes | FieldKind::RuntimeNumber(_) => return write_hex(out, bytes),
+        FieldKind::Number(number_type, endianness) => (number_type, endianness),
+    };
+    let unsigned = (0..bytes.len()).fold(0u128, |value, index| {
+        let byte = bytes[endianness.big_endian_index(index, bytes.len())];
+        value << 8 | u128::from(byte)
+    });
+    let shift = 128 - 8 * bytes.len() as u32;
+    match number_type {
+        NumberType::F32 => write!(out, "{}", f32::from_bits(unsigned as u32)),
+        NumberType::F64 => write!(out, "{}", f64::from_bits(unsigned as u64)),
+        NumberType::I16
+        | NumberType::I24
+        | NumberType::I32
+        | NumberType::I48
+        | NumberType::I64
+        | NumberType::I128 => {
+            write!(out, "{}", ((unsigned << shift) as i128) >> shift)
+        }
+        NumberType::U16
+        | NumberType::U24
+        | NumberType::U32
+        | NumberType::U48
+        | NumberType::U64
+        | NumberType::U128 => {
+            write!(out, "{unsigned}")
+        }
+    }
+}
+
+// Write bytes as space-separated hexadecimal.
+fn write_hex(out: &mut dyn Write, bytes: &[u8]) -> fmt::Result {
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            out.write_char(' ')?;
+        }
+        write!(out, "{byte:02x}")?;
+    }
+    Ok(())
+}
+
+fn pad(out: &mut dyn Write, width: usize) -> fmt::Result {
+    (0..width).try_for_each(|_| out.write_char(' '))
+}
+
+// The width of `start..end`.
+fn range_width(start: usize, end: usize) -> usize {
+    num_digits(start) + 2 + num_digits(end)
+}
+
+// The width of `[u8; len]`.
+fn bytes_type_width(len: usize) -> usize {
+    "[u8; ]".len() + num_digits(len)
+}
+
+fn num_digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
@@ -0,0 +1,174 @@
+//! Accessing generated structs through trait objects, for code that does not know their types at compile time.
+
+use std::fmt::{self, Write};
+use std::marker::PhantomData;
+
+use crate::{ByteView, FieldInfo, Layout, ParseError, Value};
+
+/// An object-safe view of a generated struct, implemented for every [`ByteView`].
+///
+/// This allows code such as a generic record browser to inspect structs of different types through the same
+/// `&dyn DynView`, using the [`Layout`] of each struct to find its fields.
+///
+/// ```
+/// use byteview::{DynView, Value, byteview_ref};
+///
+/// byteview_ref! {
+///     pub struct Header {
+///         pub magic: [u8; 2],
+///         pub count: u16be,
+///     }
+/// }
+///
+/// let header = Header::from_array(b"HD\x00\x07");
+/// let view: &dyn DynView = &header;
+/// assert_eq!("Header", view.layout().name);
+/// assert_eq!(Some(Value::Unsigned(7)), view.field_value("count"));
+/// assert_eq!(None, view.field_value("missing"));
+/// ```
+pub trait DynView {
+    /// The [`Layout`] of the struct.
+    fn layout(&self) -> Layout;
+
+    /// The total number of bytes in the struct, including the body of a variable-sized record.
+    fn num_bytes(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// The fields of the struct, including padding fields named `_`.
+    fn fields(&self) -> &'static [FieldInfo] {
+        self.layout().fields
+    }
+
+    /// The decoded value of the named field, or [`None`] if there is no such field or it is an optional field that is
+    /// missing from this struct.
+    fn field_value(&self, name: &str) -> Option<Value> {
+        let field = self.layout().field(name)?;
+        let bytes = self.as_bytes().get(field.range())?;
+        Some(Value::decode(field.kind, bytes))
+    }
+
+    /// The bytes of the struct.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Write a table of the fields of the struct to `out`, with the offset, name, type, and value of each field.
+    ///
+    /// Numbers are decoded and byte arrays are written as hexadecimal. Any bytes after the fields (e.g. the body of a
+    /// variable-sized record) are written in a final `(rest)` row. Nothing is allocated, so `out` can be a
+    /// fixed-capacity buffer or a serial console.
+    ///
+    /// ```
+    /// use byteview::{DynView, byteview_ref};
+    ///
+    /// byteview_ref! {
+    ///     pub struct Header {
+    ///         pub magic: [u8; 2],
+    ///         pub count: u16be,
+    ///     }
+    /// }
+    ///
+    /// let mut out = String::new();
+    /// Header::from_array(b"HD\x00\x07").dump_to(&mut out).unwrap();
+    /// assert_eq!(
+    ///     "Header (4 bytes)\n\
+    ///     \x20 0..2  magic  [u8; 2]  48 44\n\
+    ///     \x20 2..4  count  u16be    7",
+    ///     out
+    /// );
+    /// ```
+    fn dump_to(&self, out: &mut dyn Write) -> fmt::Result {
+        crate::dump::dump_to(&self.layout(), self.as_bytes(), out)
+    }
+
+    /// Return the table written by [`dump_to`](DynView::dump_to).
+    fn dump(&self) -> String {
+        let mut dump = String::new();
+        self.dump_to(&mut dump)
+            .expect("writing to a String cannot fail");
+        dump
+    }
+}
+
+impl<'a, T: ByteView<'a>> DynView for T {
+    fn layout(&self) -> Layout {
+        T::LAYOUT
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        ByteView::as_bytes(self)
+    }
+}
+
+/// An object-safe parser that creates a [`DynView`] from bytes, e.g. so format plugins can register their structs.
+pub trait DynViewFactory {
+    /// The [`Layout`] of the structs created by this factory.
+    fn layout(&self) -> Layout;
+
+    /// Create a struct from the start of `bytes` and return it along with the remaining bytes, like
+    /// [`ByteView::split_slice_checked`].
+    fn parse<'a>(&self, bytes: &'a [u8]) -> Result<(Box<dyn DynView + 'a>, &'a [u8]), ParseError>;
+}
+
+/// A [`DynViewFactory`] for the owned struct `T`, generated by [`byteview_owned!`](crate::byteview_owned).
+///
+/// Structs generated by [`byteview_ref!`](crate::byteview_ref) borrow their bytes, so they can only be created by
+/// implementing [`DynViewFactory`] directly.
+///
+/// ```
+/// use byteview::{DynViewFactory, Value, ViewFactory, byteview_owned};
+///
+/// byteview_owned! {
+///     pub struct Point {
+///         pub x: i8,
+///         pub y: i8,
+///     }
+/// }
+///
+/// let factories: Vec<Box<dyn DynViewFactory>> = vec![Box::new(ViewFactory::<Point>::new())];
+/// let (point, rest) = factories[0].parse(&[1, 0xFF, 9]).unwrap();
+/// assert_eq!(Some(Value::Signed(-1)), point.field_value("y"));
+/// assert_eq!([9], rest);
+/// ```
+pub struct ViewFactory<T> {
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> ViewFactory<T> {
+    /// Create a factory for `T`.
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for ViewFactory<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ViewFactory<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ViewFactory<T> {}
+
+impl<T> fmt::Debug for ViewFactory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ViewFactory").finish()
+    }
+}
+
+impl<T: for<'a> ByteView<'a> + 'static> DynViewFactory for ViewFactory<T> {
+    fn layout(&self) -> Layout {
+        T::LAYOUT
+    }
+
+    fn parse<'a>(&self, bytes: &'a [u8]) -> Result<(Box<dyn DynView + 'a>, &'a [u8]), ParseError> {
+        let (view, rest) = T::split_slice_checked(bytes)?;
+        Ok((Box::new(view), rest))
+    }
+}
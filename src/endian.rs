@@ -0,0 +1,328 @@
+//! Numbers stored as byte arrays in a fixed byte order, for use outside the macros, and the byte orders of structs
+//! declared with `#[byteview(endian = "generic")]` or `#[byteview(endian = "runtime")]`.
+
+use crate::{Endianness, OffsetError};
+use std::fmt;
+
+/// A number stored as a byte array in a fixed byte order, e.g. [`U32Be`].
+pub trait EndianNumber: Copy {
+    /// The type of the number.
+    type Value;
+
+    /// The number of bytes in the number.
+    const NUM_BYTES: usize;
+
+    /// Decode the number from exactly [`NUM_BYTES`](EndianNumber::NUM_BYTES) bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is the wrong length.
+    fn read(bytes: &[u8]) -> Self::Value;
+
+    /// Encode the number into exactly [`NUM_BYTES`](EndianNumber::NUM_BYTES) bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is the wrong length.
+    fn write(value: Self::Value, bytes: &mut [u8]);
+}
+
+/// Read the number of type `T` at `offset` in `bytes`, without defining a struct.
+///
+/// Return an error if the offset is past the end of `bytes` or there are fewer than `T::NUM_BYTES` bytes after it.
+///
+/// ```
+/// use byteview::{U32Be, read_at};
+///
+/// let bytes = [0xFF, 0x00, 0x00, 0x01, 0x02];
+/// assert_eq!(Ok(0x0102), read_at::<U32Be>(&bytes, 1));
+/// assert!(read_at::<U32Be>(&bytes, 2).is_err());
+/// ```
+pub fn read_at<T: EndianNumber>(bytes: &[u8], offset: usize) -> Result<T::Value, OffsetError> {
+    let range = checked_range::<T>(bytes.len(), offset)?;
+    Ok(T::read(&bytes[range]))
+}
+
+/// Write `value` as a number of type `T` at `offset` in `bytes`, without defining a struct.
+///
+/// Return an error without modifying `bytes` if the offset is past the end of `bytes` or there are fewer than
+/// `T::NUM_BYTES` bytes after it.
+///
+/// ```
+/// use byteview::{U16Le, write_at};
+///
+/// let mut bytes = [0; 4];
+/// write_at::<U16Le>(&mut bytes, 1, 0x0102).unwrap();
+/// assert_eq!([0x00, 0x02, 0x01, 0x00], bytes);
+/// assert!(write_at::<U16Le>(&mut bytes, 3, 0).is_err());
+/// ```
+pub fn write_at<T: EndianNumber>(
+    bytes: &mut [u8],
+    offset: usize,
+    value: T::Value,
+) -> Result<(), OffsetError> {
+    let range = checked_range::<T>(bytes.len(), offset)?;
+    T::write(value, &mut bytes[range]);
+    Ok(())
+}
+
+// The range of bytes occupied by a `T` at `offset` in a buffer of `len` bytes.
+fn checked_range<T: EndianNumber>(
+    len: usize,
+    offset: usize,
+) -> Result<std::ops::Range<usize>, OffsetError> {
+    if offset > len {
+        return Err(OffsetError::OutOfBounds {
+            offset: offset as u64,
+            len,
+        });
+    }
+    let available = len - offset;
+    if available < T::NUM_BYTES {
+        return Err(OffsetError::Truncated {
+            offset,
+            needed: T::NUM_BYTES,
+            available,
+        });
+    }
+    Ok(offset..offset + T::NUM_BYTES)
+}
+
+/// A byte order chosen by a type parameter, for structs declared with `#[byteview(endian = "generic")]`.
+///
+/// The byte order is a constant, so the checks in the "getters" are removed when the struct is compiled. This is
+/// implemented by [`BigEndian`] and [`LittleEndian`] and cannot be implemented outside this crate.
+pub trait Endian:
+    Copy + fmt::Debug + Default + Eq + Ord + std::hash::Hash + Send + Sync + 'static + private::Sealed
+{
+    /// The byte order, either [`Endianness::Big`] or [`Endianness::Little`].
+    const ENDIANNESS: Endianness;
+}
+
+/// Most significant byte first, for use as the [`Endian`] parameter of a struct.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BigEndian;
+
+/// Least significant byte first, for use as the [`Endian`] parameter of a struct.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LittleEndian;
+
+impl Endian for BigEndian {
+    const ENDIANNESS: Endianness = Endianness::Big;
+}
+
+impl Endian for LittleEndian {
+    const ENDIANNESS: Endianness = Endianness::Little;
+}
+
+/// The byte order of a struct declared with `#[byteview(endian = "runtime")]`, which is chosen when it is constructed,
+/// e.g. with `split_slice_with(bytes, ByteOrder::Little)`.
+///
+/// Unlike [`Endianness`], this only has the byte orders that such a struct can be read in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ByteOrder {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+impl ByteOrder {
+    /// The byte order of the target platform.
+    pub const NATIVE: ByteOrder = if cfg!(target_endian = "little") {
+        ByteOrder::Little
+    } else {
+        ByteOrder::Big
+    };
+}
+
+impl From<ByteOrder> for Endianness {
+    fn from(byte_order: ByteOrder) -> Self {
+        match byte_order {
+            ByteOrder::Big => Endianness::Big,
+            ByteOrder::Little => Endianness::Little,
+        }
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::BigEndian {}
+    impl Sealed for super::LittleEndian {}
+}
+
+// Reorder the bytes of a number stored in the byte order `endianness` into the byte order of the target platform. Doing
+// this twice restores the original bytes.
+pub fn swap_to_native(bytes: &mut [u8], endianness: Endianness) {
+    let big_endian = match endianness {
+        Endianness::Big => true,
+        Endianness::Little => false,
+        Endianness::Native => return,
+        Endianness::Middle => {
+            bytes.chunks_exact_mut(2).for_each(|half| half.swap(0, 1));
+            true
+        }
+    };
+    if big_endian != cfg!(target_endian = "big") {
+        bytes.reverse();
+    }
+}
+
+// Swap the bytes in each 16-bit half of a middle-endian (PDP-11) number, converting it to or from big-endian order.
+pub const fn swap_middle_endian<const N: usize>(mut bytes: [u8; N]) -> [u8; N] {
+    let mut i = 0;
+    while i + 1 < N {
+        let byte = bytes[i];
+        bytes[i] = bytes[i + 1];
+        bytes[i + 1] = byte;
+        i += 2;
+    }
+    bytes
+}
+
+// Extend the bytes of an `N`-byte integer to the bytes of a wider `M`-byte integer in the same byte order, filling the
+// new bytes with the sign bit if the integer is signed. This reads 24-bit and 48-bit integers.
+pub const fn extend_bytes<const N: usize, const M: usize>(
+    bytes: [u8; N],
+    little_endian: bool,
+    signed: bool,
+) -> [u8; M] {
+    let most_significant = if little_endian {
+        bytes[N - 1]
+    } else {
+        bytes[0]
+    };
+    let fill = if signed && most_significant & 0x80 != 0 {
+        0xFF
+    } else {
+        0
+    };
+    let mut extended = [fill; M];
+    let start = if little_endian { 0 } else { M - N };
+    let mut i = 0;
+    while i < N {
+        extended[start + i] = bytes[i];
+        i += 1;
+    }
+    extended
+}
+
+// The inverse of `extend_bytes`. The most significant `M - N` bytes are dropped.
+pub const fn truncate_bytes<const M: usize, const N: usize>(
+    bytes: [u8; M],
+    little_endian: bool,
+) -> [u8; N] {
+    let start = if little_endian { 0 } else { M - N };
+    let mut truncated = [0; N];
+    let mut i = 0;
+    while i < N {
+        truncated[i] = bytes[start + i];
+        i += 1;
+    }
+    truncated
+}
+
+macro_rules! define_endian_types {
+    ($($value:ident: $big:ident, $little:ident, $native:ident;)*) => {
+        $(
+            define_endian_type!($value, $big, from_be_bytes, to_be_bytes, "big-endian");
+            define_endian_type!($value, $little, from_le_bytes, to_le_bytes, "little-endian");
+            define_endian_type!($value, $native, from_ne_bytes, to_ne_bytes, "native-endian");
+        )*
+    };
+}
+
+macro_rules! define_endian_type {
+    ($value:ident, $name:ident, $from_bytes:ident, $to_bytes:ident, $order:literal) => {
+        #[doc = concat!("A ", $order, " `", stringify!($value), "` stored as a byte array.")]
+        ///
+        /// This has an alignment of 1, so it can be used as a field of a `#[repr(C)]` struct without padding. Equality
+        /// and hashing compare the bytes.
+        #[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+        #[repr(transparent)]
+        pub struct $name(pub [u8; size_of::<$value>()]);
+
+        impl $name {
+            /// The number of bytes in the value.
+            pub const NUM_BYTES: usize = size_of::<$value>();
+
+            /// Store a value in this byte order.
+            pub const fn new(value: $value) -> Self {
+                Self(value.$to_bytes())
+            }
+
+            /// Wrap bytes that are already in this byte order.
+            pub const fn from_bytes(bytes: [u8; size_of::<$value>()]) -> Self {
+                Self(bytes)
+            }
+
+            /// The bytes of the value, in this byte order.
+            pub const fn to_bytes(self) -> [u8; size_of::<$value>()] {
+                self.0
+            }
+
+            /// The value.
+            pub const fn get(self) -> $value {
+                <$value>::$from_bytes(self.0)
+            }
+
+            /// Replace the value.
+            pub fn set(&mut self, value: $value) {
+                self.0 = value.$to_bytes();
+            }
+        }
+
+        impl EndianNumber for $name {
+            type Value = $value;
+
+            const NUM_BYTES: usize = size_of::<$value>();
+
+            fn read(bytes: &[u8]) -> Self::Value {
+                let bytes = bytes.try_into().expect("wrong number of bytes");
+                <$value>::$from_bytes(bytes)
+            }
+
+            fn write(value: Self::Value, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&value.$to_bytes());
+            }
+        }
+
+        impl From<$value> for $name {
+            fn from(value: $value) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $value {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+define_endian_types! {
+    u16: U16Be, U16Le, U16Ne;
+    u32: U32Be, U32Le, U32Ne;
+    u64: U64Be, U64Le, U64Ne;
+    u128: U128Be, U128Le, U128Ne;
+    i16: I16Be, I16Le, I16Ne;
+    i32: I32Be, I32Le, I32Ne;
+    i64: I64Be, I64Le, I64Ne;
+    i128: I128Be, I128Le, I128Ne;
+    f32: F32Be, F32Le, F32Ne;
+    f64: F64Be, F64Le, F64Ne;
+}
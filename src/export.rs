@@ -0,0 +1,34 @@
+//! Status codes for the C functions exported with `#[byteview(export_c = "...")]`.
+
+use std::ffi::c_int;
+
+/// The status returned as an `int` by the C functions exported with `#[byteview(export_c = "...")]`.
+///
+/// The exported functions never panic, so every failure is reported with one of these codes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum ExportStatus {
+    /// The field was written to `out`.
+    Ok = 0,
+    /// `buf` or `out` was null.
+    NullPointer = -1,
+    /// There were too few bytes to contain the field.
+    TooShort = -2,
+}
+
+impl ExportStatus {
+    /// The code returned to C for this status.
+    pub const fn code(self) -> c_int {
+        self as c_int
+    }
+
+    /// The status with the given code, or [`None`] if the code is not a status.
+    pub const fn from_code(code: c_int) -> Option<Self> {
+        match code {
+            0 => Some(ExportStatus::Ok),
+            -1 => Some(ExportStatus::NullPointer),
+            -2 => Some(ExportStatus::TooShort),
+            _ => None,
+        }
+    }
+}
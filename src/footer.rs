@@ -0,0 +1,132 @@
+//! Tables located by a footer at the end of a file.
+
+use std::fmt;
+
+use crate::{ByteView, ParseError, Table};
+
+/// A [`Table`] whose offset and number of structs are stored in a footer at the end of the bytes, as in many archive
+/// formats.
+///
+/// The footer `F` is parsed from the last [`NUM_BYTES`](ByteView::NUM_BYTES) bytes with
+/// [`split_slice_checked`](ByteView::split_slice_checked), so its magic bytes are checked. The table must lie entirely
+/// before the footer. Neither `F` nor `T` may be variable-sized.
+///
+/// ```
+/// use byteview::{FooterIndexed, byteview_ref};
+///
+/// byteview_ref! {
+///     pub struct Footer {
+///         pub table_offset: u32be,
+///         pub count: u16be,
+///     }
+/// }
+///
+/// byteview_ref! {
+///     pub struct Entry {
+///         pub key: u8,
+///         pub value: u8,
+///     }
+/// }
+///
+/// let bytes = b"data\x01\xAA\x02\xBB\x00\x00\x00\x04\x00\x02";
+/// let file = FooterIndexed::<Footer, Entry>::open(bytes, |footer| (footer.table_offset(), footer.count())).unwrap();
+/// assert_eq!(2, file.table().len());
+/// assert_eq!(0xBB, file.table().get(1).unwrap().value());
+/// ```
+pub struct FooterIndexed<'a, F, T> {
+    footer: F,
+    table: Table<'a, T>,
+}
+
+/// The reason a [`FooterIndexed`] table could not be opened.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum FooterError {
+    /// The footer could not be parsed from the end of the bytes, e.g. because there are too few bytes or its magic
+    /// bytes do not match.
+    Footer(ParseError),
+    /// The table described by the footer does not fit between the start of the bytes and the footer.
+    TableOutOfBounds {
+        offset: u64,
+        count: u64,
+        available: usize,
+    },
+}
+
+impl fmt::Display for FooterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FooterError::Footer(error) => write!(f, "invalid footer: {error}"),
+            FooterError::TableOutOfBounds {
+                offset,
+                count,
+                available,
+            } => write!(
+                f,
+                "a table of {count} structs at offset {offset} does not fit in the {available} bytes before the footer"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FooterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FooterError::Footer(error) => Some(error),
+            FooterError::TableOutOfBounds { .. } => None,
+        }
+    }
+}
+
+impl<'a, F: ByteView<'a>, T: ByteView<'a>> FooterIndexed<'a, F, T> {
+    /// Parse the footer at the end of `bytes` and create the table it describes.
+    ///
+    /// `index` returns the offset of the table from the start of `bytes` and the number of structs in it. Return an
+    /// error if the footer is invalid or the table does not fit before the footer.
+    pub fn open<O: Into<u64>, C: Into<u64>>(
+        bytes: &'a [u8],
+        index: impl FnOnce(&F) -> (O, C),
+    ) -> Result<Self, FooterError> {
+        let footer_start = bytes.len().saturating_sub(F::NUM_BYTES);
+        let (footer, _) =
+            F::split_slice_checked(&bytes[footer_start..]).map_err(FooterError::Footer)?;
+        let (offset, count) = index(&footer);
+        let (offset, count) = (offset.into(), count.into());
+        let out_of_bounds = FooterError::TableOutOfBounds {
+            offset,
+            count,
+            available: footer_start,
+        };
+        let end = count
+            .checked_mul(T::NUM_BYTES as u64)
+            .and_then(|len| len.checked_add(offset))
+            .filter(|&end| end <= footer_start as u64)
+            .ok_or(out_of_bounds)?;
+        // Both fit in a usize since they are at most the number of bytes before the footer.
+        let table = Table::from_exact_bytes(&bytes[offset as usize..end as usize]);
+        Ok(Self { footer, table })
+    }
+
+    /// The footer.
+    pub fn footer(&self) -> &F {
+        &self.footer
+    }
+
+    /// The table described by the footer.
+    pub fn table(&self) -> Table<'a, T> {
+        self.table
+    }
+
+    /// The footer and the table.
+    pub fn into_parts(self) -> (F, Table<'a, T>) {
+        (self.footer, self.table)
+    }
+}
+
+impl<F: fmt::Debug, T> fmt::Debug for FooterIndexed<'_, F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FooterIndexed")
+            .field("footer", &self.footer)
+            .field("table", &self.table)
+            .finish()
+    }
+}
@@ -0,0 +1,316 @@
+//! Layout metadata describing where each field of a generated struct lives in its byte array.
+
+use std::ops::Range;
+
+//////////////////////////////////////////////////////////////////////
+// Layout
+//////////////////////////////////////////////////////////////////////
+
+/// A description of the bytes in a struct generated by [`byteview_ref!`](crate::byteview_ref) or
+/// [`byteview_owned!`](crate::byteview_owned).
+///
+/// Every generated struct has an associated `LAYOUT` constant and a `layout()` function that return its [`Layout`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Layout {
+    /// The name of the generated struct.
+    pub name: &'static str,
+    /// The total number of bytes in the struct (the same as its `NUM_BYTES` constant).
+    pub num_bytes: usize,
+    /// Every declared field, in declaration order. Anonymous padding fields (`_`) are included.
+    pub fields: &'static [FieldInfo],
+}
+
+impl Layout {
+    /// Find the field with the given name.
+    ///
+    /// Anonymous padding fields cannot be found by name.
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.named_fields().find(|field| field.name == name)
+    }
+
+    /// Iterate over every field that is not anonymous padding.
+    pub fn named_fields(&self) -> impl Iterator<Item = &'static FieldInfo> + use<> {
+        self.fields.iter().filter(|field| !field.is_padding())
+    }
+}
+
+/// A description of a single field declared in a `byteview` struct.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FieldInfo {
+    /// The name of the field as declared. Anonymous padding fields have the name `_`.
+    pub name: &'static str,
+    /// The offset of the first byte of the field.
+    pub offset: usize,
+    /// The number of bytes in the field.
+    pub size: usize,
+    /// The type of the field as declared, e.g. `u32be` or `[u8; 16]`.
+    pub ty: &'static str,
+    /// How the bytes of the field are interpreted.
+    pub kind: FieldKind,
+}
+
+impl FieldInfo {
+    /// Whether this field is anonymous padding, i.e. declared with the name `_`.
+    pub fn is_padding(&self) -> bool {
+        self.name == "_"
+    }
+
+    /// The range of bytes occupied by this field.
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.size
+    }
+}
+
+/// How the bytes of a field are interpreted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum FieldKind {
+    /// A single unsigned byte (`u8`).
+    U8,
+    /// A single signed byte (`i8`).
+    I8,
+    /// A multi-byte primitive numeric type with an explicit byte order, e.g. `u32be`.
+    Number(NumberType, Endianness),
+    /// A multi-byte primitive numeric type whose byte order is chosen when the struct is constructed, i.e. a number
+    /// declared without a byte order in a struct annotated with `#[byteview(endian = "runtime")]`.
+    RuntimeNumber(NumberType),
+    /// An array of bytes, e.g. `[u8; 16]`.
+    Bytes,
+}
+
+/// A multi-byte primitive numeric type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum NumberType {
+    U16,
+    /// A 24-bit integer, e.g. `u24be`, which is read as a `u32`.
+    U24,
+    U32,
+    /// A 48-bit integer, e.g. `u48be`, which is read as a `u64`.
+    U48,
+    U64,
+    U128,
+    I16,
+    /// A 24-bit integer, e.g. `i24be`, which is read as a sign-extended `i32`.
+    I24,
+    I32,
+    /// A 48-bit integer, e.g. `i48be`, which is read as a sign-extended `i64`.
+    I48,
+    I64,
+    I128,
+    F32,
+    F64,
+}
+
+/// The order of bytes in a multi-byte numeric type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Endianness {
+    /// Most significant byte first, e.g. `u32be`.
+    Big,
+    /// Least significant byte first, e.g. `u32le`.
+    Little,
+    /// The byte order of the target platform, e.g. `u32ne`.
+    Native,
+    /// PDP-11 order, e.g. `u32me`: the more significant 16-bit half first, with each half least significant byte first.
+    ///
+    /// The bytes `01 02 03 04` are the value `0x0201_0403`. Only `u32` and `i32` fields use this order.
+    Middle,
+}
+
+impl NumberType {
+    /// The number of bytes in the type.
+    pub fn size(self) -> usize {
+        match self {
+            NumberType::U16 | NumberType::I16 => 2,
+            NumberType::U24 | NumberType::I24 => 3,
+            NumberType::U48 | NumberType::I48 => 6,
+            NumberType::U32 | NumberType::I32 | NumberType::F32 => 4,
+            NumberType::U64 | NumberType::I64 | NumberType::F64 => 8,
+            NumberType::U128 | NumberType::I128 => 16,
+        }
+    }
+}
+
+impl FieldKind {
+    /// Whether the field is an unsigned integer of at most 64 bits, such as a length or offset.
+    pub const fn is_unsigned_integer(self) -> bool {
+        matches!(
+            self,
+            FieldKind::U8
+                | FieldKind::Number(NumberType::U16 | NumberType::U32 | NumberType::U64, _)
+        )
+    }
+
+    // Encode an unsigned integer as the bytes of a field of this kind. Return None if the field is not an unsigned
+    // integer of at most 64 bits or the value does not fit.
+    pub(crate) fn encode_unsigned(self, value: u64) -> Option<Vec<u8>> {
+        let size = match self {
+            FieldKind::U8 => 1,
+            FieldKind::Number(number_type, _) if self.is_unsigned_integer() => number_type.size(),
+            _ => return None,
+        };
+        if size < 8 && value >> (size * 8) != 0 {
+            return None;
+        }
+        let bytes = value.to_be_bytes()[8 - size..].to_vec();
+        Some(match self {
+            FieldKind::Number(_, endianness) => endianness.reorder_big_endian(bytes),
+            _ => bytes,
+        })
+    }
+}
+
+impl Endianness {
+    // Reorder big-endian bytes into this byte order.
+    pub(crate) fn reorder_big_endian(self, mut bytes: Vec<u8>) -> Vec<u8> {
+        let is_big = match self {
+            Endianness::Big => true,
+            Endianness::Little => false,
+            Endianness::Native => cfg!(target_endian = "big"),
+            Endianness::Middle => {
+                bytes.chunks_exact_mut(2).for_each(|half| half.swap(0, 1));
+                return bytes;
+            }
+        };
+        if !is_big {
+            bytes.reverse();
+        }
+        bytes
+    }
+
+    // The index of the byte of a number of `size` bytes in this byte order that holds the byte at `index` in big-endian
+    // order.
+    pub(crate) fn big_endian_index(self, index: usize, size: usize) -> usize {
+        match self {
+            Endianness::Big => index,
+            Endianness::Little => size - 1 - index,
+            Endianness::Native if cfg!(target_endian = "big") => index,
+            Endianness::Native => size - 1 - index,
+            Endianness::Middle => index ^ 1,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////
+// Diffing
+//////////////////////////////////////////////////////////////////////
+
+/// A single difference between two [`Layout`]s, as reported by [`layout_diff`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LayoutChange {
+    /// A field exists in the new layout but not the old one.
+    Added {
+        name: &'static str,
+        offset: usize,
+        size: usize,
+    },
+    /// A field exists in the old layout but not the new one.
+    Removed {
+        name: &'static str,
+        offset: usize,
+        size: usize,
+    },
+    /// A field exists in both layouts but starts at a different offset.
+    Moved {
+        name: &'static str,
+        old_offset: usize,
+        new_offset: usize,
+    },
+    /// A field exists in both layouts but occupies a different number of bytes.
+    Resized {
+        name: &'static str,
+        old_size: usize,
+        new_size: usize,
+    },
+    /// A field exists in both layouts but is declared with a different type.
+    TypeChanged {
+        name: &'static str,
+        old_ty: &'static str,
+        new_ty: &'static str,
+    },
+}
+
+impl LayoutChange {
+    /// The name of the field this change applies to.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LayoutChange::Added { name, .. }
+            | LayoutChange::Removed { name, .. }
+            | LayoutChange::Moved { name, .. }
+            | LayoutChange::Resized { name, .. }
+            | LayoutChange::TypeChanged { name, .. } => name,
+        }
+    }
+}
+
+/// Compare two layouts field-by-field, matching fields by name.
+///
+/// Fields present in both layouts are reported first (in the order of the old layout), with one change for each of
+/// offset, size, and type that differs. Removed fields are reported in the order of the old layout, followed by added
+/// fields in the order of the new layout. Anonymous padding is not compared, since it has no name to match on; its
+/// effect shows up as moved fields instead.
+///
+/// # Examples
+///
+/// ```
+/// byteview::byteview_ref! {
+///     struct HeaderV1 {
+///         index: u16be,
+///     }
+/// }
+///
+/// byteview::byteview_ref! {
+///     struct HeaderV2 {
+///         index: u16be,
+///         flags: u8,
+///     }
+/// }
+///
+/// let changes = byteview::layout_diff(&HeaderV1::layout(), &HeaderV2::layout());
+/// assert!(changes.iter().all(|change| matches!(change, byteview::LayoutChange::Added { .. })));
+/// ```
+pub fn layout_diff(old: &Layout, new: &Layout) -> Vec<LayoutChange> {
+    let mut changes = Vec::new();
+    let mut removed = Vec::new();
+    for old_field in old.named_fields() {
+        let Some(new_field) = new.field(old_field.name) else {
+            removed.push(LayoutChange::Removed {
+                name: old_field.name,
+                offset: old_field.offset,
+                size: old_field.size,
+            });
+            continue;
+        };
+        let name = old_field.name;
+        if old_field.offset != new_field.offset {
+            changes.push(LayoutChange::Moved {
+                name,
+                old_offset: old_field.offset,
+                new_offset: new_field.offset,
+            });
+        }
+        if old_field.size != new_field.size {
+            changes.push(LayoutChange::Resized {
+                name,
+                old_size: old_field.size,
+                new_size: new_field.size,
+            });
+        }
+        if old_field.ty != new_field.ty {
+            changes.push(LayoutChange::TypeChanged {
+                name,
+                old_ty: old_field.ty,
+                new_ty: new_field.ty,
+            });
+        }
+    }
+    changes.extend(removed);
+    changes.extend(
+        new.named_fields()
+            .filter(|new_field| old.field(new_field.name).is_none())
+            .map(|new_field| LayoutChange::Added {
+                name: new_field.name,
+                offset: new_field.offset,
+                size: new_field.size,
+            }),
+    );
+    changes
+}
@@ -41,84 +41,71 @@
 //! - Delayed field validation.
 //!    - Since validation on fields is only performed when the fields are used, this can push validation errors deeper
 //!      into your program instead of up-front before you start to use the data.
-//! - Fields can only be primitive numeric types.
-//!    - See the [next section](#more-than-primitive-numeric-types) for strategies on how to handle this limitation.
+//! - Fields must have a built-in field type.
+//!    - Fields are numbers, byte arrays, or one of the [other field types](#other-field-types), optionally converted to
+//!      another type with `as`. See the [next section](#more-than-primitive-numeric-types) for strategies on how to
+//!      handle other types.
 //! - Repeated validation/parsing.
 //!    - Any parsing or validation that is done on the defined "fields" will be done every time the "getter" methods are
 //!      called. The results of calling the "getter" methods are not cached.
-//! - All bytes must be present when created.
-//!    - All of the bytes must be present when instances of the generated struct are created. The generated structs do
-//!      not support any form of streaming or partial construction. If desired, you can achieve results similar to
-//!      streaming by creating structs for smaller "pieces" of the overall data, which can be processed independently as
-//!      data is available.
-//! - Size and structure must be known at compile time.
-//!    - Neither the size nor the structure can depend on runtime data.
-//! - No serialization.
-//!    - Only deserialization is currently supported. Serialization may be added in the future but is not supported
-//!      today.
+//! - No streaming.
+//!    - The bytes of a struct must be present when it is created. Optional trailing fields and prefix structs allow
+//!      creating a struct from fewer bytes, but the generated structs do not support any other form of streaming or
+//!      partial construction. If desired, you can achieve results similar to streaming by creating structs for smaller
+//!      "pieces" of the overall data, which can be processed independently as data is available.
+//! - Field offsets must be known at compile time.
+//!    - Runtime data can decide the length of a record (with `record_len`, optional fields, or trailing varints) and the
+//!      byte order of a struct declared with `#[byteview(endian = "runtime")]`, but not where a field starts.
+//! - No serialization of Rust values.
+//!    - Owned structs can change some fields in place (e.g. counters and flags), and bytes can be written with
+//!      [`Patch`], [`write_at`], [`LengthPrefixedBuilder`], and [`Document::write`], but a struct cannot be built from
+//!      its field values.
+//! - Date and time fields use one backend at a time.
+//!    - Fields such as `timestamp32be` and `#[byteview(tz_offset_minutes)]` convert to `chrono` types with the `chrono`
+//!      feature, which is enabled by default, or to `time` types with the `time` feature. Enabling both is a compile
+//!      error rather than generating two sets of accessors, so disable the default features to use `time`. Without
+//!      either, declare the field as a plain number and convert it yourself.
 //!
 //! # More than primitive numeric types
 //!
-//! Although the field declarations must use primitive numeric types (or byte arrays), there are various strategies to
-//! help deal with other types.
-//!
-//! The general strategy is to declare a private field/"getter" method and expose a public method that parses the raw
-//! bytes/number.
-//!
-//! This example shows how to provide getter methods for bytes that encode a bool and an enum that look just like the
-//! "getter" methods that return primitive numeric types.
+//! Besides primitive numeric types and byte arrays, many common types are built in (see
+//! [Other field types](#other-field-types)). For example, a `bool` field is a byte that must be 0 or 1, and `u8 as State`
+//! converts a byte to an enum declared with [`byteview_enum!`](byteview_enum!). Both "getters" return the byte as an
+//! error if it is invalid.
 //!
 //! ```
-//! byteview::byteview_owned! {
-//!     struct Post {
-//!         pub len: u32be, // This is already the appropriate type, no conversion necessary
-//!         _is_public: u8, // This is a boolean field
-//!         _state: u8,     // This is a State enum
+//! byteview::byteview_enum! {
+//!     #[derive(Debug, Eq, PartialEq)]
+//!     pub enum State: u8 {
+//!         Draft = 0,
+//!         Scheduled = 1,
+//!         Published = 2,
+//!         Retracted = 3,
 //!     }
 //! }
 //!
-//! impl Post {
-//!     // Provide a public "getter" method that returns the appropriate type (bool).
-//!     pub fn is_public(&self) -> bool {
-//!         // Use the private "getter" method generated by byteview_owned!
-//!         self._is_public() == 1
-//!     }
-//!
-//!     // Provide a public "getter" method that returns the appropriate type (State). Since the conversion is fallible, we
-//!     // wrap it in an Option.
-//!     pub fn state(&self) -> Option<State> {
-//!         // Use the private "getter" method generated by byteview_owned!
-//!         Some(match self._state() {
-//!             0 => State::Draft,
-//!             1 => State::Scheduled,
-//!             2 => State::Published,
-//!             3 => State::Retracted,
-//!             _ => return None,
-//!         })
+//! byteview::byteview_owned! {
+//!     struct Post {
+//!         pub len: u32be,         // A primitive numeric type
+//!         pub is_public: bool,    // A byte that must be 0 or 1
+//!         pub state: u8 as State, // A byte converted to a State
 //!     }
 //! }
 //!
-//! # #[derive(Debug, Eq, PartialEq)]
-//! # enum State {
-//! #     Draft,
-//! #     Scheduled,
-//! #     Published,
-//! #     Retracted,
-//! # }
-//! #
-//! let bytes = *b"\x00\x00\x00\x07\x01\x02";
-//! let post = Post::from_array(bytes);
+//! let post = Post::from_array(*b"\x00\x00\x00\x07\x01\x02");
 //! assert_eq!(7, post.len());
-//! assert!(post.is_public());
-//! assert_eq!(Some(State::Published), post.state());
-//! ```
+//! assert_eq!(Ok(true), post.is_public());
+//! assert_eq!(Ok(State::Published), post.state());
 //!
-//! Similar strategies can be used to include fields that are:
+//! let post = Post::from_array(*b"\x00\x00\x00\x07\x02\x09");
+//! assert_eq!(Err(2), post.is_public());
+//! assert_eq!(Err(9), post.state());
+//! ```
 //!
-//! - Strings
-//!    - Parse a string from a fixed-size byte array field
-//! - Nested data structures (including other structs generated by `byteview`)
-//!    - Parse the nested data structure from a fixed-size byte array
+//! Other types can implement [`FromFieldValue`] to be used with `as` in the same way. Otherwise, the general strategy is
+//! to declare a private field/"getter" method and expose a public method that parses the raw bytes/number, e.g. for
+//! strings in an encoding other than ASCII or UTF-16, or for nested data structures that are not structs generated by
+//! `byteview`.
 //!
 //! # Example
 //!
@@ -126,46 +113,363 @@
 //! byteview::byteview_ref! {
 //!     #[derive(Debug)]
 //!     pub struct EntryHeaderRef {
-//!         pub index: u32be, // Multi-byte primitive numeric type
-//!         _kind: u8,        // Single byte
-//!         _: u8,            // Single byte, unused
-//!         _name: [u8; 16],  // Byte array
+//!         pub index: u32be,     // Multi-byte primitive numeric type
+//!         pub kind: u8 as Kind, // Single byte converted to a Kind
+//!         _: u8,                // Single byte, unused
+//!         _name: [u8; 16],      // Byte array
 //!     }
 //! }
 //!
 //! impl<'a> EntryHeaderRef<'a> {
-//!     pub fn kind(&self) -> Option<Kind> {
-//!         Some(match self._kind() {
-//!             0 => Kind::Foo,
-//!             1 => Kind::Bar,
-//!             2 => Kind::Baz,
-//!             _ => return None,
-//!         })
-//!     }
-//!
 //!     pub fn name(&self) -> &[u8] {
 //!         let name = self._name();
-//!         match name.into_iter().position(|b| *b == 0) {
+//!         match name.iter().position(|b| *b == 0) {
 //!             Some(i) => &name[..i],
 //!             None => name,
 //!         }
 //!     }
 //! }
 //!
-//! #[derive(Debug, Eq, PartialEq)]
-//! pub enum Kind {
-//!     Foo,
-//!     Bar,
-//!     Baz,
+//! byteview::byteview_enum! {
+//!     #[derive(Debug, Eq, PartialEq)]
+//!     pub enum Kind: u8 {
+//!         Foo = 0,
+//!         Bar = 1,
+//!         Baz = 2,
+//!     }
 //! }
 //!
 //! let bytes = b"\x00\x00\x07\x01\x02\x2AMy Field Name\x00\x00\x00";
 //! let entry_header = EntryHeaderRef::from_array(bytes);
 //! assert_eq!(1793, entry_header.index());
-//! assert_eq!(Some(Kind::Baz), entry_header.kind());
+//! assert_eq!(Ok(Kind::Baz), entry_header.kind());
 //! assert_eq!(b"My Field Name", entry_header.name());
 //! ```
 //!
+//! Fields named `_` are padding, which takes up bytes but has no "getter". Padding can have any field type, e.g.
+//! `_: [u8; 13]` or `_: u32be` to skip several bytes at once, and a struct can have any number of padding fields.
+//!
+//! Other attributes on the struct, such as `#[derive(...)]`, are passed through to the generated struct. A borrowed
+//! struct only holds a reference to its bytes (and its endianness, if it is chosen at runtime), so it can derive `Clone`,
+//! `Copy`, `PartialEq`, `Eq`, and `Hash` and be passed around by value; two structs are equal when their bytes are equal.
+//! An owned struct can derive the same traits, except that `Copy` is rejected by the compiler when its bytes are stored
+//! in a `Vec`.
+//!
+//! To generate both a borrowed and an owned struct from one declaration, use [`byteview!`](byteview!). For a struct
+//! named `EntryHeader`, it generates `EntryHeaderRef<'a>` and `EntryHeaderOwned` (or the names given with
+//! `#[byteview(ref_name = "...", owned_name = "...")]`), along with `to_owned()` and `as_ref()` methods that convert
+//! between them. The two structs can also be compared to each other with `==`, which compares their bytes. Padding bytes
+//! are compared too, so structs whose fields are all equal are not equal if their padding bytes differ.
+//!
+//! Each macro accepts several struct declarations, which are generated independently as if each were passed to its own
+//! invocation. Structs declared together can be used as nested fields of each other.
+//!
+//! Tuple structs are also supported, e.g. `pub struct Crc(pub u32be);` for a record that is a single value. Their fields
+//! are named by position, so the "getters" are `field_0()`, `field_1()`, and so on, except that the "getter" of a tuple
+//! struct with only one field is `get()`. Its layout, constants, and other methods still use the positional name, e.g.
+//! `OFFSET_FIELD_0` and `field_0_raw()`.
+//!
+//! Structs can have const generic parameters that are used as array lengths, e.g. `pub struct Batch<const N: usize>` with
+//! a `slots: [u8; N]` field. `NUM_BYTES`, the offsets of later fields, and the "getters" depend on the parameters, e.g.
+//! `Batch::<8>::split_slice(bytes)`. Array lengths cannot be computed from the parameters, so the bytes are stored in a
+//! slice (or `Vec`) that always has exactly `NUM_BYTES` bytes, and `from_array` checks the length of the array when it is
+//! compiled. These structs cannot have optional fields, varint fields, `record_len`, `export_c`, or a prefix boundary.
+//!
+//! # Other field types
+//!
+//! In addition to primitive numeric types and byte arrays, fields can have the following types.
+//!
+//! - `[u32be; 1024]`
+//!    - An array of any numeric type. The "getter" returns an array of decoded values. For a field named `foo`,
+//!      `foo_iter()` returns an iterator that decodes the elements as they are needed and `foo_at(i)` decodes a single
+//!      element (returning `None` if `i` is out of range). Annotating the field with `#[byteview(iter_only)]` skips the
+//!      "getter", which is useful for large arrays that are usually scanned or searched rather than copied.
+//! - `[[u8; 16]; 4]`
+//!    - An array of byte arrays, e.g. four rows of sixteen bytes. The "getter" returns a reference to the rows without
+//!      copying them.
+//! - `packed<u12be, 64>`
+//!    - An array of 64 unsigned integers of 12 bits each, packed back to back (so 3 bytes hold 2 elements). The element
+//!      width can be from 1 to 64 bits. With `be`, elements are packed starting from the most significant bit of the
+//!      first byte; with `le`, they are packed starting from the least significant bit. The field occupies a whole
+//!      number of bytes, and the "getter" returns a `Packed` view that can iterate over the elements (as the smallest
+//!      unsigned integer type that holds them) or copy them into a slice.
+//! - `[u8; 16] as [Mode; 16]`
+//!    - A byte array whose bytes are each converted with `TryFrom<u8>`. The "getter" returns a `Result` containing the
+//!      converted array, or the index and value of the first byte that cannot be converted. For a field named `foo`,
+//!      `foo_at(i)` converts a single element (returning `None` if `i` is out of range).
+//! - `u8 as DataInfo`
+//!    - A number converted with [`FromFieldValue`]. The "getter" returns the type chosen by the implementation, which can be a
+//!      `Result` for fallible conversions. Types defined with [`byteview_bits!`] implement it, so a byte holding a 4-bit kind
+//!      and a 4-bit length can be declared once and used as the type of many fields. So do enums defined with
+//!      [`byteview_enum!`], whose "getters" return the raw value as an error if it does not match a variant.
+//! - `[u8; 8] as Signature`
+//!    - A byte array converted with [`FromFieldValue`], which receives a reference to the array (e.g. `FromFieldValue<&[u8; 8]>`).
+//!      This wraps the bytes in a newtype, which can be checked with a `Result` like a number.
+//! - `flags u8 { COMPRESSED = 0, ENCRYPTED = 1, TRUNCATED = 7 }`
+//!    - An unsigned integer whose bits are named flags, where bit 0 is the least significant bit. The "getter" returns the
+//!      raw value, so unnamed bits are still available. Each flag gets a method such as `is_compressed()`, a constant such
+//!      as `COMPRESSED` holding its bit (e.g. `0x01`), and on owned structs a method such as `set_compressed(true)`.
+//! - `bool`
+//!    - A byte that must be 0 (`false`) or 1 (`true`), i.e. shorthand for `u8 as bool`. The "getter" returns a
+//!      `Result<bool, u8>` containing the byte as an error if it is anything else.
+//! - `charbe`, `charle`, or `charne`
+//!    - A 32-bit Unicode scalar value, i.e. shorthand for `u32be as char` (or `u32le` or `u32ne`). The "getter" returns
+//!      an [`Option<char>`], which is [`None`] for surrogates and values above `0x10FFFF`.
+//! - `nonzero_u32be`
+//!    - An integer that must not be zero, e.g. a record ID, i.e. shorthand for `u32be as NonZero<u32>`. Any integer type
+//!      can follow `nonzero_`. The "getter" returns an `Option<NonZero<u32>>`, which is [`None`] if the value is zero.
+//! - `q16_16be` or `q8_8be`
+//!    - A signed fixed-point number in Q format, e.g. 16 integer bits and 16 fractional bits stored as an `i32be`. The
+//!      integer and fractional bits must add up to 8, 16, or 32, and 8-bit numbers have no byte order (e.g. `q1_7`). The
+//!      "getter" returns the exact value as an `f64`, e.g. the raw value divided by 65536 for `q16_16be`.
+//! - `bcd<4>`
+//!    - A 4-byte packed binary-coded decimal (BCD) number, where each nibble is a decimal digit and the most significant
+//!      digit comes first. The "getter" returns a `Result<u64, [`BcdError`]>`, which is an error identifying the byte if any
+//!      nibble is greater than 9. The field can have at most 9 bytes (18 digits).
+//! - `ascii<16>`
+//!    - Printable ASCII text padded with trailing spaces or NUL bytes. The "getter" returns an `Option<&str>` with the
+//!      padding trimmed, which is [`None`] if any other byte is not printable ASCII. The padded bytes are still available
+//!      from `foo_raw()`.
+//! - `utf16be<32>` or `utf16le<32>`
+//!    - A string of 32 UTF-16 code units (64 bytes), ending at the first NUL code unit if there is one. The "getter"
+//!      returns an `Option<String>`, which is [`None`] if the string is not valid UTF-16 (e.g. it has an unpaired
+//!      surrogate). For a field named `foo`, `foo_lossy()` replaces invalid UTF-16 with U+FFFD instead.
+//! - `uuid`
+//!    - A 16-byte UUID. The "getter" returns a [`Uuid`], which is formatted in the canonical hyphenated form (e.g.
+//!      `67e55044-10b1-426f-9247-bb680e5fe0c8`). The bytes are still available from `foo_raw()`. With the `uuid` feature,
+//!      it converts to and from `uuid::Uuid` with `From`.
+//! - `mac`
+//!    - A 6-byte MAC address. The "getter" returns a [`MacAddr`], which is formatted as `aa:bb:cc:dd:ee:ff`, can be compared
+//!      to a `[u8; 6]`, and has methods such as `is_broadcast()` and `is_multicast()`.
+//! - `ipv4` or `ipv6`
+//!    - A 4-byte or 16-byte IP address in network byte order. The "getter" returns an [`Ipv4Addr`](std::net::Ipv4Addr) or an
+//!      [`Ipv6Addr`](std::net::Ipv6Addr).
+//! - `timestamp32be` or `timestamp64be`
+//!    - The number of seconds since the Unix epoch, stored as a `u32be` or an `i64be` (or in another byte order, e.g.
+//!      `timestamp32le`). The "getter" returns an `Option<DateTime<Utc>>`, which is [`None`] if the value is zero (i.e. not
+//!      set) or out of range. This requires the `chrono` feature. With the `time` feature instead, the "getter" returns
+//!      an `Option<OffsetDateTime>`.
+//! - `timestamp_ms64be`
+//!    - The number of milliseconds since the Unix epoch, stored as a `u64be` (or in another byte order). The "getter"
+//!      returns an `Option<SystemTime>`, which is [`None`] if the value is zero or out of range. This does not require
+//!      `chrono` or `time`.
+//! - `varint`
+//!    - An unsigned LEB128 varint of 1 to 10 bytes, e.g. a count in a protobuf-style header. The "getter" returns a `u64`.
+//!      Varints must be the last fields of the struct, which makes it variable-sized like a struct with optional fields:
+//!      there is no `from_array`, and `split_slice` reads the varints to find where the record ends. `split_slice_checked`
+//!      returns [`ParseError::InvalidVarint`] if a varint is longer than 10 bytes or does not fit in a `u64`. Varints are
+//!      not counted in `NUM_BYTES` or included in the `LAYOUT`.
+//! - `DeviceId<'a>` or `DeviceIdOwned`
+//!    - Another struct generated by [`byteview_ref!`] or [`byteview_owned!`], nested inside this one. Any type whose name
+//!      starts with an uppercase letter is treated as a nested struct. In `byteview_ref!`, the "getter" of a
+//!      `DeviceId<'a>` field borrows the same bytes as the outer struct. In `byteview_owned!`, a `DeviceIdOwned` field is
+//!      copied out of the buffer, while a `DeviceId<'_>` field borrows it. The nested struct must not have optional
+//!      fields or a length field.
+//! - `[EntryHeader<'a>; 4]`
+//!    - An array of nested structs stored back to back. The "getter" returns a [`Table`] that borrows the bytes, so
+//!      elements can be indexed with `get(i)` or iterated with `iter()` without copying the array.
+//!
+//! Every field that is converted to another type also has a `foo_raw()` method that returns the value as it is stored in
+//! the bytes (with endianness applied but no other conversion), even if the conversion fails.
+//!
+//! The "getter" and the other methods of a field have the same visibility as the field, so `pub index: u32be` creates a
+//! `pub fn index()`, a `pub(crate)` field creates a `pub(crate)` method, and a field without `pub` creates a private
+//! method. This keeps fields that are only needed for the layout out of the public API of a `pub` struct. The
+//! constructors have the visibility of the struct.
+//!
+//! Doc comments and other attributes on a field are copied to its "getter", followed by a line with the bytes the field
+//! is stored in, e.g. "Bytes 4..8 (4 bytes)." for a `u32be` field after a `u32be` field.
+//!
+//! Each field also has constants with its offset and size in bytes, e.g. `OFFSET_LOG_TYPE` and `SIZE_LOG_TYPE` for a
+//! field named `log_type` (or with `#[byteview(getter = "log_type")]`), which can be used to modify individual bytes or
+//! in array lengths. Padding fields named `_` and varints do not have these constants.
+//!
+//! The same information is available at runtime from the `FIELDS` constant, which has a [`FieldInfo`] with the name,
+//! offset, size, type, and kind of every field in declaration order. Padding fields are included with the name `_`, so
+//! tools such as hexdump annotators can describe every byte of any struct, e.g. through the `ByteView` trait.
+//!
+//! To make the build fail if the layout drifts from a specification, use [`assert_size!`] and [`assert_offset!`], e.g.
+//! `byteview::assert_size!(HeaderStart, 42);` and `byteview::assert_offset!(HeaderStart, log_type, 40);`. These expand to
+//! constant assertions, so they can be used in any module or function body.
+//!
+//! Fields can have `#[cfg(...)]` attributes, e.g. for a field that is only part of one revision of a format selected with a
+//! Cargo feature. When the condition is false, the field and its methods are left out, and its bytes are not counted in
+//! `NUM_BYTES` or the offsets of later fields. Optional, varint, length, magic, and prefix boundary fields cannot have
+//! `#[cfg(...)]`, and neither can the fields of a struct with `record_len`, `export_c`, or a length field.
+//!
+//! # Attributes
+//!
+//! Fields can be annotated with `#[byteview(...)]` attributes to generate additional methods. These attributes are not
+//! passed through to the "getter" method.
+//!
+//! - `#[byteview(offset_into_buffer)]`
+//!    - The field is an unsigned integer holding an absolute offset into the buffer that contains the struct (e.g. the
+//!      whole file). For a field named `foo`, this generates `foo_target(buffer)`, which returns the bytes of `buffer`
+//!      starting at that offset, and `foo_resolve::<T>(buffer)`, which creates another `byteview` struct from the bytes
+//!      at that offset. Both return an [`OffsetError`] instead of panicking if the offset is out of bounds.
+//! - `#[byteview(len_of_rest)]` and `#[byteview(len_includes_header)]`
+//!    - The field is an unsigned integer holding the number of bytes that follow the struct (`len_of_rest`) or the
+//!      number of bytes in the struct plus the bytes that follow it (`len_includes_header`). `split_slice_checked`
+//!      returns a [`ParseError::LengthMismatch`] if fewer bytes are available than declared. Adding `truncate_rest` (e.g.
+//!      `#[byteview(len_of_rest, truncate_rest)]`) also truncates the returned remaining bytes to the declared length.
+//! - `#[byteview(prefix_boundary)]`
+//!    - Generate a second struct named `FooPrefix` (for a struct named `Foo`) containing only the fields up to and
+//!      including this one, along with a `Foo::prefix()` method that returns the prefix of a full struct. This is useful
+//!      when only the first few bytes of a record are available. Both structs are generated from the same fields, so the
+//!      offsets are always the same.
+//! - `#[byteview(optional)]`
+//!    - The field may be missing from the end of shorter records (e.g. records written by an older version of a
+//!      format). Only trailing fields may be optional. The "getter" returns an [`Option`], `split_slice` accepts bytes
+//!      that end after the last mandatory field, and `byte_len()` returns the number of bytes actually in the record.
+//!    - Adding a default value (e.g. `#[byteview(optional, default = 0xFFFF_FFFF)]`) makes the "getter" return the
+//!      default instead of an [`Option`] when the field is missing. The default must be a constant expression of the
+//!      field's type and is available as an associated constant (e.g. `CHECKSUM_DEFAULT`). A `foo_present()` method
+//!      reports whether an optional field `foo` is present, and owned structs whose optional fields all have defaults
+//!      have an `into_long_form()` method that appends the defaults of any missing fields.
+//! - `#[byteview(reserved)]`
+//!    - The field is reserved for future use. Its "getter" is generated as usual, but like padding fields named `_` it is
+//!      set to zero by `normalize()` on owned structs and in the bytes returned by `normalized()`. Records that differ
+//!      only in padding or reserved bytes then have identical bytes, e.g. for hashing.
+//! - `#[byteview(must_be_zero)]`
+//!    - The field (which may be a padding field named `_`) is reserved and must contain only zero bytes. This generates a
+//!      `validate()` method that returns a [`ValidateError`] with the name and offset of the first such field that contains
+//!      a byte other than zero. The constructors do not check this, so nonconforming records can still be inspected.
+//! - `#[byteview(bitmap)]`
+//!    - The field is a byte array whose bits each hold a flag. For a field named `foo`, this generates `foo_bit(i)`
+//!      (returning [`None`] if `i` is out of range), `foo_count_ones()`, `foo_iter_ones()` (the indices of the set bits),
+//!      and `foo_bitmap()`, along with `set_foo_bit(i, value)` on owned structs. Bits are numbered from the least
+//!      significant bit of the first byte, so bit 8 is the least significant bit of the second byte. Adding
+//!      `bit_order = "msb0"` numbers the bits of each byte from the most significant bit instead.
+//! - `#[byteview(subfields(kind = 4..8, length = 0..4))]`
+//!    - The field is an unsigned integer whose bits hold several smaller values. Each subfield gets a "getter" that
+//!      masks and shifts the field, e.g. `kind()` returns bits 4 through 7 shifted down to bit 0. Bit 0 is the least
+//!      significant bit. The masks and shifts are also available as constants, e.g. `KIND_MASK` and `KIND_SHIFT`.
+//! - `#[byteview(tz_offset_quarter_hours)]` and `#[byteview(tz_offset_minutes)]`
+//!    - The field is a signed time zone offset east of UTC, stored as an `i8` number of quarter hours or an `i16` number
+//!      of minutes (e.g. `i16be`). Its "getter" returns an `Option<chrono::FixedOffset>`, which is [`None`] if the offset
+//!      is more than 18 hours from UTC, and `foo_raw()` returns the stored number. This requires the `chrono` feature,
+//!      which is enabled by default. With the `time` feature instead, the "getter" returns an `Option<UtcOffset>`.
+//! - `#[byteview(duration(unit = "10ms"))]`
+//!    - The field is an unsigned integer number of units of time, e.g. ticks of 10 milliseconds since boot. For a field
+//!      named `foo`, `foo_duration()` returns the value as a `std::time::Duration`, saturating at `Duration::MAX` if it is
+//!      too large. The unit is `s`, `ms`, `us`, or `ns`, optionally preceded by a multiplier.
+//! - `#[byteview(counter)]`
+//!    - The field is an unsigned integer counter, e.g. a sequence number. Owned structs get
+//!      `increment_foo_wrapping()`, `increment_foo_saturating()`, `add_foo_wrapping(n)`, and `add_foo_saturating(n)`,
+//!      which update the field in place and return the new value. To update a record in a larger buffer without
+//!      creating a struct, use `add_foo_wrapping_in(bytes, n)` or `add_foo_saturating_in(bytes, n)`, which return
+//!      [`None`] if `bytes` is too short.
+//! - `#[byteview(magic = b"LOG1")]`
+//!    - The field is a byte array that must contain the given bytes, e.g. a file signature or sync word. Checked
+//!      constructors such as `split_slice_checked` return [`ParseError::MagicMismatch`] with the expected and actual
+//!      bytes if it does not, while the unchecked constructors accept any bytes. The bytes and their offset are
+//!      available as the `MAGIC` constant, `find_sync(bytes, from)` returns the offset of the next struct whose magic
+//!      bytes match, and [`Resync`] iterates over records while skipping damaged regions.
+//!    - This can also be written by giving the field a value, e.g. `magic: [u8; 4] = *b"LOG1"`.
+//! - `#[byteview(endian = "big")]`
+//!    - The field is a number (or an array of numbers) written without a byte order, e.g. `u32` or `[u16; 4]`, which is
+//!      stored in the given byte order: `"big"`, `"little"`, or `"native"`. This overrides the default byte order set on the
+//!      struct (see below), e.g. for a big-endian magic number in an otherwise little-endian format. The "getter" is the same
+//!      as for `u32be`.
+//! - `#[byteview(getter = "raw_name")]`
+//!    - The "getter" is named `raw_name()` instead of after the field, e.g. for a field named `_name` that is wrapped by a
+//!      hand-written `name()` method. The other methods created for the field use the same name, e.g.
+//!      `raw_name_bitmap()`.
+//!
+//! The struct itself can be annotated with `#[byteview(record_len = "field")]` to make it variable-sized. The named field
+//! is an unsigned integer holding the total number of bytes in the record, including the fixed fields. The generated
+//! struct stores a slice (or `Vec`) instead of an array, `split_slice` consumes the whole record, and `body()` returns
+//! the bytes following the fixed fields. Use [`Records`] to iterate over records stored back to back. For structs that are
+//! not variable-sized, [`Table`] also provides access by index and binary search, and [`FooterIndexed`] opens a table
+//! whose offset and length are stored in a footer at the end of a file. [`Document`] parses a whole file made of a header,
+//! records, and a footer, and writes it back after the parts are modified.
+//!
+//! Every struct has `MIN_NUM_BYTES` and `MAX_NUM_BYTES` constants bounding the size of a valid record, e.g. for
+//! pre-allocating buffers or rejecting corrupt lengths early. These are both `NUM_BYTES` unless the struct has optional
+//! fields (which may be missing), a length field (which may declare more bytes), or varints (which have 1 to 10 bytes
+//! each).
+//!
+//! The struct can also be annotated with `#[byteview(pad_with = 0xFF)]` to fill padding fields with the given byte
+//! instead of zero when it is normalized, e.g. for flash memory where unwritten bytes must stay `0xFF`. The byte is
+//! available as the `PAD_BYTE` constant. Reading is unaffected.
+//!
+//! With the `zeroize` feature, owned structs implement `zeroize::Zeroize`, which overwrites their bytes with zeros
+//! (including the spare capacity of a variable-sized struct's `Vec`) without changing their length. Annotate the struct
+//! with `#[byteview(zeroize_on_drop)]` to also overwrite the bytes when an owned struct is dropped, which does not need the
+//! feature. Only the struct's own buffer is wiped: a clone is a separate buffer that is not wiped when the original is
+//! zeroized, and bytes copied out with `as_bytes()`, the bytes left over by constructors like `split_vec`, and the copies
+//! left behind when a fixed-size struct is moved are not wiped at all. A struct that is wiped on drop cannot derive
+//! `Copy`, and the attribute is an error in `byteview_ref!`, since borrowed structs do not own their bytes.
+//!
+//! The struct can also be annotated with `#[byteview(export_c = "entry_header")]` to export `extern "C"` functions for C
+//! programs that link a Rust static library. For each field `foo`, `entry_header_foo(buf, len, out)` reads the field from
+//! the `len` bytes at `buf` and writes it to `out` (a byte array is copied to the bytes at `out`), and
+//! `entry_header_size()` returns `NUM_BYTES`. The functions never panic. They return [`ExportStatus`] codes as an `int`:
+//! zero on success, or a negative value if a pointer is null or there are too few bytes. Fields are read as stored, without
+//! conversions or validation, and 128-bit numbers and arrays of numbers are not exported.
+//!
+//! The struct can also be annotated with `#[byteview(endian = "little")]` to set the byte order of number fields written
+//! without one, e.g. `u32` or `[u16; 4]`. Fields with an explicit byte order (e.g. `u32be`) are unaffected, and a field
+//! annotated with `#[byteview(endian = "...")]` uses its own byte order instead.
+//!
+//! To read the same layout in either byte order, annotate the struct with `#[byteview(endian = "generic")]` instead. The
+//! struct gets a type parameter, e.g. `Record<'a, E: byteview::Endian>`, and fields without a byte order use the order of
+//! [`BigEndian`] or [`LittleEndian`], as in `Record::<LittleEndian>::split_slice(bytes)`. The order is a constant, so the
+//! "getters" compile to the same code as with an explicit byte order. These structs cannot use `export_c` or
+//! `prefix_boundary`.
+//!
+//! If the byte order is only known once the bytes are read, e.g. from a byte-order mark, annotate the struct with
+//! `#[byteview(endian = "runtime")]`. The struct stores the byte order of fields without one, which is passed to
+//! constructors like `split_slice_with(bytes, ByteOrder::Little)` and returned by `byte_order()`. The other constructors,
+//! including those of [`ByteView`], read big-endian numbers, and nested structs use their own constructors. Since the
+//! order is not known until a struct is constructed, these structs cannot also use `record_len`, length fields,
+//! `export_c`, or `prefix_boundary`, and these fields are described as [`FieldKind::RuntimeNumber`] in the [`Layout`].
+//!
+//! For code that reads a record as a packed native struct (e.g. over FFI), fixed-size structs whose byte order is known
+//! at compile time have a `swap_to_native(&mut bytes)` function. It reorders the bytes of every number field, including
+//! the elements of number arrays and the fields of nested structs, into the byte order of the target platform. Byte
+//! arrays, packed arrays, padding, and nested structs whose byte order is chosen at runtime are left unchanged. Calling
+//! it again restores the original bytes.
+//!
+//! To upgrade a record to a newer version of its struct, [`migrate`] copies each field into the field with the same name
+//! of an owned struct, widening numbers losslessly and leaving new fields zero or their declared default. Removing or
+//! narrowing a field is an error unless it is allowed with [`Migration::allow_lossy`].
+//!
+//! # Endian types
+//!
+//! Inside the macros, `u32be` is only syntax. For hand-written structs and function signatures, the crate also exports
+//! types like [`U32Be`], [`U16Le`], and [`F64Ne`] that store a number as a byte array in a fixed byte order. They have
+//! an alignment of 1, `get()` and `set()` convert to and from the number, and `new()` is a `const fn`.
+//!
+//! ```
+//! use byteview::{U16Le, U32Be};
+//!
+//! #[repr(C)]
+//! struct Header {
+//!     magic: U32Be,
+//!     count: U16Le,
+//! }
+//!
+//! const MAGIC: U32Be = U32Be::new(0x4C4F_4731);
+//! let mut header = Header { magic: MAGIC, count: U16Le::from(3) };
+//! header.count.set(header.count.get() + 1);
+//! assert_eq!([0x4C, 0x4F, 0x47, 0x31], header.magic.to_bytes());
+//! assert_eq!([0x04, 0x00], header.count.0);
+//! ```
+//!
+//! For one-off access without defining a struct, [`read_at`] and [`write_at`] read and write a number at an offset, e.g.
+//! `read_at::<U32Be>(bytes, 12)`, and return an error if the buffer is too short.
+//!
+//! # Testing
+//!
+//! [`assert_views_eq!`] compares two views like `assert_eq!`, but when they differ the panic message lists only the fields that
+//! differ, with their offsets, bytes, and decoded values, instead of two long byte arrays. The views can be different
+//! types with the same layout, e.g. a borrowed and an owned struct declared with the same fields.
+//!
+//! With the `testing` feature, `byteview::testing::check_roundtrip::<FooOwned>()` checks that a struct contains exactly
+//! the bytes it was created from and that setting each field to its own value leaves the bytes unchanged, for a set of
+//! generated buffers. `RoundTrip` adds specific buffers, skips fields, and compares against the borrowed struct.
+//!
 //! # Comparison to other crates
 //!
 //! The `byteview` crate is similar in many ways to the [`zerocopy`] crate. The biggest difference is that `zerocopy`
@@ -195,20 +499,6 @@
 //! [`strum`]: https://crates.io/crates/strum
 //! [`strum_macros`]: https://crates.io/crates/strum_macros
 
-use proc_macro::TokenStream;
-use quote::{ToTokens, TokenStreamExt, quote};
-use std::str::FromStr;
-use syn::parse::{Error, Parse};
-use syn::spanned::Spanned;
-use syn::{
-    Attribute, Expr, Field, FieldMutability, Generics, Ident, ItemFn, ItemStruct, Type, TypeArray,
-    TypePath, Visibility, parse, parse_macro_input, parse_quote,
-};
-
-//////////////////////////////////////////////////////////////////////
-// Procedural Macro Wrappers
-//////////////////////////////////////////////////////////////////////
-
 /// Define a struct that is a thin "view" into a byte array reference, i.e. `&[u8; N]`.
 ///
 /// # Motivation
@@ -222,11 +512,17 @@ use syn::{
 ///
 /// # Usage
 ///
-/// This macro expects a struct declaration. The permitted field types are:
+/// This macro expects one or more struct declarations, which are generated independently. The permitted field types
+/// are:
 /// 1. A single byte, i.e. [`u8`] or [`i8`].
 /// 2. Any other primitive integer or floating-point numeric type along with an "endianness" suffix: "be" (big endian),
 ///    "le" (little endian), or "ne" (native endian).
 ///    - E.g. `u16be` (big-endian `u16`), `i32le` (little-endian `i32`), `f64ne` (native-endian `f64`), etc.
+///    - `u32` and `i32` may also use "me" (PDP-11 middle endian): the more significant 16-bit half comes first, and each
+///      half is stored least significant byte first. The bytes `01 02 03 04` are the `u32me` value `0x0201_0403`.
+///    - `u24` and `i24` are 3-byte integers, e.g. `u24be`. Their "getters" return a `u32` or a sign-extended `i32`, and
+///      the most significant byte is dropped when a value is written. Likewise, `u48` and `i48` are 6-byte integers
+///      returned as a `u64` or `i64`.
 /// 3. An array of bytes, i.e. `[u8; N]` where `N` is a `const` `usize`.
 ///
 /// # Generated Code
@@ -248,6 +544,13 @@ use syn::{
 /// However, these fields can be "ignored" by using a field name of `_`. Any field with the name `_` will not produce a
 /// "getter" method but _will_ be used when calculating offsets and the total size.
 ///
+/// The struct also has a `LAYOUT` constant (and a `layout()` function) describing the offset, size, and type of every
+/// field as a [`Layout`]. The same information is rendered as a table in a "Layout" section appended to the struct's
+/// documentation.
+///
+/// Fields can be annotated with `#[byteview(...)]` attributes to generate additional methods. See the
+/// [crate documentation](crate#attributes) for the supported attributes.
+///
 /// # Examples
 ///
 /// ```
@@ -277,7 +580,7 @@ use syn::{
 ///     /// The name of the entry.
 ///     pub fn name(&self) -> &[u8] {
 ///         let name = self._name();
-///         match name.into_iter().position(|b| *b == 0) {
+///         match name.iter().position(|b| *b == 0) {
 ///             Some(i) => &name[..i],
 ///             None => name,
 ///         }
@@ -297,10 +600,8 @@ use syn::{
 /// assert_eq!(Some(Kind::Baz), entry_header.kind());
 /// assert_eq!(b"My Field Name", entry_header.name());
 /// ```
-#[proc_macro]
-pub fn byteview_ref(input: TokenStream) -> TokenStream {
-    byteview_ref_impl(input)
-}
+#[doc(inline)]
+pub use byteview_macros::byteview_ref;
 
 /// Define a struct that is a thin "view" into an owned byte array, i.e. `[u8; N]`.
 ///
@@ -315,11 +616,17 @@ pub fn byteview_ref(input: TokenStream) -> TokenStream {
 ///
 /// # Usage
 ///
-/// This macro expects a struct declaration. The permitted field types are:
+/// This macro expects one or more struct declarations, which are generated independently. The permitted field types
+/// are:
 /// 1. A single byte, i.e. [`u8`] or [`i8`].
 /// 2. Any other primitive integer or floating-point numeric type along with an "endianness" suffix: "be" (big endian),
 ///    "le" (little endian), or "ne" (native endian).
 ///    - E.g. `u16be` (big-endian `u16`), `i32le` (little-endian `i32`), `f64ne` (native-endian `f64`), etc.
+///    - `u32` and `i32` may also use "me" (PDP-11 middle endian): the more significant 16-bit half comes first, and each
+///      half is stored least significant byte first. The bytes `01 02 03 04` are the `u32me` value `0x0201_0403`.
+///    - `u24` and `i24` are 3-byte integers, e.g. `u24be`. Their "getters" return a `u32` or a sign-extended `i32`, and
+///      the most significant byte is dropped when a value is written. Likewise, `u48` and `i48` are 6-byte integers
+///      returned as a `u64` or `i64`.
 /// 3. An array of bytes, i.e. `[u8; N]` where `N` is a `const` `usize`.
 ///
 /// # Generated Code
@@ -341,6 +648,17 @@ pub fn byteview_ref(input: TokenStream) -> TokenStream {
 /// However, these fields can be "ignored" by using a field name of `_`. Any field with the name `_` will not produce a
 /// "getter" method but _will_ be used when calculating offsets and the total size.
 ///
+/// The struct also has a `LAYOUT` constant (and a `layout()` function) describing the offset, size, and type of every
+/// field as a [`Layout`]. The same information is rendered as a table in a "Layout" section appended to the struct's
+/// documentation.
+///
+/// Unless the struct has optional fields, it also has a `split_fields_mut()` method returning a `FooFieldsMut` struct
+/// that holds a mutable reference to the bytes of each named field (e.g. `&mut [u8; 4]` for a `u32be` field). The
+/// references can be held at the same time, e.g. to fill several fields from different sources.
+///
+/// Fields can be annotated with `#[byteview(...)]` attributes to generate additional methods. See the
+/// [crate documentation](crate#attributes) for the supported attributes.
+///
 /// # Examples
 ///
 /// ```
@@ -370,7 +688,7 @@ pub fn byteview_ref(input: TokenStream) -> TokenStream {
 ///     /// The name of the entry.
 ///     pub fn name(&self) -> &[u8] {
 ///         let name = self._name();
-///         match name.into_iter().position(|b| *b == 0) {
+///         match name.iter().position(|b| *b == 0) {
 ///             Some(i) => &name[..i],
 ///             None => name,
 ///         }
@@ -390,688 +708,329 @@ pub fn byteview_ref(input: TokenStream) -> TokenStream {
 /// assert_eq!(Some(Kind::Baz), entry_header.kind());
 /// assert_eq!(b"My Field Name", entry_header.name());
 /// ```
-#[proc_macro]
-pub fn byteview_owned(input: TokenStream) -> TokenStream {
-    byteview_owned_impl(input)
-}
-
-//////////////////////////////////////////////////////////////////////
-// Constants
-//////////////////////////////////////////////////////////////////////
-
-const UNSUPPORTED_TYPE_MESSAGE: &str =
-    "Unsupported type (see byteview documentation for supported types)";
-
-//////////////////////////////////////////////////////////////////////
-// Data Structures
-//////////////////////////////////////////////////////////////////////
-
-#[derive(Debug)]
-struct ByteViewStruct {
-    attrs: Vec<Attribute>,
-    vis: Visibility,
-    ident: Ident,
-    fields: Vec<ByteViewField>,
-}
-
-#[derive(Debug)]
-struct ByteViewField {
-    attrs: Vec<Attribute>,
-    vis: Visibility,
-    ident: Ident,
-    byteview_type: ByteViewType,
-}
-
-#[derive(Debug)]
-enum ByteViewType {
-    SingleByte(Signedness),
-    MultiByte(MultiByteType, Endianness),
-    Array(Expr),
-}
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum Signedness {
-    Unsigned,
-    Signed,
-}
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum MultiByteType {
-    U16,
-    U32,
-    U64,
-    U128,
-    I16,
-    I32,
-    I64,
-    I128,
-    F32,
-    F64,
-}
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum Endianness {
-    Big,
-    Little,
-    Native,
-}
-
-//////////////////////////////////////////////////////////////////////
-// Parsing Tokens
-//////////////////////////////////////////////////////////////////////
-
-impl Parse for ByteViewStruct {
-    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
-        let raw_struct = input.parse::<ItemStruct>()?;
-        let ItemStruct {
-            attrs,
-            vis,
-            ident,
-            generics,
-            fields,
-            ..
-        } = raw_struct;
-        if !generics.params.is_empty() {
-            return Err(Error::new(
-                generics.params.span(),
-                "Generics are not permitted on byteview struct definitions".to_string(),
-            ));
-        }
-        if let Some(where_clause) = generics.where_clause {
-            return Err(Error::new(
-                where_clause.span(),
-                "Generic types are not permitted on byteview struct definitions".to_string(),
-            ));
-        };
-        let fields: Vec<ByteViewField> = fields
-            .into_iter()
-            .map(TryFrom::try_from)
-            .collect::<Result<_, _>>()?;
-        Ok(Self {
-            attrs,
-            vis,
-            ident,
-            fields,
-        })
-    }
-}
-
-impl Parse for ByteViewField {
-    fn parse(input: parse::ParseStream) -> syn::Result<Self> {
-        let field = Field::parse_named(input)?;
-        Self::try_from(field)
-    }
-}
-
-impl TryFrom<Field> for ByteViewField {
-    type Error = syn::Error;
-
-    fn try_from(field: Field) -> std::result::Result<Self, Self::Error> {
-        let field_span = field.span();
-        let Field {
-            attrs,
-            vis,
-            mutability,
-            ident,
-            colon_token: _,
-            ty,
-        } = field;
-        // Future proof
-        let FieldMutability::None = mutability else {
-            return Err(Error::new(
-                field_span,
-                "Field mutability restrictions are not supported".to_owned(),
-            ));
-        };
-        let ident = ident.expect("Field must be named; tuple structs are not permitted");
-        let byteview_type = ByteViewType::try_from(ty)?;
-        Ok(Self {
-            attrs,
-            vis,
-            ident,
-            byteview_type,
-        })
-    }
-}
-
-impl TryFrom<Type> for ByteViewType {
-    type Error = syn::Error;
-
-    fn try_from(ty: Type) -> Result<Self, Self::Error> {
-        match ty {
-            Type::Path(type_path) => Self::try_from(type_path),
-            Type::Array(type_array) => Self::try_from(type_array),
-            Type::Paren(type_paren) => {
-                let span = type_paren.span();
-                Self::try_from(*type_paren.elem).map_err(|e| Error::new(span, e.to_string()))
-            }
-            Type::Slice(type_slice) => {
-                Err(Error::new(
-                    type_slice.span(),
-                    "Length must be provided at compile time".to_owned(),
-                ))
-            }
-            value => {
-                Err(Error::new(value.span(), UNSUPPORTED_TYPE_MESSAGE))
-            }
-        }
-    }
-}
+#[doc(inline)]
+pub use byteview_macros::byteview_owned;
 
-impl TryFrom<TypeArray> for ByteViewType {
-    type Error = syn::Error;
-
-    fn try_from(array: TypeArray) -> Result<Self, Self::Error> {
-        let TypeArray {
-            bracket_token: _,
-            elem,
-            semi_token: _,
-            len,
-        } = array;
-        let span = elem.span();
-        let err = || Err(Error::new(span, "Must be an array of u8".to_owned()));
-        let Type::Path(type_path) = *elem else {
-            return err();
-        };
-        let Some(ident) = type_path.path.get_ident() else {
-            return err();
-        };
-        if *ident != "u8" {
-            return err();
-        }
-        Ok(Self::Array(len))
-    }
-}
-
-impl TryFrom<TypePath> for ByteViewType {
-    type Error = syn::Error;
-
-    fn try_from(type_path: TypePath) -> Result<Self, Self::Error> {
-        let span = type_path.span();
-        let error = || Error::new(span, UNSUPPORTED_TYPE_MESSAGE);
-        let err = || Err(error());
-        let ident_str = type_path.path.require_ident()?.to_string();
-        match ident_str.as_str() {
-            "u8" => return Ok(Self::SingleByte(Signedness::Unsigned)),
-            "u8be" | "u8le" | "u8ne" => {
-                return Err(Error::new(
-                    span,
-                    "u8 does not need endianness specifier".to_string(),
-                ));
-            }
-            "i8" => return Ok(Self::SingleByte(Signedness::Signed)),
-            "i8be" | "i8le" | "i8ne" => {
-                return Err(Error::new(
-                    span,
-                    "i8 does not need endianness specifier".to_string(),
-                ));
-            }
-            _ => {}
-        }
-
-        let (type_string, endianness_string) = split_type_endianness_strings(&ident_str);
-
-        let multi_byte_type = type_string
-            .as_str()
-            .parse::<MultiByteType>()
-            .map_err(|_| error())?;
-
-        let endianness = match endianness_string.as_str() {
-            "be" => Endianness::Big,
-            "le" => Endianness::Little,
-            "ne" => Endianness::Native,
-            "" => {
-                return Err(Error::new(
-                    span,
-                    format!(
-                        "Endianness required, use {0}be, {0}le, or {0}ne",
-                        type_string
-                    ),
-                ));
-            }
-            _ => return err(),
-        };
-
-        Ok(Self::MultiByte(multi_byte_type, endianness))
-    }
-}
-
-// Split a type + endianness string. This is expected to be the name of a support multi-byte type name (see
-// MultiByteType) followed by an endianness string (see Endianness). This function naively splits the string into two
-// pieces: 1) the string before and including any digits and 2) the string after the first set of consecutive digits.
-fn split_type_endianness_strings(ident_str: &str) -> (String, String) {
-    // This is slightly unwieldly, but my ideas for how to make it more succinct all involve the regex crate or the
-    // itertools crate. I would like to avoid taking on such large dependencies just for this one thing.
-    let mut chars = ident_str.chars();
-    let mut type_chars = Vec::new();
-    let mut endianness_chars = Vec::new();
-    let mut seen_digit = false;
-    for c in chars.by_ref() {
-        if c.is_ascii_digit() {
-            seen_digit = true;
-            type_chars.push(c);
-        } else if seen_digit {
-            endianness_chars.push(c);
-            break;
-        } else {
-            type_chars.push(c);
-        }
-    }
-    endianness_chars.extend(chars);
-
-    (
-        type_chars.into_iter().collect(),
-        endianness_chars.into_iter().collect(),
-    )
-}
-
-//////////////////////////////////////////////////////////////////////
-// Constructing Tokens
-//////////////////////////////////////////////////////////////////////
-
-// Whether the inner bytes are borrowed or owned.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum ByteViewFieldKind {
-    Borrowed,
-    Owned,
-}
-
-// The ByteViewStruct + FieldKind. This custom struct makes it easy to implement the ToTokens trait, which in turn
-// makes it easy to implement the proc_macro(s).
-#[derive(Debug)]
-struct ByteViewStructTagged {
-    inner: ByteViewStruct,
-    field_kind: ByteViewFieldKind,
-}
-
-impl ToTokens for ByteViewStructTagged {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self { inner, field_kind } = self;
-        let ByteViewStruct {
-            attrs,
-            vis,
-            ident,
-            fields,
-        } = inner;
-
-        let (getters, total_size_expr) = create_getters_and_total_size_expr(fields);
-        let bytes_type = field_kind.create_bytes_type(ident);
-        let constructors = field_kind.create_constructors(ident);
-        let lifetime_annotation = field_kind.create_lifetime_annotation();
-        let num_bytes_doc = format!("The total number of bytes contained in a [`{ident}`].");
-
-        let token_stream = quote! {
-            #(#attrs)*
-            #vis struct #ident #lifetime_annotation {
-                bytes: #bytes_type,
-            }
-
-            impl #lifetime_annotation #ident #lifetime_annotation {
-                #[doc = #num_bytes_doc]
-                pub const NUM_BYTES: usize = #total_size_expr;
-
-                #(#constructors)*
-
-                #(#getters)*
-            }
-        };
-
-        tokens.append_all(token_stream);
-    }
-}
-
-// Create a list of "getter" functions for each ByteViewField. The "getter" will extract the bytes at the appropriate
-// offsets, which requires keeping a running tally of the cumulative length so far. Return the created "getter"
-// functions along with the final cumulative length, which is the total size required for the struct.
-fn create_getters_and_total_size_expr(fields: &[ByteViewField]) -> (Vec<ItemFn>, Expr) {
-    let mut cur_offset_expr: Expr = parse_quote! { 0 };
-    let mut getters = Vec::new();
-    for field in fields {
-        let size = field.byteview_type.get_size_expr(); // unnecessary clone
-        let next_offset_expr = parse_quote! { #cur_offset_expr + #size };
-        getters.extend(field.create_getter(&cur_offset_expr));
-        cur_offset_expr = next_offset_expr;
-    }
-    let total_size_expr = cur_offset_expr;
-    (getters, total_size_expr)
-}
-
-impl ByteViewField {
-    // Create a "getter" function for the field. The method should extract the appropriate number of bytes at the given
-    // offset from the inner `self.bytes` field. As a special case, if the field name is "_" then do not create a
-    // function, since "_" is not a valid identifier. Users can use the "_" pattern to create "padding" bytes that have
-    // no meaning but need to be there so later bytes are at the correct offsets.
-    fn create_getter(&self, offset_expr: &Expr) -> Option<ItemFn> {
-        let ByteViewField {
-            attrs,
-            vis,
-            ident,
-            byteview_type: ty,
-        } = self;
-
-        if *ident == "_" {
-            // A single underscore is not an identifier (see
-            // https://doc.rust-lang.org/reference/identifiers.html#r-ident.profile). Allow users to use them as
-            // placeholders for "padding", but do not create getter methods.
-            return None;
-        }
-        let body: Expr = ty.create_getter_expr(offset_expr);
-        let return_type = ty.get_type();
-        Some(parse_quote! {
-            #(#attrs)*
-            #vis fn #ident(&self) -> #return_type {
-                #body
-            }
-        })
-    }
-}
-
-impl ByteViewType {
-    fn get_type(&self) -> Type {
-        match self {
-            ByteViewType::SingleByte(Signedness::Unsigned) => parse_quote! { u8 },
-            ByteViewType::SingleByte(Signedness::Signed) => parse_quote! { i8 },
-            ByteViewType::MultiByte(multi_byte_type, _) => multi_byte_type.get_type(),
-            ByteViewType::Array(expr) => parse_quote! { &[u8; #expr] },
-        }
-    }
-
-    fn get_size_expr(&self) -> Expr {
-        match self {
-            Self::SingleByte(_) => parse_quote! { 1 },
-            Self::Array(expr) => expr.clone(),
-            Self::MultiByte(multi_byte_type, _) => {
-                let size = multi_byte_type.get_size();
-                parse_quote! { #size }
-            }
-        }
-    }
-
-    // Create an expression that gets the appropriate bytes from the inner `self.bytes` field.
-    fn create_getter_expr(&self, offset_expr: &Expr) -> Expr {
-        match self {
-            ByteViewType::SingleByte(Signedness::Unsigned) => {
-                parse_quote! { self.bytes[#offset_expr] }
-            }
-            ByteViewType::SingleByte(Signedness::Signed) => {
-                parse_quote! { self.bytes[#offset_expr] as i8 }
-            }
-            ByteViewType::MultiByte(multi_byte_type, endianness) => {
-                let type_name = multi_byte_type.get_type_name();
-                let function_name = endianness.create_from_bytes_function_name();
-                let size = multi_byte_type.get_size();
-                let start = offset_expr;
-                let end: Expr = parse_quote! { #start + #size };
-                parse_quote! {
-                    ::std::primitive::#type_name::#function_name(::std::result::Result::expect(::core::convert::TryInto::<[u8; #size]>::try_into(&self.bytes[#start..#end]), "invalid indices"))
-                }
-            }
-            ByteViewType::Array(size) => {
-                let start = offset_expr;
-                let end: Expr = parse_quote! { #offset_expr + #size };
-                parse_quote! {
-                    ::std::result::Result::expect(::core::convert::TryInto::<&[u8; #size]>::try_into(&self.bytes[#start..#end]), "invalid indices")
-                }
-            }
-        }
-    }
-}
-
-impl MultiByteType {
-    fn get_size(self) -> usize {
-        match self {
-            MultiByteType::U16 => 2,
-            MultiByteType::U32 => 4,
-            MultiByteType::U64 => 8,
-            MultiByteType::U128 => 16,
-            MultiByteType::I16 => 2,
-            MultiByteType::I32 => 4,
-            MultiByteType::I64 => 8,
-            MultiByteType::I128 => 16,
-            MultiByteType::F32 => 4,
-            MultiByteType::F64 => 8,
-        }
-    }
-
-    fn get_type(self) -> Type {
-        self.parse_type_name()
-    }
-
-    fn get_type_name(self) -> Ident {
-        self.parse_type_name()
-    }
-
-    // This is a helper function that is generic over the output T. It can be used to parse any kind of syn item by
-    // using the type name. This could be, for example, a syn::Ident or a syn::Type.
-    fn parse_type_name<T>(self) -> T
-    where
-        T: Parse,
-    {
-        match self {
-            MultiByteType::U16 => parse_quote! { u16 },
-            MultiByteType::U32 => parse_quote! { u32 },
-            MultiByteType::U64 => parse_quote! { u64 },
-            MultiByteType::U128 => parse_quote! { u128 },
-            MultiByteType::I16 => parse_quote! { i16 },
-            MultiByteType::I32 => parse_quote! { i32 },
-            MultiByteType::I64 => parse_quote! { i64 },
-            MultiByteType::I128 => parse_quote! { i128 },
-            MultiByteType::F32 => parse_quote! { f32 },
-            MultiByteType::F64 => parse_quote! { f64 },
-        }
-    }
-}
-
-impl FromStr for MultiByteType {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "u16" => MultiByteType::U16,
-            "u32" => MultiByteType::U32,
-            "u64" => MultiByteType::U64,
-            "u128" => MultiByteType::U128,
-            "i16" => MultiByteType::I16,
-            "i32" => MultiByteType::I32,
-            "i64" => MultiByteType::I64,
-            "i128" => MultiByteType::I128,
-            "f32" => MultiByteType::F32,
-            "f64" => MultiByteType::F64,
-            _ => return Err(s.to_owned()),
-        })
-    }
-}
-
-impl Endianness {
-    // Create a function name for the appropriate getter function for this endianness. This takes advantage of the fact
-    // that all of the supported multi-byte types have the same name for the functions that convert byte arrays to their
-    // corresponding type. These are not trait methods, so there is no requirement for them to be the same, but since
-    // they _are_ the same, we can use the same function name for all of the supported types.
-    fn create_from_bytes_function_name(self) -> Ident {
-        match self {
-            Endianness::Big => parse_quote! { from_be_bytes },
-            Endianness::Little => parse_quote! { from_le_bytes },
-            Endianness::Native => parse_quote! { from_ne_bytes },
-        }
-    }
-}
-
-impl ByteViewFieldKind {
-    fn create_bytes_type(self, ident: &Ident) -> Type {
-        match self {
-            ByteViewFieldKind::Borrowed => {
-                parse_quote! { &'a [::std::primitive::u8; #ident::NUM_BYTES] }
-            }
-            ByteViewFieldKind::Owned => parse_quote! { [::std::primitive::u8; #ident::NUM_BYTES] },
-        }
-    }
-
-    // Create the various constructors for this kind of field. For FieldKind::Borrowed, the input will be a reference to
-    // a byte array/slice. For FieldKind::Owned, the input will be an owned byte array/Vec.
-    fn create_constructors(self, ident: &Ident) -> Vec<ItemFn> {
-        match self {
-            ByteViewFieldKind::Borrowed => create_constructors_for_borrowed(ident),
-            ByteViewFieldKind::Owned => create_constructors_for_owned(ident),
-        }
-    }
-
-    // Create the appropriate lifetime annotation. This will be a <'a> if the bytes field is borrowed and nothing if the
-    // bytes field is owned.
-    fn create_lifetime_annotation(self) -> Generics {
-        match self {
-            ByteViewFieldKind::Borrowed => parse_quote! { <'a> },
-            ByteViewFieldKind::Owned => parse_quote! {},
-        }
-    }
-}
-
-// Create the appropriate constructors if the bytes field is borrowed.
-fn create_constructors_for_borrowed(ident: &Ident) -> Vec<ItemFn> {
-    let from_array_doc = format!(
-        "Create a [`{ident}`] from a reference to an array of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes."
-    );
-    let from_array = parse_quote! {
-        #[doc = #from_array_doc]
-        pub fn from_array(bytes: &'a [::std::primitive::u8; #ident::NUM_BYTES]) -> Self {
-            Self { bytes }
-        }
-    };
-
-    let from_exact_slice_doc = format!(
-        "Create a [`{ident}`] from a slice of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.\n\
-        \n\
-        If the slice does not contain exactly [`NUM_BYTES`][{ident}::NUM_BYTES], return [`Option::None`]."
-    );
-    let from_exact_slice = parse_quote! {
-        #[doc = #from_exact_slice_doc]
-        pub fn from_exact_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<Self> {
-            Some(Self { bytes: ::std::convert::TryInto::try_into(bytes).ok()? })
-        }
-
-    };
-
-    let split_slice_doc = format!(
-        "Create a [`{ident}`] from the first [`NUM_BYTES`][{ident}::NUM_BYTES] bytes of a slice and return it along with the remaining bytes.\n\
-        \n\
-        If the slice does not contain at least [`NUM_BYTES`][{ident}::NUM_BYTES] bytes, return [`Option::None`]."
-    );
-    let split_slice = parse_quote! {
-        #[doc = #split_slice_doc]
-        pub fn split_slice(bytes: &'a [::std::primitive::u8]) -> ::std::option::Option<(Self, &'a [::std::primitive::u8])> {
-            let (exact_bytes, rest_bytes) = bytes.split_at_checked(#ident::NUM_BYTES)?;
-            Some((Self { bytes: ::std::convert::TryInto::try_into(exact_bytes).ok()? }, rest_bytes))
-        }
-    };
-
-    vec![from_array, from_exact_slice, split_slice]
-}
-
-// Create the appropriate constructors if the bytes field is owned.
-fn create_constructors_for_owned(ident: &Ident) -> Vec<ItemFn> {
-    let from_array_doc =
-        format!("Create a [`{ident}`] from an array of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.");
-    let from_array = parse_quote! {
-        #[doc = #from_array_doc]
-        pub fn from_array(bytes: [::std::primitive::u8; #ident::NUM_BYTES]) -> Self {
-            Self { bytes }
-        }
-    };
-
-    let from_exact_vec_doc = format!(
-        "Create a [`{ident}`] from a [`Vec`] of [`NUM_BYTES`][{ident}::NUM_BYTES] bytes.\n\
-        \n\
-        If the `Vec` does not contain exactly [`NUM_BYTES`][{ident}::NUM_BYTES], return [`Option::None`]."
-    );
-    let from_exact_vec = parse_quote! {
-        #[doc = #from_exact_vec_doc]
-        pub fn from_exact_vec(bytes: Vec<u8>) -> Option<Self> {
-            Some(Self { bytes: bytes.try_into().ok()? })
-        }
-    };
-
-    let split_vec_doc = format!(
-        "Create a [`{ident}`] from the first [`NUM_BYTES`][{ident}::NUM_BYTES] bytes of a [`Vec`] and return it along with the remaining bytes.\n\
-        \n\
-        If the [`Vec`] does not contain at least [`NUM_BYTES`][{ident}::NUM_BYTES] bytes, return [`Option::None`]."
-    );
-    let split_vec = parse_quote! {
-        #[doc = #split_vec_doc]
-        pub fn split_vec(mut bytes: Vec<u8>) -> Option<(Self, Vec<u8>)> {
-            if bytes.len() < Self::NUM_BYTES {
-                return None;
-            }
-            let rest = bytes.split_off(Self::NUM_BYTES);
-            Some((Self { bytes: bytes.try_into().ok()? }, rest))
-        }
-    };
-
-    vec![from_array, from_exact_vec, split_vec]
-}
-
-//////////////////////////////////////////////////////////////////////
-// Procedural Macro Implementations
-//////////////////////////////////////////////////////////////////////
-
-fn byteview_ref_impl(input: TokenStream) -> TokenStream {
-    let byteview_struct = parse_macro_input!(input as ByteViewStruct);
-    let byteview_struct_tagged = ByteViewStructTagged {
-        inner: byteview_struct,
-        field_kind: ByteViewFieldKind::Borrowed,
-    };
-    quote! {
-        #byteview_struct_tagged
-    }
-    .into()
-}
-
-fn byteview_owned_impl(input: TokenStream) -> TokenStream {
-    let byteview_struct = parse_macro_input!(input as ByteViewStruct);
-    let byteview_struct_tagged = ByteViewStructTagged {
-        inner: byteview_struct,
-        field_kind: ByteViewFieldKind::Owned,
-    };
-    quote! {
-        #byteview_struct_tagged
-    }
-    .into()
-}
-
-//////////////////////////////////////////////////////////////////////
-// Tests
-//////////////////////////////////////////////////////////////////////
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    pub fn single_underscore_field_name() {
-        let tokens = quote! {
-            struct Foo {
-                x: u8,
-                y: u16be,
-                z: [u8; 3],
-                _: u8,
-            }
-        };
+/// Define both a borrowed and an owned view of the same bytes from a single struct declaration, as if it were passed to
+/// both [`byteview_ref!`] and [`byteview_owned!`].
+///
+/// For a struct named `Foo`, this generates `FooRef<'a>` and `FooOwned` by default. Other names can be chosen with
+/// `#[byteview(ref_name = "...", owned_name = "...")]` on the struct. Both structs have the same fields and
+/// attributes, and they can be converted to each other: `FooRef::to_owned` copies the bytes into a `FooOwned`, and
+/// `FooOwned::as_ref` borrows them as a `FooRef`. A `FooRef` can also be compared to a `FooOwned` with `==`, which
+/// compares their bytes, so structs whose fields are equal but whose padding bytes differ are not equal.
+///
+/// If the struct is annotated with `#[byteview(export_c = "...")]`, the C functions are only exported for the borrowed
+/// struct.
+///
+/// # Example
+///
+/// ```
+/// byteview::byteview! {
+///     #[derive(Debug)]
+///     pub struct EntryHeader {
+///         #[byteview(counter)]
+///         pub index: u32be,
+///         pub kind: u8,
+///     }
+/// }
+///
+/// let entry_header = EntryHeaderRef::from_array(b"\x00\x00\x07\x01\x02");
+/// let mut owned: EntryHeaderOwned = entry_header.to_owned();
+/// owned.increment_index_wrapping();
+/// assert_eq!(1794, owned.as_ref().index());
+/// assert_eq!(2, owned.kind());
+/// assert_ne!(entry_header, owned);
+/// assert_eq!(EntryHeaderRef::from_array(b"\x00\x00\x07\x02\x02"), owned);
+/// ```
+#[doc(inline)]
+pub use byteview_macros::byteview;
 
-        let byteview_struct: Result<ByteViewStruct, _> = syn::parse2(tokens);
-        assert!(byteview_struct.is_ok());
-        let byteview_struct = byteview_struct.unwrap();
-        let tagged = ByteViewStructTagged {
-            inner: byteview_struct,
-            field_kind: ByteViewFieldKind::Borrowed,
-        };
+/// Define a newtype around an unsigned integer whose bits hold several smaller values, e.g. a byte with a 4-bit kind and
+/// a 4-bit length.
+///
+/// The storage type follows the struct name and can be `u8` or a 16, 32, or 64-bit unsigned integer with an endianness
+/// (e.g. `u16be`), which is used by `from_bytes` and `to_bytes`. Fields are declared with their width in bits, starting
+/// from the most significant bits, and must add up to the width of the storage type. Unused bits can be declared with
+/// fields named `_`.
+///
+/// Each field gets a "getter", a `set_` method, and `_MASK` and `_SHIFT` constants. A field followed by `as Type` is
+/// converted with `TryFrom`, and its "getter" returns the bits as an error if the conversion fails. The struct also
+/// has `from_raw`, `raw`, `from_bytes`, and `to_bytes` methods, conversions to and from the storage type, and an
+/// implementation of `FromFieldValue` so it can be used as the type of a field in [`byteview_ref!`] and
+/// [`byteview_owned!`] structs, e.g. `pub data_info: u8 as DataInfo`.
+///
+/// # Example
+///
+/// ```
+/// #[derive(Debug, Eq, PartialEq)]
+/// pub enum DataKind {
+///     SignedInteger,
+///     UnsignedInteger,
+/// }
+///
+/// impl TryFrom<u8> for DataKind {
+///     type Error = ();
+///
+///     fn try_from(value: u8) -> Result<Self, Self::Error> {
+///         match value {
+///             0 => Ok(DataKind::SignedInteger),
+///             1 => Ok(DataKind::UnsignedInteger),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// byteview::byteview_bits! {
+///     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///     pub struct DataInfo: u8 {
+///         /// The kind of data this field contains.
+///         pub kind: 4 as DataKind,
+///         /// The length of the data.
+///         pub length: 4,
+///     }
+/// }
+///
+/// byteview::byteview_ref! {
+///     pub struct FieldDefinition {
+///         pub data_info: u8 as DataInfo,
+///         pub index: u8,
+///     }
+/// }
+///
+/// let definition = FieldDefinition::from_array(&[0x14, 0x02]);
+/// let mut data_info = definition.data_info();
+/// assert_eq!(Ok(DataKind::UnsignedInteger), data_info.kind());
+/// assert_eq!(4, data_info.length());
+/// assert_eq!(0xF0, DataInfo::KIND_MASK);
+/// data_info.set_length(8);
+/// assert_eq!(0x18, data_info.raw());
+/// data_info.set_kind(7);
+/// assert_eq!(Err(7), data_info.kind());
+/// ```
+#[doc(inline)]
+pub use byteview_macros::byteview_bits;
 
-        quote! { #tagged };
-    }
+/// Define an enum whose variants each correspond to a value of an integer type, e.g. the type of a log stored in a
+/// single byte.
+///
+/// The integer type follows the enum name and can be any primitive integer type up to 64 bits. Every variant must
+/// have a discriminant. This generates `TryFrom` the integer type (returning the value as an error if no variant
+/// matches) and `From` the enum to the integer type.
+///
+/// One variant may instead hold the integer type, e.g. `Unknown(u8)`. It holds any value that does not match another
+/// variant, so `From` is generated in both directions and no value is lost.
+///
+/// The enum also implements `FromFieldValue`, so it can be used as the type of a field in [`byteview_ref!`] and
+/// [`byteview_owned!`] structs, e.g. `pub log_type: u8 as LogType`. The "getter" returns a `Result` containing the
+/// raw value as an error, or just the enum if it has a variant for unknown values.
+///
+/// # Example
+///
+/// ```
+/// byteview::byteview_enum! {
+///     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///     pub enum LogType: u8 {
+///         System = 0,
+///         Comm = 1,
+///         Debug = 2,
+///     }
+/// }
+///
+/// byteview::byteview_enum! {
+///     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///     pub enum Priority: u16 {
+///         Low = 0x10,
+///         High = 0x20,
+///         Unknown(u16),
+///     }
+/// }
+///
+/// byteview::byteview_ref! {
+///     pub struct Header {
+///         pub log_type: u8 as LogType,
+///         pub priority: u16be as Priority,
+///     }
+/// }
+///
+/// let header = Header::from_array(&[0x01, 0x00, 0x20]);
+/// assert_eq!(Ok(LogType::Comm), header.log_type());
+/// assert_eq!(Priority::High, header.priority());
+///
+/// let header = Header::from_array(&[0x07, 0x00, 0x30]);
+/// assert_eq!(Err(7), header.log_type());
+/// assert_eq!(Priority::Unknown(0x30), header.priority());
+/// assert_eq!(2, u8::from(LogType::Debug));
+/// ```
+#[doc(inline)]
+pub use byteview_macros::byteview_enum;
 
-    #[test]
-    pub fn ref_field() {
-        // Use a raw string here so we can look at the span byte offsets if needed
-        let s = "struct Foo { x: &[u8; 32], }";
-        let tokens: proc_macro2::TokenStream = s.parse().unwrap();
+/// Fail to compile unless a struct generated by this crate has the given number of bytes, e.g. to check that a header
+/// matches the size stated in a format specification.
+///
+/// This expands to a constant item, so it can be used at the top level of a module (including a `#[cfg(test)]`
+/// module) or in a function body. The error names the struct and the expected size.
+///
+/// # Example
+///
+/// ```
+/// byteview::byteview_ref! {
+///     pub struct HeaderStart {
+///         pub magic: [u8; 4],
+///         pub log_type: u8,
+///         _: u8,
+///         pub num_fields: u16be,
+///     }
+/// }
+///
+/// byteview::assert_size!(HeaderStart, 8);
+/// ```
+///
+/// ```compile_fail
+/// byteview::byteview_ref! {
+///     pub struct HeaderStart {
+///         pub magic: [u8; 4],
+///         pub log_type: u8,
+///     }
+/// }
+///
+/// byteview::assert_size!(HeaderStart, 8);
+/// ```
+#[doc(inline)]
+pub use byteview_macros::assert_size;
 
-        let byteview_struct: Result<ByteViewStruct, _> = syn::parse2(tokens);
-        assert!(byteview_struct.is_err());
-    }
+/// Fail to compile unless a field of a struct generated by this crate starts at the given offset, e.g.
+/// `assert_offset!(HeaderStart, log_type, 40)`.
+///
+/// The field is named by its "getter", and the offset is checked against its `OFFSET_` constant (e.g.
+/// `HeaderStart::OFFSET_LOG_TYPE`). Like [`assert_size!`], this expands to a constant item.
+///
+/// # Example
+///
+/// ```
+/// byteview::byteview_ref! {
+///     pub struct HeaderStart {
+///         pub magic: [u8; 4],
+///         pub log_type: u8,
+///         _: u8,
+///         pub num_fields: u16be,
+///     }
+/// }
+///
+/// byteview::assert_offset!(HeaderStart, log_type, 4);
+/// byteview::assert_offset!(HeaderStart, num_fields, 6);
+/// ```
+///
+/// ```compile_fail
+/// byteview::byteview_ref! {
+///     pub struct HeaderStart {
+///         pub magic: [u8; 4],
+///         pub log_type: u8,
+///     }
+/// }
+///
+/// byteview::assert_offset!(HeaderStart, log_type, 5);
+/// ```
+#[doc(inline)]
+pub use byteview_macros::assert_offset;
+
+mod bcd;
+mod bitmap;
+mod builder;
+mod collect;
+mod compare;
+mod convert;
+mod datetime;
+mod dispatch;
+mod document;
+mod dump;
+mod dynamic;
+mod endian;
+mod export;
+mod footer;
+mod layout;
+mod mac;
+mod migrate;
+mod offset;
+mod packed;
+mod parse;
+mod patch;
+mod python;
+mod records;
+mod sync;
+mod table;
+mod template;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tlv;
+mod uuid;
+mod validate;
+mod varint;
+mod versioned;
+mod view;
+mod wipe;
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+compile_error!(
+    "the `chrono` and `time` features cannot both be enabled; disable the default features to use `time`"
+);
+
+#[cfg(feature = "chrono")]
+pub use chrono;
+#[cfg(feature = "time")]
+pub use time;
+#[cfg(feature = "zeroize")]
+pub use zeroize;
+
+pub use crate::uuid::Uuid;
+pub use bcd::BcdError;
+pub use bitmap::{BitOrder, Bitmap, BitmapOnes};
+pub use builder::{BuildError, LengthPrefixedBuilder};
+pub use collect::{ExtendRecords, RecordBytes};
+pub use convert::FromFieldValue;
+pub use dispatch::{DispatchError, DispatchIter, Dispatcher};
+pub use document::{Checksum, Document, DocumentError, DocumentFormat, NoFooter};
+pub use dynamic::{DynView, DynViewFactory, ViewFactory};
+pub use endian::{
+    BigEndian, ByteOrder, Endian, EndianNumber, F32Be, F32Le, F32Ne, F64Be, F64Le, F64Ne, I16Be,
+    I16Le, I16Ne, I32Be, I32Le, I32Ne, I64Be, I64Le, I64Ne, I128Be, I128Le, I128Ne, LittleEndian,
+    U16Be, U16Le, U16Ne, U32Be, U32Le, U32Ne, U64Be, U64Le, U64Ne, U128Be, U128Le, U128Ne, read_at,
+    write_at,
+};
+pub use export::ExportStatus;
+pub use footer::{FooterError, FooterIndexed};
+pub use layout::{Endianness, FieldInfo, FieldKind, Layout, LayoutChange, NumberType, layout_diff};
+pub use mac::MacAddr;
+pub use migrate::{MigrateError, Migration, migrate};
+pub use offset::{OffsetError, slice_from_offset, view_from_offset};
+pub use packed::{Packed, PackedElement, PackedIter};
+pub use parse::{MagicBytes, ParseError};
+pub use patch::{AppliedEdit, AppliedReport, Edit, FieldSelector, Patch, PatchError, Value};
+pub use python::PythonFormatError;
+pub use records::{FlashRecords, Records, Torn};
+pub use sync::{Magic, Resync, Skipped};
+pub use table::{Table, TableChunks, TableIter, TableWindows, dedup_records, dedup_records_by};
+pub use template::Template;
+pub use tlv::{Tlv, TlvFormat, TlvIter, TlvWidth};
+pub use validate::ValidateError;
+pub use versioned::VersionError;
+pub use view::{ByteView, LengthField};
+
+// Items used by the generated code. These are not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::bcd::decode_bcd;
+    pub use crate::compare::assert_views_eq;
+    pub use crate::convert::{convert_byte_array, decode_utf16, decode_utf16_lossy};
+    #[cfg(feature = "chrono")]
+    pub use crate::datetime::chrono_fixed_offset;
+    pub use crate::datetime::duration_from_units;
+    #[cfg(feature = "time")]
+    pub use crate::datetime::time_utc_offset;
+    pub use crate::endian::{extend_bytes, swap_middle_endian, swap_to_native, truncate_bytes};
+    pub use crate::parse::{check_declared_len, check_magic, max_declared_len};
+    pub use crate::validate::check_zero;
+    pub use crate::varint::{nth_varint, skip_varints};
+    pub use crate::view::nested_num_bytes;
+    pub use crate::wipe::{wipe, wipe_vec};
 }
@@ -0,0 +1,108 @@
+//! 6-byte MAC addresses, the type of `mac` fields.
+
+use crate::FromFieldValue;
+use std::fmt;
+
+/// A 6-byte Ethernet MAC address.
+///
+/// This is returned by the "getter" of a `mac` field. It is formatted as six colon-separated lowercase hexadecimal bytes
+/// (e.g. `aa:bb:cc:dd:ee:ff`) by both `Display` and `Debug`, and can be compared to a `[u8; 6]` directly.
+///
+/// ```
+/// byteview::byteview_ref! {
+///     pub struct Frame {
+///         pub destination: mac,
+///         pub source: mac,
+///     }
+/// }
+///
+/// let frame = Frame::from_array(b"\xFF\xFF\xFF\xFF\xFF\xFF\x00\x1A\x2B\x3C\x4D\x5E");
+/// assert!(frame.destination().is_broadcast());
+/// assert_eq!("00:1a:2b:3c:4d:5e", frame.source().to_string());
+/// assert_eq!(frame.source(), [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// The broadcast address, `ff:ff:ff:ff:ff:ff`.
+    pub const BROADCAST: Self = Self([0xFF; 6]);
+
+    /// Wrap the bytes of a MAC address.
+    pub const fn from_bytes(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+
+    /// The bytes of the address.
+    pub const fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Whether this is the broadcast address, `ff:ff:ff:ff:ff:ff`.
+    pub const fn is_broadcast(&self) -> bool {
+        matches!(self.0, [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])
+    }
+
+    /// Whether this is a multicast address (including the broadcast address), i.e. the least significant bit of the
+    /// first byte is set.
+    pub const fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Whether this is a unicast address, i.e. not a multicast address.
+    pub const fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Whether this address is locally administered rather than assigned by the manufacturer, i.e. the second least
+    /// significant bit of the first byte is set.
+    pub const fn is_local(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+}
+
+/// A `mac` field is a `[u8; 6]` converted to a [`MacAddr`].
+impl FromFieldValue<&[u8; 6]> for MacAddr {
+    type Output = MacAddr;
+
+    fn from_field_value(value: &[u8; 6]) -> Self::Output {
+        MacAddr(*value)
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    fn from(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<MacAddr> for [u8; 6] {
+    fn from(mac: MacAddr) -> Self {
+        mac.0
+    }
+}
+
+impl PartialEq<[u8; 6]> for MacAddr {
+    fn eq(&self, other: &[u8; 6]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<MacAddr> for [u8; 6] {
+    fn eq(&self, other: &MacAddr) -> bool {
+        *self == other.0
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl fmt::Debug for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
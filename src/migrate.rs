@@ -0,0 +1,286 @@
+//! Copying the fields of one struct into another with the same field names, e.g. a newer version of a format.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::patch::{EncodeError, encode, is_signed, is_unsigned};
+use crate::{ByteView, FieldInfo, FieldKind, NumberType, Value};
+
+/// Copy every field of `from` into the field with the same name of a new owned struct `T`.
+///
+/// This is shorthand for `Migration::<F, T>::new().migrate(from)`. See [`Migration`] for the conversions that are
+/// made and for allowing lossy conversions.
+///
+/// ```
+/// use byteview::{byteview_owned, byteview_ref, migrate};
+///
+/// byteview_ref! {
+///     pub struct HeaderV1 {
+///         pub version: u8,
+///         pub count: u16be,
+///     }
+/// }
+///
+/// byteview_owned! {
+///     pub struct HeaderV2 {
+///         pub version: u8,
+///         pub count: u32be,
+///         pub flags: u8,
+///     }
+/// }
+///
+/// let bytes = [1, 0xFF, 0xFF];
+/// let v2 = migrate::<HeaderV1, HeaderV2>(&HeaderV1::from_array(&bytes)).unwrap();
+/// assert_eq!((1, 0xFFFF, 0), (v2.version(), v2.count(), v2.flags()));
+/// ```
+pub fn migrate<'a, F: ByteView<'a>, T: for<'b> ByteView<'b>>(from: &F) -> Result<T, MigrateError> {
+    Migration::<F, T>::new().migrate(from)
+}
+
+/// The reason a struct could not be migrated.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MigrateError {
+    /// The old struct has a field with no field of the same name in the new struct.
+    Removed { field: &'static str },
+    /// Converting the field could lose information, e.g. from a `u32be` to a `u16be` or from an `i8` to a `u8`.
+    Lossy {
+        field: &'static str,
+        from: FieldKind,
+        to: FieldKind,
+    },
+    /// The field is allowed to be lossy, and its value does not fit in the new field.
+    DoesNotFit { field: &'static str, to: FieldKind },
+    /// The field cannot be converted at all, e.g. from a byte array to a number, between byte arrays of different
+    /// sizes, or from or to a number whose byte order is chosen when the struct is constructed.
+    TypeMismatch {
+        field: &'static str,
+        from: FieldKind,
+        to: FieldKind,
+    },
+}
+
+impl fmt::Display for MigrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrateError::Removed { field } => {
+                write!(f, "field `{field}` does not exist in the new struct")
+            }
+            MigrateError::Lossy { field, from, to } => write!(
+                f,
+                "converting field `{field}` from {from:?} to {to:?} could lose information"
+            ),
+            MigrateError::DoesNotFit { field, to } => {
+                write!(f, "the value of field `{field}` does not fit in {to:?}")
+            }
+            MigrateError::TypeMismatch { field, from, to } => write!(
+                f,
+                "field `{field}` cannot be converted from {from:?} to {to:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MigrateError {}
+
+/// A migration from the struct `F` to the owned struct `T`, which match fields by name.
+///
+/// Every named field of `T` is set to the value of the field with the same name in `F`. Integers may be widened and
+/// may change between unsigned and signed if every value fits (e.g. `u16be` to `i32le`), integers may become floats if
+/// every value is exactly representable, `f32` may become `f64`, and byte arrays must be the same size. Fields of `T`
+/// that `F` does not have, along with padding, are zero, except that optional fields declared with a default (e.g.
+/// `#[byteview(optional, default = 1)]`) are set to the default and the [`MAGIC`](ByteView::MAGIC) bytes of `T` are
+/// filled in instead of being copied from `F`. Optional fields missing from `F` are treated as new fields.
+///
+/// Any other conversion, or a field of `F` that `T` does not have, is an error unless the field is allowed with
+/// [`allow_lossy`](Migration::allow_lossy). A lossy field is converted if its value fits in the new field and dropped
+/// if `T` does not have it.
+///
+/// `T` must not have varint fields or length fields, or [`migrate`](Migration::migrate) fails to compile. The new struct
+/// always contains every optional field.
+pub struct Migration<F, T> {
+    lossy_fields: Vec<String>,
+    phantom: PhantomData<fn(F) -> T>,
+}
+
+impl<'a, F: ByteView<'a>, T: for<'b> ByteView<'b>> Default for Migration<F, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, F: ByteView<'a>, T: for<'b> ByteView<'b>> Migration<F, T> {
+    // The number of bytes in the new struct with every optional field present. Fail to compile if the struct can be
+    // longer than that.
+    const TO_BYTES: usize = {
+        assert!(
+            T::MIN_NUM_BYTES <= T::NUM_BYTES && T::MAX_NUM_BYTES == T::NUM_BYTES,
+            "migrated structs must not have varint fields or length fields"
+        );
+        T::NUM_BYTES
+    };
+
+    /// Create a migration that only allows lossless conversions.
+    pub fn new() -> Self {
+        Self {
+            lossy_fields: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Allow the field with the given name to be narrowed or dropped.
+    pub fn allow_lossy(mut self, name: impl Into<String>) -> Self {
+        self.lossy_fields.push(name.into());
+        self
+    }
+
+    /// Copy every field of `from` into a new `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field allowed to be lossy does not exist in either struct.
+    #[track_caller]
+    pub fn migrate(&self, from: &F) -> Result<T, MigrateError> {
+        let (from_layout, to_layout) = (F::LAYOUT, T::LAYOUT);
+        for name in &self.lossy_fields {
+            assert!(
+                from_layout.field(name).is_some() || to_layout.field(name).is_some(),
+                "neither `{}` nor `{}` has a field named `{name}`",
+                from_layout.name,
+                to_layout.name
+            );
+        }
+        let is_lossy_allowed = |name: &str| self.lossy_fields.iter().any(|lossy| lossy == name);
+
+        let mut bytes = vec![0; Self::TO_BYTES];
+        T::__write_defaults(&mut bytes);
+        if let Some(magic) = T::MAGIC {
+            bytes[magic.offset..magic.offset + magic.bytes.len()].copy_from_slice(magic.bytes);
+        }
+        let from_bytes = from.as_bytes();
+        for from_field in from_layout.named_fields() {
+            let name = from_field.name;
+            let Some(to_field) = to_layout.field(name) else {
+                if is_lossy_allowed(name) {
+                    continue;
+                }
+                return Err(MigrateError::Removed { field: name });
+            };
+            // The magic bytes of the new struct identify its version, so they are not copied.
+            if T::MAGIC.is_some_and(|magic| magic.offset == to_field.offset) {
+                continue;
+            }
+            // Optional fields may be missing from shorter records.
+            let Some(field_bytes) = from_bytes.get(from_field.range()) else {
+                continue;
+            };
+            let (from_kind, to_kind) = (from_field.kind, to_field.kind);
+            let type_mismatch = MigrateError::TypeMismatch {
+                field: name,
+                from: from_kind,
+                to: to_kind,
+            };
+            // The byte order of a number in a struct declared with `#[byteview(endian = "runtime")]` is not part of the
+            // layout, so it cannot be converted.
+            if (from_kind == FieldKind::Bytes) != (to_kind == FieldKind::Bytes)
+                || from_kind == FieldKind::Bytes && from_field.size != to_field.size
+                || matches!(from_kind, FieldKind::RuntimeNumber(_))
+                || matches!(to_kind, FieldKind::RuntimeNumber(_))
+            {
+                return Err(type_mismatch);
+            }
+            if !is_lossless(from_field, to_field) && !is_lossy_allowed(name) {
+                return Err(MigrateError::Lossy {
+                    field: name,
+                    from: from_kind,
+                    to: to_kind,
+                });
+            }
+            let value = Value::decode(from_kind, field_bytes);
+            let does_not_fit = MigrateError::DoesNotFit {
+                field: name,
+                to: to_kind,
+            };
+            let value = convert(value, to_kind).ok_or(does_not_fit)?;
+            let encoded =
+                encode(to_kind, to_field.size, &value, &[]).map_err(|error| match error {
+                    EncodeError::DoesNotFit => does_not_fit,
+                    EncodeError::TypeMismatch => type_mismatch,
+                })?;
+            bytes[to_field.range()].copy_from_slice(&encoded);
+        }
+        let (to, _) = T::split_slice(&bytes).expect("the bytes are exactly NUM_BYTES long");
+        Ok(to)
+    }
+}
+
+impl<F, T> Clone for Migration<F, T> {
+    fn clone(&self) -> Self {
+        Self {
+            lossy_fields: self.lossy_fields.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<F, T> fmt::Debug for Migration<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migration")
+            .field("lossy_fields", &self.lossy_fields)
+            .finish()
+    }
+}
+
+// Whether every value of the old field can be stored exactly in the new field.
+fn is_lossless(from: &FieldInfo, to: &FieldInfo) -> bool {
+    let (from_bits, to_bits) = (from.size * 8, to.size * 8);
+    let mantissa_bits = |kind| match kind {
+        FieldKind::Number(NumberType::F32, _) => Some(24),
+        FieldKind::Number(NumberType::F64, _) => Some(53),
+        _ => None,
+    };
+    match (from.kind, to.kind) {
+        (FieldKind::Bytes, FieldKind::Bytes) => from.size == to.size,
+        (from_kind, to_kind) if is_unsigned(from_kind) && is_unsigned(to_kind) => {
+            from_bits <= to_bits
+        }
+        (from_kind, to_kind) if is_signed(from_kind) && is_signed(to_kind) => from_bits <= to_bits,
+        (from_kind, to_kind) if is_unsigned(from_kind) && is_signed(to_kind) => from_bits < to_bits,
+        (from_kind, to_kind) if is_unsigned(from_kind) || is_signed(from_kind) => {
+            mantissa_bits(to_kind).is_some_and(|mantissa_bits| from_bits <= mantissa_bits)
+        }
+        (from_kind, to_kind) => mantissa_bits(from_kind)
+            .zip(mantissa_bits(to_kind))
+            .is_some_and(|(from_mantissa, to_mantissa)| from_mantissa <= to_mantissa),
+    }
+}
+
+// Convert a value to the kind of value stored in a field of the given kind, or return None if it cannot be represented
+// exactly. Whether it fits in the size of the field is checked when it is encoded.
+fn convert(value: Value, to: FieldKind) -> Option<Value> {
+    let is_float = matches!(to, FieldKind::Number(NumberType::F32 | NumberType::F64, _));
+    match value {
+        Value::Unsigned(unsigned) if is_signed(to) => {
+            i128::try_from(unsigned).ok().map(Value::Signed)
+        }
+        Value::Signed(signed) if is_unsigned(to) => {
+            u128::try_from(signed).ok().map(Value::Unsigned)
+        }
+        Value::Unsigned(unsigned) if is_float => {
+            let float = unsigned as f64;
+            (float as u128 == unsigned).then_some(Value::Float(float))
+        }
+        Value::Signed(signed) if is_float => {
+            let float = signed as f64;
+            (float as i128 == signed).then_some(Value::Float(float))
+        }
+        Value::Float(float) if is_unsigned(to) => {
+            let unsigned = float as u128;
+            (unsigned as f64 == float).then_some(Value::Unsigned(unsigned))
+        }
+        Value::Float(float) if is_signed(to) => {
+            let signed = float as i128;
+            (signed as f64 == float).then_some(Value::Signed(signed))
+        }
+        value => Some(value),
+    }
+}
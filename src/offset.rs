@@ -0,0 +1,74 @@
+//! Resolving offsets stored in a struct against the buffer that contains it.
+
+use crate::ByteView;
+use std::fmt;
+
+/// The reason an offset could not be resolved against a buffer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum OffsetError {
+    /// The offset is past the end of the buffer.
+    OutOfBounds { offset: u64, len: usize },
+    /// The offset is within the buffer, but there are not enough bytes after it for the requested type.
+    Truncated {
+        offset: usize,
+        needed: usize,
+        available: usize,
+    },
+}
+
+impl fmt::Display for OffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OffsetError::OutOfBounds { offset, len } => {
+                write!(
+                    f,
+                    "offset {offset} is out of bounds for a buffer of {len} bytes"
+                )
+            }
+            OffsetError::Truncated {
+                offset,
+                needed,
+                available,
+            } => write!(
+                f,
+                "{needed} bytes are needed at offset {offset} but only {available} are available"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OffsetError {}
+
+/// The bytes of `buffer` starting at `offset`.
+///
+/// An offset equal to the length of the buffer returns an empty slice. Return an error if the offset is past the end
+/// of `buffer`.
+///
+/// This is used by fields annotated with `#[byteview(offset_into_buffer)]`.
+pub fn slice_from_offset(buffer: &[u8], offset: u64) -> Result<&[u8], OffsetError> {
+    let out_of_bounds = OffsetError::OutOfBounds {
+        offset,
+        len: buffer.len(),
+    };
+    let start = usize::try_from(offset).map_err(|_| out_of_bounds)?;
+    buffer.get(start..).ok_or(out_of_bounds)
+}
+
+/// Create a `T` from the bytes of `buffer` starting at `offset`.
+///
+/// Return an error if the offset is past the end of `buffer` or there are fewer than `T::NUM_BYTES` bytes after it.
+///
+/// This is used by fields annotated with `#[byteview(offset_into_buffer)]`.
+pub fn view_from_offset<'a, T: ByteView<'a>>(
+    buffer: &'a [u8],
+    offset: u64,
+) -> Result<T, OffsetError> {
+    let bytes = slice_from_offset(buffer, offset)?;
+    let (view, _) = T::split_slice(bytes).ok_or(OffsetError::Truncated {
+        // The offset is known to fit in a usize since slice_from_offset succeeded.
+        offset: offset as usize,
+        needed: T::NUM_BYTES,
+        available: bytes.len(),
+    })?;
+    Ok(view)
+}
@@ -0,0 +1,185 @@
+//! Arrays of unsigned integers narrower than a byte or not a whole number of bytes wide, packed back to back.
+
+use crate::BitOrder;
+use std::iter::FusedIterator;
+
+/// An unsigned integer type that can hold the elements of a [`Packed`] array.
+pub trait PackedElement: Copy {
+    /// The maximum number of bits in an element.
+    const BITS: u32;
+
+    /// Convert the bits of an element, which fit in [`BITS`](PackedElement::BITS) bits, to this type.
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_packed_element {
+    ($($ty:ty),*) => {
+        $(
+            impl PackedElement for $ty {
+                const BITS: u32 = <$ty>::BITS;
+
+                fn from_bits(bits: u64) -> Self {
+                    bits as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_element!(u8, u16, u32, u64);
+
+/// A read-only view of an array of unsigned integers packed back to back with no padding between them, e.g. 12-bit
+/// samples where 3 bytes hold 2 samples.
+///
+/// With [`BitOrder::Msb0`], elements are read starting from the most significant bit of the first byte and the first
+/// bit read is the most significant bit of the element. With [`BitOrder::Lsb0`], elements are read starting from the
+/// least significant bit of the first byte and the first bit read is the least significant bit of the element.
+///
+/// ```
+/// use byteview::{BitOrder, Packed};
+///
+/// let packed = Packed::<u16>::new(&[0x12, 0x34, 0x56], 12, 2, BitOrder::Msb0);
+/// assert_eq!(vec![0x123, 0x456], packed.iter().collect::<Vec<_>>());
+///
+/// let packed = Packed::<u16>::new(&[0x12, 0x34, 0x56], 12, 2, BitOrder::Lsb0);
+/// assert_eq!(vec![0x412, 0x563], packed.iter().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Packed<'a, T> {
+    bytes: &'a [u8],
+    bits: u32,
+    len: usize,
+    order: BitOrder,
+    element: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: PackedElement> Packed<'a, T> {
+    /// Create a view of `len` elements of `bits` bits each, packed in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or more than [`T::BITS`](PackedElement::BITS), or if `bytes` is too short to hold `len`
+    /// elements.
+    pub fn new(bytes: &'a [u8], bits: u32, len: usize, order: BitOrder) -> Self {
+        assert!(
+            bits > 0 && bits <= T::BITS,
+            "invalid element width of {bits} bits"
+        );
+        assert!(
+            bytes.len() * 8 >= len * bits as usize,
+            "{} bytes cannot hold {len} elements of {bits} bits",
+            bytes.len()
+        );
+        Self {
+            bytes,
+            bits,
+            len,
+            order,
+            element: std::marker::PhantomData,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bits in each element.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The bytes containing the elements.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Element `i`, or `None` if `i` is out of range.
+    pub fn get(&self, i: usize) -> Option<T> {
+        if i >= self.len {
+            return None;
+        }
+        let bits = self.bits as usize;
+        let start = i * bits;
+        let window = &self.bytes[start / 8..(start + bits).div_ceil(8)];
+        let skip = start % 8;
+        // An element of at most 64 bits that starts partway through a byte spans at most 9 bytes.
+        let value = match self.order {
+            BitOrder::Msb0 => {
+                let window_value = window
+                    .iter()
+                    .fold(0u128, |value, byte| value << 8 | u128::from(*byte));
+                window_value >> (window.len() * 8 - skip - bits)
+            }
+            BitOrder::Lsb0 => {
+                let window_value = window
+                    .iter()
+                    .rev()
+                    .fold(0u128, |value, byte| value << 8 | u128::from(*byte));
+                window_value >> skip
+            }
+        };
+        let mask = (1u128 << bits) - 1;
+        Some(T::from_bits((value & mask) as u64))
+    }
+
+    /// An iterator over the elements.
+    pub fn iter(&self) -> PackedIter<'a, T> {
+        PackedIter {
+            packed: *self,
+            next: 0,
+        }
+    }
+
+    /// Copy the elements into the start of `dest` and return the number of elements copied, which is the smaller of the
+    /// number of elements and the length of `dest`.
+    pub fn copy_to_slice(&self, dest: &mut [T]) -> usize {
+        let count = dest.len().min(self.len);
+        for (i, element) in dest[..count].iter_mut().enumerate() {
+            *element = self.get(i).expect("index should be in range");
+        }
+        count
+    }
+}
+
+impl<'a, T: PackedElement> IntoIterator for Packed<'a, T> {
+    type Item = T;
+    type IntoIter = PackedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`Packed`] array.
+///
+/// See [`Packed::iter`].
+#[derive(Debug, Clone)]
+pub struct PackedIter<'a, T> {
+    packed: Packed<'a, T>,
+    next: usize,
+}
+
+impl<T: PackedElement> Iterator for PackedIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.packed.get(self.next)?;
+        self.next += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.packed.len - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: PackedElement> ExactSizeIterator for PackedIter<'_, T> {}
+
+impl<T: PackedElement> FusedIterator for PackedIter<'_, T> {}
@@ -0,0 +1,172 @@
+//! Errors produced when creating a struct from bytes with a checked constructor such as `split_slice_checked`.
+
+use std::fmt;
+
+/// The reason bytes could not be parsed as a `byteview` struct.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ParseError {
+    /// There are fewer bytes than the struct requires.
+    TooShort { needed: usize, available: usize },
+    /// A length field declares more bytes than are available.
+    ///
+    /// For fields annotated with `#[byteview(len_includes_header)]` and structs annotated with `#[byteview(record_len =
+    /// "...")]`, both `declared` and `available` include the bytes of the struct itself.
+    LengthMismatch { declared: u64, available: usize },
+    /// A field annotated with `#[byteview(len_includes_header)]` or named by `#[byteview(record_len = "...")]` declares a
+    /// length smaller than the struct itself.
+    LengthTooSmall { declared: u64, minimum: usize },
+    /// A field annotated with `#[byteview(magic = b"...")]` does not contain the expected bytes.
+    MagicMismatch {
+        offset: usize,
+        expected: &'static [u8],
+        actual: MagicBytes,
+    },
+    /// A `varint` field does not end within 10 bytes or does not fit in a `u64`.
+    InvalidVarint { offset: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::TooShort { needed, available } => {
+                write!(
+                    f,
+                    "{needed} bytes are needed but only {available} are available"
+                )
+            }
+            ParseError::LengthMismatch {
+                declared,
+                available,
+            } => write!(
+                f,
+                "the declared length is {declared} bytes but only {available} are available"
+            ),
+            ParseError::LengthTooSmall { declared, minimum } => write!(
+                f,
+                "the declared length is {declared} bytes but must be at least {minimum}"
+            ),
+            ParseError::MagicMismatch {
+                offset,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "the bytes at offset {offset} are {:02x?} instead of the magic bytes {expected:02x?}",
+                actual.as_bytes()
+            ),
+            ParseError::InvalidVarint { offset } => write!(
+                f,
+                "the varint at offset {offset} is longer than 10 bytes or does not fit in a u64"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The bytes found where magic bytes were expected, as reported by [`ParseError::MagicMismatch`].
+///
+/// Only the first [`MagicBytes::CAPACITY`] bytes are kept so that [`ParseError`] does not need to borrow or allocate.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct MagicBytes {
+    len: u8,
+    bytes: [u8; MagicBytes::CAPACITY],
+}
+
+impl MagicBytes {
+    /// The largest number of bytes that are kept.
+    pub const CAPACITY: usize = 16;
+
+    /// Copy the first [`CAPACITY`](Self::CAPACITY) bytes of `bytes`.
+    pub const fn new(bytes: &[u8]) -> Self {
+        let len = if bytes.len() < Self::CAPACITY {
+            bytes.len()
+        } else {
+            Self::CAPACITY
+        };
+        let mut copy = [0; Self::CAPACITY];
+        let mut i = 0;
+        while i < len {
+            copy[i] = bytes[i];
+            i += 1;
+        }
+        MagicBytes {
+            len: len as u8,
+            bytes: copy,
+        }
+    }
+
+    /// The bytes that were found.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+}
+
+impl fmt::Debug for MagicBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_bytes(), f)
+    }
+}
+
+impl PartialEq<[u8]> for MagicBytes {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+// Check a declared length against the bytes following a struct of `header_len` bytes. If `header_len` is zero, the
+// declared length counts only the bytes following the struct. Return the remaining bytes, truncated to the declared
+// length if requested.
+pub fn check_declared_len(
+    rest: &[u8],
+    declared: u64,
+    header_len: usize,
+    truncate: bool,
+) -> Result<&[u8], ParseError> {
+    let declared_rest =
+        declared
+            .checked_sub(header_len as u64)
+            .ok_or(ParseError::LengthTooSmall {
+                declared,
+                minimum: header_len,
+            })?;
+    let mismatch = ParseError::LengthMismatch {
+        declared,
+        available: header_len + rest.len(),
+    };
+    let declared_rest = usize::try_from(declared_rest).map_err(|_| mismatch)?;
+    let declared_bytes = rest.get(..declared_rest).ok_or(mismatch)?;
+    Ok(if truncate { declared_bytes } else { rest })
+}
+
+// The maximum number of bytes in a struct of `num_bytes` bytes whose length field holds at most `max_declared`. If
+// `includes_header` is false, the declared length counts only the bytes following the struct. Saturate at `usize::MAX`.
+pub const fn max_declared_len(num_bytes: usize, max_declared: u64, includes_header: bool) -> usize {
+    let max_declared = if max_declared > usize::MAX as u64 {
+        usize::MAX
+    } else {
+        max_declared as usize
+    };
+    if includes_header {
+        if max_declared > num_bytes {
+            max_declared
+        } else {
+            num_bytes
+        }
+    } else {
+        num_bytes.saturating_add(max_declared)
+    }
+}
+
+// Check that the bytes at `offset` match the magic bytes `expected`. If there are not enough bytes, succeed so that the
+// missing bytes are reported as `ParseError::TooShort` instead.
+pub fn check_magic(bytes: &[u8], offset: usize, expected: &'static [u8]) -> Result<(), ParseError> {
+    match bytes.get(offset..offset + expected.len()) {
+        Some(actual) if actual != expected => Err(ParseError::MagicMismatch {
+            offset,
+            expected,
+            actual: MagicBytes::new(actual),
+        }),
+        _ => Ok(()),
+    }
+}
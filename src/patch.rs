@@ -0,0 +1,400 @@
+//! Applying a list of field edits to a byte buffer.
+
+use crate::{Endianness, FieldInfo, FieldKind, Layout, NumberType};
+use std::fmt;
+use std::ops::Range;
+
+/// Selects the field to edit within a record.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldSelector {
+    /// A field described by a [`FieldInfo`], e.g. from a generated `LAYOUT` constant.
+    Field(FieldInfo),
+    /// The field with the given name in a [`Layout`].
+    Name { layout: Layout, name: String },
+}
+
+impl FieldSelector {
+    /// Select the field with the given name in a [`Layout`].
+    pub fn name(layout: Layout, name: impl Into<String>) -> Self {
+        FieldSelector::Name {
+            layout,
+            name: name.into(),
+        }
+    }
+
+    // Find the field, or return None if there is no field with the given name.
+    fn resolve(&self) -> Option<FieldInfo> {
+        match self {
+            FieldSelector::Field(field) => Some(*field),
+            FieldSelector::Name { layout, name } => layout.field(name).copied(),
+        }
+    }
+}
+
+impl From<FieldInfo> for FieldSelector {
+    fn from(field: FieldInfo) -> Self {
+        FieldSelector::Field(field)
+    }
+}
+
+/// The new value of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// The value of an unsigned integer field.
+    Unsigned(u128),
+    /// The value of a signed integer field.
+    Signed(i128),
+    /// The value of a floating point field. For `f32` fields, the value must be exactly representable as an `f32`.
+    Float(f64),
+    /// The bytes of a byte array field. There must be exactly as many bytes as the field contains.
+    Bytes(Vec<u8>),
+    /// Set (`true`) or clear (`false`) a single bit of an unsigned integer field. Bit 0 is the least significant bit.
+    Bit { index: u32, set: bool },
+}
+
+impl Value {
+    // Decode the bytes of a field of the given kind. Unsigned and signed integers of every size decode to `Unsigned` and
+    // `Signed`, floats decode to `Float`, and byte arrays decode to `Bytes`, as do numbers whose byte order is chosen
+    // when the struct is constructed.
+    pub(crate) fn decode(kind: FieldKind, bytes: &[u8]) -> Value {
+        let number_type = match kind {
+            FieldKind::U8 => return Value::Unsigned(bytes[0].into()),
+            FieldKind::I8 => return Value::Signed((bytes[0] as i8).into()),
+            FieldKind::Number(number_type, _) => number_type,
+            FieldKind::Bytes | FieldKind::RuntimeNumber(_) => return Value::Bytes(bytes.to_vec()),
+        };
+        let endianness = match kind {
+            FieldKind::Number(_, endianness) => endianness,
+            _ => Endianness::Big,
+        };
+        let big_endian = endianness.reorder_big_endian(bytes.to_vec());
+        let mut padded = [0; 16];
+        padded[16 - big_endian.len()..].copy_from_slice(&big_endian);
+        let unsigned = u128::from_be_bytes(padded);
+        let shift = 128 - 8 * big_endian.len() as u32;
+        match number_type {
+            NumberType::F32 => Value::Float(f32::from_bits(unsigned as u32).into()),
+            NumberType::F64 => Value::Float(f64::from_bits(unsigned as u64)),
+            _ if is_signed(kind) => Value::Signed(((unsigned << shift) as i128) >> shift),
+            _ => Value::Unsigned(unsigned),
+        }
+    }
+}
+
+/// A single edit in a [`Patch`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edit {
+    /// The offset of the record containing the field within the buffer.
+    pub record_offset: usize,
+    /// The field to edit within the record.
+    pub field: FieldSelector,
+    /// The new value of the field.
+    pub value: Value,
+}
+
+/// A list of field edits that can be applied to a byte buffer.
+///
+/// Every edit is validated before any bytes are modified, so a patch that fails leaves the buffer untouched.
+///
+/// ```
+/// use byteview::{FieldSelector, Patch, Value, byteview_ref};
+///
+/// byteview_ref! {
+///     pub struct Header {
+///         pub flags: u8,
+///         pub count: u16be,
+///     }
+/// }
+///
+/// let mut buffer = [0x00, 0x00, 0x01];
+/// let mut patch = Patch::new();
+/// patch
+///     .push(0, FieldSelector::name(Header::LAYOUT, "flags"), Value::Bit { index: 3, set: true })
+///     .push(0, FieldSelector::name(Header::LAYOUT, "count"), Value::Unsigned(9));
+/// patch.apply(&mut buffer).unwrap();
+/// assert_eq!([0x08, 0x00, 0x09], buffer);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Patch {
+    edits: Vec<Edit>,
+}
+
+impl Patch {
+    /// Create an empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an edit setting a field of the record at `record_offset` to `value`.
+    pub fn push(
+        &mut self,
+        record_offset: usize,
+        field: impl Into<FieldSelector>,
+        value: Value,
+    ) -> &mut Self {
+        self.edits.push(Edit {
+            record_offset,
+            field: field.into(),
+            value,
+        });
+        self
+    }
+
+    /// The edits in this patch, in the order they are applied.
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    /// Apply every edit to `buffer` in order.
+    ///
+    /// If any edit is invalid, return an error without modifying `buffer`.
+    pub fn apply(&self, buffer: &mut [u8]) -> Result<AppliedReport, PatchError> {
+        let fields = self
+            .edits
+            .iter()
+            .enumerate()
+            .map(|(index, edit)| validate(index, edit, buffer.len()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let changes = self
+            .edits
+            .iter()
+            .zip(fields)
+            .enumerate()
+            .map(|(index, (edit, field))| {
+                let range = edit.record_offset + field.offset
+                    ..edit.record_offset + field.offset + field.size;
+                let old = buffer[range.clone()].to_vec();
+                let new = encode(field.kind, field.size, &edit.value, &old)
+                    .expect("edits should have been validated");
+                buffer[range.clone()].copy_from_slice(&new);
+                AppliedEdit {
+                    index,
+                    range,
+                    old,
+                    new,
+                }
+            })
+            .collect();
+        Ok(AppliedReport { changes })
+    }
+}
+
+/// A description of the bytes changed by [`Patch::apply`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AppliedReport {
+    /// One entry for each edit, in the order they were applied.
+    pub changes: Vec<AppliedEdit>,
+}
+
+impl AppliedReport {
+    /// The number of bytes whose value changed.
+    pub fn bytes_changed(&self) -> usize {
+        self.changes
+            .iter()
+            .map(|change| {
+                change
+                    .old
+                    .iter()
+                    .zip(&change.new)
+                    .filter(|(old, new)| old != new)
+                    .count()
+            })
+            .sum()
+    }
+}
+
+/// The bytes changed by a single edit.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AppliedEdit {
+    /// The index of the edit in the patch.
+    pub index: usize,
+    /// The range of bytes in the buffer occupied by the field.
+    pub range: Range<usize>,
+    /// The bytes of the field before the edit.
+    pub old: Vec<u8>,
+    /// The bytes of the field after the edit.
+    pub new: Vec<u8>,
+}
+
+/// The reason a [`Patch`] could not be applied. Each variant includes the index of the invalid edit.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum PatchError {
+    /// There is no field with the selected name.
+    UnknownField { index: usize, name: String },
+    /// The field extends past the end of the buffer.
+    OutOfBounds {
+        index: usize,
+        range: Range<usize>,
+        len: usize,
+    },
+    /// The value is a different kind than the field, e.g. bytes for an integer field. A number whose byte order is
+    /// chosen when the struct is constructed ([`FieldKind::RuntimeNumber`]) cannot be patched.
+    TypeMismatch { index: usize, kind: FieldKind },
+    /// The value is the right kind but does not fit in the field.
+    DoesNotFit { index: usize, kind: FieldKind },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::UnknownField { index, name } => {
+                write!(f, "edit {index}: there is no field named `{name}`")
+            }
+            PatchError::OutOfBounds { index, range, len } => write!(
+                f,
+                "edit {index}: bytes {}..{} are out of bounds for a buffer of {len} bytes",
+                range.start, range.end
+            ),
+            PatchError::TypeMismatch { index, kind } => {
+                write!(
+                    f,
+                    "edit {index}: the value cannot be stored in a field of kind {kind:?}"
+                )
+            }
+            PatchError::DoesNotFit { index, kind } => {
+                write!(
+                    f,
+                    "edit {index}: the value does not fit in a field of kind {kind:?}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+// Check that an edit can be applied to a buffer of `len` bytes and return the selected field.
+fn validate(index: usize, edit: &Edit, len: usize) -> Result<FieldInfo, PatchError> {
+    let field = edit
+        .field
+        .resolve()
+        .ok_or_else(|| PatchError::UnknownField {
+            index,
+            name: match &edit.field {
+                FieldSelector::Name { name, .. } => name.clone(),
+                FieldSelector::Field(field) => field.name.to_owned(),
+            },
+        })?;
+    let start = edit.record_offset.saturating_add(field.offset);
+    let end = start.saturating_add(field.size);
+    if end > len {
+        return Err(PatchError::OutOfBounds {
+            index,
+            range: start..end,
+            len,
+        });
+    }
+    // The current bytes only matter for bit edits, which cannot fail once the bit index is checked.
+    encode(field.kind, field.size, &edit.value, &vec![0; field.size]).map_err(
+        |error| match error {
+            EncodeError::TypeMismatch => PatchError::TypeMismatch {
+                index,
+                kind: field.kind,
+            },
+            EncodeError::DoesNotFit => PatchError::DoesNotFit {
+                index,
+                kind: field.kind,
+            },
+        },
+    )?;
+    Ok(field)
+}
+
+#[derive(Debug)]
+pub(crate) enum EncodeError {
+    TypeMismatch,
+    DoesNotFit,
+}
+
+// Encode a value as the bytes of a field. `current` holds the bytes of the field before the edit.
+pub(crate) fn encode(
+    kind: FieldKind,
+    size: usize,
+    value: &Value,
+    current: &[u8],
+) -> Result<Vec<u8>, EncodeError> {
+    let endianness = match kind {
+        FieldKind::Number(_, endianness) => endianness,
+        _ => Endianness::Big,
+    };
+    let bits = size as u32 * 8;
+    match (kind, value) {
+        (FieldKind::Bytes, Value::Bytes(bytes)) if bytes.len() == size => Ok(bytes.clone()),
+        (FieldKind::Bytes, Value::Bytes(_)) => Err(EncodeError::DoesNotFit),
+        (FieldKind::Number(NumberType::F32, _), Value::Float(float)) => {
+            let narrowed = *float as f32;
+            if f64::from(narrowed) != *float && !float.is_nan() {
+                return Err(EncodeError::DoesNotFit);
+            }
+            Ok(endianness.reorder_big_endian(narrowed.to_be_bytes().to_vec()))
+        }
+        (FieldKind::Number(NumberType::F64, _), Value::Float(float)) => {
+            Ok(endianness.reorder_big_endian(float.to_be_bytes().to_vec()))
+        }
+        (kind, Value::Unsigned(unsigned)) if is_unsigned(kind) => {
+            if bits < 128 && unsigned >> bits != 0 {
+                return Err(EncodeError::DoesNotFit);
+            }
+            Ok(encode_u128(*unsigned, size, endianness))
+        }
+        (kind, Value::Signed(signed)) if is_signed(kind) => {
+            let shift = 128 - bits;
+            if (signed << shift) >> shift != *signed {
+                return Err(EncodeError::DoesNotFit);
+            }
+            Ok(encode_u128(*signed as u128, size, endianness))
+        }
+        (kind, Value::Bit { index, set }) if is_unsigned(kind) => {
+            if *index >= bits {
+                return Err(EncodeError::DoesNotFit);
+            }
+            let mut big_endian = endianness.reorder_big_endian(current.to_vec());
+            let byte = &mut big_endian[size - 1 - (*index / 8) as usize];
+            let mask = 1 << (*index % 8);
+            if *set {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+            Ok(endianness.reorder_big_endian(big_endian))
+        }
+        _ => Err(EncodeError::TypeMismatch),
+    }
+}
+
+pub(crate) fn is_unsigned(kind: FieldKind) -> bool {
+    matches!(
+        kind,
+        FieldKind::U8
+            | FieldKind::Number(
+                NumberType::U16
+                    | NumberType::U24
+                    | NumberType::U32
+                    | NumberType::U48
+                    | NumberType::U64
+                    | NumberType::U128,
+                _
+            )
+    )
+}
+
+pub(crate) fn is_signed(kind: FieldKind) -> bool {
+    matches!(
+        kind,
+        FieldKind::I8
+            | FieldKind::Number(
+                NumberType::I16
+                    | NumberType::I24
+                    | NumberType::I32
+                    | NumberType::I48
+                    | NumberType::I64
+                    | NumberType::I128,
+                _
+            )
+    )
+}
+
+// Encode the low `size` bytes of a value in the given byte order.
+fn encode_u128(value: u128, size: usize, endianness: Endianness) -> Vec<u8> {
+    endianness.reorder_big_endian(value.to_be_bytes()[16 - size..].to_vec())
+}
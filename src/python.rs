@@ -0,0 +1,160 @@
+//! Conversion of a [`Layout`] into a format string for Python's [`struct`] module.
+//!
+//! [`struct`]: https://docs.python.org/3/library/struct.html
+
+use crate::{Endianness, FieldKind, Layout, NumberType};
+use std::fmt;
+
+/// The reason a [`Layout`] cannot be expressed as a Python `struct` format string.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PythonFormatError {
+    /// The field's type has no corresponding format character (e.g. `u128be` or `u32me`).
+    UnsupportedType {
+        field: &'static str,
+        ty: &'static str,
+    },
+    /// The layout contains fields with different byte orders. A format string only has a single byte order.
+    MixedEndianness {
+        first: &'static str,
+        second: &'static str,
+    },
+    /// The field's byte order is chosen when the struct is constructed, so it is not part of the layout.
+    RuntimeEndianness { field: &'static str },
+}
+
+impl fmt::Display for PythonFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PythonFormatError::UnsupportedType { field, ty } => {
+                write!(
+                    f,
+                    "field `{field}` has type `{ty}`, which Python's struct module does not support"
+                )
+            }
+            PythonFormatError::MixedEndianness { first, second } => {
+                write!(
+                    f,
+                    "fields `{first}` and `{second}` have different endianness"
+                )
+            }
+            PythonFormatError::RuntimeEndianness { field } => {
+                write!(
+                    f,
+                    "field `{field}` has a byte order that is chosen when the struct is constructed"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PythonFormatError {}
+
+impl Layout {
+    /// Create a format string for Python's [`struct`] module (e.g. for `struct.unpack`) that describes this layout.
+    ///
+    /// The format string starts with a byte order character: `>` (big endian), `<` (little endian), or `=` (native).
+    /// If there are no multi-byte fields, `=` is used since the byte order does not matter. Byte arrays use the `s`
+    /// format character (e.g. `16s`) and anonymous padding uses the `x` format character (e.g. `3x`), which means
+    /// padding does not produce a value when unpacking in Python.
+    ///
+    /// Python's struct module only supports a single byte order and has no 128-bit types, so a [`PythonFormatError`]
+    /// is returned if the layout mixes endianness or contains `u128`/`i128` fields. It is also returned for structs
+    /// declared with `#[byteview(endian = "runtime")]`, whose byte order is not known until they are constructed.
+    ///
+    /// [`struct`]: https://docs.python.org/3/library/struct.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// byteview::byteview_ref! {
+    ///     struct Record {
+    ///         name: [u8; 8],
+    ///         count: u32be,
+    ///         _: [u8; 2],
+    ///         flags: u8,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Ok(">8sI2xB".to_owned()), Record::layout().python_struct_format());
+    /// ```
+    pub fn python_struct_format(&self) -> Result<String, PythonFormatError> {
+        let mut endianness: Option<(Endianness, &'static str)> = None;
+        let mut format = String::new();
+        for field in self.fields {
+            if field.is_padding() {
+                push_count(&mut format, field.size, 'x');
+                continue;
+            }
+            let c = match field.kind {
+                FieldKind::U8 => 'B',
+                FieldKind::I8 => 'b',
+                FieldKind::Bytes => {
+                    push_count(&mut format, field.size, 's');
+                    continue;
+                }
+                FieldKind::RuntimeNumber(_) => {
+                    return Err(PythonFormatError::RuntimeEndianness { field: field.name });
+                }
+                FieldKind::Number(_, Endianness::Middle) => {
+                    // The struct module has no middle-endian byte order.
+                    return Err(PythonFormatError::UnsupportedType {
+                        field: field.name,
+                        ty: field.ty,
+                    });
+                }
+                FieldKind::Number(number_type, field_endianness) => {
+                    match endianness {
+                        Some((endianness, first)) if endianness != field_endianness => {
+                            return Err(PythonFormatError::MixedEndianness {
+                                first,
+                                second: field.name,
+                            });
+                        }
+                        Some(_) => {}
+                        None => endianness = Some((field_endianness, field.name)),
+                    }
+                    match number_type {
+                        NumberType::U16 => 'H',
+                        NumberType::U32 => 'I',
+                        NumberType::U64 => 'Q',
+                        NumberType::I16 => 'h',
+                        NumberType::I32 => 'i',
+                        NumberType::I64 => 'q',
+                        NumberType::F32 => 'f',
+                        NumberType::F64 => 'd',
+                        NumberType::U24
+                        | NumberType::I24
+                        | NumberType::U48
+                        | NumberType::I48
+                        | NumberType::U128
+                        | NumberType::I128 => {
+                            return Err(PythonFormatError::UnsupportedType {
+                                field: field.name,
+                                ty: field.ty,
+                            });
+                        }
+                    }
+                }
+            };
+            format.push(c);
+        }
+        let byte_order = match endianness {
+            Some((Endianness::Big, _)) => '>',
+            Some((Endianness::Little, _)) => '<',
+            Some((Endianness::Native, _)) | None => '=',
+            Some((Endianness::Middle, _)) => {
+                unreachable!("middle-endian fields are rejected above")
+            }
+        };
+        format.insert(0, byte_order);
+        Ok(format)
+    }
+}
+
+// Push a format character with a repeat count. A count of 1 is left implicit.
+fn push_count(format: &mut String, count: usize, c: char) {
+    if count != 1 {
+        format.push_str(&count.to_string());
+    }
+    format.push(c);
+}
@@ -0,0 +1,174 @@
+//! Iterating over a sequence of structs stored back to back.
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::{ByteView, ParseError};
+
+/// An iterator over the structs stored back to back in a slice of bytes.
+///
+/// Each struct is created with [`ByteView::split_slice_checked`], so for structs with a `#[byteview(record_len =
+/// "...")]` attribute each record may be a different size. The iterator yields an error if the remaining bytes do not
+/// contain a valid struct and then stops.
+///
+/// ```
+/// use byteview::{ByteView, Records, byteview_ref};
+///
+/// byteview_ref! {
+///     #[byteview(record_len = "record_len")]
+///     struct Record {
+///         kind: u8,
+///         record_len: u8,
+///     }
+/// }
+///
+/// let bytes = [1, 3, 0xAA, 2, 2];
+/// let kinds: Vec<u8> = Records::<Record>::new(&bytes)
+///     .map(|record| record.unwrap().kind())
+///     .collect();
+/// assert_eq!(kinds, [1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Records<'a, T> {
+    bytes: &'a [u8],
+    failed: bool,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: ByteView<'a>> Records<'a, T> {
+    /// Create an iterator over the structs stored back to back in `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            failed: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The bytes that have not been consumed by the iterator.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a, T: ByteView<'a>> Iterator for Records<'a, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.bytes.is_empty() {
+            return None;
+        }
+        match T::split_slice_checked(self.bytes) {
+            Ok((record, rest)) => {
+                self.bytes = rest;
+                Some(Ok(record))
+            }
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed || self.bytes.is_empty() {
+            return (0, Some(0));
+        }
+        // Every record is at least `MIN_NUM_BYTES` long, and an error ends the iteration.
+        let max_records = self.bytes.len().checked_div(T::MIN_NUM_BYTES);
+        (1, max_records.map(|max_records| max_records + 1))
+    }
+}
+
+impl<'a, T: ByteView<'a>> FusedIterator for Records<'a, T> {}
+
+/// An iterator over the structs written back to back to a page of erased flash memory, where unwritten bytes are
+/// `0xFF`.
+///
+/// The iterator stops at the first slot of [`NUM_BYTES`](ByteView::NUM_BYTES) bytes that is entirely `0xFF` (or at the
+/// end of the page), so [`offset`](FlashRecords::offset) is then where the next record can be written. A slot that is
+/// neither erased nor a valid struct, e.g. a record that was only partially written before power was lost, is yielded
+/// as a [`Torn`] error and the iterator continues with the next slot.
+///
+/// ```
+/// use byteview::{FlashRecords, byteview_ref};
+///
+/// byteview_ref! {
+///     #[derive(Debug)]
+///     struct Entry {
+///         #[byteview(magic = b"E")]
+///         magic: [u8; 1],
+///         value: u8,
+///     }
+/// }
+///
+/// let page = [b'E', 1, 0x00, 0xFF, b'E', 2, 0xFF, 0xFF, 0xFF, 0xFF];
+/// let mut records = FlashRecords::<Entry>::new(&page);
+/// assert_eq!(1, records.next().unwrap().unwrap().value());
+/// assert_eq!(2, records.next().unwrap().unwrap_err().offset);
+/// assert_eq!(2, records.next().unwrap().unwrap().value());
+/// assert!(records.next().is_none());
+/// assert_eq!(6, records.offset());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlashRecords<'a, T> {
+    page: &'a [u8],
+    offset: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+/// A slot in a page of flash memory that is neither erased nor a valid struct.
+///
+/// See [`FlashRecords`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Torn {
+    /// The offset of the slot in the page.
+    pub offset: usize,
+    /// The error from parsing the slot.
+    pub error: ParseError,
+}
+
+impl<'a, T: ByteView<'a>> FlashRecords<'a, T> {
+    /// The value of unwritten bytes in erased flash memory.
+    pub const ERASED: u8 = 0xFF;
+
+    /// Create an iterator over the structs written to `page`.
+    pub fn new(page: &'a [u8]) -> Self {
+        Self {
+            page,
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The offset of the next slot in the page. Once the iterator is exhausted, this is the offset of the first erased
+    /// slot, or the length of the page if it is full.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'a, T: ByteView<'a>> Iterator for FlashRecords<'a, T> {
+    type Item = Result<T, Torn>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = &self.page[self.offset..];
+        let slot = &bytes[..bytes.len().min(T::NUM_BYTES)];
+        if slot.iter().all(|&byte| byte == Self::ERASED) {
+            return None;
+        }
+        match T::split_slice_checked(bytes) {
+            Ok((record, rest)) => {
+                self.offset = self.page.len() - rest.len();
+                Some(Ok(record))
+            }
+            Err(error) => {
+                let offset = self.offset;
+                self.offset += slot.len();
+                Some(Err(Torn { offset, error }))
+            }
+        }
+    }
+}
+
+impl<'a, T: ByteView<'a>> FusedIterator for FlashRecords<'a, T> {}
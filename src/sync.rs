@@ -0,0 +1,142 @@
+//! Finding the magic bytes of a struct to resynchronize a damaged stream of records.
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::{ByteView, ParseError};
+
+/// Bytes that every valid struct contains at a fixed offset, declared with `#[byteview(magic = b"...")]`.
+///
+/// See [`ByteView::MAGIC`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Magic {
+    /// The offset of the magic bytes within the struct.
+    pub offset: usize,
+    /// The magic bytes.
+    pub bytes: &'static [u8],
+}
+
+impl Magic {
+    /// Return the offset of the first struct at or after `from` in `bytes` whose magic bytes match, or [`None`] if there
+    /// is no such struct.
+    ///
+    /// Only the magic bytes are checked, so the struct may still be invalid, e.g. if the magic bytes happen to appear
+    /// inside the body of another record.
+    pub fn find(&self, bytes: &[u8], from: usize) -> Option<usize> {
+        if self.bytes.is_empty() {
+            return (from <= bytes.len()).then_some(from);
+        }
+        let start = from.checked_add(self.offset)?;
+        let position = bytes
+            .get(start..)?
+            .windows(self.bytes.len())
+            .position(|window| window == self.bytes)?;
+        Some(from + position)
+    }
+}
+
+/// An iterator over the structs stored back to back in a slice of bytes that skips over damaged regions.
+///
+/// This is like [`Records`](crate::Records), but when the remaining bytes do not contain a valid struct, it scans
+/// forward for the next offset where a valid struct starts and yields the range of bytes that were skipped instead of
+/// stopping. If `T` has [`MAGIC`](ByteView::MAGIC) bytes, only offsets where they match are tried. Each candidate is
+/// parsed with [`ByteView::split_slice_checked`] before it is accepted, so magic bytes that appear inside a record are
+/// not mistaken for the start of one.
+///
+/// ```
+/// use byteview::{Resync, byteview_ref};
+///
+/// byteview_ref! {
+///     #[derive(Debug)]
+///     pub struct Sample {
+///         #[byteview(magic = b"S!")]
+///         pub magic: [u8; 2],
+///         pub value: u8,
+///     }
+/// }
+///
+/// let bytes = b"S!\x01garbageS!\x02";
+/// let mut records = Resync::<Sample>::new(bytes);
+/// assert_eq!(1, records.next().unwrap().unwrap().value());
+/// assert_eq!(3..10, records.next().unwrap().unwrap_err().range);
+/// assert_eq!(2, records.next().unwrap().unwrap().value());
+/// assert!(records.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Resync<'a, T> {
+    bytes: &'a [u8],
+    offset: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+/// A range of bytes skipped by [`Resync`] because it did not contain a valid struct.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Skipped {
+    /// The range of bytes that was skipped.
+    pub range: Range<usize>,
+    /// The error from parsing a struct at the start of the range.
+    pub error: ParseError,
+}
+
+impl<'a, T: ByteView<'a>> Resync<'a, T> {
+    /// Create an iterator over the structs stored back to back in `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The offset of the next byte that has not been consumed by the iterator.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // Find the first offset after `start` where a valid struct begins, or the end of the bytes if there is none.
+    fn find_next_valid(&self, start: usize) -> usize {
+        let mut candidate = start + 1;
+        while candidate < self.bytes.len() {
+            let found = match T::MAGIC {
+                Some(magic) => match magic.find(self.bytes, candidate) {
+                    Some(found) => found,
+                    None => break,
+                },
+                None => candidate,
+            };
+            if T::split_slice_checked(&self.bytes[found..]).is_ok() {
+                return found;
+            }
+            candidate = found + 1;
+        }
+        self.bytes.len()
+    }
+}
+
+impl<'a, T: ByteView<'a>> Iterator for Resync<'a, T> {
+    type Item = Result<T, Skipped>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self
+            .bytes
+            .get(self.offset..)
+            .filter(|bytes| !bytes.is_empty())?;
+        match T::split_slice_checked(bytes) {
+            Ok((record, rest)) => {
+                self.offset = self.bytes.len() - rest.len();
+                Some(Ok(record))
+            }
+            Err(error) => {
+                let start = self.offset;
+                self.offset = self.find_next_valid(start);
+                Some(Err(Skipped {
+                    range: start..self.offset,
+                    error,
+                }))
+            }
+        }
+    }
+}
+
+impl<'a, T: ByteView<'a>> FusedIterator for Resync<'a, T> {}
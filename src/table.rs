@@ -0,0 +1,410 @@
+//! Tables of fixed-size structs stored back to back.
+
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::slice::{Chunks, ChunksExact};
+
+use crate::ByteView;
+
+/// A table of fixed-size structs stored back to back in a slice of bytes, e.g. an index file.
+///
+/// Unlike [`Records`](crate::Records), every struct in a table is exactly [`NUM_BYTES`](ByteView::NUM_BYTES) long, so
+/// structs can be accessed by index. Each struct is created when it is accessed, so searching a table does not
+/// create the structs that are skipped. `T` must not be variable-sized (i.e. it must not have optional fields, varint
+/// fields, or a `#[byteview(record_len = "...")]` attribute), which is checked at compile time.
+///
+/// ```
+/// use byteview::{Table, byteview_ref};
+///
+/// byteview_ref! {
+///     struct Entry {
+///         key: u16be,
+///         value: u8,
+///     }
+/// }
+///
+/// let bytes = [0, 1, 0xAA, 0, 5, 0xBB, 0, 9, 0xCC];
+/// let table = Table::<Entry>::new(&bytes).unwrap();
+/// assert_eq!(3, table.len());
+/// assert!(table.is_sorted_by_key(|entry| entry.key()));
+/// assert_eq!(Ok(1), table.binary_search_by_key(&5, |entry| entry.key()));
+/// assert_eq!(2, table.partition_point(|entry| entry.key() < 7));
+/// ```
+///
+/// ```compile_fail
+/// use byteview::{Table, byteview_ref};
+///
+/// byteview_ref! {
+///     #[byteview(record_len = "len")]
+///     struct Entry {
+///         len: u8,
+///         value: u8,
+///     }
+/// }
+///
+/// let table = Table::<Entry>::new(&[2, 5, 2, 2]);
+/// ```
+pub struct Table<'a, T> {
+    bytes: &'a [u8],
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: ByteView<'a>> Table<'a, T> {
+    // The number of bytes in each struct. Fail to compile if the size of the struct can vary.
+    const RECORD_BYTES: usize = fixed_num_bytes::<T>();
+
+    /// Create a table from the structs stored back to back in `bytes`.
+    ///
+    /// If the length of `bytes` is not a multiple of [`NUM_BYTES`](ByteView::NUM_BYTES), return [`None`].
+    pub fn new(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.len().checked_rem(Self::RECORD_BYTES)? != 0 {
+            return None;
+        }
+        Some(Self::from_exact_bytes(bytes))
+    }
+
+    /// The bytes of the table.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The number of structs in the table.
+    pub fn len(&self) -> usize {
+        self.bytes.len() / Self::RECORD_BYTES
+    }
+
+    /// Return `true` if the table contains no structs.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The struct at index `i`, or [`None`] if `i` is out of range.
+    pub fn get(&self, i: usize) -> Option<T> {
+        (i < self.len()).then(|| self.view(i))
+    }
+
+    /// An iterator over the structs in the table.
+    pub fn iter(&self) -> TableIter<'a, T> {
+        TableIter {
+            chunks: self.bytes.chunks_exact(Self::RECORD_BYTES),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Return `true` if the keys of the structs are sorted in ascending order, like [`slice::is_sorted_by_key`].
+    pub fn is_sorted_by_key<K: PartialOrd>(&self, mut f: impl FnMut(T) -> K) -> bool {
+        let mut keys = self.iter().map(&mut f);
+        let Some(mut previous) = keys.next() else {
+            return true;
+        };
+        for key in keys {
+            if previous > key {
+                return false;
+            }
+            previous = key;
+        }
+        true
+    }
+
+    /// Return the index of the first struct for which `pred` returns `false`, like [`slice::partition_point`].
+    ///
+    /// The table must be partitioned by `pred`: every struct for which it returns `true` must come before every struct
+    /// for which it returns `false`. Otherwise, the result is unspecified.
+    pub fn partition_point(&self, mut pred: impl FnMut(T) -> bool) -> usize {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(self.view(mid)) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Binary search a table sorted by key, like [`slice::binary_search_by_key`].
+    ///
+    /// If a struct with the given key is found, return [`Ok`] with its index. Otherwise, return [`Err`] with the index
+    /// where a struct with the key could be inserted while keeping the table sorted.
+    pub fn binary_search_by_key<K: Ord>(
+        &self,
+        key: &K,
+        mut f: impl FnMut(T) -> K,
+    ) -> Result<usize, usize> {
+        let i = self.partition_point(|view| f(view) < *key);
+        if self.get(i).is_some_and(|view| f(view) == *key) {
+            Ok(i)
+        } else {
+            Err(i)
+        }
+    }
+
+    /// An iterator over every contiguous sub-table of `size` structs, like [`slice::windows`]. The sub-tables overlap.
+    ///
+    /// If the table contains fewer than `size` structs, the iterator yields nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn windows(&self, size: usize) -> TableWindows<'a, T> {
+        assert!(size != 0, "window size must be non-zero");
+        TableWindows {
+            bytes: self.bytes,
+            size,
+            phantom: PhantomData,
+        }
+    }
+
+    /// An iterator over sub-tables of `chunk_size` structs, like [`slice::chunks`]. The sub-tables do not overlap, and
+    /// the last one contains fewer than `chunk_size` structs if the length of the table is not a multiple of
+    /// `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn chunks(&self, chunk_size: usize) -> TableChunks<'a, T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        TableChunks {
+            // A chunk larger than the table holds the whole table, so the size can saturate.
+            chunks: self
+                .bytes
+                .chunks(chunk_size.saturating_mul(Self::RECORD_BYTES)),
+            phantom: PhantomData,
+        }
+    }
+
+    // A table of the structs in `bytes`, whose length must be a multiple of `NUM_BYTES`.
+    pub(crate) fn from_exact_bytes(bytes: &'a [u8]) -> Self {
+        let _ = Self::RECORD_BYTES;
+        Self {
+            bytes,
+            phantom: PhantomData,
+        }
+    }
+
+    // The struct at index `i`, which must be in range.
+    fn view(&self, i: usize) -> T {
+        view_exact(&self.bytes[i * Self::RECORD_BYTES..(i + 1) * Self::RECORD_BYTES])
+    }
+}
+
+/// Remove consecutive identical structs from the structs stored back to back in `buf`, keeping the first of each run,
+/// and return the number of structs removed.
+///
+/// Like [`Vec::dedup`], only adjacent duplicates are removed. The remaining structs are moved down in place and the
+/// buffer is truncated. Any bytes at the end of `buf` that do not form a whole struct are kept after the remaining
+/// structs. `T` must not be variable-sized, like the structs in a [`Table`].
+///
+/// ```
+/// byteview::byteview_ref! {
+///     struct Sample {
+///         sensor: u8,
+///         value: u16be,
+///     }
+/// }
+///
+/// let mut buf = vec![1, 0, 5, 1, 0, 5, 2, 0, 7, 1, 0, 5];
+/// assert_eq!(1, byteview::dedup_records::<Sample>(&mut buf));
+/// assert_eq!(vec![1, 0, 5, 2, 0, 7, 1, 0, 5], buf);
+/// ```
+pub fn dedup_records<'a, T: ByteView<'a>>(buf: &mut Vec<u8>) -> usize {
+    dedup_chunks(
+        buf,
+        const { fixed_num_bytes::<T>() },
+        |previous, current| previous == current,
+    )
+}
+
+/// Remove consecutive structs that `same` considers equal from the structs stored back to back in `buf`, keeping the
+/// first of each run, and return the number of structs removed.
+///
+/// This is like [`dedup_records`], but each struct is compared with the last struct that was kept using `same`, e.g. to
+/// ignore padding or a timestamp. `T` must be an owned struct generated by
+/// [`byteview_owned!`](crate::byteview_owned), since the structs are compared while the buffer is being modified, and
+/// it must not be variable-sized.
+///
+/// ```
+/// byteview::byteview_owned! {
+///     struct Sample {
+///         sensor: u8,
+///         _: u8,
+///         value: u16be,
+///     }
+/// }
+///
+/// let mut buf = vec![1, 0, 0, 5, 1, 0xFF, 0, 5, 2, 0, 0, 7];
+/// let removed = byteview::dedup_records_by::<Sample>(&mut buf, |previous, current| {
+///     (previous.sensor(), previous.value()) == (current.sensor(), current.value())
+/// });
+/// assert_eq!(1, removed);
+/// assert_eq!(vec![1, 0, 0, 5, 2, 0, 0, 7], buf);
+/// ```
+pub fn dedup_records_by<T: for<'a> ByteView<'a>>(
+    buf: &mut Vec<u8>,
+    mut same: impl FnMut(&T, &T) -> bool,
+) -> usize {
+    dedup_chunks(
+        buf,
+        const { fixed_num_bytes::<'static, T>() },
+        |previous, current| same(&view_exact(previous), &view_exact(current)),
+    )
+}
+
+// Remove consecutive chunks of `size` bytes for which `same` returns true when compared to the last chunk kept, and
+// return the number of chunks removed.
+fn dedup_chunks(
+    buf: &mut Vec<u8>,
+    size: usize,
+    mut same: impl FnMut(&[u8], &[u8]) -> bool,
+) -> usize {
+    let Some(count) = buf.len().checked_div(size) else {
+        return 0;
+    };
+    let chunk = |i: usize| i * size..(i + 1) * size;
+    let mut kept = count.min(1);
+    for i in 1..count {
+        if same(&buf[chunk(kept - 1)], &buf[chunk(i)]) {
+            continue;
+        }
+        if kept != i {
+            buf.copy_within(chunk(i), kept * size);
+        }
+        kept += 1;
+    }
+    let rest = count * size;
+    let rest_len = buf.len() - rest;
+    buf.copy_within(rest.., kept * size);
+    buf.truncate(kept * size + rest_len);
+    count - kept
+}
+
+// The number of bytes in each struct of a table. Fail to compile if the size of the struct can vary.
+const fn fixed_num_bytes<'a, T: ByteView<'a>>() -> usize {
+    assert!(
+        T::MIN_NUM_BYTES == T::NUM_BYTES && T::MAX_NUM_BYTES == T::NUM_BYTES,
+        "structs in a table must not have optional fields, varint fields, or length fields"
+    );
+    T::NUM_BYTES
+}
+
+// Create a struct from exactly `NUM_BYTES` bytes of a struct that is not variable-sized.
+fn view_exact<'a, T: ByteView<'a>>(bytes: &'a [u8]) -> T {
+    let (view, _) = T::split_slice(bytes).expect("the bytes are exactly NUM_BYTES long");
+    view
+}
+
+impl<'a, T: ByteView<'a>> IntoIterator for Table<'a, T> {
+    type Item = T;
+    type IntoIter = TableIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Clone for Table<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Table<'_, T> {}
+
+impl<T> fmt::Debug for Table<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table").field("bytes", &self.bytes).finish()
+    }
+}
+
+/// An iterator over the structs in a [`Table`].
+///
+/// This is created by [`Table::iter`].
+#[derive(Debug, Clone)]
+pub struct TableIter<'a, T> {
+    chunks: ChunksExact<'a, u8>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: ByteView<'a>> Iterator for TableIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(view_exact)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<'a, T: ByteView<'a>> DoubleEndedIterator for TableIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(view_exact)
+    }
+}
+
+impl<'a, T: ByteView<'a>> ExactSizeIterator for TableIter<'a, T> {}
+
+impl<'a, T: ByteView<'a>> FusedIterator for TableIter<'a, T> {}
+
+/// An iterator over overlapping sub-tables of a [`Table`].
+///
+/// This is created by [`Table::windows`].
+#[derive(Debug, Clone)]
+pub struct TableWindows<'a, T> {
+    bytes: &'a [u8],
+    size: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: ByteView<'a>> Iterator for TableWindows<'a, T> {
+    type Item = Table<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.bytes.get(..self.size.checked_mul(T::NUM_BYTES)?)?;
+        self.bytes = &self.bytes[T::NUM_BYTES..];
+        Some(Table::from_exact_bytes(window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.bytes.len() / T::NUM_BYTES + 1).saturating_sub(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: ByteView<'a>> ExactSizeIterator for TableWindows<'a, T> {}
+
+impl<'a, T: ByteView<'a>> FusedIterator for TableWindows<'a, T> {}
+
+/// An iterator over non-overlapping sub-tables of a [`Table`].
+///
+/// This is created by [`Table::chunks`].
+#[derive(Debug, Clone)]
+pub struct TableChunks<'a, T> {
+    chunks: Chunks<'a, u8>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: ByteView<'a>> Iterator for TableChunks<'a, T> {
+    type Item = Table<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(Table::from_exact_bytes)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<'a, T: ByteView<'a>> DoubleEndedIterator for TableChunks<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(Table::from_exact_bytes)
+    }
+}
+
+impl<'a, T: ByteView<'a>> ExactSizeIterator for TableChunks<'a, T> {}
+
+impl<'a, T: ByteView<'a>> FusedIterator for TableChunks<'a, T> {}
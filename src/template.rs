@@ -0,0 +1,128 @@
+//! Matching bytes against a pattern in which some bytes are ignored.
+
+use crate::{ByteView, Layout};
+
+/// A pattern of bytes to match, in which some bits are ignored, e.g. to classify records that differ only in a
+/// sequence number or timestamp.
+///
+/// A byte of `bytes` matches the pattern when every bit that is set in the mask is equal to the same bit of the
+/// pattern. A template can be created from a pattern and mask with [`new`](Template::new), or from a struct with
+/// [`of`](Template::of) and then have whole fields ignored by name with [`ignore`](Template::ignore).
+///
+/// ```
+/// use byteview::{Template, byteview_ref};
+///
+/// byteview_ref! {
+///     pub struct Entry {
+///         pub kind: u8,
+///         pub index: u16be,
+///     }
+/// }
+///
+/// let template = Template::of(&Entry::from_array(&[0x01, 0x00, 0x00])).ignore("index");
+/// assert!(template.matches(&[0x01, 0x12, 0x34]));
+/// assert!(!template.matches(&[0x02, 0x12, 0x34]));
+/// assert_eq!(Some(2), template.find_in(&[0xFF, 0xFF, 0x01, 0x56, 0x78]));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Template {
+    pattern: Vec<u8>,
+    mask: Vec<u8>,
+    layout: Option<Layout>,
+}
+
+impl Template {
+    /// Create a template from a pattern and a mask of the bits to compare.
+    ///
+    /// If `pattern` and `mask` are different lengths, return [`None`]. Fields cannot be ignored by name in a template
+    /// created this way.
+    ///
+    /// ```
+    /// use byteview::Template;
+    ///
+    /// let template = Template::new([0xA0, 0x00], [0xF0, 0x00]).unwrap();
+    /// assert!(template.matches(&[0xA7, 0x42]));
+    /// assert!(!template.matches(&[0xB0, 0x00]));
+    /// ```
+    pub fn new(pattern: impl Into<Vec<u8>>, mask: impl Into<Vec<u8>>) -> Option<Self> {
+        let (pattern, mask) = (pattern.into(), mask.into());
+        if pattern.len() != mask.len() {
+            return None;
+        }
+        Some(Self {
+            pattern,
+            mask,
+            layout: None,
+        })
+    }
+
+    /// Create a template that matches exactly the bytes of the fields of `view`.
+    ///
+    /// Fields of `T` can then be ignored by name with [`ignore`](Template::ignore). Any bytes after the fields (e.g. the
+    /// body of a variable-sized record) are not part of the template, and neither are optional fields that are not
+    /// present in `view`.
+    pub fn of<'a, T: ByteView<'a>>(view: &T) -> Self {
+        let bytes = view.as_bytes();
+        let pattern = bytes[..bytes.len().min(T::NUM_BYTES)].to_vec();
+        let mask = vec![0xFF; pattern.len()];
+        Self {
+            pattern,
+            mask,
+            layout: Some(T::LAYOUT),
+        }
+    }
+
+    /// Ignore every byte of the field with the given name. If the field is optional and was not present in the struct
+    /// the template was created from, this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the template was not created with [`of`](Template::of) or the struct has no field with the given name.
+    #[track_caller]
+    pub fn ignore(mut self, name: &str) -> Self {
+        let layout = self
+            .layout
+            .expect("fields can only be ignored in a template created from a struct");
+        let field = layout
+            .field(name)
+            .unwrap_or_else(|| panic!("`{}` has no field named `{name}` to ignore", layout.name));
+        let range = field.range();
+        let end = range.end.min(self.mask.len());
+        self.mask[range.start.min(end)..end].fill(0);
+        self
+    }
+
+    /// The bytes to match. Bits that are ignored are included as they were given.
+    pub fn pattern(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    /// The bits of the pattern that are compared.
+    pub fn mask(&self) -> &[u8] {
+        &self.mask
+    }
+
+    /// Return `true` if the start of `bytes` matches the template.
+    ///
+    /// Any bytes after the length of the pattern are ignored. If `bytes` is shorter than the pattern, return `false`.
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        bytes.len() >= self.pattern.len()
+            && self
+                .pattern
+                .iter()
+                .zip(&self.mask)
+                .zip(bytes)
+                .all(|((pattern, mask), byte)| (pattern ^ byte) & mask == 0)
+    }
+
+    /// Return `true` if the bytes of `view` match the template.
+    pub fn matches_view<'a, T: ByteView<'a>>(&self, view: &T) -> bool {
+        self.matches(view.as_bytes())
+    }
+
+    /// Return the offset of the first match of the template in `haystack`, or [`None`] if there is no match.
+    pub fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        let last = haystack.len().checked_sub(self.pattern.len())?;
+        (0..=last).find(|&offset| self.matches(&haystack[offset..]))
+    }
+}
@@ -0,0 +1,251 @@
+//! Helpers for testing structs generated by `byteview`. This module requires the `testing` feature.
+
+use crate::{ByteView, FieldSelector, Patch, Value};
+use std::marker::PhantomData;
+
+/// Check that an owned struct round-trips a set of generated byte buffers.
+///
+/// This is shorthand for `RoundTrip::<T>::new().check()`. See [`RoundTrip`] for the checks that are made and for
+/// adding buffers, skipping fields, or comparing against the borrowed version of the struct.
+///
+/// ```
+/// byteview::byteview_owned! {
+///     pub struct HeaderOwned {
+///         pub index: u32be,
+///         pub kind: u8,
+///     }
+/// }
+///
+/// byteview::testing::check_roundtrip::<HeaderOwned>();
+/// ```
+#[track_caller]
+pub fn check_roundtrip<T: for<'a> ByteView<'a>>() {
+    RoundTrip::<T>::new().check();
+}
+
+/// A set of byte buffers to round-trip through the owned struct `T`.
+///
+/// For every buffer, [`check`](RoundTrip::check) asserts that:
+///
+/// - The struct created from the buffer contains exactly the bytes of the buffer.
+/// - Setting each field to its own value with a [`Patch`] leaves the bytes unchanged.
+///
+/// [`check_with_ref`](RoundTrip::check_with_ref) also asserts that the borrowed version of the struct created from the
+/// same buffer contains the same bytes and decodes every field to the same value.
+///
+/// By default, the buffers are all zeros, all ones, alternating bits, and several pseudo-random buffers, each
+/// [`NUM_BYTES`](ByteView::NUM_BYTES) long. Generated buffers that the struct rejects (e.g. because a record length is
+/// invalid) are skipped, but buffers added with [`buffer`](RoundTrip::buffer) must be accepted.
+///
+/// ```
+/// byteview::byteview_ref! {
+///     pub struct HeaderRef {
+///         pub index: u32be,
+///         pub ratio: f32le,
+///     }
+/// }
+///
+/// byteview::byteview_owned! {
+///     pub struct HeaderOwned {
+///         pub index: u32be,
+///         pub ratio: f32le,
+///     }
+/// }
+///
+/// byteview::testing::RoundTrip::<HeaderOwned>::new()
+///     .buffer([0, 0, 0, 1, 0, 0, 0x80, 0x3F])
+///     .skip_field("ratio")
+///     .check_with_ref::<HeaderRef>();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoundTrip<T> {
+    generated: Vec<Vec<u8>>,
+    provided: Vec<Vec<u8>>,
+    skipped_fields: Vec<String>,
+    view: PhantomData<fn() -> T>,
+}
+
+impl<T: for<'a> ByteView<'a>> Default for RoundTrip<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: for<'a> ByteView<'a>> RoundTrip<T> {
+    /// Create a round trip with the default generated buffers.
+    pub fn new() -> Self {
+        let len = T::NUM_BYTES;
+        let mut generated = vec![
+            vec![0x00; len],
+            vec![0xFF; len],
+            vec![0x55; len],
+            vec![0xAA; len],
+        ];
+        // A fixed seed keeps failures reproducible.
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        for _ in 0..8 {
+            let buffer = (0..len)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect();
+            generated.push(buffer);
+        }
+        Self {
+            generated,
+            provided: Vec::new(),
+            skipped_fields: Vec::new(),
+            view: PhantomData,
+        }
+    }
+
+    /// Also check a specific buffer, which must be accepted by the struct.
+    pub fn buffer(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.provided.push(bytes.into());
+        self
+    }
+
+    /// Do not check the field with the given name, e.g. because it is converted with a fallible conversion or holds a
+    /// float that may be NaN.
+    pub fn skip_field(mut self, name: impl Into<String>) -> Self {
+        self.skipped_fields.push(name.into());
+        self
+    }
+
+    /// Run the checks on every buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any check fails or a skipped field does not exist.
+    #[track_caller]
+    pub fn check(&self) {
+        for (bytes, view) in self.views() {
+            self.check_view(bytes, &view);
+        }
+    }
+
+    /// Run the checks on every buffer, and also check that the borrowed version of the struct `R` agrees with `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any check fails, a skipped field does not exist, or `R` has a different layout than `T`.
+    #[track_caller]
+    pub fn check_with_ref<'a, R: ByteView<'a>>(&'a self) {
+        let (layout, ref_layout) = (T::LAYOUT, R::LAYOUT);
+        assert!(
+            layout.num_bytes == ref_layout.num_bytes && layout.fields == ref_layout.fields,
+            "`{}` and `{}` have different layouts",
+            layout.name,
+            ref_layout.name
+        );
+        self.check();
+        for bytes in self.buffers() {
+            let (Some((view, _)), Some((ref_view, _))) =
+                (T::split_slice(bytes), R::split_slice(bytes))
+            else {
+                assert!(
+                    T::split_slice(bytes).is_none() && R::split_slice(bytes).is_none(),
+                    "only one of `{}` and `{}` accepts {bytes:02x?}",
+                    layout.name,
+                    ref_layout.name
+                );
+                continue;
+            };
+            assert_eq!(
+                view.as_bytes(),
+                ref_view.as_bytes(),
+                "`{}` and `{}` contain different bytes",
+                layout.name,
+                ref_layout.name
+            );
+            for field in self.checked_fields() {
+                let value = Value::decode(field.kind, &view.as_bytes()[field.range()]);
+                let ref_value = Value::decode(field.kind, &ref_view.as_bytes()[field.range()]);
+                assert!(
+                    value == ref_value || is_nan(&value) && is_nan(&ref_value),
+                    "`{}` and `{}` disagree on `{}`: {value:?} != {ref_value:?}",
+                    layout.name,
+                    ref_layout.name,
+                    field.name
+                );
+            }
+        }
+    }
+
+    // Every buffer that should be checked: the generated buffers followed by the provided ones.
+    fn buffers(&self) -> impl Iterator<Item = &[u8]> {
+        self.generated
+            .iter()
+            .chain(&self.provided)
+            .map(Vec::as_slice)
+    }
+
+    // The view of each buffer accepted by `T`. Panic if a provided buffer is rejected.
+    #[track_caller]
+    fn views(&self) -> Vec<(&[u8], T)> {
+        let generated = self
+            .generated
+            .iter()
+            .filter_map(|bytes| T::split_slice(bytes).map(|(view, _)| (bytes.as_slice(), view)));
+        let provided = self.provided.iter().map(|bytes| {
+            let (view, _) = T::split_slice(bytes)
+                .unwrap_or_else(|| panic!("`{}` rejects {bytes:02x?}", T::LAYOUT.name));
+            (bytes.as_slice(), view)
+        });
+        generated.chain(provided).collect()
+    }
+
+    // The named fields of `T` that are not skipped. Panic if a skipped field does not exist.
+    #[track_caller]
+    fn checked_fields(&self) -> Vec<crate::FieldInfo> {
+        let layout = T::LAYOUT;
+        for name in &self.skipped_fields {
+            assert!(
+                layout.field(name).is_some(),
+                "`{}` has no field named `{name}` to skip",
+                layout.name
+            );
+        }
+        layout
+            .named_fields()
+            .filter(|field| !self.skipped_fields.iter().any(|name| name == field.name))
+            .copied()
+            .collect()
+    }
+
+    #[track_caller]
+    fn check_view(&self, bytes: &[u8], view: &T) {
+        let name = T::LAYOUT.name;
+        let view_bytes = view.as_bytes();
+        assert_eq!(
+            &bytes[..view_bytes.len()],
+            view_bytes,
+            "`{name}` does not contain the bytes it was created from"
+        );
+        for field in self.checked_fields() {
+            let value = Value::decode(field.kind, &view_bytes[field.range()]);
+            if is_nan(&value) {
+                // NaN payloads are not guaranteed to survive the conversion to and from f64.
+                continue;
+            }
+            let mut patched = view_bytes.to_vec();
+            let mut patch = Patch::new();
+            patch.push(0, FieldSelector::Field(field), value.clone());
+            patch.apply(&mut patched).unwrap_or_else(|error| {
+                panic!("`{name}.{}` could not be set: {error}", field.name)
+            });
+            assert_eq!(
+                view_bytes, patched,
+                "setting `{name}.{}` to its own value {value:?} changed the bytes",
+                field.name
+            );
+        }
+    }
+}
+
+fn is_nan(value: &Value) -> bool {
+    matches!(value, Value::Float(float) if float.is_nan())
+}
@@ -0,0 +1,201 @@
+//! Tag-length-value (TLV) records.
+
+use crate::ParseError;
+use std::iter::FusedIterator;
+
+/// The width and encoding of the tag or length of a [`Tlv`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TlvWidth {
+    /// A single byte.
+    U8,
+    /// A big-endian 16-bit integer.
+    U16Be,
+    /// A big-endian 32-bit integer.
+    U32Be,
+}
+
+impl TlvWidth {
+    /// The number of bytes used by this width.
+    pub const fn size(self) -> usize {
+        match self {
+            TlvWidth::U8 => 1,
+            TlvWidth::U16Be => 2,
+            TlvWidth::U32Be => 4,
+        }
+    }
+
+    // Read a value of this width from the start of `bytes`, which must contain at least `self.size()` bytes.
+    fn read(self, bytes: &[u8]) -> u32 {
+        match self {
+            TlvWidth::U8 => u32::from(bytes[0]),
+            TlvWidth::U16Be => u32::from(u16::from_be_bytes([bytes[0], bytes[1]])),
+            TlvWidth::U32Be => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        }
+    }
+}
+
+/// The widths of the tag and length of a [`Tlv`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TlvFormat {
+    pub tag: TlvWidth,
+    pub len: TlvWidth,
+}
+
+impl TlvFormat {
+    /// Create a format with the given tag and length widths.
+    pub const fn new(tag: TlvWidth, len: TlvWidth) -> Self {
+        Self { tag, len }
+    }
+
+    /// The number of bytes before the value of each TLV.
+    pub const fn header_len(self) -> usize {
+        self.tag.size() + self.len.size()
+    }
+
+    /// Parse the first TLV of `bytes` and return it along with the remaining bytes.
+    ///
+    /// Return [`ParseError::TooShort`] if the tag or length is truncated and [`ParseError::LengthMismatch`] if the
+    /// value is truncated.
+    pub fn split_slice(self, bytes: &[u8]) -> Result<(Tlv<'_>, &[u8]), ParseError> {
+        let header_len = self.header_len();
+        if bytes.len() < header_len {
+            return Err(ParseError::TooShort {
+                needed: header_len,
+                available: bytes.len(),
+            });
+        }
+        let tag = self.tag.read(bytes);
+        let len = self.len.read(&bytes[self.tag.size()..]);
+        let rest = &bytes[header_len..];
+        let value = usize::try_from(len)
+            .ok()
+            .and_then(|len| rest.get(..len))
+            .ok_or(ParseError::LengthMismatch {
+                declared: u64::from(len),
+                available: rest.len(),
+            })?;
+        let tlv = Tlv {
+            format: self,
+            tag,
+            value,
+        };
+        Ok((tlv, &rest[value.len()..]))
+    }
+
+    /// An iterator over the TLVs stored back to back in `bytes`.
+    pub fn iter(self, bytes: &[u8]) -> TlvIter<'_> {
+        TlvIter::new(self, bytes)
+    }
+}
+
+/// A single tag-length-value record.
+///
+/// ```
+/// use byteview::{TlvFormat, TlvWidth};
+///
+/// let format = TlvFormat::new(TlvWidth::U8, TlvWidth::U16Be);
+/// let bytes = [0x01, 0x00, 0x02, b'h', b'i', 0x02, 0x00, 0x00];
+/// let tlvs: Vec<_> = format.iter(&bytes).map(|tlv| tlv.unwrap()).collect();
+/// assert_eq!(1, tlvs[0].tag());
+/// assert_eq!(b"hi", tlvs[0].value());
+/// assert!(tlvs[1].is_empty());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Tlv<'a> {
+    format: TlvFormat,
+    tag: u32,
+    value: &'a [u8],
+}
+
+impl<'a> Tlv<'a> {
+    /// The tag.
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    /// The number of bytes in the value.
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Whether the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// The value.
+    pub fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// The format used to parse this TLV.
+    pub fn format(&self) -> TlvFormat {
+        self.format
+    }
+
+    /// An iterator over the TLVs nested in the value, using the same format as this TLV.
+    pub fn value_as_tlvs(&self) -> TlvIter<'a> {
+        TlvIter::new(self.format, self.value)
+    }
+}
+
+/// An iterator over TLVs stored back to back in a slice of bytes.
+///
+/// The iterator yields an error if a TLV is truncated and then stops.
+#[derive(Debug, Clone)]
+pub struct TlvIter<'a> {
+    format: TlvFormat,
+    bytes: &'a [u8],
+    failed: bool,
+}
+
+impl<'a> TlvIter<'a> {
+    /// Create an iterator over the TLVs stored back to back in `bytes`.
+    pub fn new(format: TlvFormat, bytes: &'a [u8]) -> Self {
+        Self {
+            format,
+            bytes,
+            failed: false,
+        }
+    }
+
+    /// The bytes that have not been consumed by the iterator.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Find the first remaining TLV with the given tag.
+    ///
+    /// Return an error if a truncated TLV is found before it.
+    pub fn find_tag(&mut self, tag: u32) -> Result<Option<Tlv<'a>>, ParseError> {
+        for tlv in self {
+            let tlv = tlv?;
+            if tlv.tag() == tag {
+                return Ok(Some(tlv));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'a> Iterator for TlvIter<'a> {
+    type Item = Result<Tlv<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.bytes.is_empty() {
+            return None;
+        }
+        match self.format.split_slice(self.bytes) {
+            Ok((tlv, rest)) => {
+                self.bytes = rest;
+                Some(Ok(tlv))
+            }
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl FusedIterator for TlvIter<'_> {}
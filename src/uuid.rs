@@ -0,0 +1,105 @@
+//! 16-byte UUIDs, the type of `uuid` fields.
+
+use crate::FromFieldValue;
+use std::fmt;
+
+/// A 16-byte UUID, stored big-endian as in [RFC 9562](https://www.rfc-editor.org/rfc/rfc9562).
+///
+/// This is returned by the "getter" of a `uuid` field. It is formatted in the canonical hyphenated form by both
+/// `Display` and `Debug`.
+///
+/// ```
+/// byteview::byteview_ref! {
+///     pub struct Device {
+///         pub id: uuid,
+///     }
+/// }
+///
+/// let bytes = *b"\x67\xE5\x50\x44\x10\xB1\x42\x6F\x92\x47\xBB\x68\x0E\x5F\xE0\xC8";
+/// let device = Device::from_array(&bytes);
+/// assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", device.id().to_string());
+/// assert_eq!(&bytes, device.id_raw());
+/// ```
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Uuid(pub [u8; 16]);
+
+impl Uuid {
+    /// The nil UUID, whose bytes are all zero.
+    pub const NIL: Self = Self([0; 16]);
+
+    /// Wrap the bytes of a UUID.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// The bytes of the UUID.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Whether this is the nil UUID.
+    pub const fn is_nil(&self) -> bool {
+        u128::from_be_bytes(self.0) == 0
+    }
+}
+
+/// A `uuid` field is a `[u8; 16]` converted to a [`Uuid`].
+impl FromFieldValue<&[u8; 16]> for Uuid {
+    type Output = Uuid;
+
+    fn from_field_value(value: &[u8; 16]) -> Self::Output {
+        Uuid(*value)
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Uuid> for [u8; 16] {
+    fn from(uuid: Uuid) -> Self {
+        uuid.0
+    }
+}
+
+/// Convert to a [`uuid::Uuid`](::uuid::Uuid) from the `uuid` crate. This requires the `uuid` feature.
+#[cfg(feature = "uuid")]
+impl From<Uuid> for ::uuid::Uuid {
+    fn from(uuid: Uuid) -> Self {
+        Self::from_bytes(uuid.0)
+    }
+}
+
+/// Convert from a [`uuid::Uuid`](::uuid::Uuid) from the `uuid` crate. This requires the `uuid` feature.
+#[cfg(feature = "uuid")]
+impl From<::uuid::Uuid> for Uuid {
+    fn from(uuid: ::uuid::Uuid) -> Self {
+        Self(uuid.into_bytes())
+    }
+}
+
+impl PartialEq<[u8; 16]> for Uuid {
+    fn eq(&self, other: &[u8; 16]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
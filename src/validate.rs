@@ -0,0 +1,40 @@
+//! Errors produced by the `validate()` method of structs with fields annotated with `#[byteview(must_be_zero)]`.
+
+use std::fmt;
+
+/// The reason a struct does not conform to its format, as reported by its `validate()` method.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ValidateError {
+    /// A field annotated with `#[byteview(must_be_zero)]` contains a byte that is not zero. Padding fields are named
+    /// `_`.
+    NotZero { field: &'static str, offset: usize },
+}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidateError::NotZero { field, offset } => write!(
+                f,
+                "the field `{field}` at offset {offset} must be zero but is not"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidateError {}
+
+// Check that the `size` bytes of the field `field` at `offset` are all zero. If the bytes are missing, e.g. because the
+// field is optional, succeed.
+pub fn check_zero(
+    bytes: &[u8],
+    field: &'static str,
+    offset: usize,
+    size: usize,
+) -> Result<(), ValidateError> {
+    match bytes.get(offset..offset + size) {
+        Some(value) if value.iter().any(|byte| *byte != 0) => {
+            Err(ValidateError::NotZero { field, offset })
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,64 @@
+//! Unsigned LEB128 varints, the type of trailing `varint` fields.
+
+use crate::ParseError;
+
+/// The largest number of bytes in a varint holding a u64.
+const MAX_VARINT_LEN: usize = 10;
+
+// Decode the varint at the start of `bytes` and return its value and the number of bytes it occupies. Return the number
+// of bytes needed if `bytes` ends first, or `None` if the varint is longer than 10 bytes or does not fit in a u64.
+fn decode_varint(bytes: &[u8]) -> Result<Option<(u64, usize)>, usize> {
+    let mut value = 0;
+    for (i, &byte) in bytes.iter().take(MAX_VARINT_LEN).enumerate() {
+        let low_bits = u64::from(byte & 0x7F);
+        // The tenth byte holds only the most significant bit of a u64.
+        if i == MAX_VARINT_LEN - 1 && low_bits > 1 {
+            return Ok(None);
+        }
+        value |= low_bits << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(Some((value, i + 1)));
+        }
+    }
+    if bytes.len() < MAX_VARINT_LEN {
+        Err(bytes.len() + 1)
+    } else {
+        Ok(None)
+    }
+}
+
+// Skip `count` varints starting at `start` and return the offset of the first byte after them.
+pub fn skip_varints(bytes: &[u8], start: usize, count: usize) -> Result<usize, ParseError> {
+    let mut offset = start;
+    for _ in 0..count {
+        match decode_varint(&bytes[offset..]) {
+            Ok(Some((_, len))) => offset += len,
+            Ok(None) => return Err(ParseError::InvalidVarint { offset }),
+            Err(needed) => {
+                return Err(ParseError::TooShort {
+                    needed: offset + needed,
+                    available: bytes.len(),
+                });
+            }
+        }
+    }
+    Ok(offset)
+}
+
+// Decode varint number `index` (counting from zero) of the varints at the start of `bytes`. The varints are checked by
+// `skip_varints` when a struct is created, so they are known to be valid.
+pub fn nth_varint(bytes: &[u8], index: usize) -> u64 {
+    let mut offset = 0;
+    for _ in 0..index {
+        let (_, len) = decode_varint(&bytes[offset..])
+            .ok()
+            .flatten()
+            .expect("varints are checked when the struct is created");
+        offset += len;
+    }
+    let (value, _) = decode_varint(&bytes[offset..])
+        .ok()
+        .flatten()
+        .expect("varints are checked when the struct is created");
+    value
+}
@@ -0,0 +1,150 @@
+//! Choosing between several layouts of a struct based on a version byte.
+
+use crate::ParseError;
+use std::fmt;
+
+/// The reason bytes could not be parsed as one of the layouts of a versioned enum.
+///
+/// See [`byteview_versioned!`](crate::byteview_versioned).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum VersionError {
+    /// The version byte does not match any of the declared versions.
+    UnknownVersion(u8),
+    /// The version byte matches a declared version, but the bytes could not be parsed as that layout. This is also
+    /// returned if there are not enough bytes to read the version byte.
+    Parse(ParseError),
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionError::UnknownVersion(version) => write!(f, "unknown version {version}"),
+            VersionError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for VersionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VersionError::UnknownVersion(_) => None,
+            VersionError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseError> for VersionError {
+    fn from(error: ParseError) -> Self {
+        VersionError::Parse(error)
+    }
+}
+
+/// Declare an enum with one variant for each layout of a versioned struct.
+///
+/// The version byte is read from `version_offset`, and `split_slice` creates the variant whose version matches it. An
+/// optional `common` block lists "getter" methods present in every layout; these are generated on the enum and call
+/// the method of whichever layout was parsed. The enum also has a `version()` method returning the version byte of its
+/// variant.
+///
+/// ```
+/// use byteview::{VersionError, byteview_ref, byteview_versioned};
+///
+/// byteview_ref! {
+///     pub struct HeaderV1 {
+///         pub magic: [u8; 4],
+///         pub version: u8,
+///         pub len: u16be,
+///     }
+/// }
+///
+/// byteview_ref! {
+///     pub struct HeaderV2 {
+///         pub magic: [u8; 4],
+///         pub version: u8,
+///         pub len: u32be,
+///     }
+/// }
+///
+/// byteview_versioned! {
+///     pub enum AnyHeader<'a> {
+///         version_offset = 4;
+///         common {
+///             fn magic(&self) -> &[u8; 4];
+///         }
+///         1 => V1(HeaderV1<'a>),
+///         2 => V2(HeaderV2<'a>),
+///     }
+/// }
+///
+/// let bytes = b"HEAD\x02\x00\x00\x00\x10";
+/// let (header, _) = AnyHeader::split_slice(bytes).unwrap();
+/// assert!(matches!(header, AnyHeader::V2(_)));
+/// assert_eq!(2, header.version());
+/// assert_eq!(b"HEAD", header.magic());
+///
+/// let bytes = b"HEAD\x03\x00\x00\x00\x10";
+/// assert_eq!(Some(VersionError::UnknownVersion(3)), AnyHeader::split_slice(bytes).err());
+/// ```
+#[macro_export]
+macro_rules! byteview_versioned {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident $(<$lifetime:lifetime>)? {
+            version_offset = $offset:expr;
+            $(common $common:tt)?
+            $($version:literal => $variant:ident($ty:ty)),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis enum $name $(<$lifetime>)? {
+            $($variant($ty)),+
+        }
+
+        impl $(<$lifetime>)? $name $(<$lifetime>)? {
+            /// Create the variant whose version matches the version byte and return it along with the remaining
+            /// bytes.
+            pub fn split_slice<'__bytes>(
+                bytes: &'__bytes [::std::primitive::u8],
+            ) -> ::std::result::Result<(Self, &'__bytes [::std::primitive::u8]), $crate::VersionError>
+            where
+                $($ty: $crate::ByteView<'__bytes>),+
+            {
+                let offset: ::std::primitive::usize = $offset;
+                let version = *bytes.get(offset).ok_or($crate::ParseError::TooShort {
+                    needed: offset + 1,
+                    available: bytes.len(),
+                })?;
+                match version {
+                    $($version => {
+                        let (view, rest) = <$ty as $crate::ByteView>::split_slice_checked(bytes)?;
+                        ::std::result::Result::Ok(($name::$variant(view), rest))
+                    })+
+                    version => ::std::result::Result::Err($crate::VersionError::UnknownVersion(version)),
+                }
+            }
+
+            /// The version byte of this variant.
+            pub fn version(&self) -> ::std::primitive::u8 {
+                match self {
+                    $($name::$variant(_) => $version,)+
+                }
+            }
+
+            $crate::byteview_versioned!(@common $name [$($variant)+] $($common)?);
+        }
+    };
+    (@common $name:ident $variants:tt) => {};
+    (@common $name:ident $variants:tt { $(fn $method:ident(&self) -> $return_type:ty;)* }) => {
+        $(
+            /// Call the method of the same name on the layout of this variant.
+            pub fn $method(&self) -> $return_type {
+                $crate::byteview_versioned!(@dispatch self $name $variants $method)
+            }
+        )*
+    };
+    (@dispatch $self:ident $name:ident [$($variant:ident)+] $method:ident) => {
+        match $self {
+            $($name::$variant(view) => view.$method(),)+
+        }
+    };
+}
@@ -0,0 +1,105 @@
+//! The trait implemented by every generated struct.
+
+use crate::{FieldInfo, Layout, Magic, ParseError};
+
+/// A struct generated by [`byteview_ref!`](crate::byteview_ref) or [`byteview_owned!`](crate::byteview_owned).
+///
+/// This allows writing code that is generic over generated structs. The lifetime `'a` is the lifetime of the bytes the
+/// struct can be created from. Structs generated by `byteview_ref!` borrow their bytes and implement `ByteView<'a>`
+/// for their own lifetime, while structs generated by `byteview_owned!` copy their bytes and implement `ByteView<'a>`
+/// for every lifetime.
+pub trait ByteView<'a>: Sized {
+    /// The total number of bytes contained in the struct.
+    const NUM_BYTES: usize;
+
+    /// The smallest number of bytes in a valid struct.
+    ///
+    /// This is the same as [`NUM_BYTES`](ByteView::NUM_BYTES) unless the struct has optional fields, in which case it
+    /// is the offset of the first optional field.
+    const MIN_NUM_BYTES: usize = Self::NUM_BYTES;
+
+    /// The largest number of bytes in a valid struct, including any bytes counted by its length field.
+    ///
+    /// This is the same as [`NUM_BYTES`](ByteView::NUM_BYTES) unless the struct has a
+    /// [`LENGTH_FIELD`](ByteView::LENGTH_FIELD), in which case it is the largest length the field can declare (plus the
+    /// struct itself if the length only counts the following bytes), saturating at [`usize::MAX`].
+    const MAX_NUM_BYTES: usize = Self::NUM_BYTES;
+
+    /// The [`Layout`] of the struct.
+    const LAYOUT: Layout;
+
+    /// The [`FieldInfo`] of every field of the struct, in declaration order (the same as the fields of its
+    /// [`LAYOUT`](ByteView::LAYOUT)).
+    ///
+    /// Padding fields named `_` are included, so the fields cover every byte of a struct without varints.
+    const FIELDS: &'static [FieldInfo] = Self::LAYOUT.fields;
+
+    /// The field holding the length of the struct or the bytes following it, if there is one.
+    ///
+    /// This is set for structs with a field annotated with `#[byteview(len_of_rest)]` or
+    /// `#[byteview(len_includes_header)]` and for structs annotated with `#[byteview(record_len = "...")]`.
+    const LENGTH_FIELD: Option<LengthField> = None;
+
+    /// The bytes that every valid struct contains at a fixed offset, if there are any.
+    ///
+    /// This is set for structs with a field annotated with `#[byteview(magic = b"...")]`. Checked constructors such as
+    /// [`split_slice_checked`](ByteView::split_slice_checked) return [`ParseError::MagicMismatch`] if the field does not
+    /// contain these bytes.
+    const MAGIC: Option<Magic> = None;
+
+    /// The bytes of the struct.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Create the struct from the first [`NUM_BYTES`](ByteView::NUM_BYTES) bytes of a slice and return it along with
+    /// the remaining bytes.
+    ///
+    /// If the slice does not contain at least [`NUM_BYTES`](ByteView::NUM_BYTES) bytes, return [`Option::None`].
+    fn split_slice(bytes: &'a [u8]) -> Option<(Self, &'a [u8])>;
+
+    /// Create the struct from the first [`NUM_BYTES`](ByteView::NUM_BYTES) bytes of a slice and return it along with
+    /// the remaining bytes.
+    ///
+    /// Unlike [`split_slice`](ByteView::split_slice), this returns a [`ParseError`] describing why the bytes are
+    /// invalid. Generated structs also check any length fields against the remaining bytes.
+    fn split_slice_checked(bytes: &'a [u8]) -> Result<(Self, &'a [u8]), ParseError> {
+        Self::split_slice(bytes).ok_or(ParseError::TooShort {
+            needed: Self::NUM_BYTES,
+            available: bytes.len(),
+        })
+    }
+
+    // Reorder the bytes of each number field in `bytes`, which hold this struct, into the byte order of the target
+    // platform, so that the generated `swap_to_native` function of a struct that contains this one can reorder its
+    // fields too. Structs without a byte order known at compile time leave the bytes unchanged.
+    #[doc(hidden)]
+    fn __swap_to_native(bytes: &mut [u8]) {
+        let _ = bytes;
+    }
+
+    // Write the default value of every optional field declared with `#[byteview(optional, default = ...)]` into
+    // `bytes`, which hold this struct, so that `migrate` can fill in new optional fields. Other fields are unchanged.
+    #[doc(hidden)]
+    fn __write_defaults(bytes: &mut [u8]) {
+        let _ = bytes;
+    }
+}
+
+/// A field holding the length of a struct or the bytes following it.
+///
+/// See [`ByteView::LENGTH_FIELD`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LengthField {
+    /// The length field.
+    pub field: FieldInfo,
+    /// Whether the length includes the bytes of the struct itself, or only the bytes following it.
+    pub includes_header: bool,
+}
+
+// The number of bytes in a struct nested in another struct. Fail to compile if the size of the struct can vary.
+pub const fn nested_num_bytes<'a, T: ByteView<'a>>() -> usize {
+    assert!(
+        T::MIN_NUM_BYTES == T::NUM_BYTES && T::MAX_NUM_BYTES == T::NUM_BYTES,
+        "nested structs must not have optional fields or length fields"
+    );
+    T::NUM_BYTES
+}
@@ -0,0 +1,27 @@
+//! Wiping the bytes of owned structs declared with `#[byteview(zeroize_on_drop)]` or zeroized with the `zeroize`
+//! feature.
+
+use std::ptr;
+use std::sync::atomic::{Ordering, compiler_fence};
+
+// Overwrite `bytes` with zeros. The writes are volatile and followed by a fence so that the compiler does not remove
+// them, even though the bytes are never read again when they are being dropped.
+pub fn wipe(bytes: &mut [u8]) {
+    for byte in bytes {
+        // SAFETY: `byte` is a valid, aligned, exclusive reference to a `u8`.
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+// Overwrite the bytes of `bytes` with zeros, along with its spare capacity, which may hold bytes that were split off
+// (e.g. by `split_vec`). The length is unchanged.
+pub fn wipe_vec(bytes: &mut Vec<u8>) {
+    wipe(bytes);
+    for byte in bytes.spare_capacity_mut() {
+        // SAFETY: `byte` is a valid, aligned, exclusive reference to a `MaybeUninit<u8>`, and writing to it does not
+        // require it to be initialized.
+        unsafe { ptr::write_volatile(byte.as_mut_ptr(), 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
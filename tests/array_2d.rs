@@ -0,0 +1,44 @@
+use byteview::ByteView;
+
+const ROW_LEN: usize = 4;
+
+byteview::byteview_ref! {
+    pub struct Bitmap {
+        pub width: u8,
+        pub rows: [[u8; ROW_LEN]; 3],
+        pub flags: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct BitmapOwned {
+        pub width: u8,
+        pub rows: [[u8; ROW_LEN]; 3],
+        pub flags: u8,
+        #[byteview(optional)]
+        pub mask: [[u8; 2]; 2],
+    }
+}
+
+#[test]
+fn test_array_2d() {
+    assert_eq!(1 + 12 + 1, Bitmap::NUM_BYTES);
+    assert_eq!(13, Bitmap::LAYOUT.field("flags").unwrap().offset);
+    assert_eq!("[[u8; ROW_LEN]; 3]", Bitmap::LAYOUT.fields[1].ty);
+
+    let bytes: [u8; 14] = *b"\x04abcdefghijkl\x01";
+    let bitmap = Bitmap::from_array(&bytes);
+    assert_eq!(4, bitmap.width());
+    assert_eq!(&[*b"abcd", *b"efgh", *b"ijkl"], bitmap.rows());
+    assert_eq!(b"efgh", &bitmap.rows()[1]);
+    assert_eq!(1, bitmap.flags());
+
+    let (bitmap, _) = BitmapOwned::split_slice(&bytes).unwrap();
+    assert_eq!(&[*b"abcd", *b"efgh", *b"ijkl"], bitmap.rows());
+    assert_eq!(None, bitmap.mask());
+
+    let mut bytes = bytes.to_vec();
+    bytes.extend_from_slice(&[1, 2, 3, 4]);
+    let (bitmap, _) = BitmapOwned::split_slice(&bytes).unwrap();
+    assert_eq!(Some(&[[1, 2], [3, 4]]), bitmap.mask());
+}
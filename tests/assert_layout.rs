@@ -0,0 +1,53 @@
+use byteview::{BigEndian, LittleEndian};
+
+byteview::byteview_ref! {
+    pub struct HeaderStart {
+        _file_name: [u8; 32],
+        pub earliest_date: u32be,
+        pub latest_date: u32be,
+        pub log_type: u8,
+        pub num_fields: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderStartOwned {
+        #[byteview(getter = "file_name")]
+        _file_name: [u8; 32],
+        pub log_type: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(endian = "generic")]
+    pub struct Generic {
+        pub kind: u8,
+        pub value: u32,
+    }
+}
+
+byteview::assert_size!(HeaderStart, 42);
+byteview::assert_offset!(HeaderStart, log_type, 40);
+byteview::assert_offset!(HeaderStart, num_fields, 41,);
+byteview::assert_size!(HeaderStartOwned, 33);
+byteview::assert_offset!(HeaderStartOwned, file_name, 0);
+byteview::assert_size!(Generic<BigEndian>, 5);
+byteview::assert_offset!(Generic<LittleEndian>, value, 1);
+
+const HEADER_LEN: usize = 42;
+byteview::assert_size!(HeaderStart, HEADER_LEN);
+
+#[test]
+fn test_in_function_body() {
+    byteview::assert_size!(HeaderStart, 40 + 2);
+    byteview::assert_offset!(HeaderStart, earliest_date, 32);
+    assert_eq!(42, HeaderStart::NUM_BYTES);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderStart;
+
+    byteview::assert_size!(HeaderStart, 42);
+    byteview::assert_offset!(HeaderStart, latest_date, 36);
+}
@@ -0,0 +1,101 @@
+use std::panic;
+
+byteview::byteview_ref! {
+    pub struct EntryRef {
+        pub magic: [u8; 4],
+        pub index: u32be,
+        pub delta: i16le,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct EntryOwned {
+        pub magic: [u8; 4],
+        pub index: u32be,
+        pub delta: i16le,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Other {
+        pub magic: [u8; 4],
+        pub index: u32le,
+        pub delta: i16le,
+        pub kind: u8,
+    }
+}
+
+const BYTES: [u8; 11] = *b"LOG1\x00\x00\x01\x00\xFE\xFF\x02";
+
+// Run a closure that should panic and return the panic message.
+fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+    let payload = panic::catch_unwind(f).expect_err("the assertion should have failed");
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast::<&str>()
+            .expect("the panic message should be a string")
+            .to_string(),
+    }
+}
+
+#[test]
+fn test_equal() {
+    byteview::assert_views_eq!(EntryRef::from_array(&BYTES), EntryRef::from_array(&BYTES));
+    byteview::assert_views_eq!(
+        EntryRef::from_array(&BYTES),
+        EntryOwned::from_array(BYTES),
+        "entry {}",
+        1
+    );
+}
+
+#[test]
+fn test_one_field() {
+    let mut actual = BYTES;
+    actual[7] = 0x01;
+    let message = panic_message(|| {
+        byteview::assert_views_eq!(EntryRef::from_array(&BYTES), EntryOwned::from_array(actual));
+    });
+    assert_eq!(
+        "assertion `expected == actual` failed: `EntryRef` views differ in 1 field\n  \
+        field  bytes  expected     actual       expected value  actual value\n  \
+        index  4..8   00 00 01 00  00 00 01 01  256             257",
+        message
+    );
+}
+
+#[test]
+fn test_several_fields() {
+    let actual = *b"LOG2\x00\x00\x01\x00\xFF\xFF\x03";
+    let message = panic_message(|| {
+        byteview::assert_views_eq!(
+            EntryOwned::from_array(BYTES),
+            EntryRef::from_array(&actual),
+            "entry {}",
+            3
+        );
+    });
+    assert_eq!(
+        "assertion `expected == actual` failed: entry 3\n\
+        `EntryOwned` views differ in 3 fields\n  \
+        field  bytes   expected     actual       expected value  actual value\n  \
+        magic  0..4    4c 4f 47 31  4c 4f 47 32  -               -\n  \
+        delta  8..10   fe ff        ff ff        -2              -1\n  \
+        kind   10..11  02           03           2               3",
+        message
+    );
+}
+
+#[test]
+fn test_different_layouts() {
+    let message = panic_message(|| {
+        byteview::assert_views_eq!(EntryRef::from_array(&BYTES), Other::from_array(&BYTES));
+    });
+    assert_eq!(
+        "assertion `expected == actual` failed: `EntryRef` and `Other` have different layouts",
+        message
+    );
+}
@@ -0,0 +1,67 @@
+use byteview::BitOrder;
+
+byteview::byteview_ref! {
+    pub struct AllocationTable {
+        pub num_blocks: u16be,
+        #[byteview(bitmap)]
+        pub blocks: [u8; 4],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct AllocationTableOwned {
+        pub num_blocks: u16be,
+        #[byteview(bitmap)]
+        pub blocks: [u8; 4],
+        #[byteview(bitmap, bit_order = "msb0")]
+        pub msb_blocks: [u8; 2],
+    }
+}
+
+#[test]
+fn test_bits() {
+    let bytes = [0, 32, 0b1000_0001, 0b0000_0001, 0, 0b1000_0000];
+    let table = AllocationTable::from_array(&bytes);
+    assert_eq!(Some(true), table.blocks_bit(0));
+    assert_eq!(Some(false), table.blocks_bit(1));
+    assert_eq!(Some(true), table.blocks_bit(7));
+    assert_eq!(Some(true), table.blocks_bit(8));
+    assert_eq!(Some(false), table.blocks_bit(9));
+    assert_eq!(Some(true), table.blocks_bit(31));
+    assert_eq!(None, table.blocks_bit(32));
+    assert_eq!(4, table.blocks_count_ones());
+    assert_eq!(
+        vec![0, 7, 8, 31],
+        table.blocks_iter_ones().collect::<Vec<_>>()
+    );
+    assert_eq!(BitOrder::Lsb0, table.blocks_bitmap().order());
+    assert_eq!(32, table.blocks_bitmap().len());
+}
+
+#[test]
+fn test_set_bit() {
+    let mut table = AllocationTableOwned::from_array([0; 8]);
+    assert_eq!(Some(false), table.set_blocks_bit(7, true));
+    assert_eq!(Some(false), table.set_blocks_bit(8, true));
+    assert_eq!(Some(false), table.set_blocks_bit(31, true));
+    assert_eq!(None, table.set_blocks_bit(32, true));
+    assert_eq!(&[0b1000_0000, 0b0000_0001, 0, 0b1000_0000], table.blocks());
+    assert_eq!(Some(true), table.set_blocks_bit(8, false));
+    assert_eq!(vec![7, 31], table.blocks_iter_ones().collect::<Vec<_>>());
+    assert_eq!(0, table.num_blocks());
+}
+
+#[test]
+fn test_msb0() {
+    let mut table = AllocationTableOwned::from_array([0; 8]);
+    assert_eq!(Some(false), table.set_msb_blocks_bit(0, true));
+    assert_eq!(Some(false), table.set_msb_blocks_bit(7, true));
+    assert_eq!(Some(false), table.set_msb_blocks_bit(8, true));
+    assert_eq!(Some(false), table.set_msb_blocks_bit(15, true));
+    assert_eq!(None, table.set_msb_blocks_bit(16, true));
+    assert_eq!(&[0b1000_0001, 0b1000_0001], table.msb_blocks());
+    assert_eq!(Some(true), table.msb_blocks_bit(15));
+    assert_eq!(None, table.msb_blocks_bit(16));
+    assert_eq!(4, table.msb_blocks_count_ones());
+    assert_eq!(&[0; 4], table.blocks());
+}
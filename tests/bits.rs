@@ -0,0 +1,130 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DataKind {
+    SignedInteger,
+    UnsignedInteger,
+    Float,
+    String,
+    Bool,
+}
+
+impl TryFrom<u8> for DataKind {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => DataKind::SignedInteger,
+            1 => DataKind::UnsignedInteger,
+            2 => DataKind::Float,
+            3 => DataKind::String,
+            4 => DataKind::Bool,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl From<DataKind> for u8 {
+    fn from(kind: DataKind) -> Self {
+        kind as u8
+    }
+}
+
+byteview::byteview_bits! {
+    #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+    pub struct DataInfo: u8 {
+        /// The kind of data this field contains.
+        pub kind: 4 as DataKind,
+        /// The length of the data.
+        pub length: 4,
+    }
+}
+
+byteview::byteview_bits! {
+    #[derive(Debug, Copy, Clone)]
+    pub struct Control: u16le {
+        pub version: 3,
+        _: 5,
+        pub sequence: 8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct FieldDefinition {
+        _name: [u8; 4],
+        pub data_info: u8 as DataInfo,
+        pub index: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Packet {
+        pub control: u16le as Control,
+    }
+}
+
+#[test]
+fn test_data_info() {
+    let expected = [
+        (0x00, Ok(DataKind::SignedInteger), 0),
+        (0x14, Ok(DataKind::UnsignedInteger), 4),
+        (0x28, Ok(DataKind::Float), 8),
+        (0x3F, Ok(DataKind::String), 15),
+        (0x41, Ok(DataKind::Bool), 1),
+        (0x52, Err(5), 2),
+        (0xF0, Err(15), 0),
+    ];
+    for (raw, kind, length) in expected {
+        let data_info = DataInfo::from_raw(raw);
+        assert_eq!(kind, data_info.kind());
+        assert_eq!(length, data_info.length());
+        assert_eq!(raw, data_info.raw());
+        assert_eq!(raw, u8::from(data_info));
+        assert_eq!(data_info, DataInfo::from(raw));
+    }
+    assert_eq!(1, DataInfo::NUM_BYTES);
+    assert_eq!((0xF0, 4), (DataInfo::KIND_MASK, DataInfo::KIND_SHIFT));
+    assert_eq!((0x0F, 0), (DataInfo::LENGTH_MASK, DataInfo::LENGTH_SHIFT));
+}
+
+#[test]
+fn test_setters() {
+    let mut data_info = DataInfo::from_raw(0);
+    data_info.set_kind(DataKind::String);
+    data_info.set_length(9);
+    assert_eq!(0x39, data_info.raw());
+    data_info.set_length(0x1F);
+    assert_eq!(0x3F, data_info.raw());
+    data_info.set_kind(6);
+    assert_eq!(Err(6), data_info.kind());
+    assert_eq!(15, data_info.length());
+}
+
+#[test]
+fn test_bytes() {
+    let control = Control::from_bytes([0x42, 0xA0]);
+    assert_eq!(0xA042, control.raw());
+    assert_eq!(0b101, control.version());
+    assert_eq!(0x42, control.sequence());
+    assert_eq!([0x42, 0xA0], control.to_bytes());
+    assert_eq!(2, Control::NUM_BYTES);
+    assert_eq!(0xE000, Control::VERSION_MASK);
+    assert_eq!(0x00FF, Control::SEQUENCE_MASK);
+}
+
+#[test]
+fn test_field() {
+    let bytes = *b"name\x24\x07";
+    let definition = FieldDefinition::from_array(&bytes);
+    assert_eq!(Ok(DataKind::Float), definition.data_info().kind());
+    assert_eq!(4, definition.data_info().length());
+    assert_eq!(0x24, definition.data_info_raw());
+    assert_eq!(7, definition.index());
+
+    let bytes = *b"name\x94\x07";
+    let definition = FieldDefinition::from_array(&bytes);
+    assert_eq!(Err(9), definition.data_info().kind());
+
+    let packet = Packet::from_array([0x01, 0x20]);
+    assert_eq!(1, packet.control().version());
+    assert_eq!(1, packet.control().sequence());
+    assert_eq!(0x2001, packet.control_raw());
+}
@@ -0,0 +1,107 @@
+use byteview::{BuildError, ByteView, LengthPrefixedBuilder};
+
+byteview::byteview_owned! {
+    pub struct Packet {
+        pub kind: u8,
+        #[byteview(len_of_rest)]
+        pub body_len: u16le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Chunk {
+        #[byteview(len_includes_header)]
+        pub total_len: u32be,
+        pub id: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(record_len = "record_len")]
+    pub struct Entry {
+        pub record_len: u8,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Plain {
+        pub kind: u8,
+    }
+}
+
+#[test]
+fn test_empty_body() {
+    let mut buffer = vec![0xEE];
+    let builder =
+        LengthPrefixedBuilder::new(&mut buffer, &Packet::from_array([1, 0xFF, 0xFF])).unwrap();
+    assert_eq!(3, builder.finish().unwrap());
+    assert_eq!(vec![0xEE, 1, 0, 0], buffer);
+
+    let mut buffer = Vec::new();
+    let builder =
+        LengthPrefixedBuilder::new(&mut buffer, &Chunk::from_array([0, 0, 0, 0, 9])).unwrap();
+    assert_eq!(5, builder.finish().unwrap());
+    assert_eq!(vec![0, 0, 0, 5, 9], buffer);
+}
+
+#[test]
+fn test_body() {
+    let mut buffer = Vec::new();
+    let mut builder =
+        LengthPrefixedBuilder::new(&mut buffer, &Packet::from_array([1, 0, 0])).unwrap();
+    builder.extend_from_slice(b"abc").push(b'd');
+    assert_eq!(7, builder.record_len());
+    builder.finish().unwrap();
+    let (packet, rest) = Packet::split_slice_checked(&buffer).unwrap();
+    assert_eq!(4, packet.body_len());
+    assert_eq!(b"abcd", rest);
+}
+
+#[test]
+fn test_nested() {
+    let mut buffer = Vec::new();
+    let mut outer =
+        LengthPrefixedBuilder::new(&mut buffer, &Chunk::from_array([0, 0, 0, 0, 1])).unwrap();
+    for kind in [b'x', b'y'] {
+        let mut entry = outer
+            .nested(&Entry::from_exact_vec(vec![2, kind]).unwrap())
+            .unwrap();
+        entry.extend_from_slice(&[kind; 3]);
+        assert_eq!(5, entry.finish().unwrap());
+    }
+    outer.append(&Plain::from_array([b'z']));
+    assert_eq!(16, outer.finish().unwrap());
+
+    let (chunk, rest) = Chunk::split_slice_checked(&buffer).unwrap();
+    assert_eq!(16, chunk.total_len());
+    let entries = byteview::Records::<Entry>::new(&rest[..10])
+        .map(|entry| {
+            let entry = entry.unwrap();
+            (entry.kind(), entry.body().to_vec())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![(b'x', b"xxx".to_vec()), (b'y', b"yyy".to_vec())],
+        entries
+    );
+    assert_eq!(b"z", &rest[10..]);
+}
+
+#[test]
+fn test_errors() {
+    let mut buffer = Vec::new();
+    assert_eq!(
+        Some(BuildError::NoLengthField),
+        LengthPrefixedBuilder::new(&mut buffer, &Plain::from_array([0])).err()
+    );
+
+    let mut builder =
+        LengthPrefixedBuilder::new(&mut buffer, &Entry::from_exact_vec(vec![2, 0]).unwrap())
+            .unwrap();
+    builder.extend_from_slice(&[0; 254]);
+    assert_eq!(
+        Err(BuildError::LengthOverflow { len: 256, size: 1 }),
+        builder.finish()
+    );
+}
@@ -0,0 +1,57 @@
+use byteview::ByteView;
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub version: u8,
+        #[cfg(all())]
+        pub flags: u16be,
+        #[cfg(any())]
+        pub revision: u16be,
+        pub length: u32be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Status {
+        pub kind: u8,
+        #[cfg(feature = "chrono")]
+        #[byteview(counter)]
+        pub count: u16be,
+        #[cfg(feature = "chrono")]
+        _: u8,
+        pub code: u8,
+    }
+}
+
+#[test]
+fn test_fields_follow_cfg() {
+    assert_eq!(7, Header::NUM_BYTES);
+    assert_eq!(3, Header::LAYOUT.fields.len());
+    assert_eq!("length", Header::LAYOUT.fields[2].name);
+    assert_eq!(3, Header::LAYOUT.fields[2].offset);
+    let header = Header::from_array(b"\x01\x00\x02\x00\x00\x00\x2A");
+    assert_eq!(1, header.version());
+    assert_eq!(2, header.flags());
+    assert_eq!(42, header.length());
+    let (_, rest) = <Header as ByteView>::split_slice(b"\x01\x00\x02\x00\x00\x00\x2A\xFF").unwrap();
+    assert_eq!(b"\xFF", rest);
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_feature_enabled() {
+    assert_eq!(5, Status::NUM_BYTES);
+    let mut status = Status::from_array([1, 0, 2, 0xAA, 3]);
+    assert_eq!(3, status.increment_count_wrapping());
+    assert_eq!(3, status.code());
+    assert_eq!(&[1, 0, 3, 0, 3], status.normalized().as_bytes());
+}
+
+#[test]
+#[cfg(not(feature = "chrono"))]
+fn test_feature_disabled() {
+    assert_eq!(2, Status::NUM_BYTES);
+    let status = Status::from_array([1, 3]);
+    assert_eq!(1, status.kind());
+    assert_eq!(3, status.code());
+}
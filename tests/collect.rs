@@ -0,0 +1,73 @@
+use byteview::{ExtendRecords, RecordBytes, Records};
+
+byteview::byteview_owned! {
+    pub struct ReadingOwned {
+        pub sensor: u8,
+        pub value: u16be,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(record_len = "record_len")]
+    pub struct Message {
+        pub kind: u8,
+        pub record_len: u8,
+    }
+}
+
+fn reading(sensor: u8, value: u16) -> ReadingOwned {
+    let [high, low] = value.to_be_bytes();
+    ReadingOwned::from_array([sensor, high, low])
+}
+
+#[test]
+fn test_collect() {
+    let readings = [(1, 0x0102), (2, 0x0304), (3, 0x0506)];
+    let collected = readings
+        .iter()
+        .map(|&(sensor, value)| reading(sensor, value))
+        .collect::<RecordBytes<ReadingOwned>>();
+    assert_eq!(3, collected.len());
+    assert!(!collected.is_empty());
+
+    let mut manual = Vec::new();
+    for &(sensor, value) in &readings {
+        manual.extend_from_slice(reading(sensor, value).as_bytes());
+    }
+    assert_eq!(manual, collected.as_bytes());
+    assert_eq!(manual, collected.into_vec());
+
+    let empty: RecordBytes<ReadingOwned> = std::iter::empty().collect();
+    assert!(empty.is_empty());
+    assert!(empty.as_bytes().is_empty());
+}
+
+#[test]
+fn test_extend() {
+    let mut collected = RecordBytes::new();
+    collected.extend([reading(1, 2)]);
+    collected.extend(vec![reading(3, 4), reading(5, 6)]);
+    assert_eq!(3, collected.len());
+    assert_eq!(vec![1, 0, 2, 3, 0, 4, 5, 0, 6], Vec::<u8>::from(collected));
+}
+
+#[test]
+fn test_extend_records() {
+    let mut buf = b"HDR".to_vec();
+    buf.extend_records((1..=3).map(|i| reading(i, 0x1000 + u16::from(i))));
+    assert_eq!(b"HDR\x01\x10\x01\x02\x10\x02\x03\x10\x03".to_vec(), buf);
+}
+
+#[test]
+fn test_variable_size() {
+    let source = [1, 3, 0xAA, 2, 2, 3, 4, 0xBB, 0xCC];
+    let mut buf = Vec::new();
+    buf.extend_records(Records::<Message>::new(&source).map(Result::unwrap));
+    assert_eq!(source.to_vec(), buf);
+
+    let collected: RecordBytes<Message> = Records::<Message>::new(&source)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(3, collected.len());
+    assert_eq!(&source, collected.as_bytes());
+}
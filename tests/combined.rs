@@ -0,0 +1,70 @@
+use byteview::{BigEndian, ByteOrder, ByteView, LittleEndian};
+
+byteview::byteview! {
+    /// The header for an entry.
+    #[derive(Debug)]
+    pub struct EntryHeader {
+        /// The index of the entry.
+        pub index: u32be,
+        #[byteview(getter = "raw_name")]
+        _name: [u8; 4],
+    }
+}
+
+byteview::byteview! {
+    #[byteview(ref_name = "RecordView", owned_name = "Record", record_len = "size")]
+    pub struct Record {
+        pub size: u8,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview! {
+    #[byteview(endian = "generic")]
+    pub struct Generic {
+        pub value: u16,
+    }
+}
+
+byteview::byteview! {
+    #[byteview(endian = "runtime")]
+    pub struct Runtime {
+        pub value: u16,
+    }
+}
+
+#[test]
+fn test_ref_and_owned() {
+    let bytes = b"\x00\x00\x07\x01NAME";
+    let entry_header = EntryHeaderRef::from_array(bytes);
+    assert_eq!(1793, entry_header.index());
+    let owned = entry_header.to_owned();
+    assert_eq!(b"NAME", owned.raw_name());
+    assert_eq!(bytes, owned.as_ref().as_bytes());
+    assert_eq!(
+        EntryHeaderRef::LAYOUT.fields,
+        EntryHeaderOwned::LAYOUT.fields
+    );
+}
+
+#[test]
+fn test_custom_names_and_variable_size() {
+    let (record, rest) = <RecordView as ByteView>::split_slice(b"\x03\x2A\xFF\x00").unwrap();
+    assert_eq!(b"\x00", rest);
+    let mut owned: Record = record.to_owned();
+    assert_eq!(&[0xFF], owned.body());
+    owned.normalize();
+    assert_eq!(42, owned.as_ref().kind());
+}
+
+#[test]
+fn test_byte_order_is_kept() {
+    let generic = GenericRef::<LittleEndian>::from_array(b"\x01\x02");
+    assert_eq!(0x0201, generic.to_owned().value());
+    let owned = GenericOwned::<BigEndian>::from_array(*b"\x01\x02");
+    assert_eq!(0x0102, owned.as_ref().value());
+
+    let runtime = RuntimeRef::from_array_with(b"\x01\x02", ByteOrder::Little);
+    assert_eq!(0x0201, runtime.to_owned().value());
+    assert_eq!(ByteOrder::Little, runtime.to_owned().as_ref().byte_order());
+}
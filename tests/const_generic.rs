@@ -0,0 +1,80 @@
+use byteview::{ByteView, ParseError};
+
+byteview::byteview_ref! {
+    pub struct Batch<const N: usize> {
+        pub header: u16be,
+        pub slots: [u8; N],
+        pub trailer: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(endian = "little")]
+    pub struct Samples<const N: usize, const M: usize> {
+        #[byteview(counter)]
+        pub count: u16,
+        pub values: [i16; N],
+        pub flags: [u8; M],
+    }
+}
+
+byteview::byteview! {
+    #[byteview(endian = "generic")]
+    pub struct Table<const N: usize> {
+        pub entries: [u32; N],
+    }
+}
+
+#[test]
+fn test_sizes_depend_on_parameters() {
+    assert_eq!(7, Batch::<4>::NUM_BYTES);
+    assert_eq!(19, Batch::<16>::NUM_BYTES);
+    assert_eq!(7, <Batch<4> as ByteView>::MAX_NUM_BYTES);
+    assert_eq!(4, Batch::<4>::LAYOUT.fields[1].size);
+    assert_eq!(6, Batch::<4>::LAYOUT.fields[2].offset);
+}
+
+#[test]
+fn test_borrowed() {
+    let bytes = b"\x00\x07\x01\x02\x03\x04\x2A\xFF";
+    let batch = Batch::<4>::from_array(b"\x00\x07\x01\x02\x03\x04\x2A");
+    assert_eq!(7, batch.header());
+    assert_eq!(&[1, 2, 3, 4], batch.slots());
+    assert_eq!(42, batch.trailer());
+
+    let (batch, rest) = Batch::<4>::split_slice(bytes).unwrap();
+    assert_eq!(42, batch.trailer());
+    assert_eq!(b"\xFF", rest);
+    let (batch, rest) = Batch::<2>::split_slice_checked(bytes).unwrap();
+    assert_eq!(&[1, 2], batch.slots());
+    assert_eq!(3, batch.trailer());
+    assert_eq!(b"\x04\x2A\xFF", rest);
+    assert!(Batch::<8>::from_exact_slice(bytes).is_none());
+    assert_eq!(
+        Err(ParseError::TooShort {
+            needed: 11,
+            available: 8
+        }),
+        Batch::<8>::split_slice_checked(bytes).map(|_| ())
+    );
+}
+
+#[test]
+fn test_owned() {
+    let mut samples = Samples::<2, 1>::from_array([1, 0, 0xFF, 0xFF, 2, 0, 0x80]);
+    assert_eq!([-1, 2], samples.values());
+    assert_eq!(&[0x80], samples.flags());
+    assert_eq!(2, samples.increment_count_wrapping());
+    let (samples, rest) = Samples::<1, 0>::split_vec(vec![1, 0, 2, 0, 3]).unwrap();
+    assert_eq!([2], samples.values());
+    assert_eq!(vec![3], rest);
+}
+
+#[test]
+fn test_generic_byte_order() {
+    let table =
+        TableRef::<byteview::LittleEndian, 2>::from_array(b"\x01\x00\x00\x00\x02\x00\x00\x00");
+    assert_eq!([1, 2], table.entries());
+    let owned = table.to_owned();
+    assert_eq!([1, 2], owned.as_ref().entries());
+}
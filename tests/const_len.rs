@@ -0,0 +1,71 @@
+const NAME_LEN: usize = 32;
+const NUM_SLOTS: usize = 2;
+
+mod sizes {
+    pub const TAG_LEN: usize = 4;
+}
+
+byteview::byteview_ref! {
+    pub struct Named {
+        pub name: [u8; NAME_LEN],
+        pub tag: [u8; sizes::TAG_LEN],
+        pub slots: [u16be; NUM_SLOTS * 2],
+        pub padded: [u8; NAME_LEN / 8 + 1],
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct NamedOwned {
+        pub name: [u8; NAME_LEN],
+        pub tag: [u8; sizes::TAG_LEN],
+        pub slots: [u16be; NUM_SLOTS * 2],
+        pub padded: [u8; NAME_LEN / 8 + 1],
+        pub kind: u8,
+    }
+}
+
+#[test]
+fn test_const_len() {
+    assert_eq!(32 + 4 + 8 + 5 + 1, Named::NUM_BYTES);
+    assert_eq!(49, Named::LAYOUT.field("kind").unwrap().offset);
+    assert_eq!("[u8; NAME_LEN]", Named::LAYOUT.fields[0].ty);
+
+    let mut bytes = [0; 50];
+    bytes[..5].copy_from_slice(b"hello");
+    bytes[32..36].copy_from_slice(b"TAG1");
+    bytes[36..44].copy_from_slice(&[0, 1, 0, 2, 0, 3, 0, 4]);
+    bytes[49] = 9;
+
+    let named = Named::from_array(&bytes);
+    assert_eq!(b"hello", &named.name()[..5]);
+    assert_eq!(b"TAG1", named.tag());
+    assert_eq!([1, 2, 3, 4], named.slots());
+    assert_eq!(&[0; 5], named.padded());
+    assert_eq!(9, named.kind());
+
+    let named = NamedOwned::from_array(bytes);
+    assert_eq!(NAME_LEN, named.name().len());
+    assert_eq!([1, 2, 3, 4], named.slots());
+    assert_eq!(9, named.kind());
+}
+
+byteview::byteview_ref! {
+    pub struct Slots {
+        pub slots: [u16be; NUM_SLOTS + 1],
+        pub flags: [u8; NUM_SLOTS + 1],
+        pub kind: u8,
+    }
+}
+
+#[test]
+fn test_compound_len() {
+    assert_eq!(6 + 3 + 1, Slots::NUM_BYTES);
+    assert_eq!(9, Slots::LAYOUT.field("kind").unwrap().offset);
+
+    let bytes = [0, 1, 0, 2, 0, 3, 4, 5, 6, 7];
+    let slots = Slots::from_array(&bytes);
+    assert_eq!([1, 2, 3], slots.slots());
+    assert_eq!(&[4, 5, 6], slots.flags());
+    assert_eq!(7, slots.kind());
+}
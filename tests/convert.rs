@@ -0,0 +1,539 @@
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChannelMode {
+    Off,
+    Mono,
+    Stereo,
+}
+
+impl TryFrom<u8> for ChannelMode {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ChannelMode::Off),
+            1 => Ok(ChannelMode::Mono),
+            2 => Ok(ChannelMode::Stereo),
+            _ => Err(()),
+        }
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct ChannelMap {
+        pub version: u8,
+        pub modes: [u8; 16] as [ChannelMode; 16],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ChannelMapOwned {
+        pub version: u8,
+        pub modes: [u8; 16] as [ChannelMode; 16],
+    }
+}
+
+fn channel_map_bytes() -> [u8; 17] {
+    let mut bytes = [0; 17];
+    bytes[0] = 3;
+    for (i, byte) in bytes[1..].iter_mut().enumerate() {
+        *byte = (i % 3) as u8;
+    }
+    bytes
+}
+
+#[test]
+fn test_valid_array() {
+    use ChannelMode::*;
+    let bytes = channel_map_bytes();
+    let map = ChannelMap::from_array(&bytes);
+    assert_eq!(3, map.version());
+    assert_eq!(
+        Ok([
+            Off, Mono, Stereo, Off, Mono, Stereo, Off, Mono, Stereo, Off, Mono, Stereo, Off, Mono,
+            Stereo, Off
+        ]),
+        map.modes()
+    );
+    assert_eq!(Some(Ok(Stereo)), map.modes_at(2));
+    assert_eq!(&bytes[1..], map.modes_raw());
+    assert_eq!(
+        map.modes().unwrap().map(|mode| mode as u8),
+        *map.modes_raw()
+    );
+    assert_eq!(Some(Ok(Off)), map.modes_at(15));
+    assert_eq!(None, map.modes_at(16));
+
+    let owned = ChannelMapOwned::from_array(bytes);
+    assert_eq!(map.modes(), owned.modes());
+}
+
+#[test]
+fn test_invalid_element() {
+    let mut bytes = channel_map_bytes();
+    bytes[1 + 7] = 0x42;
+    bytes[1 + 9] = 0x43;
+    let map = ChannelMap::from_array(&bytes);
+    assert_eq!(Err((7, 0x42)), map.modes());
+    assert_eq!(0x42, map.modes_raw()[7]);
+    assert_eq!(&bytes[1..], map.modes_raw());
+    assert_eq!(Some(Err(0x42)), map.modes_at(7));
+    assert_eq!(Some(Ok(ChannelMode::Stereo)), map.modes_at(8));
+}
+
+byteview::byteview_ref! {
+    pub struct Settings {
+        pub enabled: bool,
+        pub verbose: bool,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SettingsOwned {
+        pub enabled: bool,
+        pub verbose: bool,
+    }
+}
+
+#[test]
+fn test_bool() {
+    assert_eq!(2, Settings::NUM_BYTES);
+    let settings = Settings::from_array(&[1, 0]);
+    assert_eq!(Ok(true), settings.enabled());
+    assert_eq!(Ok(false), settings.verbose());
+
+    let settings = SettingsOwned::from_array([0x2A, 1]);
+    assert_eq!(Err(0x2A), settings.enabled());
+    assert_eq!(0x2A, settings.enabled_raw());
+    assert_eq!(Ok(true), settings.verbose());
+}
+
+byteview::byteview_ref! {
+    pub struct Glyph {
+        pub code_point: charbe,
+        pub fallback: charle,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct GlyphOwned {
+        pub code_point: charbe,
+        pub fallback: charle,
+    }
+}
+
+#[test]
+fn test_char() {
+    assert_eq!(8, Glyph::NUM_BYTES);
+    let glyph = Glyph::from_array(b"\x00\x01\xF6\x00\x41\x00\x00\x00");
+    assert_eq!(Some('😀'), glyph.code_point());
+    assert_eq!(Some('A'), glyph.fallback());
+
+    let glyph = GlyphOwned::from_array(*b"\x00\x00\xD8\x00\x00\x00\x11\x00");
+    assert_eq!(None, glyph.code_point());
+    assert_eq!(0xD800, glyph.code_point_raw());
+    assert_eq!(None, glyph.fallback());
+    assert_eq!(0x11_0000, glyph.fallback_raw());
+}
+
+byteview::byteview_ref! {
+    pub struct Record {
+        pub id: nonzero_u32be,
+        pub parent: nonzero_u16le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct RecordOwned {
+        pub id: nonzero_u32be,
+        pub parent: nonzero_u16le,
+    }
+}
+
+#[test]
+fn test_nonzero() {
+    use std::num::NonZero;
+
+    assert_eq!(6, Record::NUM_BYTES);
+    let record = Record::from_array(b"\x00\x00\x01\x00\x07\x00");
+    assert_eq!(NonZero::new(256), record.id());
+    assert_eq!(NonZero::new(7), record.parent());
+
+    let record = RecordOwned::from_array([0; 6]);
+    assert_eq!(None, record.id());
+    assert_eq!(0, record.id_raw());
+    assert_eq!(None, record.parent());
+}
+
+const NAME_LEN: usize = 8;
+
+byteview::byteview_ref! {
+    pub struct Dataset {
+        pub name: ascii<NAME_LEN>,
+        pub owner: ascii<4>,
+        pub label: [u8; 4],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct DatasetOwned {
+        pub name: ascii<NAME_LEN>,
+        pub owner: ascii<4>,
+        pub label: [u8; 4],
+    }
+}
+
+#[test]
+fn test_ascii() {
+    assert_eq!(16, Dataset::NUM_BYTES);
+    let dataset = Dataset::from_array(b"PAYROLL OPS\0AB\0\0");
+    assert_eq!(Some("PAYROLL"), dataset.name());
+    assert_eq!(b"PAYROLL ", dataset.name_raw());
+    assert_eq!(Some("OPS"), dataset.owner());
+    assert_eq!(b"AB\0\0", dataset.label());
+
+    let dataset = DatasetOwned::from_array(*b"        A\0B \xC3\xA9  ");
+    assert_eq!(Some(""), dataset.name());
+    assert_eq!(None, dataset.owner());
+    assert_eq!(b"A\0B ", dataset.owner_raw());
+    assert_eq!(b"\xC3\xA9  ", dataset.label());
+}
+
+byteview::byteview_ref! {
+    pub struct Machine {
+        pub name: utf16be<4>,
+        pub domain: utf16le<2>,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct MachineOwned {
+        pub name: utf16be<4>,
+        pub domain: utf16le<2>,
+        pub kind: u8,
+    }
+}
+
+#[test]
+fn test_utf16() {
+    assert_eq!(8 + 4 + 1, Machine::NUM_BYTES);
+    assert_eq!(12, Machine::LAYOUT.field("kind").unwrap().offset);
+
+    let machine = Machine::from_array(b"\x00P\x00C\x00\x00\x00Z\xE9\x00\x00\x00\x07");
+    assert_eq!(Some("PC".to_owned()), machine.name());
+    assert_eq!([0x50, 0x43, 0, 0x5A], machine.name_raw());
+    assert_eq!(Some("é".to_owned()), machine.domain());
+    assert_eq!(7, machine.kind());
+
+    let machine = MachineOwned::from_array(*b"\xD8\x3D\xDE\x00\x00A\x00B\x3D\xD8\x00\x00\x00");
+    assert_eq!(Some("😀AB".to_owned()), machine.name());
+    assert_eq!(None, machine.domain());
+    assert_eq!("\u{FFFD}", machine.domain_lossy());
+    assert_eq!("😀AB", machine.name_lossy());
+}
+
+byteview::byteview_ref! {
+    pub struct Device {
+        pub id: uuid,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct DeviceOwned {
+        pub id: uuid,
+        pub kind: u8,
+    }
+}
+
+#[test]
+fn test_uuid() {
+    assert_eq!(17, Device::NUM_BYTES);
+    assert_eq!("[u8; 16]", Device::LAYOUT.fields[0].ty);
+    let bytes = *b"\x67\xE5\x50\x44\x10\xB1\x42\x6F\x92\x47\xBB\x68\x0E\x5F\xE0\xC8\x02";
+    let device = Device::from_array(&bytes);
+    assert_eq!(
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        device.id().to_string()
+    );
+    assert_eq!(
+        "Some(67e55044-10b1-426f-9247-bb680e5fe0c8)",
+        format!("{:?}", Some(device.id()))
+    );
+    assert_eq!(device.id(), *device.id_raw());
+    assert!(!device.id().is_nil());
+    assert_eq!(2, device.kind());
+
+    let device = DeviceOwned::from_array([0; 17]);
+    assert_eq!(byteview::Uuid::NIL, device.id());
+    assert!(device.id().is_nil());
+    assert_eq!(&[0; 16], device.id_raw());
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_crate() {
+    let bytes = *b"\x67\xE5\x50\x44\x10\xB1\x42\x6F\x92\x47\xBB\x68\x0E\x5F\xE0\xC8\x02";
+    let device = Device::from_array(&bytes);
+    let id = uuid::Uuid::from(device.id());
+    assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", id.to_string());
+    assert_eq!(4, id.get_version_num());
+    assert_eq!(device.id(), byteview::Uuid::from(id));
+    assert_eq!(byteview::Uuid::NIL, byteview::Uuid::from(uuid::Uuid::nil()));
+}
+
+byteview::byteview_ref! {
+    pub struct Connection {
+        pub source: ipv4,
+        pub destination: ipv6,
+        pub port: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ConnectionOwned {
+        pub source: ipv4,
+        pub destination: ipv6,
+        pub port: u16be,
+    }
+}
+
+#[test]
+fn test_ip_addr() {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    assert_eq!(4 + 16 + 2, Connection::NUM_BYTES);
+    let bytes = *b"\xC0\xA8\x01\x01\x20\x01\x0D\xB8\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x01\xBB";
+    let connection = Connection::from_array(&bytes);
+    assert_eq!(Ipv4Addr::new(192, 168, 1, 1), connection.source());
+    assert_eq!("192.168.1.1", connection.source().to_string());
+    assert_eq!(b"\xC0\xA8\x01\x01", connection.source_raw());
+    assert_eq!("2001:db8::1", format!("{:?}", connection.destination()));
+    assert_eq!(443, connection.port());
+
+    let connection = ConnectionOwned::from_array(bytes);
+    assert_eq!(Ipv4Addr::new(192, 168, 1, 1), connection.source());
+    assert_eq!(
+        Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1),
+        connection.destination()
+    );
+}
+
+byteview::byteview_ref! {
+    pub struct EthernetHeader {
+        pub destination: mac,
+        pub source: mac,
+        pub ether_type: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct EthernetHeaderOwned {
+        pub destination: mac,
+        pub source: mac,
+        pub ether_type: u16be,
+    }
+}
+
+#[test]
+fn test_mac() {
+    assert_eq!(14, EthernetHeader::NUM_BYTES);
+    let bytes = *b"\x01\x00\x5E\x00\x00\xFB\x02\x1A\x2B\x3C\x4D\x5E\x08\x00";
+    let header = EthernetHeader::from_array(&bytes);
+    assert_eq!("01:00:5e:00:00:fb", header.destination().to_string());
+    assert!(header.destination().is_multicast());
+    assert!(!header.destination().is_broadcast());
+    assert_eq!(header.source(), [0x02, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+    assert!(header.source().is_unicast());
+    assert!(header.source().is_local());
+    assert_eq!(b"\x02\x1A\x2B\x3C\x4D\x5E", header.source_raw());
+    assert_eq!(0x0800, header.ether_type());
+
+    let header = EthernetHeaderOwned::from_array([0xFF; 14]);
+    assert_eq!(byteview::MacAddr::BROADCAST, header.destination());
+    assert!(header.destination().is_broadcast());
+    assert_eq!("ff:ff:ff:ff:ff:ff", format!("{:?}", header.source()));
+}
+
+byteview::byteview_ref! {
+    pub struct Sample {
+        pub captured: timestamp_ms64be,
+        pub value: u16le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SampleOwned {
+        pub captured: timestamp_ms64be,
+        pub value: u16le,
+    }
+}
+
+#[test]
+fn test_timestamp_ms() {
+    use std::time::{Duration, SystemTime};
+
+    assert_eq!(10, Sample::NUM_BYTES);
+    let sample = Sample::from_array(b"\x00\x00\x01\x8B\xCF\xE5\x68\x01\x2A\x00");
+    let expected = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_001);
+    assert_eq!(Some(expected), sample.captured());
+    assert_eq!(1_700_000_000_001, sample.captured_raw());
+    assert_eq!(42, sample.value());
+
+    let sample = SampleOwned::from_array([0; 10]);
+    assert_eq!(None, sample.captured());
+
+    let sample = SampleOwned::from_array([0xFF; 10]);
+    let expected = SystemTime::UNIX_EPOCH.checked_add(Duration::from_millis(u64::MAX));
+    assert_eq!(expected, sample.captured());
+    assert_eq!(u64::MAX, sample.captured_raw());
+}
+
+byteview::byteview_ref! {
+    pub struct Calibration {
+        pub gain: q16_16be,
+        pub offset: q8_8be,
+        pub trim: q1_7,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct CalibrationOwned {
+        pub gain: q16_16be,
+        pub offset: q8_8le,
+        pub trim: q1_7,
+    }
+}
+
+#[test]
+fn test_fixed_point() {
+    assert_eq!(4 + 2 + 1, Calibration::NUM_BYTES);
+    let calibration = Calibration::from_array(&[0x00, 0x01, 0x80, 0x00, 0xFF, 0x40, 0x60]);
+    assert_eq!(1.5, calibration.gain());
+    assert_eq!(0x0001_8000, calibration.gain_raw());
+    assert_eq!(-0.75, calibration.offset());
+    assert_eq!(-0xC0, calibration.offset_raw());
+    assert_eq!(0.75, calibration.trim());
+
+    let calibration = CalibrationOwned::from_array([0x80, 0x00, 0x00, 0x00, 0x01, 0x00, 0x80]);
+    assert_eq!(-32768.0, calibration.gain());
+    assert_eq!(i32::MIN, calibration.gain_raw());
+    assert_eq!(1.0 / 256.0, calibration.offset());
+    assert_eq!(-1.0, calibration.trim());
+
+    let calibration = CalibrationOwned::from_array([0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x7F]);
+    assert_eq!(32768.0 - 1.0 / 65536.0, calibration.gain());
+    assert_eq!(128.0 - 1.0 / 256.0, calibration.offset());
+    assert_eq!(1.0 - 1.0 / 128.0, calibration.trim());
+}
+
+byteview::byteview_ref! {
+    pub struct Counters {
+        pub events: bcd<4>,
+        pub errors: bcd<2>,
+        pub total: bcd<9>,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct CountersOwned {
+        pub events: bcd<4>,
+        pub errors: bcd<2>,
+        pub total: bcd<9>,
+    }
+}
+
+#[test]
+fn test_bcd() {
+    use byteview::BcdError;
+
+    assert_eq!(4 + 2 + 9, Counters::NUM_BYTES);
+    let mut bytes = [0; 15];
+    bytes[..4].copy_from_slice(&[0x00, 0x01, 0x23, 0x45]);
+    bytes[4..6].copy_from_slice(&[0x09, 0x90]);
+    let counters = Counters::from_array(&bytes);
+    assert_eq!(Ok(12345), counters.events());
+    assert_eq!(&[0x00, 0x01, 0x23, 0x45], counters.events_raw());
+    assert_eq!(Ok(990), counters.errors());
+    assert_eq!(Ok(0), counters.total());
+
+    let counters = CountersOwned::from_array([0x99; 15]);
+    assert_eq!(Ok(99_999_999), counters.events());
+    assert_eq!(Ok(9_999), counters.errors());
+    assert_eq!(Ok(999_999_999_999_999_999), counters.total());
+
+    bytes[2] = 0x2A;
+    bytes[4] = 0xF0;
+    let counters = CountersOwned::from_array(bytes);
+    let error = BcdError {
+        index: 2,
+        byte: 0x2A,
+    };
+    assert_eq!(Err(error), counters.events());
+    assert_eq!(
+        "byte 2 of a BCD number is 0x2a, which is not two decimal digits",
+        error.to_string()
+    );
+    assert_eq!(
+        Err(BcdError {
+            index: 0,
+            byte: 0xF0
+        }),
+        counters.errors()
+    );
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Signature([u8; 8]);
+
+impl byteview::FromFieldValue<&[u8; 8]> for Signature {
+    type Output = Signature;
+
+    fn from_field_value(value: &[u8; 8]) -> Self::Output {
+        Signature(*value)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Tag([u8; 4]);
+
+impl byteview::FromFieldValue<&[u8; 4]> for Tag {
+    type Output = Result<Tag, [u8; 4]>;
+
+    fn from_field_value(value: &[u8; 4]) -> Self::Output {
+        if value.iter().all(u8::is_ascii_alphanumeric) {
+            Ok(Tag(*value))
+        } else {
+            Err(*value)
+        }
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Chunk {
+        pub tag: [u8; 4] as Tag,
+        pub signature: [u8; 8] as Signature,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ChunkOwned {
+        pub signature: [u8; 8] as Signature,
+    }
+}
+
+#[test]
+fn test_byte_array_newtypes() {
+    let bytes = *b"RIFF\x89PNG\r\n\x1A\n";
+    let chunk = Chunk::from_array(&bytes);
+    assert_eq!(Ok(Tag(*b"RIFF")), chunk.tag());
+    assert_eq!(Signature(*b"\x89PNG\r\n\x1A\n"), chunk.signature());
+    assert_eq!(b"\x89PNG\r\n\x1A\n", chunk.signature_raw());
+
+    let chunk = Chunk::from_array(b"RI F\0\0\0\0\0\0\0\0");
+    assert_eq!(Err(*b"RI F"), chunk.tag());
+    assert_eq!(Signature([0; 8]), chunk.signature());
+
+    let chunk = ChunkOwned::from_array(*b"\x89PNG\r\n\x1A\n");
+    assert_eq!(Signature(*b"\x89PNG\r\n\x1A\n"), chunk.signature());
+}
@@ -0,0 +1,84 @@
+byteview::byteview_ref! {
+    pub struct Heartbeat {
+        pub id: u8,
+        #[byteview(counter)]
+        pub sequence: u16be,
+        #[byteview(counter)]
+        pub uptime: u32le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeartbeatOwned {
+        pub id: u8,
+        #[byteview(counter)]
+        pub sequence: u16be,
+        #[byteview(counter)]
+        pub uptime: u32le,
+    }
+}
+
+#[test]
+fn test_increment() {
+    let mut heartbeat = HeartbeatOwned::from_array([7, 0x01, 0xFF, 0, 0, 0, 0]);
+    assert_eq!(0x0200, heartbeat.increment_sequence_wrapping());
+    assert_eq!(0x0201, heartbeat.increment_sequence_saturating());
+    assert_eq!(1, heartbeat.increment_uptime_wrapping());
+    assert_eq!(&[7, 0x02, 0x01, 1, 0, 0, 0], heartbeat.as_bytes());
+}
+
+#[test]
+fn test_wrapping() {
+    let mut heartbeat = HeartbeatOwned::from_array([7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(0, heartbeat.increment_sequence_wrapping());
+    assert_eq!(0, heartbeat.sequence());
+    assert_eq!(2, heartbeat.add_uptime_wrapping(3));
+    assert_eq!(&[7, 0, 0, 2, 0, 0, 0], heartbeat.as_bytes());
+}
+
+#[test]
+fn test_saturating() {
+    let mut heartbeat = HeartbeatOwned::from_array([7, 0xFF, 0xFE, 0xFE, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(0xFFFF, heartbeat.increment_sequence_saturating());
+    assert_eq!(0xFFFF, heartbeat.increment_sequence_saturating());
+    assert_eq!(0xFFFF, heartbeat.sequence());
+    assert_eq!(u32::MAX, heartbeat.add_uptime_saturating(10));
+    assert_eq!(
+        &[7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+        heartbeat.as_bytes()
+    );
+}
+
+#[test]
+fn test_in_buffer() {
+    let mut bytes = [7, 0xFF, 0xFF, 0, 0, 0, 0, 0xAA];
+    assert_eq!(Some(0), Heartbeat::add_sequence_wrapping_in(&mut bytes, 1));
+    assert_eq!(Some(5), Heartbeat::add_uptime_saturating_in(&mut bytes, 5));
+    assert_eq!(
+        Some(0xFFFF),
+        Heartbeat::add_sequence_saturating_in(&mut bytes, 0xFFFF)
+    );
+    assert_eq!(
+        Some(0xFFFF),
+        Heartbeat::add_sequence_saturating_in(&mut bytes, 1)
+    );
+    assert_eq!([7, 0xFF, 0xFF, 5, 0, 0, 0, 0xAA], bytes);
+    assert_eq!(
+        0xFFFF,
+        Heartbeat::from_exact_slice(&bytes[..7]).unwrap().sequence()
+    );
+
+    assert_eq!(
+        Some(0),
+        HeartbeatOwned::add_sequence_wrapping_in(&mut bytes, 1)
+    );
+    assert_eq!([7, 0, 0, 5, 0, 0, 0, 0xAA], bytes);
+}
+
+#[test]
+fn test_in_short_buffer() {
+    let mut bytes = [7, 0xFF, 0xFF, 0, 0, 0];
+    assert_eq!(None, Heartbeat::add_uptime_wrapping_in(&mut bytes, 1));
+    assert_eq!(Some(0), Heartbeat::add_sequence_wrapping_in(&mut bytes, 1));
+    assert_eq!([7, 0, 0, 0, 0, 0], bytes);
+}
@@ -0,0 +1,74 @@
+use byteview::{dedup_records, dedup_records_by};
+
+byteview::byteview_ref! {
+    pub struct Reading {
+        pub sensor: u8,
+        pub value: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ReadingOwned {
+        pub sensor: u8,
+        pub value: u16be,
+        #[byteview(reserved)]
+        pub flags: u8,
+    }
+}
+
+#[test]
+fn test_runs() {
+    let mut buf = vec![
+        1, 0, 1, // first
+        1, 0, 1, // duplicate
+        2, 0, 2, // second
+        2, 0, 2, // duplicate
+        2, 0, 2, // duplicate
+        1, 0, 1, // not adjacent to the first, so kept
+    ];
+    assert_eq!(3, dedup_records::<Reading>(&mut buf));
+    assert_eq!(vec![1, 0, 1, 2, 0, 2, 1, 0, 1], buf);
+}
+
+#[test]
+fn test_no_duplicates() {
+    let original = vec![1, 0, 1, 2, 0, 2, 1, 0, 2];
+    let mut buf = original.clone();
+    assert_eq!(0, dedup_records::<Reading>(&mut buf));
+    assert_eq!(original, buf);
+
+    let mut buf = vec![1, 0, 1];
+    assert_eq!(0, dedup_records::<Reading>(&mut buf));
+    assert_eq!(vec![1, 0, 1], buf);
+
+    let mut buf = Vec::new();
+    assert_eq!(0, dedup_records::<Reading>(&mut buf));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_trailing_bytes() {
+    let mut buf = vec![1, 0, 1, 1, 0, 1, 0xAA, 0xBB];
+    assert_eq!(1, dedup_records::<Reading>(&mut buf));
+    assert_eq!(vec![1, 0, 1, 0xAA, 0xBB], buf);
+}
+
+#[test]
+fn test_dedup_by() {
+    let mut buf = vec![
+        1, 0, 1, 0x00, // first
+        1, 0, 1, 0x01, // differs only in flags
+        1, 0, 1, 0x02, // differs only in flags
+        2, 0, 2, 0x00, // second
+        2, 0, 2, 0x07, // differs only in flags
+    ];
+    let removed = dedup_records_by::<ReadingOwned>(&mut buf, |previous, current| {
+        previous.normalized().as_bytes() == current.normalized().as_bytes()
+    });
+    assert_eq!(3, removed);
+    assert_eq!(vec![1, 0, 1, 0x00, 2, 0, 2, 0x00], buf);
+
+    let mut buf = vec![1, 0, 1, 0x00, 1, 0, 1, 0x01];
+    assert_eq!(0, dedup_records::<ReadingOwned>(&mut buf));
+    assert_eq!(8, buf.len());
+}
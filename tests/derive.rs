@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use byteview::{BigEndian, ByteOrder, LittleEndian};
+
+byteview::byteview_ref! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Point {
+        pub x: u16be,
+        pub y: u16be,
+    }
+}
+
+byteview::byteview_ref! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Message {
+        pub kind: u8,
+        #[byteview(optional)]
+        pub value: u16be,
+    }
+}
+
+byteview::byteview_ref! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[byteview(endian = "generic")]
+    pub struct Generic {
+        pub value: u32,
+    }
+}
+
+byteview::byteview_ref! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[byteview(endian = "runtime")]
+    pub struct Runtime {
+        pub value: u16,
+    }
+}
+
+byteview::byteview_owned! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct OwnedPoint {
+        #[byteview(counter)]
+        pub x: u16be,
+        pub y: u16be,
+    }
+}
+
+byteview::byteview! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    #[byteview(ref_name = "EntryRef", owned_name = "Entry")]
+    pub struct Entry {
+        pub id: u8,
+        #[byteview(optional)]
+        pub extra: u8,
+    }
+}
+
+fn takes_by_value(point: Point<'_>) -> u16 {
+    point.x()
+}
+
+#[test]
+fn test_ref_is_copy() {
+    let point = Point::from_array(b"\x00\x01\x00\x02");
+    let copy = point;
+    assert_eq!(1, takes_by_value(point));
+    assert_eq!(2, copy.y());
+    #[allow(clippy::clone_on_copy)]
+    let clone = point.clone();
+    assert_eq!(point, clone);
+}
+
+#[test]
+fn test_eq_compares_bytes() {
+    let first = *b"\x00\x01\x00\x02";
+    let second = *b"\x00\x01\x00\x02";
+    let other = *b"\x00\x01\x00\x03";
+    assert_eq!(Point::from_array(&first), Point::from_array(&second));
+    assert_ne!(Point::from_array(&first), Point::from_array(&other));
+
+    let (short, _) = Message::split_slice(b"\x01").unwrap();
+    let (long, _) = Message::split_slice(b"\x01\x00\x02").unwrap();
+    assert_ne!(short, long);
+    assert_eq!(long, Message::from_exact_slice(b"\x01\x00\x02").unwrap());
+
+    let points: HashSet<_> = [&first, &second, &other]
+        .into_iter()
+        .map(Point::from_array)
+        .collect();
+    assert_eq!(2, points.len());
+}
+
+#[test]
+fn test_endianness() {
+    let bytes = *b"\x00\x00\x00\x01";
+    let big = Generic::<BigEndian>::from_array(&bytes);
+    assert_eq!(big, big);
+    assert_eq!(
+        Generic::<LittleEndian>::from_array(&bytes),
+        Generic::<LittleEndian>::from_array(&bytes)
+    );
+
+    let bytes = *b"\x00\x01";
+    let big = Runtime::from_array_with(&bytes, ByteOrder::Big);
+    let little = Runtime::from_array_with(&bytes, ByteOrder::Little);
+    let copy = big;
+    assert_eq!(big, copy);
+    assert_ne!(big, little);
+}
+
+#[test]
+fn test_owned() {
+    let mut point = OwnedPoint::from_array(*b"\x00\x01\x00\x02");
+    let copy = point;
+    point.increment_x_wrapping();
+    assert_eq!(1, copy.x());
+    assert_eq!(2, point.x());
+    assert!(copy < point);
+
+    let entry = Entry::from_exact_vec(vec![1, 2]).unwrap();
+    let clone = entry.clone();
+    assert_eq!(entry, clone);
+    assert_eq!(entry.as_ref(), clone.as_ref());
+    assert_eq!(Some(2), clone.extra());
+}
@@ -0,0 +1,102 @@
+use byteview::{DispatchError, Dispatcher, ParseError};
+
+byteview::byteview_ref! {
+    pub struct Temperature {
+        pub tag: u8,
+        pub record_len: u8,
+        pub sensor: u8,
+        pub celsius: i16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Reset {
+        pub tag: u8,
+        pub record_len: u8,
+        pub reason: u8,
+    }
+}
+
+enum Record<'a> {
+    Temperature(Temperature<'a>),
+    Reset(Reset),
+}
+
+fn dispatcher<'a>() -> Dispatcher<'a, Record<'a>> {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher
+        .register(1, Record::Temperature)
+        .register(2, Record::Reset)
+        .skip_unknown(|bytes| bytes.get(1).map(|len| usize::from(*len)));
+    dispatcher
+}
+
+// Temperature, unknown (tag 7, 4 bytes), reset, temperature
+const STREAM: &[u8] = &[
+    1, 5, 3, 0xFF, 0xF6, 7, 4, 0xAA, 0xBB, 2, 3, 9, 1, 5, 4, 0x00, 0x15,
+];
+
+#[test]
+fn test_interleaved() {
+    let dispatcher = dispatcher();
+    let records = dispatcher
+        .iter(STREAM)
+        .map(|record| match record.unwrap() {
+            Record::Temperature(temperature) => (temperature.sensor(), temperature.celsius()),
+            Record::Reset(reset) => (reset.reason(), 0),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![(3, -10), (9, 0), (4, 21)], records);
+
+    let (record, rest) = dispatcher.next(&STREAM[5..]).unwrap();
+    assert!(matches!(record, Record::Reset(reset) if reset.reason() == 9));
+    assert_eq!(&STREAM[12..], rest);
+}
+
+#[test]
+fn test_errors() {
+    let mut dispatcher = dispatcher();
+    assert_eq!(Some(DispatchError::Empty), dispatcher.next(&[]).err());
+    assert_eq!(Some(DispatchError::Empty), dispatcher.next(&[7, 2]).err());
+    assert_eq!(
+        Some(DispatchError::Parse {
+            tag: 7,
+            error: ParseError::LengthMismatch {
+                declared: 9,
+                available: 3
+            }
+        }),
+        dispatcher.next(&[7, 9, 0]).err()
+    );
+    assert_eq!(
+        Some(DispatchError::Parse {
+            tag: 1,
+            error: ParseError::TooShort {
+                needed: 5,
+                available: 3
+            }
+        }),
+        dispatcher.next(&[1, 5, 3]).err()
+    );
+
+    let mut iter = dispatcher.iter(&[1, 5, 3]);
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+
+    dispatcher.register_fn(7, |_| {
+        Err(ParseError::TooShort {
+            needed: 10,
+            available: 0,
+        })
+    });
+    assert!(matches!(
+        dispatcher.next(&[7, 2]),
+        Err(DispatchError::Parse { tag: 7, .. })
+    ));
+
+    let dispatcher = Dispatcher::<'_, Record<'_>>::new();
+    assert_eq!(
+        Some(DispatchError::UnknownTag(1)),
+        dispatcher.next(STREAM).err()
+    );
+}
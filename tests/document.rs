@@ -0,0 +1,227 @@
+use byteview::{Checksum, Document, DocumentError, DocumentFormat, NoFooter, ParseError};
+
+byteview::byteview_owned! {
+    pub struct HeaderStart {
+        pub file_name: [u8; 32],
+        pub earliest_date_epoch: u32be,
+        pub latest_date_epoch: u32be,
+        pub log_type: u8,
+        pub num_fields: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct FieldDefinition {
+        pub name: [u8; 32],
+        pub data_info: u8,
+        pub index: u8,
+    }
+}
+
+struct LogFile;
+
+impl DocumentFormat for LogFile {
+    type Header = HeaderStart;
+    type Record = FieldDefinition;
+    type Footer = NoFooter;
+
+    const COUNT_FIELD: Option<&'static str> = Some("num_fields");
+}
+
+const SAMPLE_LOG: &[u8] = include_bytes!("../examples/log/sample.log");
+
+fn write<D: DocumentFormat>(document: &Document<D>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    document.write(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn test_round_trip() {
+    let document = Document::<LogFile>::parse(SAMPLE_LOG).unwrap();
+    assert_eq!(3, document.header.num_fields());
+    assert_eq!(3, document.records.len());
+    assert_eq!(b"Field2", &document.records[1].name()[..6]);
+    assert_eq!(SAMPLE_LOG, write(&document));
+}
+
+#[test]
+fn test_modify() {
+    let mut document = Document::<LogFile>::parse(SAMPLE_LOG).unwrap();
+    let FieldDefinitionFieldsMut { name, index, .. } = document.records[1].split_fields_mut();
+    name.fill(0);
+    name[..7].copy_from_slice(b"Renamed");
+    *index = [9];
+    let written = write(&document);
+    assert_eq!(SAMPLE_LOG.len(), written.len());
+
+    let reparsed = Document::<LogFile>::parse(&written).unwrap();
+    assert_eq!(b"Renamed\0", &reparsed.records[1].name()[..8]);
+    assert_eq!(9, reparsed.records[1].index());
+    assert_eq!(
+        document.records[0].as_bytes(),
+        reparsed.records[0].as_bytes()
+    );
+    assert_eq!(written, write(&reparsed));
+
+    // The count field follows the records.
+    document.records.remove(0);
+    let written = write(&document);
+    let reparsed = Document::<LogFile>::parse(&written).unwrap();
+    assert_eq!(2, reparsed.header.num_fields());
+    assert_eq!(b"Renamed\0", &reparsed.records[0].name()[..8]);
+}
+
+#[test]
+fn test_count_errors() {
+    let mut truncated = SAMPLE_LOG[..SAMPLE_LOG.len() - 1].to_vec();
+    assert_eq!(
+        Err(DocumentError::Record {
+            index: 2,
+            error: ParseError::TooShort {
+                needed: 34,
+                available: 33,
+            },
+        }),
+        Document::<LogFile>::parse(&truncated).map(|_| ())
+    );
+    truncated.extend([0; 2]);
+    assert_eq!(
+        Err(DocumentError::TrailingBytes {
+            offset: 144,
+            len: 1,
+        }),
+        Document::<LogFile>::parse(&truncated).map(|_| ())
+    );
+    assert!(matches!(
+        Document::<LogFile>::parse(&SAMPLE_LOG[..10]),
+        Err(DocumentError::Header(ParseError::TooShort { .. }))
+    ));
+
+    let mut document = Document::<LogFile>::parse(SAMPLE_LOG).unwrap();
+    let records =
+        std::iter::repeat_with(|| FieldDefinition::from_array([0; FieldDefinition::NUM_BYTES]));
+    document.records.extend(records.take(253));
+    let mut out = vec![0xAA];
+    assert_eq!(
+        Err(DocumentError::TooManyRecords { count: 256 }),
+        document.write(&mut out)
+    );
+    assert_eq!(vec![0xAA], out);
+}
+
+byteview::byteview_owned! {
+    pub struct ArchiveHeader {
+        #[byteview(magic = b"AR")]
+        pub magic: [u8; 2],
+        pub version: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(record_len = "record_len")]
+    pub struct Entry {
+        pub kind: u8,
+        pub record_len: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ArchiveFooter {
+        pub checksum: u16be,
+        #[byteview(magic = b"END")]
+        pub magic: [u8; 3],
+    }
+}
+
+struct Archive;
+
+impl DocumentFormat for Archive {
+    type Header = ArchiveHeader;
+    type Record = Entry;
+    type Footer = ArchiveFooter;
+
+    const CHECKSUM: Option<Checksum> = Some(Checksum {
+        field: "checksum",
+        compute: sum,
+    });
+}
+
+fn sum(bytes: &[u8]) -> u64 {
+    bytes.iter().map(|&byte| u64::from(byte) * 0x101).sum()
+}
+
+fn archive() -> Vec<u8> {
+    let mut bytes = b"AR\x01".to_vec();
+    bytes.extend([1, 4, 0xAA, 0xBB]);
+    bytes.extend([2, 2]);
+    bytes.extend([3, 3, 0xCC]);
+    let checksum = sum(&bytes) as u16;
+    bytes.extend(checksum.to_be_bytes());
+    bytes.extend(b"END");
+    bytes
+}
+
+#[test]
+fn test_until_footer() {
+    let bytes = archive();
+    let document = Document::<Archive>::parse(&bytes).unwrap();
+    assert_eq!(
+        vec![1, 2, 3],
+        document
+            .records
+            .iter()
+            .map(|entry| entry.kind())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!([0xAA, 0xBB], document.records[0].body());
+    assert_eq!(bytes, write(&document));
+
+    // Removing a record updates the checksum.
+    let mut document = document;
+    document.records.remove(1);
+    let written = write(&document);
+    assert_eq!(bytes.len() - 2, written.len());
+    let reparsed = Document::<Archive>::parse(&written).unwrap();
+    assert_eq!(2, reparsed.records.len());
+    assert_ne!(reparsed.footer.checksum(), document.footer.checksum());
+
+    let mut empty = b"AR\x01".to_vec();
+    empty.extend((sum(&empty) as u16).to_be_bytes());
+    empty.extend(b"END");
+    assert!(
+        Document::<Archive>::parse(&empty)
+            .unwrap()
+            .records
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_footer_errors() {
+    let mut bytes = archive();
+    bytes[4] ^= 1;
+    let computed = sum(&bytes[..bytes.len() - 5]) as u16;
+    let stored = sum(&archive()[..bytes.len() - 5]) as u16;
+    assert_eq!(
+        Err(DocumentError::ChecksumMismatch {
+            stored: stored.into(),
+            computed: computed.into(),
+        }),
+        Document::<Archive>::parse(&bytes).map(|_| ())
+    );
+
+    let mut bytes = archive();
+    *bytes.last_mut().unwrap() = b'!';
+    assert!(matches!(
+        Document::<Archive>::parse(&bytes),
+        Err(DocumentError::Footer(ParseError::MagicMismatch { .. }))
+    ));
+    assert_eq!(
+        Err(DocumentError::Footer(ParseError::TooShort {
+            needed: 5,
+            available: 4,
+        })),
+        Document::<Archive>::parse(b"AR\x01\x00\x00\x00\x00").map(|_| ())
+    );
+}
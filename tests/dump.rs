@@ -0,0 +1,101 @@
+use byteview::DynView;
+use std::fmt::{self, Write};
+
+byteview::byteview_ref! {
+    pub struct Sample {
+        pub sensor: u8,
+        _: u8,
+        pub value: i32le,
+        pub ratio: f32be,
+        pub serial: [u8; 20],
+        #[byteview(optional)]
+        pub extra: u16be,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(record_len = "record_len")]
+    pub struct Message {
+        pub kind: u8,
+        pub record_len: u8,
+    }
+}
+
+// A fixed-capacity sink, like a `heapless::String` on a target without an allocator.
+struct FixedBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedBuf<N> {
+    fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> Write for FixedBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn sample_bytes() -> Vec<u8> {
+    let mut bytes = vec![7, 0xAA, 0xFE, 0xFF, 0xFF, 0xFF, 0x3F, 0xC0, 0x00, 0x00];
+    bytes.extend(0..20);
+    bytes
+}
+
+#[test]
+fn test_dump() {
+    let bytes = sample_bytes();
+    let (sample, _) = Sample::split_slice(&bytes).unwrap();
+    assert_eq!(
+        "Sample (30 bytes)\n\
+        \x20 0..1    sensor  u8        7\n\
+        \x20 1..2    _       u8        170\n\
+        \x20 2..6    value   i32le     -2\n\
+        \x20 6..10   ratio   f32be     1.5\n\
+        \x20 10..30  serial  [u8; 20]  \
+        00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f 10 11 12 13\n\
+        \x20 30..32  extra   u16be     (missing)",
+        sample.dump()
+    );
+}
+
+#[test]
+fn test_dump_rest() {
+    let bytes = [1, 5, 0xDE, 0xAD, 0xBE];
+    let message = Message::from_exact_slice(&bytes).unwrap();
+    assert_eq!(
+        "Message (5 bytes)\n\
+        \x20 0..1  kind        u8       1\n\
+        \x20 1..2  record_len  u8       5\n\
+        \x20 2..5  (rest)      [u8; 3]  de ad be",
+        message.dump()
+    );
+}
+
+#[test]
+fn test_dump_to_fixed_capacity() {
+    let bytes = sample_bytes();
+    let (sample, _) = Sample::split_slice(&bytes).unwrap();
+    let mut out = FixedBuf::<512>::new();
+    sample.dump_to(&mut out).unwrap();
+    assert_eq!(sample.dump(), out.as_str());
+
+    let mut small = FixedBuf::<32>::new();
+    assert_eq!(Err(fmt::Error), sample.dump_to(&mut small));
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+byteview::byteview_ref! {
+    pub struct Status {
+        #[byteview(duration(unit = "10ms"))]
+        pub uptime: u32be,
+        #[byteview(duration(unit = "s"))]
+        pub timeout: u16le,
+        #[byteview(duration(unit = "us"))]
+        pub latency: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct StatusOwned {
+        #[byteview(duration(unit = "10ms"))]
+        pub uptime: u32be,
+        #[byteview(duration(unit = "1000s"))]
+        pub lifetime: u64le,
+    }
+}
+
+#[test]
+fn test_duration() {
+    let status = Status::from_array(&[0x00, 0x00, 0x01, 0x2C, 0x3C, 0x00, 0xFA]);
+    assert_eq!(300, status.uptime());
+    assert_eq!(Duration::from_secs(3), status.uptime_duration());
+    assert_eq!(60, status.timeout());
+    assert_eq!(Duration::from_secs(60), status.timeout_duration());
+    assert_eq!(Duration::from_micros(250), status.latency_duration());
+
+    let status = StatusOwned::from_array([0xFF; 12]);
+    assert_eq!(
+        Duration::from_millis(10 * u64::from(u32::MAX)),
+        status.uptime_duration()
+    );
+    assert_eq!(u64::MAX, status.lifetime());
+    assert_eq!(Duration::MAX, status.lifetime_duration());
+}
@@ -0,0 +1,109 @@
+use byteview::{DynView, DynViewFactory, ParseError, Value, ViewFactory};
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub magic: [u8; 2],
+        pub count: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SampleOwned {
+        pub sensor: u8,
+        _: u8,
+        pub value: i32le,
+        #[byteview(optional)]
+        pub extra: u8,
+    }
+}
+
+// Browse any struct through the same code path, as a record browser would.
+fn describe(view: &dyn DynView) -> Vec<String> {
+    view.fields()
+        .iter()
+        .filter(|field| !field.is_padding())
+        .map(|field| format!("{}={:?}", field.name, view.field_value(field.name)))
+        .collect()
+}
+
+#[test]
+fn test_browse() {
+    let header = Header::from_array(b"HD\x01\x02");
+    let sample = SampleOwned::from_exact_vec(vec![3, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 9]).unwrap();
+    let views: [&dyn DynView; 2] = [&header, &sample];
+
+    assert_eq!(
+        vec![
+            "magic=Some(Bytes([72, 68]))".to_owned(),
+            "count=Some(Unsigned(258))".to_owned(),
+        ],
+        describe(views[0])
+    );
+    assert_eq!(
+        vec![
+            "sensor=Some(Unsigned(3))".to_owned(),
+            "value=Some(Signed(-2))".to_owned(),
+            "extra=Some(Unsigned(9))".to_owned(),
+        ],
+        describe(views[1])
+    );
+    assert_eq!(4, views[0].num_bytes());
+    assert_eq!(7, views[1].num_bytes());
+    assert_eq!("SampleOwned", views[1].layout().name);
+    assert_eq!(b"HD\x01\x02", views[0].as_bytes());
+}
+
+#[test]
+fn test_missing_fields() {
+    let sample = SampleOwned::from_exact_vec(vec![3, 0, 1, 0, 0, 0]).unwrap();
+    let view: &dyn DynView = &sample;
+    assert_eq!(Some(Value::Signed(1)), view.field_value("value"));
+    assert_eq!(None, view.field_value("extra"));
+    assert_eq!(None, view.field_value("_"));
+    assert_eq!(None, view.field_value("unknown"));
+}
+
+// A factory for a borrowed struct, implemented by hand.
+struct HeaderFactory;
+
+impl DynViewFactory for HeaderFactory {
+    fn layout(&self) -> byteview::Layout {
+        Header::LAYOUT
+    }
+
+    fn parse<'a>(&self, bytes: &'a [u8]) -> Result<(Box<dyn DynView + 'a>, &'a [u8]), ParseError> {
+        let (header, rest) = Header::split_slice(bytes).ok_or(ParseError::TooShort {
+            needed: Header::NUM_BYTES,
+            available: bytes.len(),
+        })?;
+        Ok((Box::new(header), rest))
+    }
+}
+
+#[test]
+fn test_factories() {
+    let factories: Vec<Box<dyn DynViewFactory>> = vec![
+        Box::new(HeaderFactory),
+        Box::new(ViewFactory::<SampleOwned>::new()),
+    ];
+    let names: Vec<&str> = factories
+        .iter()
+        .map(|factory| factory.layout().name)
+        .collect();
+    assert_eq!(vec!["Header", "SampleOwned"], names);
+
+    let bytes = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70];
+    let (header, rest) = factories[0].parse(&bytes).unwrap();
+    assert_eq!(Some(Value::Unsigned(0x3040)), header.field_value("count"));
+    assert_eq!([0x50, 0x60, 0x70], rest);
+
+    let (sample, rest) = factories[1].parse(&bytes).unwrap();
+    assert_eq!(
+        Some(Value::Signed(0x6050_4030)),
+        sample.field_value("value")
+    );
+    assert_eq!(Some(Value::Unsigned(0x10)), sample.field_value("sensor"));
+    assert!(rest.is_empty());
+
+    assert!(factories[0].parse(&bytes[..3]).is_err());
+}
@@ -0,0 +1,66 @@
+use byteview::{F32Le, F64Be, I16Be, I64Le, U16Be, U16Le, U32Be, U32Le, U64Ne, U128Be};
+
+#[test]
+fn test_round_trip() {
+    assert_eq!(0x1234, U16Be::new(0x1234).get());
+    assert_eq!([0x12, 0x34], U16Be::new(0x1234).to_bytes());
+    assert_eq!([0x34, 0x12], U16Le::new(0x1234).to_bytes());
+    assert_eq!([0xDE, 0xAD, 0xBE, 0xEF], U32Be::new(0xDEAD_BEEF).0);
+    assert_eq!([0xEF, 0xBE, 0xAD, 0xDE], U32Le::new(0xDEAD_BEEF).0);
+    assert_eq!(
+        0xDEAD_BEEF,
+        U32Le::from_bytes([0xEF, 0xBE, 0xAD, 0xDE]).get()
+    );
+    assert_eq!(u64::MAX - 1, U64Ne::new(u64::MAX - 1).get());
+    assert_eq!(1, U128Be::new(1).0[15]);
+    assert_eq!([0xFF, 0xFE], I16Be::new(-2).0);
+    assert_eq!(i64::MIN, I64Le::new(i64::MIN).get());
+    assert_eq!(1.5, F32Le::new(1.5).get());
+    assert_eq!([0x3F, 0xF8, 0, 0, 0, 0, 0, 0], F64Be::new(1.5).0);
+    for value in [0, 1, 0x7FFF_FFFF, u32::MAX] {
+        assert_eq!(value, u32::from(U32Be::from(value)));
+        assert_eq!(value, u32::from(U32Le::from(value)));
+    }
+}
+
+#[test]
+fn test_set() {
+    let mut value = U32Be::default();
+    assert_eq!(0, value.get());
+    value.set(0x0102_0304);
+    assert_eq!([1, 2, 3, 4], value.0);
+    assert_eq!("U32Be(16909060)", format!("{value:?}"));
+    assert_eq!("16909060", value.to_string());
+}
+
+#[repr(C)]
+#[derive(Debug, Eq, PartialEq)]
+struct Header {
+    magic: U32Be,
+    version: u8,
+    count: U16Le,
+}
+
+const MAGIC: U32Be = U32Be::new(0x4C4F_4731);
+
+#[test]
+fn test_struct_field() {
+    assert_eq!(7, size_of::<Header>());
+    assert_eq!(1, align_of::<Header>());
+    let mut header = Header {
+        magic: MAGIC,
+        version: 2,
+        count: U16Le::new(0x0100),
+    };
+    header.count.set(header.count.get() + 1);
+    assert_eq!(*b"LOG1", header.magic.to_bytes());
+    assert_eq!([0x01, 0x01], header.count.0);
+    assert_eq!(
+        Header {
+            magic: U32Be::from_bytes(*b"LOG1"),
+            version: 2,
+            count: U16Le::from(0x0101),
+        },
+        header
+    );
+}
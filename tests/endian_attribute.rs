@@ -0,0 +1,44 @@
+byteview::byteview_ref! {
+    #[byteview(endian = "little")]
+    pub struct Header {
+        #[byteview(endian = "big")]
+        pub magic: u32,
+        pub length: u32,
+        pub checksums: [u16; 2],
+        pub version: u16be,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Mixed {
+        #[byteview(endian = "big")]
+        pub big: u32,
+        #[byteview(endian = "little")]
+        pub little: u32,
+        #[byteview(endian = "native")]
+        pub native: i16,
+    }
+}
+
+#[test]
+fn test_struct_default_and_override() {
+    let bytes = b"\x01\x02\x03\x04\x01\x02\x03\x04\x01\x02\x03\x04\x01\x02\x2A";
+    let header = Header::from_array(bytes);
+    assert_eq!(0x0102_0304, header.magic());
+    assert_eq!(0x0403_0201, header.length());
+    assert_eq!([0x0201, 0x0403], header.checksums());
+    assert_eq!(0x0102, header.version());
+    assert_eq!(42, header.kind());
+    assert_eq!("u32be", Header::LAYOUT.fields[0].ty);
+    assert_eq!("u32le", Header::LAYOUT.fields[1].ty);
+    assert_eq!("[u16le; 2]", Header::LAYOUT.fields[2].ty);
+}
+
+#[test]
+fn test_field_attributes() {
+    let mixed = Mixed::from_array(*b"\xDE\xAD\xBE\xEF\xDE\xAD\xBE\xEF\x12\x34");
+    assert_eq!(0xDEAD_BEEF, mixed.big());
+    assert_eq!(0xEFBE_ADDE, mixed.little());
+    assert_eq!(i16::from_ne_bytes([0x12, 0x34]), mixed.native());
+}
@@ -4,27 +4,18 @@ byteview::byteview_ref! {
     pub struct EntryHeaderRef {
         /// The index of the entry.
         pub index: u32be,
-        _kind: u8,
+        /// What [`Kind`] of entry this is.
+        pub kind: u8 as Kind,
         _: u8,
         _name: [u8; 16],
     }
 }
 
 impl<'a> EntryHeaderRef<'a> {
-    /// What [`Kind`] of entry this is.
-    pub fn kind(&self) -> Option<Kind> {
-        Some(match self._kind() {
-            0 => Kind::Foo,
-            1 => Kind::Bar,
-            2 => Kind::Baz,
-            _ => return None,
-        })
-    }
-
     /// The name of the entry.
     pub fn name(&self) -> &[u8] {
         let name = self._name();
-        match name.into_iter().position(|b| *b == 0) {
+        match name.iter().position(|b| *b == 0) {
             Some(i) => &name[..i],
             None => name,
         }
@@ -37,7 +28,8 @@ byteview::byteview_owned! {
     pub struct EntryHeaderOwned {
         /// The index of the entry.
         pub index: u32be,
-        _kind: u8,
+        /// What [`Kind`] of entry this is.
+        pub kind: u8 as Kind,
         _: u8,
         /// The number of items in the entry.
         _name: [u8; 16],
@@ -45,41 +37,22 @@ byteview::byteview_owned! {
 }
 
 impl EntryHeaderOwned {
-    /// What [`Kind`] of entry this is.
-    pub fn kind(&self) -> Option<Kind> {
-        Some(match self._kind() {
-            0 => Kind::Foo,
-            1 => Kind::Bar,
-            2 => Kind::Baz,
-            _ => return None,
-        })
-    }
-
     /// The name of the entry.
     pub fn name(&self) -> &[u8] {
         let name = self._name();
-        match name.into_iter().position(|b| *b == 0) {
+        match name.iter().position(|b| *b == 0) {
             Some(i) => &name[..i],
             None => name,
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-pub enum Kind {
-    Foo,
-    Bar,
-    Baz,
-}
-
-impl Kind {
-    pub fn from_byte(byte: u8) -> Option<Kind> {
-        Some(match byte {
-            0 => Kind::Foo,
-            1 => Kind::Bar,
-            2 => Kind::Baz,
-            _ => return None,
-        })
+byteview::byteview_enum! {
+    #[derive(Debug, Eq, PartialEq)]
+    pub enum Kind: u8 {
+        Foo = 0,
+        Bar = 1,
+        Baz = 2,
     }
 }
 
@@ -90,12 +63,34 @@ fn test_entry() {
     let entry_header = EntryHeaderRef::from_array(bytes);
     print!("{entry_header:#?}");
     assert_eq!(1793, entry_header.index());
-    assert_eq!(Some(Kind::Baz), entry_header.kind());
+    assert_eq!(Ok(Kind::Baz), entry_header.kind());
     assert_eq!(b"My Field Name", entry_header.name());
 
     let entry_header = EntryHeaderOwned::from_array(bytes.to_owned());
     print!("{entry_header:#?}");
     assert_eq!(1793, entry_header.index());
-    assert_eq!(Some(Kind::Baz), entry_header.kind());
+    assert_eq!(Ok(Kind::Baz), entry_header.kind());
     assert_eq!(b"My Field Name", entry_header.name());
 }
+
+#[test]
+fn test_unknown_kind() {
+    let bytes = b"\x00\x00\x00\x01\x07\x00Name\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00";
+
+    let entry_header = EntryHeaderRef::from_array(bytes);
+    assert_eq!(Err(7), entry_header.kind());
+    assert_eq!(7, entry_header.kind_raw());
+
+    let entry_header = EntryHeaderOwned::from_array(bytes.to_owned());
+    assert_eq!(Err(7), entry_header.kind());
+    assert_eq!(7, entry_header.kind_raw());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_roundtrip() {
+    byteview::testing::check_roundtrip::<EntryHeaderOwned>();
+    byteview::testing::RoundTrip::<EntryHeaderOwned>::new()
+        .buffer(*b"\x00\x00\x07\x01\x02\x2AMy Field Name\x00\x00\x00")
+        .check_with_ref::<EntryHeaderRef>();
+}
@@ -0,0 +1,83 @@
+byteview::byteview_enum! {
+    #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone)]
+    pub enum LogType: u8 {
+        System = 0,
+        Comm = 1,
+        /// Debugging output.
+        Debug = 2,
+    }
+}
+
+byteview::byteview_enum! {
+    #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+    pub enum Command: u16 {
+        Read = 0x0100,
+        Write = 0x0200,
+        Erase = 0x0100 + 0x0200,
+        Unknown(u16),
+    }
+}
+
+byteview::byteview_enum! {
+    #[derive(Debug, Eq, PartialEq, Copy, Clone)]
+    enum Offset: i32 {
+        Before = -1,
+        After = 1,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct HeaderStart {
+        pub log_type: u8 as LogType,
+        pub command: u16le as Command,
+        offset: i32be as Offset,
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for (log_type, value) in [
+        (LogType::System, 0),
+        (LogType::Comm, 1),
+        (LogType::Debug, 2),
+    ] {
+        assert_eq!(value, u8::from(log_type));
+        assert_eq!(value, log_type as u8);
+        assert_eq!(Ok(log_type), LogType::try_from(value));
+    }
+    for (command, value) in [
+        (Command::Read, 0x0100),
+        (Command::Write, 0x0200),
+        (Command::Erase, 0x0300),
+        (Command::Unknown(0x0400), 0x0400),
+    ] {
+        assert_eq!(value, u16::from(command));
+        assert_eq!(command, Command::from(value));
+    }
+    assert_eq!(Ok(Offset::Before), Offset::try_from(-1));
+    assert_eq!(-1, i32::from(Offset::Before));
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(Err(3), LogType::try_from(3));
+    assert_eq!(Err(0xFF), LogType::try_from(0xFF));
+    assert_eq!(Command::Unknown(0), Command::from(0));
+    assert_eq!(Err(0), Offset::try_from(0));
+}
+
+#[test]
+fn test_field() {
+    let bytes = [0x02, 0x00, 0x03, 0xFF, 0xFF, 0xFF, 0xFF];
+    let header = HeaderStart::from_array(&bytes);
+    assert_eq!(Ok(LogType::Debug), header.log_type());
+    assert_eq!(Command::Erase, header.command());
+    assert_eq!(Ok(Offset::Before), header.offset());
+
+    let bytes = [0x09, 0x34, 0x12, 0x00, 0x00, 0x00, 0x02];
+    let header = HeaderStart::from_array(&bytes);
+    assert_eq!(Err(9), header.log_type());
+    assert_eq!(9, header.log_type_raw());
+    assert_eq!(Command::Unknown(0x1234), header.command());
+    assert_eq!(Err(2), header.offset());
+}
@@ -0,0 +1,124 @@
+use byteview::ExportStatus;
+use std::ptr;
+
+byteview::byteview_ref! {
+    #[byteview(export_c = "entry_header")]
+    pub struct EntryHeader {
+        pub index: u32be,
+        pub kind: u8 as Kind,
+        pub offset: i16le,
+        _: u8,
+        pub name: [u8; 4],
+        pub id: u128be,
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Kind(u8);
+
+impl byteview::FromFieldValue<u8> for Kind {
+    type Output = Self;
+
+    fn from_field_value(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+// The exported functions as a C program would declare them, linked by symbol name rather than by Rust path.
+mod c {
+    use std::ffi::c_int;
+
+    unsafe extern "C" {
+        pub fn entry_header_size() -> usize;
+        pub fn entry_header_index(buf: *const u8, len: usize, out: *mut u32) -> c_int;
+        pub fn entry_header_kind(buf: *const u8, len: usize, out: *mut u8) -> c_int;
+        pub fn entry_header_offset(buf: *const u8, len: usize, out: *mut i16) -> c_int;
+        pub fn entry_header_name(buf: *const u8, len: usize, out: *mut u8) -> c_int;
+    }
+}
+
+fn header_bytes() -> Vec<u8> {
+    let mut bytes = vec![0x00, 0x00, 0x01, 0x02, 0x07, 0xFE, 0xFF, 0x00];
+    bytes.extend(b"LOG1");
+    bytes.extend([0; 16]);
+    bytes
+}
+
+#[test]
+fn test_export_c() {
+    let bytes = header_bytes();
+    assert_eq!(EntryHeader::NUM_BYTES, unsafe { c::entry_header_size() });
+
+    let ok = ExportStatus::Ok.code();
+    let mut index = 0;
+    let mut kind = 0;
+    let mut offset = 0;
+    let mut name = [0; 4];
+    unsafe {
+        assert_eq!(
+            ok,
+            c::entry_header_index(bytes.as_ptr(), bytes.len(), &mut index)
+        );
+        assert_eq!(
+            ok,
+            c::entry_header_kind(bytes.as_ptr(), bytes.len(), &mut kind)
+        );
+        assert_eq!(
+            ok,
+            c::entry_header_offset(bytes.as_ptr(), bytes.len(), &mut offset)
+        );
+        assert_eq!(
+            ok,
+            c::entry_header_name(bytes.as_ptr(), bytes.len(), name.as_mut_ptr())
+        );
+    }
+    assert_eq!(0x0102, index);
+    assert_eq!(7, kind);
+    assert_eq!(-2, offset);
+    assert_eq!(*b"LOG1", name);
+}
+
+#[test]
+fn test_errors() {
+    let bytes = header_bytes();
+    let mut index = 0xDEAD;
+    let mut name = [0; 4];
+    unsafe {
+        assert_eq!(
+            ExportStatus::NullPointer.code(),
+            c::entry_header_index(ptr::null(), 4, &mut index)
+        );
+        assert_eq!(
+            ExportStatus::NullPointer.code(),
+            c::entry_header_index(bytes.as_ptr(), bytes.len(), ptr::null_mut())
+        );
+        assert_eq!(
+            ExportStatus::TooShort.code(),
+            c::entry_header_index(bytes.as_ptr(), 3, &mut index)
+        );
+        assert_eq!(
+            ExportStatus::TooShort.code(),
+            c::entry_header_name(bytes.as_ptr(), 11, name.as_mut_ptr())
+        );
+        // Only the bytes up to the end of the field are needed.
+        assert_eq!(
+            ExportStatus::Ok.code(),
+            c::entry_header_name(bytes.as_ptr(), 12, name.as_mut_ptr())
+        );
+    }
+    assert_eq!(0xDEAD, index);
+    assert_eq!(*b"LOG1", name);
+}
+
+#[test]
+fn test_status_codes() {
+    for status in [
+        ExportStatus::Ok,
+        ExportStatus::NullPointer,
+        ExportStatus::TooShort,
+    ] {
+        assert_eq!(Some(status), ExportStatus::from_code(status.code()));
+    }
+    assert_eq!(0, ExportStatus::Ok.code());
+    assert_eq!(None, ExportStatus::from_code(1));
+}
@@ -0,0 +1,102 @@
+use byteview::{BigEndian, LittleEndian};
+
+byteview::byteview_ref! {
+    pub struct HeaderStart {
+        pub magic: [u8; 4],
+        _: u8,
+        pub log_type: u8,
+        pub num_fields: u16be,
+        #[byteview(getter = "raw_name")]
+        _name: [u8; 8],
+        pub checksum: u32le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderStartOwned {
+        pub magic: [u8; 4],
+        _: u8,
+        pub log_type: u8,
+        pub num_fields: u16be,
+        #[byteview(optional)]
+        pub checksum: u32le,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(endian = "generic")]
+    pub struct Generic {
+        pub kind: u8,
+        pub value: u32,
+    }
+}
+
+// The constants can be used in array lengths.
+const NAME_BUFFER: [u8; HeaderStart::SIZE_RAW_NAME] = *b"FILENAME";
+
+fn header_bytes() -> [u8; HeaderStart::NUM_BYTES] {
+    *b"LOG1\xEE\x03\x00\x02FILENAME\x78\x56\x34\x12"
+}
+
+#[test]
+fn test_constants_match_getters() {
+    assert_eq!(0, HeaderStart::OFFSET_MAGIC);
+    assert_eq!(4, HeaderStart::SIZE_MAGIC);
+    assert_eq!(5, HeaderStart::OFFSET_LOG_TYPE);
+    assert_eq!(1, HeaderStart::SIZE_LOG_TYPE);
+    assert_eq!(6, HeaderStart::OFFSET_NUM_FIELDS);
+    assert_eq!(2, HeaderStart::SIZE_NUM_FIELDS);
+    assert_eq!(8, HeaderStart::OFFSET_RAW_NAME);
+    assert_eq!(16, HeaderStart::OFFSET_CHECKSUM);
+    assert_eq!(
+        HeaderStart::NUM_BYTES,
+        HeaderStart::OFFSET_CHECKSUM + HeaderStart::SIZE_CHECKSUM
+    );
+
+    let mut bytes = header_bytes();
+    bytes[HeaderStart::OFFSET_LOG_TYPE] = 7;
+    let range = HeaderStart::OFFSET_NUM_FIELDS
+        ..HeaderStart::OFFSET_NUM_FIELDS + HeaderStart::SIZE_NUM_FIELDS;
+    bytes[range].copy_from_slice(&[0x01, 0x02]);
+    let (header, _) = HeaderStart::split_slice(&bytes).unwrap();
+    assert_eq!(7, header.log_type());
+    assert_eq!(0x0102, header.num_fields());
+    assert_eq!(&NAME_BUFFER, header.raw_name());
+    for field in HeaderStart::LAYOUT.fields {
+        let offset = match field.name {
+            "magic" => HeaderStart::OFFSET_MAGIC,
+            "log_type" => HeaderStart::OFFSET_LOG_TYPE,
+            "num_fields" => HeaderStart::OFFSET_NUM_FIELDS,
+            "_name" => HeaderStart::OFFSET_RAW_NAME,
+            "checksum" => HeaderStart::OFFSET_CHECKSUM,
+            _ => continue,
+        };
+        assert_eq!(field.offset, offset);
+    }
+}
+
+#[test]
+fn test_match_guards() {
+    let describe = |offset: usize| match offset {
+        offset if offset < HeaderStart::OFFSET_LOG_TYPE => "before",
+        HeaderStart::OFFSET_LOG_TYPE => "log_type",
+        _ => "after",
+    };
+    assert_eq!("before", describe(4));
+    assert_eq!("log_type", describe(5));
+    assert_eq!("after", describe(6));
+}
+
+#[test]
+fn test_owned_and_generic() {
+    assert_eq!(5, HeaderStartOwned::OFFSET_LOG_TYPE);
+    assert_eq!(8, HeaderStartOwned::OFFSET_CHECKSUM);
+    assert_eq!(4, HeaderStartOwned::SIZE_CHECKSUM);
+    let mut bytes = vec![0; HeaderStartOwned::NUM_BYTES];
+    bytes[HeaderStartOwned::OFFSET_CHECKSUM] = 0x2A;
+    let header = HeaderStartOwned::from_exact_vec(bytes).unwrap();
+    assert_eq!(Some(0x2A), header.checksum());
+
+    assert_eq!(1, Generic::<BigEndian>::OFFSET_VALUE);
+    assert_eq!(4, Generic::<LittleEndian>::SIZE_VALUE);
+}
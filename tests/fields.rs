@@ -0,0 +1,69 @@
+use byteview::{ByteView, Endianness, FieldInfo, FieldKind, NumberType};
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub kind: u8,
+        _: [u8; 3],
+        pub length: u32be,
+        pub name: [u8; 4],
+        pub offset: i16le,
+        _: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderOwned {
+        pub kind: u8,
+        _: u8,
+        pub length: u16be,
+    }
+}
+
+// Describe each byte of a struct by the field it belongs to, as a hexdump annotator would.
+fn annotate<'a, T: ByteView<'a>>() -> Vec<&'static str> {
+    let mut names = Vec::new();
+    for field in T::FIELDS {
+        assert_eq!(names.len(), field.offset);
+        names.extend(field.range().map(|_| field.name));
+    }
+    names
+}
+
+#[test]
+fn test_fields() {
+    assert_eq!(Header::LAYOUT.fields, Header::FIELDS);
+    assert_eq!(6, Header::FIELDS.len());
+    assert_eq!(
+        FieldInfo {
+            name: "length",
+            offset: 4,
+            size: 4,
+            ty: "u32be",
+            kind: FieldKind::Number(NumberType::U32, Endianness::Big),
+        },
+        Header::FIELDS[2]
+    );
+    assert_eq!(FieldKind::U8, Header::FIELDS[0].kind);
+    assert_eq!(FieldKind::Bytes, Header::FIELDS[3].kind);
+    assert!(Header::FIELDS[1].is_padding());
+    assert!(Header::FIELDS[5].is_padding());
+}
+
+#[test]
+fn test_fields_cover_every_byte() {
+    let names = annotate::<Header>();
+    assert_eq!(Header::NUM_BYTES, names.len());
+    assert_eq!(
+        vec![
+            "kind", "_", "_", "_", "length", "length", "length", "length", "name", "name", "name",
+            "name", "offset", "offset", "_", "_",
+        ],
+        names
+    );
+
+    assert_eq!(
+        vec!["kind", "_", "length", "length"],
+        annotate::<HeaderOwned>()
+    );
+    assert_eq!(HeaderOwned::FIELDS, <HeaderOwned as ByteView>::FIELDS);
+}
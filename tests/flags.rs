@@ -0,0 +1,65 @@
+byteview::byteview_ref! {
+    pub struct FileHeader {
+        pub flags: flags u8 { COMPRESSED = 0, ENCRYPTED = 1, TRUNCATED = 7 },
+        pub size: u32be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Status {
+        pub version: u8,
+        pub status: flags u16le { READY = 0, ERROR = 9, Busy = 15 },
+    }
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(0x01u8, FileHeader::COMPRESSED);
+    assert_eq!(0x02u8, FileHeader::ENCRYPTED);
+    assert_eq!(0x80u8, FileHeader::TRUNCATED);
+    assert_eq!(0x0001u16, Status::READY);
+    assert_eq!(0x0200u16, Status::ERROR);
+    assert_eq!(0x8000u16, Status::BUSY);
+}
+
+#[test]
+fn test_flags() {
+    let header = FileHeader::from_array(b"\x81\x00\x00\x01\x00");
+    assert!(header.is_compressed());
+    assert!(!header.is_encrypted());
+    assert!(header.is_truncated());
+    assert_eq!(256, header.size());
+
+    // Unnamed bits are still available from the raw value.
+    let header = FileHeader::from_array(b"\x7E\x00\x00\x00\x00");
+    assert!(!header.is_compressed());
+    assert!(header.is_encrypted());
+    assert!(!header.is_truncated());
+    assert_eq!(0x7E, header.flags());
+    assert_eq!(
+        0x7C,
+        header.flags() & !(FileHeader::COMPRESSED | FileHeader::ENCRYPTED | FileHeader::TRUNCATED)
+    );
+
+    let status = Status::from_array([1, 0x01, 0x80]);
+    assert!(status.is_ready());
+    assert!(!status.is_error());
+    assert!(status.is_busy());
+}
+
+#[test]
+fn test_set_flags() {
+    let mut status = Status::from_array([1, 0x00, 0x00]);
+    status.set_error(true);
+    assert!(status.is_error());
+    assert_eq!(Status::ERROR, status.status());
+    assert_eq!([1, 0x00, 0x02], status.as_bytes());
+
+    let mut status = Status::from_array([1, 0xFF, 0xFF]);
+    status.set_ready(false);
+    status.set_busy(false);
+    assert_eq!(0x7FFE, status.status());
+    status.set_busy(true);
+    assert_eq!(0xFFFE, status.status());
+    assert_eq!(1, status.version());
+}
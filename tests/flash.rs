@@ -0,0 +1,88 @@
+use byteview::{FlashRecords, MagicBytes, ParseError, Torn};
+
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct LogEntry {
+        #[byteview(magic = b"\x4C")]
+        pub magic: [u8; 1],
+        pub sequence: u8,
+        pub value: u16le,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Plain {
+        pub a: u8,
+        pub b: u8,
+    }
+}
+
+#[test]
+fn test_records_torn_erased() {
+    let mut page = vec![0xFF; 32];
+    page[0..4].copy_from_slice(&[0x4C, 1, 0x10, 0x00]);
+    page[4..8].copy_from_slice(&[0x4C, 2, 0x20, 0x00]);
+    // A record whose first byte was never written.
+    page[8..12].copy_from_slice(&[0xFF, 3, 0x30, 0x00]);
+
+    let mut records = FlashRecords::<LogEntry>::new(&page);
+    assert_eq!(1, records.next().unwrap().unwrap().sequence());
+    assert_eq!(0x0020, records.next().unwrap().unwrap().value());
+    assert_eq!(
+        Torn {
+            offset: 8,
+            error: ParseError::MagicMismatch {
+                offset: 0,
+                expected: b"\x4C",
+                actual: MagicBytes::new(b"\xFF"),
+            }
+        },
+        records.next().unwrap().unwrap_err()
+    );
+    assert!(records.next().is_none());
+    assert!(records.next().is_none());
+    assert_eq!(12, records.offset());
+}
+
+#[test]
+fn test_erased_page() {
+    let page = [0xFF; 16];
+    let mut records = FlashRecords::<LogEntry>::new(&page);
+    assert!(records.next().is_none());
+    assert_eq!(0, records.offset());
+
+    let mut records = FlashRecords::<LogEntry>::new(&[]);
+    assert!(records.next().is_none());
+}
+
+#[test]
+fn test_full_page() {
+    let page = [1, 2, 3, 4, 0xFF, 5];
+    let values: Vec<(u8, u8)> = FlashRecords::<Plain>::new(&page)
+        .map(|record| {
+            let record = record.unwrap();
+            (record.a(), record.b())
+        })
+        .collect();
+    assert_eq!(vec![(1, 2), (3, 4), (0xFF, 5)], values);
+}
+
+#[test]
+fn test_partial_slot() {
+    // The page ends partway through a record.
+    let page = [0x4C, 1, 0, 0, 0x4C, 2];
+    let mut records = FlashRecords::<LogEntry>::new(&page);
+    assert!(records.next().unwrap().is_ok());
+    let torn = records.next().unwrap().unwrap_err();
+    assert_eq!(4, torn.offset);
+    assert!(matches!(torn.error, ParseError::TooShort { .. }));
+    assert!(records.next().is_none());
+    assert_eq!(6, records.offset());
+
+    // A partial slot that is erased ends the records.
+    let page = [0x4C, 1, 0, 0, 0xFF, 0xFF];
+    let mut records = FlashRecords::<LogEntry>::new(&page);
+    assert!(records.next().unwrap().is_ok());
+    assert!(records.next().is_none());
+    assert_eq!(4, records.offset());
+}
@@ -0,0 +1,123 @@
+use byteview::{FooterError, FooterIndexed, MagicBytes, ParseError};
+
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct Footer {
+        pub table_offset: u32le,
+        pub count: u16le,
+        #[byteview(magic = b"FT")]
+        pub magic: [u8; 2],
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct IndexEntry {
+        pub key: u16be,
+        pub offset: u16be,
+    }
+}
+
+// An archive with a data section, a table of entries, and a footer describing the table.
+fn archive(table_offset: u32, count: u16) -> Vec<u8> {
+    let mut bytes = b"some data".to_vec();
+    for (key, offset) in [(1u16, 0u16), (5, 4), (9, 7)] {
+        bytes.extend(key.to_be_bytes());
+        bytes.extend(offset.to_be_bytes());
+    }
+    bytes.extend(table_offset.to_le_bytes());
+    bytes.extend(count.to_le_bytes());
+    bytes.extend(b"FT");
+    bytes
+}
+
+fn open<'a>(bytes: &'a [u8]) -> Result<FooterIndexed<'a, Footer<'a>, IndexEntry<'a>>, FooterError> {
+    FooterIndexed::open(bytes, |footer: &Footer| {
+        (footer.table_offset(), footer.count())
+    })
+}
+
+#[test]
+fn test_open() {
+    let bytes = archive(9, 3);
+    let file = open(&bytes).unwrap();
+    assert_eq!(3, file.footer().count());
+    let table = file.table();
+    assert_eq!(3, table.len());
+    assert_eq!(
+        vec![1, 5, 9],
+        table.iter().map(|entry| entry.key()).collect::<Vec<_>>()
+    );
+    assert_eq!(Ok(2), table.binary_search_by_key(&9, |entry| entry.key()));
+
+    let (footer, table) = open(&bytes).unwrap().into_parts();
+    assert_eq!(9, footer.table_offset());
+    assert_eq!(7, table.get(2).unwrap().offset());
+
+    // A table may be empty or a prefix of the bytes before the footer.
+    assert!(open(&archive(21, 0)).unwrap().table().is_empty());
+    assert_eq!(2, open(&archive(9, 2)).unwrap().table().len());
+}
+
+#[test]
+fn test_table_out_of_bounds() {
+    // The table would overlap the footer.
+    assert_eq!(
+        Err(FooterError::TableOutOfBounds {
+            offset: 9,
+            count: 4,
+            available: 21,
+        }),
+        open(&archive(9, 4)).map(|_| ())
+    );
+    // The offset is past the end of the file.
+    assert_eq!(
+        Err(FooterError::TableOutOfBounds {
+            offset: 1000,
+            count: 1,
+            available: 21,
+        }),
+        open(&archive(1000, 1)).map(|_| ())
+    );
+    assert_eq!(
+        Err(FooterError::TableOutOfBounds {
+            offset: u64::from(u32::MAX),
+            count: 0,
+            available: 21,
+        }),
+        open(&archive(u32::MAX, 0)).map(|_| ())
+    );
+
+    // The size of the table overflows.
+    let bytes = archive(9, 3);
+    for (offset, count) in [(1, u64::MAX), (u64::MAX, 1)] {
+        assert_eq!(
+            Err(FooterError::TableOutOfBounds {
+                offset,
+                count,
+                available: 21,
+            }),
+            FooterIndexed::<Footer, IndexEntry>::open(&bytes, |_| (offset, count)).map(|_| ())
+        );
+    }
+}
+
+#[test]
+fn test_bad_footer() {
+    let mut bytes = archive(9, 3);
+    *bytes.last_mut().unwrap() = b'X';
+    assert_eq!(
+        Err(FooterError::Footer(ParseError::MagicMismatch {
+            offset: 6,
+            expected: b"FT",
+            actual: MagicBytes::new(b"FX"),
+        })),
+        open(&bytes).map(|_| ())
+    );
+    assert_eq!(
+        Err(FooterError::Footer(ParseError::TooShort {
+            needed: 8,
+            available: 3,
+        })),
+        open(b"abc").map(|_| ())
+    );
+}
@@ -0,0 +1,74 @@
+use byteview::{BigEndian, ByteView, Endianness, FieldKind, LittleEndian, NumberType};
+
+byteview::byteview_ref! {
+    #[byteview(endian = "generic")]
+    pub struct Record {
+        pub length: u32,
+        pub offset: i24,
+        pub channels: [u16; 2],
+        pub version: u16be,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(endian = "generic")]
+    pub struct Counters {
+        #[byteview(counter)]
+        pub count: u16,
+        #[byteview(optional)]
+        pub total: u32,
+    }
+}
+
+const BYTES: [u8; 16] = *b"\x01\x02\x03\x04\xFF\xFF\xFE\x01\x02\x03\x04\x00\x07\x2A\xAA\xBB";
+
+#[test]
+fn test_big_and_little_endian() {
+    let big = Record::<BigEndian>::from_array(BYTES[..14].try_into().unwrap());
+    assert_eq!(0x0102_0304, big.length());
+    assert_eq!(-2, big.offset());
+    assert_eq!([0x0102, 0x0304], big.channels());
+    assert_eq!(7, big.version());
+    assert_eq!(42, big.kind());
+
+    let (little, rest) = Record::<LittleEndian>::split_slice(&BYTES).unwrap();
+    assert_eq!(0x0403_0201, little.length());
+    assert_eq!(-0x0001_0001, little.offset());
+    assert_eq!([0x0201, 0x0403], little.channels());
+    assert_eq!(7, little.version());
+    assert_eq!(42, little.kind());
+    assert_eq!(b"\xAA\xBB", rest);
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!(14, Record::<BigEndian>::NUM_BYTES);
+    assert_eq!(14, <Record<LittleEndian> as ByteView>::NUM_BYTES);
+    assert_eq!("u32", Record::<BigEndian>::LAYOUT.fields[0].ty);
+    assert_eq!(
+        FieldKind::Number(NumberType::U32, Endianness::Big),
+        Record::<BigEndian>::LAYOUT.fields[0].kind
+    );
+    assert_eq!(
+        FieldKind::Number(NumberType::U32, Endianness::Little),
+        Record::<LittleEndian>::LAYOUT.fields[0].kind
+    );
+    assert_eq!(
+        FieldKind::Number(NumberType::U16, Endianness::Big),
+        Record::<LittleEndian>::LAYOUT.fields[3].kind
+    );
+}
+
+#[test]
+fn test_owned_and_variable_size() {
+    let mut counters = Counters::<LittleEndian>::from_exact_vec(vec![0xFF, 0x00]).unwrap();
+    assert_eq!(256, counters.increment_count_wrapping());
+    assert_eq!(&[0x00, 0x01], counters.as_bytes());
+    assert_eq!(None, counters.total());
+
+    let (counters, rest) = Counters::<BigEndian>::split_vec(vec![0, 1, 0, 0, 0, 2, 3]).unwrap();
+    assert_eq!(1, counters.count());
+    assert_eq!(Some(2), counters.total());
+    assert_eq!(vec![3], rest);
+}
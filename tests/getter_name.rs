@@ -0,0 +1,59 @@
+byteview::byteview_ref! {
+    pub struct Header {
+        #[byteview(getter = "raw_name")]
+        _name: [u8; 4],
+        #[byteview(getter = "raw_len", len_of_rest)]
+        _len: u16be,
+    }
+}
+
+impl Header<'_> {
+    pub fn name(&self) -> &str {
+        std::str::from_utf8(self.raw_name()).unwrap()
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Status {
+        #[byteview(getter = "raw_flags", bitmap)]
+        _flags: [u8; 1],
+        #[byteview(getter = "raw_count", counter)]
+        _count: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Entry {
+        #[byteview(getter = "raw_kind", prefix_boundary)]
+        _kind: u8,
+        pub value: u16be,
+    }
+}
+
+#[test]
+fn test_getter_name() {
+    let bytes = b"NAME\x00\x02\xAA\xBB";
+    let (header, rest) = Header::split_slice_checked(bytes).unwrap();
+    assert_eq!(b"NAME", header.raw_name());
+    assert_eq!("NAME", header.name());
+    assert_eq!(2, header.raw_len());
+    assert_eq!(b"\xAA\xBB", rest);
+}
+
+#[test]
+fn test_owned_methods_use_getter_name() {
+    let mut status = Status::from_array([0b101, 7]);
+    assert_eq!(&[0b101], status.raw_flags());
+    assert_eq!(2, status.raw_flags_count_ones());
+    assert_eq!(Some(false), status.set_raw_flags_bit(1, true));
+    assert_eq!(3, status.raw_flags_count_ones());
+    assert_eq!(8, status.increment_raw_count_wrapping());
+    assert_eq!(8, status.raw_count());
+}
+
+#[test]
+fn test_prefix_uses_getter_name() {
+    let entry = Entry::from_array(b"\x07\x00\x01");
+    assert_eq!(7, entry.raw_kind());
+    assert_eq!(7, entry.prefix().raw_kind());
+}
@@ -0,0 +1,87 @@
+use byteview::{FieldInfo, LayoutChange, layout_diff};
+
+byteview::byteview_ref! {
+    struct HeaderV1 {
+        magic: [u8; 4],
+        index: u16be,
+        _: u8,
+        kind: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    struct HeaderV2 {
+        magic: [u8; 4],
+        index: u32be,
+        _: u8,
+        kind: u8,
+        flags: u8,
+    }
+}
+
+#[test]
+fn test_layout() {
+    let layout = HeaderV1::layout();
+    assert_eq!("HeaderV1", layout.name);
+    assert_eq!(HeaderV1::NUM_BYTES, layout.num_bytes);
+    let fields: Vec<_> = layout
+        .fields
+        .iter()
+        .map(|field| (field.name, field.offset, field.size, field.ty))
+        .collect();
+    assert_eq!(
+        vec![
+            ("magic", 0, 4, "[u8; 4]"),
+            ("index", 4, 2, "u16be"),
+            ("_", 6, 1, "u8"),
+            ("kind", 7, 1, "u8"),
+        ],
+        fields
+    );
+    assert_eq!(Some(7..8), layout.field("kind").map(FieldInfo::range));
+    assert_eq!(None, layout.field("_"));
+}
+
+#[test]
+fn test_layout_diff() {
+    let changes = layout_diff(&HeaderV1::layout(), &HeaderV2::layout());
+    assert_eq!(
+        vec![
+            LayoutChange::Resized {
+                name: "index",
+                old_size: 2,
+                new_size: 4
+            },
+            LayoutChange::TypeChanged {
+                name: "index",
+                old_ty: "u16be",
+                new_ty: "u32be"
+            },
+            LayoutChange::Moved {
+                name: "kind",
+                old_offset: 7,
+                new_offset: 9
+            },
+            LayoutChange::Added {
+                name: "flags",
+                offset: 10,
+                size: 1
+            },
+        ],
+        changes
+    );
+}
+
+#[test]
+fn test_layout_diff_removed() {
+    let changes = layout_diff(&HeaderV2::layout(), &HeaderV1::layout());
+    assert_eq!(
+        Some(&LayoutChange::Removed {
+            name: "flags",
+            offset: 10,
+            size: 1
+        }),
+        changes.last()
+    );
+    assert!(layout_diff(&HeaderV1::layout(), &HeaderV1::layout()).is_empty());
+}
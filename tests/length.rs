@@ -0,0 +1,94 @@
+use byteview::{ByteView, ParseError};
+
+byteview::byteview_ref! {
+    pub struct Packet {
+        pub kind: u8,
+        #[byteview(len_of_rest)]
+        pub payload_len: u16be,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct TruncatingPacket {
+        pub kind: u8,
+        #[byteview(len_of_rest, truncate_rest)]
+        pub payload_len: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Record {
+        #[byteview(len_includes_header, truncate_rest)]
+        pub total_len: u32be,
+        pub kind: u8,
+    }
+}
+
+#[test]
+fn test_len_of_rest() {
+    // Shorter than declared
+    let bytes = b"\x01\x00\x04abc";
+    assert_eq!(
+        Some(ParseError::LengthMismatch {
+            declared: 4,
+            available: 3
+        }),
+        Packet::split_slice_checked(bytes).err()
+    );
+
+    // Exact
+    let bytes = b"\x01\x00\x03abc";
+    let (packet, rest) = Packet::split_slice_checked(bytes).unwrap();
+    assert_eq!(3, packet.payload_len());
+    assert_eq!(b"abc", rest);
+
+    // Longer than declared
+    let bytes = b"\x01\x00\x02abc";
+    let (_, rest) = Packet::split_slice_checked(bytes).unwrap();
+    assert_eq!(b"abc", rest);
+    let (_, rest) = TruncatingPacket::split_slice_checked(bytes).unwrap();
+    assert_eq!(b"ab", rest);
+
+    // Too short for the struct itself
+    assert_eq!(
+        Some(ParseError::TooShort {
+            needed: 3,
+            available: 2
+        }),
+        Packet::split_slice_checked(b"\x01\x00").err()
+    );
+}
+
+#[test]
+fn test_len_includes_header() {
+    // Shorter than declared
+    let bytes = b"\x00\x00\x00\x09\x01abc";
+    assert_eq!(
+        Some(ParseError::LengthMismatch {
+            declared: 9,
+            available: 8
+        }),
+        Record::split_slice_checked(bytes).err()
+    );
+
+    // Exact
+    let bytes = b"\x00\x00\x00\x08\x01abc";
+    let (record, rest) = Record::split_slice_checked(bytes).unwrap();
+    assert_eq!(1, record.kind());
+    assert_eq!(b"abc", rest);
+
+    // Longer than declared
+    let bytes = b"\x00\x00\x00\x06\x01abc";
+    let (_, rest) = Record::split_slice_checked(bytes).unwrap();
+    assert_eq!(b"a", rest);
+
+    // Smaller than the struct itself
+    let bytes = b"\x00\x00\x00\x04\x01abc";
+    assert_eq!(
+        Some(ParseError::LengthTooSmall {
+            declared: 4,
+            minimum: 5
+        }),
+        Record::split_slice_checked(bytes).err()
+    );
+}
@@ -0,0 +1,66 @@
+use byteview::{ByteView, MagicBytes, ParseError};
+
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct LogHeader {
+        pub magic: [u8; 4] = *b"LOG1",
+        pub count: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    #[derive(Debug)]
+    pub struct ClassFile {
+        pub signature: [u8; 4] = *b"\xCA\xFE\xBA\xBE",
+        pub minor_version: u16be,
+        pub major_version: u16be,
+    }
+}
+
+#[test]
+fn test_checked() {
+    let (header, rest) = LogHeader::split_slice_checked(b"LOG1\x00\x02\xFF").unwrap();
+    assert_eq!(b"LOG1", header.magic());
+    assert_eq!(2, header.count());
+    assert_eq!(b"\xFF", rest);
+    assert_eq!(b"LOG1", LogHeader::MAGIC.bytes);
+    assert_eq!(Some(LogHeader::MAGIC), <LogHeader as ByteView>::MAGIC);
+
+    let error = LogHeader::split_slice_checked(b"LOG2\x00\x02").unwrap_err();
+    assert_eq!(
+        ParseError::MagicMismatch {
+            offset: 0,
+            expected: b"LOG1",
+            actual: MagicBytes::new(b"LOG2"),
+        },
+        error
+    );
+    assert_eq!(
+        "the bytes at offset 0 are [4c, 4f, 47, 32] instead of the magic bytes [4c, 4f, 47, 31]",
+        error.to_string()
+    );
+
+    assert!(ClassFile::split_slice_checked(b"\xCA\xFE\xBA\xBE\x00\x00\x00\x41").is_ok());
+    assert!(matches!(
+        ClassFile::split_slice_checked(b"\xCA\xFE\xBA\xBF\x00\x00\x00\x41"),
+        Err(ParseError::MagicMismatch { actual, .. }) if actual == *b"\xCA\xFE\xBA\xBF".as_slice()
+    ));
+}
+
+#[test]
+fn test_unchecked() {
+    let header = LogHeader::from_array(b"LOG2\x00\x02");
+    assert_eq!(b"LOG2", header.magic());
+    assert_eq!(2, header.count());
+
+    let class_file = ClassFile::from_array(*b"\xCA\xFE\xBA\xBE\x00\x00\x00\x41");
+    assert_eq!(0x41, class_file.major_version());
+}
+
+#[test]
+fn test_magic_bytes() {
+    let bytes = MagicBytes::new(&[7; 20]);
+    assert_eq!(&[7; MagicBytes::CAPACITY], bytes.as_bytes());
+    assert_eq!(b"", MagicBytes::default().as_bytes());
+    assert_eq!("[1, 2]", format!("{:?}", MagicBytes::new(&[1, 2])));
+}
@@ -0,0 +1,92 @@
+use byteview::{
+    DynView, Endianness, FieldKind, FieldSelector, NumberType, Patch, PythonFormatError, Value,
+};
+
+byteview::byteview_ref! {
+    pub struct Sample {
+        pub counter: u32me,
+        pub offset: i32me,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SampleOwned {
+        #[byteview(counter)]
+        pub counter: u32me,
+        pub offset: i32me,
+    }
+}
+
+byteview::byteview_bits! {
+    #[derive(Debug, Copy, Clone)]
+    pub struct Status: u32me {
+        pub high: 16,
+        pub low: 16,
+    }
+}
+
+#[test]
+fn test_get() {
+    let bytes = [0x01, 0x02, 0x03, 0x04, 0xFF, 0xFF, 0xFE, 0xFF];
+    let sample = Sample::from_array(&bytes);
+    assert_eq!(0x0201_0403, sample.counter());
+    assert_eq!(-2, sample.offset());
+}
+
+#[test]
+fn test_set() {
+    let mut bytes = [0x00, 0x00, 0xFF, 0xFF, 0, 0, 0, 0];
+    let mut patch = Patch::new();
+    patch.push(
+        0,
+        FieldSelector::name(Sample::LAYOUT, "offset"),
+        Value::Signed(-0x0001_0002),
+    );
+    patch.apply(&mut bytes).unwrap();
+    assert_eq!([0x00, 0x00, 0xFF, 0xFF, 0xFE, 0xFF, 0xFE, 0xFF], bytes);
+
+    // The counter carries from the low half into the high half.
+    let mut sample = SampleOwned::from_array(bytes);
+    assert_eq!(0x0001_0000, sample.increment_counter_wrapping());
+    assert_eq!([0x01, 0x00, 0x00, 0x00], sample.as_bytes()[..4]);
+    assert_eq!(0x0001_0000, sample.counter());
+    assert_eq!(-0x0001_0002, sample.offset());
+}
+
+#[test]
+fn test_bits() {
+    let status = Status::from_bytes([0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(0x0201, status.high());
+    assert_eq!(0x0403, status.low());
+    assert_eq!([0x01, 0x02, 0x03, 0x04], status.to_bytes());
+}
+
+#[test]
+fn test_layout() {
+    let bytes = [0x01, 0x02, 0x03, 0x04, 0xFF, 0xFF, 0xFE, 0xFF];
+    let sample = Sample::from_array(&bytes);
+    let field = Sample::layout().field("counter").unwrap();
+    assert_eq!(
+        FieldKind::Number(NumberType::U32, Endianness::Middle),
+        field.kind
+    );
+    assert_eq!("u32me", field.ty);
+    assert_eq!(
+        Some(Value::Unsigned(0x0201_0403)),
+        sample.field_value("counter")
+    );
+    assert_eq!(Some(Value::Signed(-2)), sample.field_value("offset"));
+    assert_eq!(
+        "Sample (8 bytes)\n\
+        \x20 0..4  counter  u32me  33620995\n\
+        \x20 4..8  offset   i32me  -2",
+        sample.dump()
+    );
+    assert_eq!(
+        Err(PythonFormatError::UnsupportedType {
+            field: "counter",
+            ty: "u32me"
+        }),
+        Sample::layout().python_struct_format()
+    );
+}
@@ -0,0 +1,186 @@
+use byteview::{ByteView, Endianness, FieldKind, MigrateError, Migration, NumberType, migrate};
+
+byteview::byteview_ref! {
+    pub struct HeaderV1 {
+        #[byteview(magic = b"H1")]
+        pub magic: [u8; 2],
+        pub version: u8,
+        pub count: u16be,
+        pub offset: i16le,
+        pub name: [u8; 4],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderV2 {
+        #[byteview(magic = b"H2")]
+        pub magic: [u8; 2],
+        pub version: u8,
+        pub flags: u8,
+        pub count: u32be,
+        pub offset: i64be,
+        pub name: [u8; 4],
+        pub checksum: u32le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderNarrow {
+        pub version: u8,
+        pub count: u8,
+        pub name: [u8; 4],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderFloat {
+        pub count: f32be,
+        pub offset: f64le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderNumericName {
+        pub name: u32be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderOptional {
+        pub version: u8,
+        pub count: u8,
+        pub name: [u8; 4],
+        #[byteview(optional, default = 0x1234)]
+        pub limit: u16be,
+        #[byteview(optional)]
+        pub extra: u8,
+    }
+}
+
+fn v1_bytes(count: u16) -> [u8; 11] {
+    let mut bytes = b"H1\x01".to_vec();
+    bytes.extend(count.to_be_bytes());
+    bytes.extend((-2i16).to_le_bytes());
+    bytes.extend(b"LOGS");
+    bytes.try_into().unwrap()
+}
+
+#[test]
+fn test_migrate_widens_fields() {
+    let bytes = v1_bytes(0xFFFF);
+    let v2 = migrate::<HeaderV1, HeaderV2>(&HeaderV1::from_array(&bytes)).unwrap();
+    assert_eq!(b"H2", v2.magic());
+    assert_eq!(1, v2.version());
+    assert_eq!(0xFFFF, v2.count());
+    assert_eq!(-2, v2.offset());
+    assert_eq!(b"LOGS", v2.name());
+    assert_eq!(0, v2.flags());
+    assert_eq!(0, v2.checksum());
+}
+
+#[test]
+fn test_migrate_optional_defaults() {
+    let narrow = HeaderNarrow::from_array([1, 7, b'L', b'O', b'G', b'S']);
+    let optional = migrate::<HeaderNarrow, HeaderOptional>(&narrow).unwrap();
+    assert_eq!(HeaderOptional::NUM_BYTES, optional.as_bytes().len());
+    assert_eq!(
+        (1, 7, b"LOGS"),
+        (optional.version(), optional.count(), optional.name())
+    );
+    assert_eq!(0x1234, optional.limit());
+    assert_eq!(Some(0), optional.extra());
+
+    // Optional fields missing from a short record get their default too.
+    let bytes = [1, 7, b'L', b'O', b'G', b'S'];
+    let (short, _) = HeaderOptional::split_slice(&bytes).unwrap();
+    assert!(!short.limit_present());
+    let long = migrate::<HeaderOptional, HeaderOptional>(&short).unwrap();
+    assert_eq!(0x1234, long.limit());
+    assert_eq!(Some(0), long.extra());
+
+    let bytes = [1, 7, b'L', b'O', b'G', b'S', 0, 5, 9];
+    let (full, _) = HeaderOptional::split_slice(&bytes).unwrap();
+    let copied = migrate::<HeaderOptional, HeaderOptional>(&full).unwrap();
+    assert_eq!(bytes, *copied.as_bytes());
+}
+
+#[test]
+fn test_migrate_to_float() {
+    let bytes = v1_bytes(0xFFFF);
+    let migration = Migration::<HeaderV1, HeaderFloat>::new()
+        .allow_lossy("magic")
+        .allow_lossy("version")
+        .allow_lossy("name");
+    let floats = migration.migrate(&HeaderV1::from_array(&bytes)).unwrap();
+    assert_eq!(65535.0, floats.count());
+    assert_eq!(-2.0, floats.offset());
+}
+
+#[test]
+fn test_migrate_removed_field() {
+    let bytes = v1_bytes(7);
+    let result = migrate::<HeaderV1, HeaderNarrow>(&HeaderV1::from_array(&bytes));
+    assert_eq!(Some(MigrateError::Removed { field: "magic" }), result.err());
+}
+
+#[test]
+fn test_migrate_lossy() {
+    let bytes = v1_bytes(7);
+    let migration = Migration::<HeaderV1, HeaderNarrow>::new()
+        .allow_lossy("magic")
+        .allow_lossy("offset");
+    let result = migration.migrate(&HeaderV1::from_array(&bytes));
+    assert_eq!(
+        Some(MigrateError::Lossy {
+            field: "count",
+            from: FieldKind::Number(NumberType::U16, Endianness::Big),
+            to: FieldKind::U8,
+        }),
+        result.err()
+    );
+
+    let migration = migration.allow_lossy("count");
+    let narrow = migration.migrate(&HeaderV1::from_array(&bytes)).unwrap();
+    assert_eq!(
+        (1, 7, b"LOGS"),
+        (narrow.version(), narrow.count(), narrow.name())
+    );
+
+    let bytes = v1_bytes(0x100);
+    let result = migration.migrate(&HeaderV1::from_array(&bytes));
+    assert_eq!(
+        Some(MigrateError::DoesNotFit {
+            field: "count",
+            to: FieldKind::U8,
+        }),
+        result.err()
+    );
+}
+
+#[test]
+fn test_migrate_type_mismatch() {
+    let bytes = v1_bytes(7);
+    let result = Migration::<HeaderV1, HeaderNumericName>::new()
+        .allow_lossy("magic")
+        .allow_lossy("version")
+        .allow_lossy("count")
+        .allow_lossy("offset")
+        .migrate(&HeaderV1::from_array(&bytes));
+    assert_eq!(
+        Some(MigrateError::TypeMismatch {
+            field: "name",
+            from: FieldKind::Bytes,
+            to: FieldKind::Number(NumberType::U32, Endianness::Big),
+        }),
+        result.err()
+    );
+}
+
+#[test]
+#[should_panic(expected = "has a field named `missing`")]
+fn test_migrate_unknown_lossy_field() {
+    let bytes = v1_bytes(7);
+    let _ = Migration::<HeaderV1, HeaderV2>::new()
+        .allow_lossy("missing")
+        .migrate(&HeaderV1::from_array(&bytes));
+}
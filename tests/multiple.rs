@@ -0,0 +1,62 @@
+byteview::byteview_ref! {
+    /// A version number.
+    #[byteview(endian = "little")]
+    pub struct Version {
+        pub major: u16,
+        pub minor: u16,
+    }
+
+    /// A header that contains a version.
+    pub struct Header {
+        pub kind: u8,
+        pub version: Version<'a>,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Point {
+        pub x: i16be,
+        pub y: i16be,
+    }
+
+    pub struct Line {
+        pub start: Point,
+        pub end: Point,
+    }
+}
+
+byteview::byteview! {
+    pub struct Ping {
+        pub id: u8,
+    }
+
+    pub struct Pong {
+        pub id: u8,
+        pub delay: u16be,
+    }
+}
+
+#[test]
+fn test_borrowed() {
+    let header = Header::from_array(b"\x2A\x01\x00\x02\x00");
+    assert_eq!(42, header.kind());
+    assert_eq!(1, header.version().major());
+    assert_eq!(2, header.version().minor());
+    assert_eq!(4, Version::NUM_BYTES);
+}
+
+#[test]
+fn test_owned() {
+    let line = Line::from_array(*b"\x00\x01\xFF\xFF\x00\x03\x00\x04");
+    assert_eq!(1, line.start().x());
+    assert_eq!(-1, line.start().y());
+    assert_eq!(3, line.end().x());
+    assert_eq!(4, line.end().y());
+}
+
+#[test]
+fn test_borrowed_and_owned() {
+    assert_eq!(7, PingRef::from_array(&[7]).to_owned().id());
+    let pong = PongOwned::from_array([7, 0, 9]);
+    assert_eq!(9, pong.as_ref().delay());
+}
@@ -0,0 +1,77 @@
+use byteview::ValidateError;
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub version: u8,
+        #[byteview(must_be_zero)]
+        _: u8,
+        pub count: u16be,
+        #[byteview(must_be_zero)]
+        pub reserved: [u8; 3],
+        #[byteview(optional, must_be_zero)]
+        pub extension: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Entry {
+        pub kind: u8,
+        #[byteview(must_be_zero)]
+        pub flags: u8,
+    }
+}
+
+#[test]
+fn test_valid() {
+    let (header, _) = Header::split_slice(b"\x01\x00\x00\x02\x00\x00\x00").unwrap();
+    assert_eq!(Ok(()), header.validate());
+    let header = Header::from_exact_slice(b"\x01\x00\x00\x02\x00\x00\x00\x00").unwrap();
+    assert_eq!(Ok(()), header.validate());
+}
+
+#[test]
+fn test_nonzero_reserved_byte() {
+    // The unchecked constructors accept the bytes, so the nonconforming file can still be read.
+    let header = Header::from_exact_slice(b"\x01\x00\x00\x02\x00\x04\x00").unwrap();
+    assert_eq!(2, header.count());
+    assert_eq!(&[0, 4, 0], header.reserved());
+    let error = header.validate().unwrap_err();
+    assert_eq!(
+        ValidateError::NotZero {
+            field: "reserved",
+            offset: 4
+        },
+        error
+    );
+    assert_eq!(
+        "the field `reserved` at offset 4 must be zero but is not",
+        error.to_string()
+    );
+
+    // The first violation is reported.
+    let header = Header::from_exact_slice(b"\x01\xFF\x00\x02\x00\x04\x00\x01").unwrap();
+    assert_eq!(
+        Err(ValidateError::NotZero {
+            field: "_",
+            offset: 1
+        }),
+        header.validate()
+    );
+    let header = Header::from_exact_slice(b"\x01\x00\x00\x02\x00\x00\x00\x01").unwrap();
+    assert_eq!(
+        Err(ValidateError::NotZero {
+            field: "extension",
+            offset: 7
+        }),
+        header.validate()
+    );
+}
+
+#[test]
+fn test_normalize() {
+    let mut entry = Entry::from_array([7, 0x80]);
+    assert!(entry.validate().is_err());
+    entry.normalize();
+    assert_eq!(Ok(()), entry.validate());
+    assert_eq!(&[7, 0], entry.as_bytes());
+}
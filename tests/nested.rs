@@ -0,0 +1,142 @@
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct Version {
+        pub major: u8,
+        pub minor: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct DeviceId {
+        pub vendor: u16be,
+        pub product: u16be,
+        pub version: Version<'a>,
+        pub serial: [u8; 6],
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub magic: [u8; 2],
+        pub device: DeviceId<'a>,
+        pub flags: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct VersionOwned {
+        pub major: u8,
+        pub minor: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct DeviceIdOwned {
+        pub vendor: u16be,
+        pub product: u16be,
+        pub version: VersionOwned,
+        pub serial: [u8; 6],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderOwned {
+        pub magic: [u8; 2],
+        pub device: DeviceIdOwned,
+        pub device_ref: DeviceId<'_>,
+        pub flags: u8,
+    }
+}
+
+const BYTES: [u8; 15] = *b"HD\x12\x34\x56\x78\x01\x02SERIAL\x80";
+
+#[test]
+fn test_nested_ref() {
+    assert_eq!(12, DeviceId::NUM_BYTES);
+    assert_eq!(15, Header::NUM_BYTES);
+    assert_eq!(14, Header::LAYOUT.field("flags").unwrap().offset);
+    assert_eq!("DeviceId<'a>", Header::LAYOUT.field("device").unwrap().ty);
+
+    let header = Header::from_array(&BYTES);
+    let device = header.device();
+    assert_eq!(b"HD", header.magic());
+    assert_eq!(0x1234, device.vendor());
+    assert_eq!(0x5678, device.product());
+    assert_eq!(1, device.version().major());
+    assert_eq!(2, device.version().minor());
+    assert_eq!(b"SERIAL", device.serial());
+    assert_eq!(0x80, header.flags());
+}
+
+// The nested view borrows the bytes of the outer struct, not the outer struct itself.
+fn device_of<'a>(bytes: &'a [u8; 15]) -> DeviceId<'a> {
+    Header::from_array(bytes).device()
+}
+
+#[test]
+fn test_nested_borrow() {
+    let device = device_of(&BYTES);
+    assert_eq!(&BYTES[2..14], device.as_bytes());
+}
+
+#[test]
+fn test_nested_owned() {
+    let mut bytes = [0; 27];
+    bytes[..14].copy_from_slice(&BYTES[..14]);
+    bytes[14..26].copy_from_slice(&BYTES[2..14]);
+    bytes[26] = 0x80;
+    assert_eq!(27, HeaderOwned::NUM_BYTES);
+
+    let header = HeaderOwned::from_array(bytes);
+    let device = header.device();
+    assert_eq!(0x1234, device.vendor());
+    assert_eq!(2, device.version().minor());
+    assert_eq!(b"SERIAL", device.serial());
+    assert_eq!(0x5678, header.device_ref().product());
+    assert_eq!(1, header.device_ref().version().major());
+    assert_eq!(0x80, header.flags());
+}
+
+byteview::byteview_ref! {
+    pub struct VersionList {
+        pub count: u8,
+        pub versions: [Version<'a>; 3],
+        pub flags: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct VersionListOwned {
+        pub count: u8,
+        pub versions: [VersionOwned; 3],
+        pub flags: u8,
+    }
+}
+
+#[test]
+fn test_nested_array() {
+    assert_eq!(8, VersionList::NUM_BYTES);
+    assert_eq!(7, VersionList::LAYOUT.field("flags").unwrap().offset);
+    assert_eq!("[Version<'a>; 3]", VersionList::LAYOUT.fields[1].ty);
+    let bytes = [3, 1, 0, 1, 2, 2, 0, 0xFF];
+
+    let list = VersionList::from_array(&bytes);
+    let versions = list.versions();
+    assert_eq!(3, versions.len());
+    assert_eq!(Some(2), versions.get(1).map(|version| version.minor()));
+    assert!(versions.get(3).is_none());
+    let majors: Vec<_> = versions.iter().map(|version| version.major()).collect();
+    assert_eq!([1, 1, 2], majors.as_slice());
+    assert_eq!(&bytes[1..7], versions.as_bytes());
+    assert_eq!(0xFF, list.flags());
+
+    let list = VersionListOwned::from_array(bytes);
+    let minors: Vec<_> = list
+        .versions()
+        .iter()
+        .map(|version| version.minor())
+        .collect();
+    assert_eq!([0, 2, 0], minors.as_slice());
+    assert_eq!(0xFF, list.flags());
+}
@@ -0,0 +1,65 @@
+byteview::byteview_owned! {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct EntryOwned {
+        pub index: u32be,
+        _: [u8; 2],
+        #[byteview(reserved)]
+        pub flags: u8,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Entry {
+        pub index: u32be,
+        _: [u8; 2],
+        #[byteview(reserved)]
+        pub flags: u8,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Short {
+        pub a: u8,
+        #[byteview(optional)]
+        _: u16be,
+    }
+}
+
+#[test]
+fn test_normalize() {
+    let mut a = EntryOwned::from_array([0, 0, 0, 1, 0xAB, 0xCD, 0x80, 7]);
+    let b = EntryOwned::from_array([0, 0, 0, 1, 0x00, 0x11, 0x00, 7]);
+    assert_ne!(a.as_bytes(), b.as_bytes());
+    assert_eq!(a.normalized().as_bytes(), b.normalized().as_bytes());
+
+    a.normalize();
+    assert_eq!(&[0, 0, 0, 1, 0, 0, 0, 7], a.as_bytes());
+    assert_eq!(a, b.normalized());
+    assert_eq!(1, a.index());
+    assert_eq!(7, a.kind());
+}
+
+#[test]
+fn test_normalized_ref() {
+    let a = Entry::from_array(&[0, 0, 0, 1, 0xAB, 0xCD, 0x80, 7]);
+    let b = Entry::from_array(&[0, 0, 0, 1, 0x00, 0x11, 0x00, 7]);
+    assert_eq!(a.normalized(), b.normalized());
+    assert_eq!(vec![0, 0, 0, 1, 0, 0, 0, 7], a.normalized());
+    assert_eq!(
+        EntryOwned::from_exact_vec(a.normalized()).unwrap(),
+        EntryOwned::from_array([0, 0, 0, 1, 0, 0, 0, 7])
+    );
+}
+
+#[test]
+fn test_normalize_missing_optional() {
+    let mut short = Short::from_exact_vec(vec![5]).unwrap();
+    short.normalize();
+    assert_eq!(&[5], short.as_bytes());
+
+    let mut long = Short::from_exact_vec(vec![5, 0xFF, 0xFF]).unwrap();
+    long.normalize();
+    assert_eq!(&[5, 0, 0], long.as_bytes());
+}
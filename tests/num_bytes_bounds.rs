@@ -0,0 +1,89 @@
+use byteview::{ByteView, Records};
+
+byteview::byteview_ref! {
+    pub struct Fixed {
+        pub kind: u8,
+        pub value: u32be,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Payload {
+        pub kind: u8,
+        #[byteview(len_of_rest)]
+        pub payload_len: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct PayloadIncludingHeader {
+        pub kind: u8,
+        #[byteview(len_includes_header)]
+        pub total_len: u16le,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(record_len = "record_len")]
+    pub struct Record {
+        pub kind: u8,
+        pub record_len: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Versioned {
+        pub version: u8,
+        pub a: u16be,
+        #[byteview(optional)]
+        pub b: u32be,
+        #[byteview(optional)]
+        pub c: u8,
+    }
+}
+
+#[test]
+fn test_fixed() {
+    assert_eq!(5, Fixed::NUM_BYTES);
+    assert_eq!(5, Fixed::MIN_NUM_BYTES);
+    assert_eq!(5, Fixed::MAX_NUM_BYTES);
+    assert_eq!(5, <Fixed as ByteView>::MIN_NUM_BYTES);
+    assert_eq!(5, <Fixed as ByteView>::MAX_NUM_BYTES);
+}
+
+#[test]
+fn test_length_prefixed() {
+    assert_eq!(2, Payload::MIN_NUM_BYTES);
+    assert_eq!(2 + 255, Payload::MAX_NUM_BYTES);
+    assert_eq!(3, PayloadIncludingHeader::MIN_NUM_BYTES);
+    assert_eq!(0xFFFF, PayloadIncludingHeader::MAX_NUM_BYTES);
+    assert_eq!(0xFFFF, <PayloadIncludingHeader as ByteView>::MAX_NUM_BYTES);
+}
+
+#[test]
+fn test_record_len() {
+    assert_eq!(2, Record::NUM_BYTES);
+    assert_eq!(2, Record::MIN_NUM_BYTES);
+    assert_eq!(255, Record::MAX_NUM_BYTES);
+}
+
+#[test]
+fn test_optional() {
+    assert_eq!(8, Versioned::NUM_BYTES);
+    assert_eq!(3, Versioned::MIN_NUM_BYTES);
+    assert_eq!(8, Versioned::MAX_NUM_BYTES);
+    assert_eq!(3, <Versioned as ByteView>::MIN_NUM_BYTES);
+}
+
+#[test]
+fn test_records_size_hint() {
+    let bytes = [1, 2, 2, 2, 3, 3, 0xAA];
+    let records = Records::<Record>::new(&bytes);
+    assert_eq!((1, Some(4)), records.size_hint());
+    assert_eq!(3, records.count());
+
+    let mut records = Records::<Record>::new(&bytes[..1]);
+    assert_eq!((1, Some(1)), records.size_hint());
+    assert!(records.next().unwrap().is_err());
+    assert_eq!((0, Some(0)), records.size_hint());
+}
@@ -0,0 +1,118 @@
+byteview::byteview_ref! {
+    pub struct Small {
+        pub count: u8,
+        pub values: [u32be; 4],
+        pub deltas: [i16le; 3],
+        pub signed: [i8; 2],
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct BlockTable {
+        pub num_blocks: u32be,
+        #[byteview(iter_only)]
+        pub offsets: [u32be; 1024],
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct SectionTable {
+        pub offsets: [u32be; 8],
+        pub count: u16le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SectionTableOwned {
+        pub offsets: [u32be; 8],
+        pub count: u16le,
+    }
+}
+
+#[test]
+fn test_small() {
+    let mut bytes = vec![4];
+    for value in [1u32, 0x0102_0304, u32::MAX, 7] {
+        bytes.extend(value.to_be_bytes());
+    }
+    for delta in [-1i16, 300, -300] {
+        bytes.extend(delta.to_le_bytes());
+    }
+    bytes.extend([0xFF, 0x7F]);
+    assert_eq!(Small::NUM_BYTES, bytes.len());
+    let small = Small::from_exact_slice(&bytes).unwrap();
+
+    assert_eq!([1, 0x0102_0304, u32::MAX, 7], small.values());
+    assert_eq!(
+        small.values().to_vec(),
+        small.values_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(4, small.values_iter().len());
+    assert_eq!(Some(u32::MAX), small.values_at(2));
+    assert_eq!(None, small.values_at(4));
+
+    assert_eq!([-1, 300, -300], small.deltas());
+    assert_eq!(
+        small.deltas().to_vec(),
+        small.deltas_iter().collect::<Vec<_>>()
+    );
+    assert_eq!([-1, 127], small.signed());
+    assert_eq!(Some(127), small.signed_at(1));
+
+    assert_eq!("[u32be; 4]", Small::LAYOUT.fields[1].ty);
+    assert_eq!(16, Small::LAYOUT.fields[1].size);
+}
+
+#[test]
+fn test_large() {
+    let mut bytes = 1024u32.to_be_bytes().to_vec();
+    for i in 0..1024u32 {
+        bytes.extend((i * 16).to_be_bytes());
+    }
+    let table = BlockTable::from_exact_slice(&bytes).unwrap();
+    assert_eq!(1024, table.offsets_iter().len());
+    assert_eq!(
+        (0..1024).map(|i| i * 16).sum::<u32>(),
+        table.offsets_iter().sum()
+    );
+    assert!(table.offsets_iter().is_sorted());
+    assert_eq!(Some(1023 * 16), table.offsets_at(1023));
+    assert_eq!(None, table.offsets_at(1024));
+
+    // Binary search for the block containing an offset.
+    let (mut low, mut high) = (0, 1024);
+    while high - low > 1 {
+        let mid = (low + high) / 2;
+        if table.offsets_at(mid).unwrap() <= 500 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    assert_eq!(31, low);
+}
+
+#[test]
+fn test_trailing_field() {
+    assert_eq!(34, SectionTable::NUM_BYTES);
+    assert_eq!(32, SectionTable::LAYOUT.field("count").unwrap().offset);
+    let offsets = [0, 0x40, 0x1000, 0x0102_0304, 0x8000_0000, 7, 8, u32::MAX];
+    let mut bytes = offsets
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<_>>();
+    bytes.extend(8u16.to_le_bytes());
+    let bytes: [u8; 34] = bytes.try_into().unwrap();
+
+    let table = SectionTable::from_array(&bytes);
+    assert_eq!(offsets, table.offsets());
+    assert_eq!(offsets.to_vec(), table.offsets_iter().collect::<Vec<_>>());
+    assert_eq!(Some(u32::MAX), table.offsets_at(7));
+    assert_eq!(8, table.count());
+
+    let table = SectionTableOwned::from_array(bytes);
+    assert_eq!(offsets, table.offsets());
+    assert_eq!(Some(0x0102_0304), table.offsets_at(3));
+    assert_eq!(None, table.offsets_at(8));
+    assert_eq!(8, table.count());
+}
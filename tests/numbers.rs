@@ -0,0 +1,401 @@
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct VersionRef {
+        pub length: u16be,
+        _version: u16be,
+        pub versions: [u16be; 2],
+    }
+}
+
+byteview::byteview_owned! {
+    #[derive(Debug)]
+    pub struct VersionOwned {
+        pub length: u16be,
+        _version: u16be,
+        pub versions: [u16be; 2],
+    }
+}
+
+#[test]
+fn test_u16be() {
+    assert_eq!(8, VersionRef::NUM_BYTES);
+    assert_eq!(8, VersionOwned::NUM_BYTES);
+    let bytes = b"\x01\x02\xFF\xFE\x00\x01\x80\x00";
+
+    let version = VersionRef::from_array(bytes);
+    assert_eq!(0x0102, version.length());
+    assert_eq!(0xFFFE, version._version());
+    assert_eq!([1, 0x8000], version.versions());
+
+    let version = VersionOwned::from_array(bytes.to_owned());
+    assert_eq!(0x0102, version.length());
+    assert_eq!(0xFFFE, version._version());
+    assert_eq!([1, 0x8000], version.versions());
+
+    let (version, rest) = VersionRef::split_slice(b"\x00\x2A\x00\x00\x00\x00\x00\x00\xEE").unwrap();
+    assert_eq!(42, version.length());
+    assert_eq!(b"\xEE", rest);
+    assert!(VersionRef::split_slice(&bytes[..7]).is_none());
+}
+
+byteview::byteview_ref! {
+    pub struct SectionRef {
+        pub kind: u8,
+        pub offset: u64be,
+        pub name: [u8; 16],
+        pub size: u64be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SectionOwned {
+        pub kind: u8,
+        pub offset: u64be,
+        pub name: [u8; 16],
+        pub size: u64be,
+    }
+}
+
+#[test]
+fn test_u64be() {
+    assert_eq!(33, SectionRef::NUM_BYTES);
+    assert_eq!(33, SectionOwned::NUM_BYTES);
+    let mut bytes = vec![3];
+    bytes.extend(0x0102_0304_0506_0708u64.to_be_bytes());
+    bytes.extend(b"section name\x00\x00\x00\x00");
+    bytes.extend(u64::MAX.to_be_bytes());
+    let bytes: [u8; 33] = bytes.try_into().unwrap();
+
+    let section = SectionRef::from_array(&bytes);
+    assert_eq!(3, section.kind());
+    assert_eq!(0x0102_0304_0506_0708, section.offset());
+    assert_eq!(b"section name\x00\x00\x00\x00", section.name());
+    assert_eq!(u64::MAX, section.size());
+
+    let section = SectionOwned::from_array(bytes);
+    assert_eq!(3, section.kind());
+    assert_eq!(0x0102_0304_0506_0708, section.offset());
+    assert_eq!(b"section name\x00\x00\x00\x00", section.name());
+    assert_eq!(u64::MAX, section.size());
+
+    let offsets: Vec<_> = SectionRef::LAYOUT
+        .fields
+        .iter()
+        .map(|field| field.offset)
+        .collect();
+    assert_eq!([0, 1, 9, 25], offsets.as_slice());
+}
+
+byteview::byteview_ref! {
+    pub struct MixedRef {
+        pub big: u32be,
+        pub little: u32le,
+        pub short: u16le,
+        pub long: u64le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct MixedOwned {
+        pub big: u32be,
+        pub little: u32le,
+        pub short: u16le,
+        pub long: u64le,
+    }
+}
+
+#[test]
+fn test_little_endian() {
+    let bytes = b"\x01\x02\x03\x04\x01\x02\x03\x04\x01\x02\x01\x02\x03\x04\x05\x06\x07\x08";
+
+    let mixed = MixedRef::from_array(bytes);
+    assert_eq!(0x0102_0304, mixed.big());
+    assert_eq!(0x0403_0201, mixed.little());
+    assert_eq!(0x0201, mixed.short());
+    assert_eq!(0x0807_0605_0403_0201, mixed.long());
+
+    let mixed = MixedOwned::from_array(bytes.to_owned());
+    assert_eq!(0x0102_0304, mixed.big());
+    assert_eq!(0x0403_0201, mixed.little());
+    assert_eq!(0x0201, mixed.short());
+    assert_eq!(0x0807_0605_0403_0201, mixed.long());
+}
+
+byteview::byteview_ref! {
+    pub struct TelemetryRef {
+        pub delta: i8,
+        pub temperature: i16be,
+        pub offset: i32be,
+        pub total: i64be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct TelemetryOwned {
+        pub delta: i8,
+        pub temperature: i16be,
+        pub offset: i32be,
+        pub total: i64be,
+    }
+}
+
+#[test]
+fn test_signed() {
+    let mut bytes = vec![0x80, 0xFF, 0xFE];
+    bytes.extend((-100_000i32).to_be_bytes());
+    bytes.extend(i64::MIN.to_be_bytes());
+    let bytes: [u8; 15] = bytes.try_into().unwrap();
+
+    let telemetry = TelemetryRef::from_array(&bytes);
+    assert_eq!(-128, telemetry.delta());
+    assert_eq!(-2, telemetry.temperature());
+    assert_eq!(-100_000, telemetry.offset());
+    assert_eq!(i64::MIN, telemetry.total());
+
+    let telemetry = TelemetryOwned::from_array(bytes);
+    assert_eq!(-128, telemetry.delta());
+    assert_eq!(-2, telemetry.temperature());
+    assert_eq!(-100_000, telemetry.offset());
+    assert_eq!(i64::MIN, telemetry.total());
+}
+
+byteview::byteview_ref! {
+    pub struct CalibrationRef {
+        pub gain: f32be,
+        pub offset: f64be,
+        pub scale: f32le,
+        pub bias: f64le,
+        pub count: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct CalibrationOwned {
+        pub gain: f32be,
+        pub offset: f64be,
+        pub scale: f32le,
+        pub bias: f64le,
+        pub count: u8,
+    }
+}
+
+#[test]
+fn test_float() {
+    assert_eq!(25, CalibrationRef::NUM_BYTES);
+    assert_eq!(24, CalibrationRef::LAYOUT.field("count").unwrap().offset);
+    // A signaling NaN with a payload, and negative zero.
+    let nan32 = 0x7FA0_1234u32;
+    let nan64 = 0xFFF4_0000_DEAD_BEEFu64;
+    let mut bytes = Vec::new();
+    bytes.extend(nan32.to_be_bytes());
+    bytes.extend((-0.0f64).to_be_bytes());
+    bytes.extend((-0.0f32).to_le_bytes());
+    bytes.extend(nan64.to_le_bytes());
+    bytes.push(7);
+    let bytes: [u8; 25] = bytes.try_into().unwrap();
+
+    let calibration = CalibrationRef::from_array(&bytes);
+    assert_eq!(nan32, calibration.gain().to_bits());
+    assert_eq!((-0.0f64).to_bits(), calibration.offset().to_bits());
+    assert_eq!((-0.0f32).to_bits(), calibration.scale().to_bits());
+    assert_eq!(nan64, calibration.bias().to_bits());
+    assert_eq!(7, calibration.count());
+
+    let calibration = CalibrationOwned::from_array(bytes);
+    assert_eq!(nan32, calibration.gain().to_bits());
+    assert_eq!((-0.0f64).to_bits(), calibration.offset().to_bits());
+    assert_eq!((-0.0f32).to_bits(), calibration.scale().to_bits());
+    assert_eq!(nan64, calibration.bias().to_bits());
+    assert_eq!(7, calibration.count());
+
+    let bytes = 1.5f32.to_be_bytes().repeat(7);
+    let (calibration, _) = CalibrationRef::split_slice(&bytes).unwrap();
+    assert_eq!(1.5, calibration.gain());
+}
+
+byteview::byteview_ref! {
+    pub struct SampleRef {
+        pub length: u24be,
+        pub rate: u24le,
+        pub delta: i24be,
+        pub deltas: [i24le; 2],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SampleOwned {
+        pub length: u24be,
+        pub rate: u24le,
+        pub delta: i24be,
+        pub deltas: [i24le; 2],
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Only24 {
+        pub value: u24be,
+    }
+}
+
+#[test]
+fn test_24_bit() {
+    assert_eq!(3, Only24::NUM_BYTES);
+    assert_eq!(0xFF_FFFE, Only24::from_array(&[0xFF, 0xFF, 0xFE]).value());
+    assert_eq!("u24be", Only24::LAYOUT.fields[0].ty);
+
+    assert_eq!(15, SampleRef::NUM_BYTES);
+    let bytes = b"\x01\x02\x03\x01\x02\x03\xFF\xFF\xFE\x00\x00\x80\xFF\xFF\x7F";
+
+    let sample = SampleRef::from_array(bytes);
+    assert_eq!(0x01_0203, sample.length());
+    assert_eq!(0x03_0201, sample.rate());
+    assert_eq!(-2, sample.delta());
+    assert_eq!([-0x80_0000, 0x7F_FFFF], sample.deltas());
+
+    let sample = SampleOwned::from_array(bytes.to_owned());
+    assert_eq!(0x01_0203, sample.length());
+    assert_eq!(0x03_0201, sample.rate());
+    assert_eq!(-2, sample.delta());
+    assert_eq!([-0x80_0000, 0x7F_FFFF], sample.deltas());
+}
+
+byteview::byteview_ref! {
+    pub struct FrameRef {
+        pub timestamp: u48be,
+        pub length: u16be,
+        pub delta: i48le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct FrameOwned {
+        pub timestamp: u48be,
+        pub length: u16be,
+        pub delta: i48le,
+    }
+}
+
+#[test]
+fn test_48_bit() {
+    assert_eq!(14, FrameRef::NUM_BYTES);
+    assert_eq!(6, FrameRef::LAYOUT.field("length").unwrap().offset);
+    let bytes = b"\xFF\xFF\xFF\xFF\xFF\xFF\x01\x02\xFE\xFF\xFF\xFF\xFF\xFF";
+
+    let frame = FrameRef::from_array(bytes);
+    assert_eq!(0xFFFF_FFFF_FFFF, frame.timestamp());
+    assert_eq!(0x0102, frame.length());
+    assert_eq!(-2, frame.delta());
+
+    let frame = FrameOwned::from_array(bytes.to_owned());
+    assert_eq!(0xFFFF_FFFF_FFFF, frame.timestamp());
+    assert_eq!(0x0102, frame.length());
+    assert_eq!(-2, frame.delta());
+}
+
+byteview::byteview_ref! {
+    pub struct NonceRef {
+        pub version: u8,
+        pub nonce: u128be,
+        pub counter: u128le,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct NonceOwned {
+        pub version: u8,
+        pub nonce: u128be,
+        pub counter: u128le,
+    }
+}
+
+#[test]
+fn test_128_bit() {
+    assert_eq!(33, NonceRef::NUM_BYTES);
+    assert_eq!(17, NonceRef::LAYOUT.field("counter").unwrap().offset);
+    let value = 0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10u128;
+    let mut bytes = vec![2];
+    bytes.extend(value.to_be_bytes());
+    bytes.extend(value.to_le_bytes());
+    let bytes: [u8; 33] = bytes.try_into().unwrap();
+
+    let nonce = NonceRef::from_array(&bytes);
+    assert_eq!(2, nonce.version());
+    assert_eq!(value, nonce.nonce());
+    assert_eq!(value, nonce.counter());
+
+    let nonce = NonceOwned::from_array(bytes);
+    assert_eq!(2, nonce.version());
+    assert_eq!(value, nonce.nonce());
+    assert_eq!(value, nonce.counter());
+}
+
+byteview::byteview_ref! {
+    pub struct NativeRef {
+        pub short: u16ne,
+        pub word: u32ne,
+        pub long: u64ne,
+        pub delta: i16ne,
+        pub offset: i32ne,
+        pub total: i64ne,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct NativeOwned {
+        pub short: u16ne,
+        pub word: u32ne,
+        pub long: u64ne,
+        pub delta: i16ne,
+        pub offset: i32ne,
+        pub total: i64ne,
+    }
+}
+
+#[test]
+fn test_native_endian() {
+    assert_eq!(28, NativeRef::NUM_BYTES);
+    let offsets: Vec<_> = NativeRef::LAYOUT
+        .fields
+        .iter()
+        .map(|field| field.offset)
+        .collect();
+    assert_eq!([0, 2, 6, 14, 16, 20], offsets.as_slice());
+    let mut bytes = Vec::new();
+    bytes.extend(0x0102u16.to_ne_bytes());
+    bytes.extend(0x0102_0304u32.to_ne_bytes());
+    bytes.extend(0x0102_0304_0506_0708u64.to_ne_bytes());
+    bytes.extend((-2i16).to_ne_bytes());
+    bytes.extend((-100_000i32).to_ne_bytes());
+    bytes.extend(i64::MIN.to_ne_bytes());
+    let bytes: [u8; 28] = bytes.try_into().unwrap();
+
+    let native = NativeRef::from_array(&bytes);
+    assert_eq!(0x0102, native.short());
+    assert_eq!(0x0102_0304, native.word());
+    assert_eq!(0x0102_0304_0506_0708, native.long());
+    assert_eq!(-2, native.delta());
+    assert_eq!(-100_000, native.offset());
+    assert_eq!(i64::MIN, native.total());
+
+    let native = NativeOwned::from_array(bytes);
+    assert_eq!(0x0102, native.short());
+    assert_eq!(0x0102_0304, native.word());
+    assert_eq!(0x0102_0304_0506_0708, native.long());
+    assert_eq!(-2, native.delta());
+    assert_eq!(-100_000, native.offset());
+    assert_eq!(i64::MIN, native.total());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_roundtrip() {
+    byteview::testing::RoundTrip::<VersionOwned>::new().check_with_ref::<VersionRef>();
+    byteview::testing::RoundTrip::<SectionOwned>::new().check_with_ref::<SectionRef>();
+    byteview::testing::RoundTrip::<MixedOwned>::new().check_with_ref::<MixedRef>();
+    byteview::testing::RoundTrip::<TelemetryOwned>::new().check_with_ref::<TelemetryRef>();
+    byteview::testing::RoundTrip::<CalibrationOwned>::new().check_with_ref::<CalibrationRef>();
+    byteview::testing::RoundTrip::<SampleOwned>::new().check_with_ref::<SampleRef>();
+    byteview::testing::RoundTrip::<FrameOwned>::new().check_with_ref::<FrameRef>();
+    byteview::testing::RoundTrip::<NonceOwned>::new().check_with_ref::<NonceRef>();
+    byteview::testing::RoundTrip::<NativeOwned>::new().check_with_ref::<NativeRef>();
+}
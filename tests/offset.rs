@@ -0,0 +1,75 @@
+use byteview::OffsetError;
+
+byteview::byteview_ref! {
+    /// A file header pointing to a name table elsewhere in the file.
+    pub struct FileHeader {
+        pub magic: [u8; 4],
+        #[byteview(offset_into_buffer)]
+        pub name_offset: u32be,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct NameEntry {
+        pub name_len: u8,
+        pub name: [u8; 3],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct NameEntryOwned {
+        pub name_len: u8,
+        pub name: [u8; 3],
+    }
+}
+
+const FILE: &[u8] = b"FILE\x00\x00\x00\x0Cabcd\x03foo";
+
+#[test]
+fn test_offset_target() {
+    let (header, _) = FileHeader::split_slice(FILE).unwrap();
+    assert_eq!(12, header.name_offset());
+    assert_eq!(Ok(&b"\x03foo"[..]), header.name_offset_target(FILE));
+}
+
+#[test]
+fn test_offset_resolve() {
+    let (header, _) = FileHeader::split_slice(FILE).unwrap();
+
+    let entry: NameEntry = header.name_offset_resolve(FILE).unwrap();
+    assert_eq!(3, entry.name_len());
+    assert_eq!(b"foo", entry.name());
+
+    let entry: NameEntryOwned = header.name_offset_resolve(FILE).unwrap();
+    assert_eq!(3, entry.name_len());
+    assert_eq!(b"foo", entry.name());
+}
+
+#[test]
+fn test_offset_errors() {
+    let bytes = b"FILE\x00\x00\x01\x00";
+    let header = FileHeader::from_array(bytes);
+    assert_eq!(
+        Err(OffsetError::OutOfBounds {
+            offset: 256,
+            len: 8
+        }),
+        header.name_offset_target(bytes)
+    );
+
+    let bytes = b"FILE\x00\x00\x00\x07\x03f";
+    let header = FileHeader::from_exact_slice(&bytes[..8]).unwrap();
+    assert_eq!(
+        Err(OffsetError::Truncated {
+            offset: 7,
+            needed: 4,
+            available: 3
+        }),
+        header.name_offset_resolve::<NameEntry>(bytes).map(|_| ())
+    );
+
+    // An offset at the very end of the buffer is an empty target, not an error.
+    let bytes = b"FILE\x00\x00\x00\x08";
+    let header = FileHeader::from_array(bytes);
+    assert_eq!(Ok(&b""[..]), header.name_offset_target(bytes));
+}
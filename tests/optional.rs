@@ -0,0 +1,149 @@
+use byteview::{ByteView, ParseError};
+
+byteview::byteview_ref! {
+    pub struct Record {
+        pub id: u32be,
+        pub name: [u8; 20],
+        #[byteview(optional)]
+        pub timestamp: u64be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct RecordOwned {
+        pub id: u32be,
+        pub name: [u8; 20],
+        #[byteview(optional)]
+        pub timestamp: u64be,
+        #[byteview(optional)]
+        pub flags: u8,
+    }
+}
+
+fn v1_bytes() -> Vec<u8> {
+    let mut bytes = vec![0, 0, 0, 7];
+    bytes.extend(b"first record\0\0\0\0\0\0\0\0");
+    bytes
+}
+
+fn v2_bytes() -> Vec<u8> {
+    let mut bytes = v1_bytes();
+    bytes.extend(0x0102_0304_0506_0708u64.to_be_bytes());
+    bytes
+}
+
+#[test]
+fn test_short_record() {
+    let bytes = v1_bytes();
+    let (record, rest) = Record::split_slice(&bytes).unwrap();
+    assert_eq!(7, record.id());
+    assert_eq!(None, record.timestamp());
+    assert_eq!(24, record.byte_len());
+    assert!(rest.is_empty());
+
+    // A partial optional field is not consumed
+    let mut bytes = v1_bytes();
+    bytes.extend([0xAA; 5]);
+    let (record, rest) = Record::split_slice(&bytes).unwrap();
+    assert_eq!(None, record.timestamp());
+    assert_eq!(24, record.byte_len());
+    assert_eq!([0xAA; 5], rest);
+    assert!(Record::from_exact_slice(&bytes).is_none());
+}
+
+#[test]
+fn test_long_record() {
+    let bytes = v2_bytes();
+    let record = Record::from_exact_slice(&bytes).unwrap();
+    assert_eq!(7, record.id());
+    assert_eq!(Some(0x0102_0304_0506_0708), record.timestamp());
+    assert_eq!(32, record.byte_len());
+    assert_eq!(32, Record::NUM_BYTES);
+
+    let (record, rest) = RecordOwned::split_vec(bytes.clone()).unwrap();
+    assert_eq!(Some(0x0102_0304_0506_0708), record.timestamp());
+    assert_eq!(None, record.flags());
+    assert_eq!(32, record.byte_len());
+    assert!(rest.is_empty());
+
+    let mut bytes = bytes;
+    bytes.push(0x80);
+    let record = RecordOwned::from_exact_vec(bytes).unwrap();
+    assert_eq!(Some(0x80), record.flags());
+    assert_eq!(33, record.byte_len());
+}
+
+#[test]
+fn test_too_short() {
+    let bytes = &v1_bytes()[..23];
+    assert!(Record::split_slice(bytes).is_none());
+    assert_eq!(
+        Some(ParseError::TooShort {
+            needed: 24,
+            available: 23
+        }),
+        <RecordOwned as ByteView>::split_slice_checked(bytes).err()
+    );
+}
+
+byteview::byteview_ref! {
+    pub struct Defaults {
+        pub id: u32be,
+        #[byteview(optional, default = 0xFFFF_FFFF)]
+        pub checksum: u32le,
+        #[byteview(optional, default = *b"none")]
+        pub tag: [u8; 4],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct DefaultsOwned {
+        pub id: u32be,
+        #[byteview(optional, default = 0xFFFF_FFFF)]
+        pub checksum: u32le,
+        #[byteview(optional, default = -1)]
+        pub adjustment: i8,
+    }
+}
+
+#[test]
+fn test_default_short_record() {
+    let bytes = [0, 0, 0, 1];
+    let record = Defaults::from_exact_slice(&bytes).unwrap();
+    assert_eq!(0xFFFF_FFFF, record.checksum());
+    assert!(!record.checksum_present());
+    assert_eq!(b"none", record.tag());
+    assert!(!record.tag_present());
+    assert_eq!(0xFFFF_FFFF, Defaults::CHECKSUM_DEFAULT);
+}
+
+#[test]
+fn test_default_long_record() {
+    let bytes = [0, 0, 0, 1, 0x78, 0x56, 0x34, 0x12, b'a', b'b', b'c', b'd'];
+    let record = Defaults::from_exact_slice(&bytes).unwrap();
+    assert_eq!(0x1234_5678, record.checksum());
+    assert!(record.checksum_present());
+    assert_eq!(b"abcd", record.tag());
+    assert!(record.tag_present());
+
+    let record = DefaultsOwned::from_exact_vec(bytes[..8].to_vec()).unwrap();
+    assert_eq!(0x1234_5678, record.checksum());
+    assert_eq!(-1, record.adjustment());
+    assert!(!record.adjustment_present());
+}
+
+#[test]
+fn test_into_long_form() {
+    let record = DefaultsOwned::from_exact_vec(vec![0, 0, 0, 1]).unwrap();
+    let record = record.into_long_form();
+    assert_eq!(DefaultsOwned::NUM_BYTES, record.byte_len());
+    assert!(record.checksum_present());
+    assert_eq!(0xFFFF_FFFF, record.checksum());
+    assert!(record.adjustment_present());
+    assert_eq!(-1, record.adjustment());
+
+    let record = DefaultsOwned::from_exact_vec(vec![0, 0, 0, 1, 1, 0, 0, 0]).unwrap();
+    let record = record.into_long_form();
+    assert_eq!(1, record.checksum());
+    assert_eq!(-1, record.adjustment());
+}
@@ -0,0 +1,53 @@
+byteview::byteview_ref! {
+    pub struct SensorBlob {
+        pub channel: u8,
+        pub samples: packed<u12be, 4>,
+        pub lsb_samples: packed<u12le, 4>,
+        pub flags: packed<u3be, 5>,
+        pub checksum: u8,
+    }
+}
+
+#[test]
+fn test_size() {
+    assert_eq!(1 + 6 + 6 + 2 + 1, SensorBlob::NUM_BYTES);
+    let samples = SensorBlob::LAYOUT.field("samples").unwrap();
+    assert_eq!((1, 6), (samples.offset, samples.size));
+    assert_eq!("packed<u12be, 4>", samples.ty);
+}
+
+#[test]
+fn test_packed() {
+    let channel = [7];
+    let samples = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+    // 001 010 011 100 101, followed by an unused bit
+    let flags = [0b0010_1001, 0b1100_1010];
+    let checksum = [0xEE];
+    let bytes = [&channel[..], &samples, &samples, &flags, &checksum].concat();
+    let blob = SensorBlob::from_exact_slice(&bytes).unwrap();
+    assert_eq!(7, blob.channel());
+    assert_eq!(0xEE, blob.checksum());
+
+    let samples = blob.samples();
+    assert_eq!(4, samples.len());
+    assert_eq!(12, samples.bits());
+    assert_eq!(
+        vec![0x123, 0x456, 0x789, 0xABC],
+        samples.iter().collect::<Vec<u16>>()
+    );
+    assert_eq!(Some(0x789), samples.get(2));
+    assert_eq!(None, samples.get(4));
+
+    let lsb_samples = blob.lsb_samples();
+    assert_eq!(
+        vec![0x412, 0x563, 0xA78, 0xBC9],
+        lsb_samples.into_iter().collect::<Vec<_>>()
+    );
+
+    let mut buffer = [0u16; 3];
+    assert_eq!(3, samples.copy_to_slice(&mut buffer));
+    assert_eq!([0x123, 0x456, 0x789], buffer);
+
+    let flags = blob.flags().iter().collect::<Vec<u8>>();
+    assert_eq!(vec![1, 2, 3, 4, 5], flags);
+}
@@ -0,0 +1,62 @@
+byteview::byteview_owned! {
+    #[byteview(pad_with = 0xFF)]
+    pub struct FlashRecord {
+        pub id: u16be,
+        _: u8,
+        pub value: u32le,
+        _: [u8; 3],
+        #[byteview(reserved)]
+        pub reserved: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(pad_with = 0xFF)]
+    pub struct FlashRecordRef {
+        pub id: u16be,
+        _: u8,
+        pub value: u32le,
+        _: [u8; 3],
+        #[byteview(reserved)]
+        pub reserved: u8,
+    }
+}
+
+fn padding_offsets() -> Vec<usize> {
+    FlashRecord::LAYOUT
+        .fields
+        .iter()
+        .filter(|field| field.is_padding())
+        .flat_map(|field| field.range())
+        .collect()
+}
+
+#[test]
+fn test_pad_with() {
+    assert_eq!(0xFF, FlashRecord::PAD_BYTE);
+    let mut bytes = [0; FlashRecord::NUM_BYTES];
+    bytes[..2].copy_from_slice(&0x1234u16.to_be_bytes());
+    bytes[3..7].copy_from_slice(&0xDEADBEEFu32.to_le_bytes());
+    bytes[10] = 0x55;
+    let mut record = FlashRecord::from_array(bytes);
+    record.normalize();
+
+    let padding = padding_offsets();
+    assert_eq!(vec![2, 7, 8, 9], padding);
+    for offset in padding {
+        assert_eq!(0xFF, record.as_bytes()[offset], "offset {offset}");
+    }
+    assert_eq!(0x1234, record.id());
+    assert_eq!(0xDEADBEEF, record.value());
+    assert_eq!(0, record.reserved());
+}
+
+#[test]
+fn test_pad_with_ref() {
+    let bytes = [0x12, 0x34, 0, 1, 2, 3, 4, 0, 0, 0, 9];
+    let normalized = FlashRecordRef::from_array(&bytes).normalized();
+    assert_eq!(
+        vec![0x12, 0x34, 0xFF, 1, 2, 3, 4, 0xFF, 0xFF, 0xFF, 0],
+        normalized
+    );
+}
@@ -0,0 +1,113 @@
+byteview::byteview_ref! {
+    pub struct Record {
+        _: [u8; 2],
+        pub first: u16be,
+        _: [u8; 13],
+        pub second: u8,
+        _: u32be,
+        _: [u16le; 2],
+        pub third: [u8; 2],
+        _: [u8; 3],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct RecordOwned {
+        _: [u8; 2],
+        pub first: u16be,
+        _: [u8; 13],
+        #[byteview(counter, prefix_boundary)]
+        pub second: u8,
+        _: u32be,
+        _: [i16be; 2],
+        pub third: [u8; 2],
+        _: [u8; 3],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Short {
+        _: [u8; 2],
+        pub first: u8,
+        #[byteview(optional, default = [0; 2])]
+        pub second: [u8; 2],
+        #[byteview(optional)]
+        _: [u8; 3],
+    }
+}
+
+fn record_bytes() -> Vec<u8> {
+    let mut bytes = vec![0xEE; 32];
+    bytes[2..4].copy_from_slice(&[0x01, 0x02]);
+    bytes[17] = 0x2A;
+    bytes[26..28].copy_from_slice(b"OK");
+    bytes
+}
+
+#[test]
+fn test_offsets() {
+    assert_eq!(31, Record::NUM_BYTES);
+    let offsets = Record::LAYOUT
+        .fields
+        .iter()
+        .map(|field| (field.name, field.range()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("_", 0..2),
+            ("first", 2..4),
+            ("_", 4..17),
+            ("second", 17..18),
+            ("_", 18..22),
+            ("_", 22..26),
+            ("third", 26..28),
+            ("_", 28..31),
+        ],
+        offsets
+    );
+
+    let bytes = record_bytes();
+    let (record, rest) = Record::split_slice(&bytes).unwrap();
+    assert_eq!(0x0102, record.first());
+    assert_eq!(42, record.second());
+    assert_eq!(b"OK", record.third());
+    assert_eq!(&[0xEE], rest);
+}
+
+#[test]
+fn test_owned() {
+    assert_eq!(31, RecordOwned::NUM_BYTES);
+    let bytes = record_bytes();
+    let mut record = RecordOwned::from_exact_vec(bytes[..31].to_vec()).unwrap();
+    assert_eq!(43, record.increment_second_wrapping());
+    assert_eq!(0x0102, record.prefix().first());
+    let RecordOwnedFieldsMut {
+        first,
+        second,
+        third,
+    } = record.split_fields_mut();
+    assert_eq!([0x01, 0x02], *first);
+    assert_eq!([43], *second);
+    *third = *b"ok";
+    assert_eq!(b"ok", record.third());
+
+    record.normalize();
+    let mut expected = vec![0; 31];
+    expected[2..4].copy_from_slice(&[0x01, 0x02]);
+    expected[17] = 43;
+    expected[26..28].copy_from_slice(b"ok");
+    assert_eq!(expected, record.as_bytes());
+}
+
+#[test]
+fn test_optional_padding() {
+    assert_eq!(3, Short::MIN_NUM_BYTES);
+    assert_eq!(8, Short::NUM_BYTES);
+    let short = Short::from_exact_vec(vec![0xEE, 0xEE, 7]).unwrap();
+    assert_eq!(7, short.first());
+    assert_eq!(&[0, 0], short.second());
+    let (long, rest) = Short::split_vec(vec![0xEE, 0xEE, 7, 1, 2, 0xEE, 0xEE, 0xEE, 9]).unwrap();
+    assert_eq!(&[1, 2], long.second());
+    assert_eq!(8, long.byte_len());
+    assert_eq!(vec![9], rest);
+}
@@ -0,0 +1,155 @@
+use byteview::{AppliedEdit, FieldSelector, Patch, PatchError, Value};
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub magic: [u8; 4],
+        pub flags: u16le,
+        pub num_entries: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Entry {
+        pub index: u32be,
+        pub offset: i16be,
+    }
+}
+
+fn image() -> Vec<u8> {
+    let mut bytes = b"IMG1\x01\x00\x03".to_vec();
+    for index in 0..3u32 {
+        bytes.extend(index.to_be_bytes());
+        bytes.extend([0, 0]);
+    }
+    bytes
+}
+
+fn entry_offset(i: usize) -> usize {
+    Header::NUM_BYTES + i * Entry::NUM_BYTES
+}
+
+#[test]
+fn test_apply() {
+    let mut bytes = image();
+    let mut patch = Patch::new();
+    patch
+        .push(
+            0,
+            FieldSelector::name(Header::LAYOUT, "flags"),
+            Value::Bit {
+                index: 11,
+                set: true,
+            },
+        )
+        .push(
+            0,
+            FieldSelector::name(Header::LAYOUT, "flags"),
+            Value::Bit {
+                index: 0,
+                set: false,
+            },
+        )
+        .push(
+            entry_offset(2),
+            *Entry::LAYOUT.field("index").unwrap(),
+            Value::Unsigned(9),
+        )
+        .push(
+            entry_offset(1),
+            FieldSelector::name(Entry::LAYOUT, "offset"),
+            Value::Signed(-2),
+        )
+        .push(
+            0,
+            FieldSelector::name(Header::LAYOUT, "magic"),
+            Value::Bytes(b"IMG2".to_vec()),
+        );
+    let report = patch.apply(&mut bytes).unwrap();
+
+    let header = Header::split_slice(&bytes).unwrap().0;
+    assert_eq!(b"IMG2", header.magic());
+    assert_eq!(0x0800, header.flags());
+    let entry = Entry::split_slice(&bytes[entry_offset(2)..]).unwrap().0;
+    assert_eq!(9, entry.index());
+    let entry = Entry::split_slice(&bytes[entry_offset(1)..]).unwrap().0;
+    assert_eq!(-2, entry.offset());
+
+    assert_eq!(5, report.changes.len());
+    assert_eq!(
+        AppliedEdit {
+            index: 2,
+            range: 19..23,
+            old: vec![0, 0, 0, 2],
+            new: vec![0, 0, 0, 9],
+        },
+        report.changes[2]
+    );
+    assert_eq!(6, report.bytes_changed());
+}
+
+#[test]
+fn test_invalid_patch_leaves_buffer_untouched() {
+    let original = image();
+    let mut bytes = original.clone();
+    let mut patch = Patch::new();
+    patch
+        .push(
+            0,
+            FieldSelector::name(Header::LAYOUT, "num_entries"),
+            Value::Unsigned(4),
+        )
+        .push(
+            entry_offset(0),
+            FieldSelector::name(Entry::LAYOUT, "offset"),
+            Value::Signed(40_000),
+        );
+    assert_eq!(
+        Err(PatchError::DoesNotFit {
+            index: 1,
+            kind: Entry::LAYOUT.fields[1].kind
+        }),
+        patch.apply(&mut bytes)
+    );
+    assert_eq!(original, bytes);
+
+    let mut patch = Patch::new();
+    patch.push(
+        entry_offset(3),
+        FieldSelector::name(Entry::LAYOUT, "index"),
+        Value::Unsigned(0),
+    );
+    assert_eq!(
+        Err(PatchError::OutOfBounds {
+            index: 0,
+            range: 25..29,
+            len: 25
+        }),
+        patch.apply(&mut bytes)
+    );
+
+    let mut patch = Patch::new();
+    patch.push(
+        0,
+        FieldSelector::name(Header::LAYOUT, "missing"),
+        Value::Unsigned(0),
+    );
+    assert_eq!(
+        Err(PatchError::UnknownField {
+            index: 0,
+            name: "missing".to_owned()
+        }),
+        patch.apply(&mut bytes)
+    );
+
+    let mut patch = Patch::new();
+    patch.push(
+        0,
+        FieldSelector::name(Header::LAYOUT, "magic"),
+        Value::Unsigned(0),
+    );
+    assert!(matches!(
+        patch.apply(&mut bytes),
+        Err(PatchError::TypeMismatch { index: 0, .. })
+    ));
+    assert_eq!(original, bytes);
+}
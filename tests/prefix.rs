@@ -0,0 +1,62 @@
+use byteview::ByteView;
+
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    pub struct DirEntry {
+        pub inode: u32be,
+        pub kind: u8,
+        pub _: u8,
+        #[byteview(prefix_boundary)]
+        pub name_len: u16be,
+        pub name: [u8; 24],
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct DirEntryOwned {
+        pub inode: u32be,
+        pub kind: u8,
+        #[byteview(prefix_boundary)]
+        pub name_len: u16be,
+        pub name: [u8; 24],
+    }
+}
+
+const PREFIX: &[u8] = &[0, 0, 0x12, 0x34, 2, 0, 0, 5];
+
+#[test]
+fn test_prefix_from_truncated_buffer() {
+    assert!(DirEntry::split_slice(PREFIX).is_none());
+
+    let (prefix, rest) = DirEntryPrefix::split_slice(PREFIX).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(8, DirEntryPrefix::NUM_BYTES);
+    assert_eq!(0x1234, prefix.inode());
+    assert_eq!(2, prefix.kind());
+    assert_eq!(5, prefix.name_len());
+
+    let prefix = DirEntryOwnedPrefix::from_exact_vec(vec![0, 0, 0x12, 0x34, 2, 0, 5]).unwrap();
+    assert_eq!(0x1234, prefix.inode());
+    assert_eq!(5, prefix.name_len());
+}
+
+#[test]
+fn test_prefix_of_full_view() {
+    let mut bytes = PREFIX.to_vec();
+    bytes.extend(b"hello\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0");
+    let entry = DirEntry::from_exact_slice(&bytes).unwrap();
+    let prefix = entry.prefix();
+    assert_eq!(entry.inode(), prefix.inode());
+    assert_eq!(entry.name_len(), prefix.name_len());
+
+    let entry = DirEntryOwned::split_vec(bytes[1..].to_vec()).unwrap().0;
+    assert_eq!(entry.name_len(), entry.prefix().name_len());
+}
+
+#[test]
+fn test_prefix_offsets_match() {
+    let prefix_fields = DirEntryPrefix::LAYOUT.fields;
+    assert_eq!(4, prefix_fields.len());
+    assert_eq!(&DirEntry::LAYOUT.fields[..4], prefix_fields);
+    assert_eq!(8, <DirEntryPrefix as ByteView>::LAYOUT.num_bytes);
+}
@@ -0,0 +1,64 @@
+use byteview::PythonFormatError;
+
+byteview::byteview_ref! {
+    pub struct HeaderStart {
+        _file_name: [u8; 32],
+        _earliest_date_epoch: u32be,
+        _latest_date_epoch: u32be,
+        _log_type: u8,
+        pub num_fields: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct FieldDefinition {
+        _name: [u8; 32],
+        _data_info: u8,
+        pub index: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Mixed {
+        big: u16be,
+        little: u16le,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Wide {
+        _: [u8; 4],
+        nonce: u128le,
+        delta: i64le,
+    }
+}
+
+#[test]
+fn test_python_struct_format() {
+    assert_eq!(
+        Ok(">32sIIBB".to_owned()),
+        HeaderStart::layout().python_struct_format()
+    );
+    assert_eq!(
+        Ok("=32sBB".to_owned()),
+        FieldDefinition::layout().python_struct_format()
+    );
+}
+
+#[test]
+fn test_python_struct_format_unsupported() {
+    assert_eq!(
+        Err(PythonFormatError::MixedEndianness {
+            first: "big",
+            second: "little"
+        }),
+        Mixed::layout().python_struct_format()
+    );
+    assert_eq!(
+        Err(PythonFormatError::UnsupportedType {
+            field: "nonce",
+            ty: "u128le"
+        }),
+        Wide::layout().python_struct_format()
+    );
+}
@@ -0,0 +1,61 @@
+use byteview::{I16Le, OffsetError, U16Le, U32Be, U64Be, read_at, write_at};
+
+#[test]
+fn test_read_at() {
+    let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+    assert_eq!(Ok(0x0102_0304), read_at::<U32Be>(&bytes, 0));
+    assert_eq!(Ok(0x0304_0506), read_at::<U32Be>(&bytes, 2));
+    assert_eq!(Ok(0x0605), read_at::<U16Le>(&bytes, 4));
+    assert_eq!(Ok(0x0201), read_at::<I16Le>(&bytes, 0));
+    assert_eq!(
+        Err(OffsetError::Truncated {
+            offset: 3,
+            needed: 4,
+            available: 3,
+        }),
+        read_at::<U32Be>(&bytes, 3)
+    );
+    assert_eq!(
+        Err(OffsetError::Truncated {
+            offset: 6,
+            needed: 2,
+            available: 0,
+        }),
+        read_at::<U16Le>(&bytes, 6)
+    );
+    assert_eq!(
+        Err(OffsetError::OutOfBounds { offset: 7, len: 6 }),
+        read_at::<U16Le>(&bytes, 7)
+    );
+    assert_eq!(
+        Err(OffsetError::OutOfBounds {
+            offset: usize::MAX as u64,
+            len: 6,
+        }),
+        read_at::<U64Be>(&bytes, usize::MAX)
+    );
+}
+
+#[test]
+fn test_write_at() {
+    let mut bytes = [0; 6];
+    write_at::<U32Be>(&mut bytes, 0, 0x0102_0304).unwrap();
+    assert_eq!([1, 2, 3, 4, 0, 0], bytes);
+    write_at::<U16Le>(&mut bytes, 3, 0x0A0B).unwrap();
+    assert_eq!([1, 2, 3, 0x0B, 0x0A, 0], bytes);
+    write_at::<I16Le>(&mut bytes, 4, -1).unwrap();
+    assert_eq!([1, 2, 3, 0x0B, 0xFF, 0xFF], bytes);
+    assert_eq!(
+        Err(OffsetError::Truncated {
+            offset: 4,
+            needed: 4,
+            available: 2,
+        }),
+        write_at::<U32Be>(&mut bytes, 4, 0)
+    );
+    assert_eq!(
+        Err(OffsetError::OutOfBounds { offset: 8, len: 6 }),
+        write_at::<U16Le>(&mut bytes, 8, 0)
+    );
+    assert_eq!([1, 2, 3, 0x0B, 0xFF, 0xFF], bytes);
+}
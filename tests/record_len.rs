@@ -0,0 +1,94 @@
+use byteview::{ByteView, ParseError, Records};
+
+byteview::byteview_ref! {
+    #[byteview(record_len = "record_len")]
+    pub struct Record {
+        pub kind: u8,
+        pub record_len: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(record_len = "record_len")]
+    pub struct RecordOwned {
+        pub kind: u8,
+        pub record_len: u16be,
+    }
+}
+
+const RECORDS: &[u8] = b"\x01\x00\x05ab\x02\x00\x03\x03\x00\x07wxyz";
+
+#[test]
+fn test_split_slice() {
+    let (record, rest) = Record::split_slice(RECORDS).unwrap();
+    assert_eq!(1, record.kind());
+    assert_eq!(5, record.record_len());
+    assert_eq!(b"ab", record.body());
+    assert_eq!(&RECORDS[5..], rest);
+
+    let (record, rest) = RecordOwned::split_vec(RECORDS.to_vec()).unwrap();
+    assert_eq!(1, record.kind());
+    assert_eq!(b"ab", record.body());
+    assert_eq!(&RECORDS[5..], rest);
+
+    let record = Record::from_exact_slice(&RECORDS[5..8]).unwrap();
+    assert_eq!(2, record.kind());
+    assert_eq!(b"", record.body());
+    assert!(Record::from_exact_slice(&RECORDS[..6]).is_none());
+}
+
+#[test]
+fn test_records() {
+    let records = Records::<Record>::new(RECORDS)
+        .map(|record| {
+            let record = record.unwrap();
+            (record.kind(), record.body())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![(1, &b"ab"[..]), (2, &b""[..]), (3, &b"wxyz"[..])],
+        records
+    );
+
+    let records = Records::<RecordOwned>::new(RECORDS)
+        .map(|record| record.unwrap().body().to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![b"ab".to_vec(), vec![], b"wxyz".to_vec()], records);
+}
+
+#[test]
+fn test_records_overrun() {
+    let bytes = b"\x01\x00\x04a\x02\x00\x09abc";
+    let mut records = Records::<Record>::new(bytes);
+    assert_eq!(b"a", records.next().unwrap().unwrap().body());
+    assert_eq!(
+        Some(Err(ParseError::LengthMismatch {
+            declared: 9,
+            available: 6
+        })),
+        records
+            .next()
+            .map(|record| record.map(|record| record.kind()))
+    );
+    assert!(records.next().is_none());
+    assert_eq!(&bytes[4..], records.remaining());
+}
+
+#[test]
+fn test_split_slice_checked_errors() {
+    assert_eq!(
+        Some(ParseError::TooShort {
+            needed: 3,
+            available: 2
+        }),
+        Record::split_slice_checked(b"\x01\x00").err()
+    );
+    assert_eq!(
+        Some(ParseError::LengthTooSmall {
+            declared: 2,
+            minimum: 3
+        }),
+        RecordOwned::split_slice_checked(b"\x01\x00\x02").err()
+    );
+    assert!(Record::split_slice(b"\x01\x00\x02").is_none());
+}
@@ -0,0 +1,143 @@
+use byteview::{ByteOrder, ByteView, Endianness, FieldKind, NumberType, PythonFormatError};
+use byteview::{DynView, FieldSelector, Layout, ParseError, Patch, PatchError, Value};
+
+byteview::byteview_ref! {
+    #[byteview(endian = "runtime")]
+    pub struct HeaderStart {
+        #[byteview(endian = "big")]
+        pub byte_order_mark: u16,
+        pub version: u16,
+        pub offset: i24,
+        pub sizes: [u32; 2],
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(endian = "runtime")]
+    pub struct Counters {
+        #[byteview(counter)]
+        pub count: u16,
+        #[byteview(optional)]
+        pub total: u32,
+    }
+}
+
+const BYTES: [u8; 16] = *b"\xFF\xFE\x01\x00\xFE\xFF\xFF\x01\x00\x00\x00\x02\x00\x00\x00\x2A";
+
+fn read_header(bytes: &[u8]) -> Option<HeaderStart<'_>> {
+    let byte_order = match bytes.get(..2)? {
+        b"\xFE\xFF" => ByteOrder::Big,
+        b"\xFF\xFE" => ByteOrder::Little,
+        _ => return None,
+    };
+    Some(HeaderStart::split_slice_with(bytes, byte_order)?.0)
+}
+
+#[test]
+fn test_byte_order_chosen_by_constructor() {
+    let header = read_header(&BYTES).unwrap();
+    assert_eq!(ByteOrder::Little, header.byte_order());
+    assert_eq!(0xFFFE, header.byte_order_mark());
+    assert_eq!(1, header.version());
+    assert_eq!(-2, header.offset());
+    assert_eq!([1, 2], header.sizes());
+    assert_eq!(42, header.kind());
+
+    let header = HeaderStart::from_array_with(&BYTES, ByteOrder::Big);
+    assert_eq!(ByteOrder::Big, header.byte_order());
+    assert_eq!(0x0100, header.version());
+    assert_eq!(-0x0001_0001, header.offset());
+    assert_eq!([0x0100_0000, 0x0200_0000], header.sizes());
+
+    let header = HeaderStart::from_exact_slice_with(&BYTES, ByteOrder::NATIVE).unwrap();
+    assert_eq!(u16::from_ne_bytes([0x01, 0x00]), header.version());
+    assert!(HeaderStart::from_exact_slice_with(&BYTES[1..], ByteOrder::Little).is_none());
+}
+
+#[test]
+fn test_default_constructors_are_big_endian() {
+    let header = HeaderStart::from_array(&BYTES);
+    assert_eq!(ByteOrder::Big, header.byte_order());
+    assert_eq!(0x0100, header.version());
+    let (header, _) = <HeaderStart as ByteView>::split_slice(&BYTES).unwrap();
+    assert_eq!(0x0100, header.version());
+}
+
+#[test]
+fn test_layout() {
+    const LAYOUT: Layout = HeaderStart::LAYOUT;
+    assert_eq!(
+        FieldKind::Number(NumberType::U16, Endianness::Big),
+        LAYOUT.fields[0].kind
+    );
+    assert_eq!(
+        FieldKind::RuntimeNumber(NumberType::U16),
+        LAYOUT.fields[1].kind
+    );
+    assert_eq!(
+        FieldKind::RuntimeNumber(NumberType::I24),
+        LAYOUT.fields[2].kind
+    );
+    assert_eq!("u16", LAYOUT.fields[1].ty);
+    assert_eq!(
+        Err(PythonFormatError::RuntimeEndianness { field: "version" }),
+        LAYOUT.python_struct_format()
+    );
+
+    // The byte order is not part of the layout, so the numbers are shown as bytes and cannot be patched.
+    let dump = HeaderStart::from_array_with(&BYTES, ByteOrder::Little).dump();
+    assert!(dump.contains("version          u16       01 00"), "{dump}");
+    let mut buffer = BYTES;
+    let mut patch = Patch::new();
+    patch.push(
+        0,
+        FieldSelector::name(LAYOUT, "version"),
+        Value::Unsigned(2),
+    );
+    assert_eq!(
+        Err(PatchError::TypeMismatch {
+            index: 0,
+            kind: FieldKind::RuntimeNumber(NumberType::U16)
+        }),
+        patch.apply(&mut buffer)
+    );
+}
+
+#[test]
+fn test_checked_constructor() {
+    let (header, rest) = HeaderStart::split_slice_checked_with(&BYTES, ByteOrder::Little).unwrap();
+    assert_eq!(1, header.version());
+    assert!(rest.is_empty());
+    assert_eq!(
+        Err(ParseError::TooShort {
+            needed: 16,
+            available: 15
+        }),
+        HeaderStart::split_slice_checked_with(&BYTES[1..], ByteOrder::Little).map(|_| ())
+    );
+}
+
+#[test]
+fn test_owned() {
+    let mut counters = Counters::from_exact_vec_with(vec![0xFF, 0x00], ByteOrder::Little).unwrap();
+    assert_eq!(256, counters.increment_count_wrapping());
+    assert_eq!(&[0x00, 0x01], counters.as_bytes());
+    assert_eq!(None, counters.total());
+
+    let (counters, rest) =
+        Counters::split_vec_with(vec![0, 1, 0, 0, 0, 2, 3], ByteOrder::Big).unwrap();
+    assert_eq!(1, counters.count());
+    assert_eq!(Some(2), counters.total());
+    assert_eq!(vec![3], rest);
+}
+
+#[test]
+fn test_native_byte_order() {
+    let expected = if cfg!(target_endian = "little") {
+        Endianness::Little
+    } else {
+        Endianness::Big
+    };
+    assert_eq!(expected, Endianness::from(ByteOrder::NATIVE));
+}
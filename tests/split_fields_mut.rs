@@ -0,0 +1,60 @@
+use byteview::{EndianNumber, U32Be};
+
+byteview::byteview_owned! {
+    pub struct EntryOwned {
+        pub index: u32be,
+        pub kind: u8,
+        _: u8,
+        pub name: [u8; 8],
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(record_len = "record_len")]
+    pub struct RecordOwned {
+        pub kind: u8,
+        pub record_len: u8,
+    }
+}
+
+#[test]
+fn test_simultaneous_handles() {
+    let mut entry = EntryOwned::from_array([0; EntryOwned::NUM_BYTES]);
+    let source = b"logfile";
+    let EntryOwnedFieldsMut { index, kind, name } = entry.split_fields_mut();
+    name[..source.len()].copy_from_slice(source);
+    *index = U32Be::new(source.len() as u32).to_bytes();
+    U32Be::write(U32Be::read(index) + 1, index);
+    *kind = [3];
+    name[7] = b'!';
+
+    assert_eq!(8, entry.index());
+    assert_eq!(3, entry.kind());
+    assert_eq!(b"logfile!", entry.name());
+    assert_eq!(
+        &[
+            0, 0, 0, 8, 3, 0, b'l', b'o', b'g', b'f', b'i', b'l', b'e', b'!'
+        ],
+        entry.as_bytes()
+    );
+}
+
+#[test]
+fn test_padding_is_skipped() {
+    let mut entry = EntryOwned::from_array([0xFF; EntryOwned::NUM_BYTES]);
+    let fields = entry.split_fields_mut();
+    *fields.kind = [0];
+    fields.name.fill(0);
+    assert_eq!(0xFF, entry.as_bytes()[5]);
+    assert_eq!(&[0xFF; 4], &entry.as_bytes()[..4]);
+}
+
+#[test]
+fn test_variable_size() {
+    let (mut record, _) = RecordOwned::split_vec(vec![1, 4, 0xAA, 0xBB]).unwrap();
+    let RecordOwnedFieldsMut { kind, record_len } = record.split_fields_mut();
+    kind[0] = 2;
+    assert_eq!([4], *record_len);
+    assert_eq!(2, record.kind());
+    assert_eq!(&[0xAA, 0xBB], record.body());
+}
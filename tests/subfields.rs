@@ -0,0 +1,75 @@
+byteview::byteview_ref! {
+    pub struct PacketHeader {
+        #[byteview(subfields(kind = 4..8, length = 0..4))]
+        pub info: u8,
+        #[byteview(subfields(version = 13..16, flags = 8..=12, sequence = 0..8))]
+        pub control: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct WideHeader {
+        #[byteview(subfields(high = 32..64, low = 0..32, top = 63..64))]
+        pub word: u64le,
+    }
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(0xF0u8, PacketHeader::KIND_MASK);
+    assert_eq!(4u32, PacketHeader::KIND_SHIFT);
+    assert_eq!(0x0Fu8, PacketHeader::LENGTH_MASK);
+    assert_eq!(0u32, PacketHeader::LENGTH_SHIFT);
+    assert_eq!(0xE000u16, PacketHeader::VERSION_MASK);
+    assert_eq!(13, PacketHeader::VERSION_SHIFT);
+    assert_eq!(0x1F00u16, PacketHeader::FLAGS_MASK);
+    assert_eq!(8, PacketHeader::FLAGS_SHIFT);
+    assert_eq!(0x00FFu16, PacketHeader::SEQUENCE_MASK);
+    assert_eq!(0, PacketHeader::SEQUENCE_SHIFT);
+    assert_eq!(0xFFFF_FFFF_0000_0000u64, WideHeader::HIGH_MASK);
+    assert_eq!(0x8000_0000_0000_0000u64, WideHeader::TOP_MASK);
+    assert_eq!(63, WideHeader::TOP_SHIFT);
+}
+
+#[test]
+fn test_accessors() {
+    let header = PacketHeader::from_array(&[0xA5, 0b1011_0110, 0x42]);
+    assert_eq!(0xA, header.kind());
+    assert_eq!(0x5, header.length());
+    assert_eq!(0b101, header.version());
+    assert_eq!(0b1_0110, header.flags());
+    assert_eq!(0x42, header.sequence());
+
+    let header = WideHeader::from_array([0x78, 0x56, 0x34, 0x12, 0xEF, 0xCD, 0xAB, 0x89]);
+    assert_eq!(0x89AB_CDEF, header.high());
+    assert_eq!(0x1234_5678, header.low());
+    assert_eq!(1, header.top());
+}
+
+#[test]
+fn test_accessors_match_constants() {
+    for info in 0..=u8::MAX {
+        let bytes = [info, info.rotate_left(3), info ^ 0x5A];
+        let header = PacketHeader::from_array(&bytes);
+        assert_eq!(
+            (header.info() & PacketHeader::KIND_MASK) >> PacketHeader::KIND_SHIFT,
+            header.kind()
+        );
+        assert_eq!(
+            (header.info() & PacketHeader::LENGTH_MASK) >> PacketHeader::LENGTH_SHIFT,
+            header.length()
+        );
+        assert_eq!(
+            (header.control() & PacketHeader::VERSION_MASK) >> PacketHeader::VERSION_SHIFT,
+            header.version()
+        );
+        assert_eq!(
+            (header.control() & PacketHeader::FLAGS_MASK) >> PacketHeader::FLAGS_SHIFT,
+            header.flags()
+        );
+        assert_eq!(
+            (header.control() & PacketHeader::SEQUENCE_MASK) >> PacketHeader::SEQUENCE_SHIFT,
+            header.sequence()
+        );
+    }
+}
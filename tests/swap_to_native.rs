@@ -0,0 +1,149 @@
+use byteview::{BigEndian, LittleEndian};
+
+byteview::byteview_owned! {
+    pub struct Inner {
+        pub value: u16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Record {
+        pub kind: u8,
+        pub length: u16be,
+        pub offset: u32le,
+        pub timestamp: u48be,
+        pub legacy: u32me,
+        pub scale: f32be,
+        pub samples: [i16be; 2],
+        pub name: [u8; 4],
+        _: u16be,
+        pub inner: Inner,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Pair {
+        pub kind: u8,
+        pub items: [Inner; 2],
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(endian = "runtime")]
+    pub struct RuntimeInner {
+        pub value: u16,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct WithRuntime {
+        pub value: u16be,
+        pub inner: RuntimeInner,
+    }
+}
+
+byteview::byteview_ref! {
+    #[byteview(endian = "generic")]
+    pub struct Generic {
+        pub length: u32,
+        pub flags: u16le,
+    }
+}
+
+fn record_bytes() -> [u8; Record::NUM_BYTES] {
+    *b"\x2A\x01\x02\x03\x04\x05\x06\x01\x02\x03\x04\x05\x06\x01\x02\x03\x04\x3F\x80\x00\x00\xFF\xFE\x00\x07NAME\xAA\xBB\x12\x34"
+}
+
+#[test]
+fn test_swap_to_native() {
+    let mut bytes = record_bytes();
+    let record = Record::from_array(bytes);
+    Record::swap_to_native(&mut bytes);
+    assert_eq!(record.kind(), bytes[0]);
+    assert_eq!(record.length(), u16::from_ne_bytes([bytes[1], bytes[2]]));
+    assert_eq!(
+        record.offset(),
+        u32::from_ne_bytes(bytes[3..7].try_into().unwrap())
+    );
+    let timestamp = &bytes[7..13];
+    let timestamp = if cfg!(target_endian = "big") {
+        u64::from_be_bytes([&[0, 0], timestamp].concat().try_into().unwrap())
+    } else {
+        u64::from_le_bytes([timestamp, &[0, 0]].concat().try_into().unwrap())
+    };
+    assert_eq!(record.timestamp(), timestamp);
+    assert_eq!(
+        record.legacy(),
+        u32::from_ne_bytes(bytes[13..17].try_into().unwrap())
+    );
+    assert_eq!(
+        record.scale(),
+        f32::from_ne_bytes(bytes[17..21].try_into().unwrap())
+    );
+    assert_eq!(
+        record.samples()[0],
+        i16::from_ne_bytes([bytes[21], bytes[22]])
+    );
+    assert_eq!(
+        record.samples()[1],
+        i16::from_ne_bytes([bytes[23], bytes[24]])
+    );
+    assert_eq!(b"NAME", &bytes[25..29]);
+    assert_eq!(b"\xAA\xBB", &bytes[29..31]);
+    assert_eq!(
+        record.inner().value(),
+        u16::from_ne_bytes([bytes[31], bytes[32]])
+    );
+}
+
+#[test]
+fn test_nested_array() {
+    let mut bytes = *b"\x07\x12\x34\x56\x78";
+    let pair = Pair::from_array(bytes);
+    Pair::swap_to_native(&mut bytes);
+    assert_eq!(7, bytes[0]);
+    let items = pair.items();
+    assert_eq!(
+        items.get(0).unwrap().value(),
+        u16::from_ne_bytes([bytes[1], bytes[2]])
+    );
+    assert_eq!(
+        items.get(1).unwrap().value(),
+        u16::from_ne_bytes([bytes[3], bytes[4]])
+    );
+}
+
+#[test]
+fn test_swap_twice_restores_bytes() {
+    let mut bytes = record_bytes();
+    Record::swap_to_native(&mut bytes);
+    Record::swap_to_native(&mut bytes);
+    assert_eq!(record_bytes(), bytes);
+}
+
+#[test]
+fn test_nested_runtime_byte_order() {
+    let mut bytes = *b"\x12\x34\x56\x78";
+    WithRuntime::swap_to_native(&mut bytes);
+    assert_eq!(0x1234, u16::from_ne_bytes([bytes[0], bytes[1]]));
+    assert_eq!(b"\x56\x78", &bytes[2..]);
+}
+
+#[test]
+fn test_generic() {
+    let original = *b"\x01\x02\x03\x04\x05\x06";
+    let mut big = original;
+    Generic::<BigEndian>::swap_to_native(&mut big);
+    let mut little = original;
+    Generic::<LittleEndian>::swap_to_native(&mut little);
+    assert_eq!(
+        0x0102_0304,
+        u32::from_ne_bytes(big[..4].try_into().unwrap())
+    );
+    assert_eq!(
+        0x0403_0201,
+        u32::from_ne_bytes(little[..4].try_into().unwrap())
+    );
+    assert_eq!(0x0605, u16::from_ne_bytes([big[4], big[5]]));
+    assert_eq!(0x0605, u16::from_ne_bytes([little[4], little[5]]));
+}
@@ -0,0 +1,148 @@
+use byteview::{ByteView, Magic, MagicBytes, ParseError, Resync, Skipped};
+
+byteview::byteview_ref! {
+    #[derive(Debug)]
+    #[byteview(record_len = "record_len")]
+    pub struct Frame {
+        pub record_len: u8,
+        #[byteview(magic = b"\xA5\x5A")]
+        pub sync: [u8; 2],
+        pub sequence: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderOwned {
+        #[byteview(magic = b"LOG1")]
+        pub magic: [u8; 4],
+        pub count: u16be,
+    }
+}
+
+fn frame(sequence: u8, body: &[u8]) -> Vec<u8> {
+    let mut frame = vec![4 + body.len() as u8, 0xA5, 0x5A, sequence];
+    frame.extend_from_slice(body);
+    frame
+}
+
+#[test]
+fn test_magic_constants() {
+    assert_eq!(
+        Magic {
+            offset: 1,
+            bytes: b"\xA5\x5A"
+        },
+        Frame::MAGIC
+    );
+    assert_eq!(Some(Frame::MAGIC), <Frame as ByteView>::MAGIC);
+    assert_eq!(0, HeaderOwned::MAGIC.offset);
+    assert_eq!(b"LOG1", HeaderOwned::MAGIC.bytes);
+}
+
+#[test]
+fn test_magic_checked() {
+    assert!(HeaderOwned::split_slice_checked(b"LOG1\x00\x02").is_ok());
+    assert_eq!(
+        Err(ParseError::MagicMismatch {
+            offset: 0,
+            expected: b"LOG1",
+            actual: MagicBytes::new(b"LOG2"),
+        }),
+        HeaderOwned::split_slice_checked(b"LOG2\x00\x02").map(|_| ())
+    );
+    // The unchecked constructors do not check the magic bytes.
+    assert_eq!(b"LOG2", HeaderOwned::from_array(*b"LOG2\x00\x02").magic());
+    // Missing bytes are reported as too short rather than a mismatch.
+    assert!(matches!(
+        HeaderOwned::split_slice_checked(b"LO"),
+        Err(ParseError::TooShort { .. })
+    ));
+}
+
+#[test]
+fn test_find_sync() {
+    let bytes = [frame(1, b"ab"), frame(2, b"")].concat();
+    assert_eq!(Some(0), Frame::find_sync(&bytes, 0));
+    assert_eq!(Some(6), Frame::find_sync(&bytes, 1));
+    assert_eq!(Some(6), Frame::find_sync(&bytes, 6));
+    assert_eq!(None, Frame::find_sync(&bytes, 7));
+    assert_eq!(None, Frame::find_sync(&bytes, 100));
+    assert_eq!(Some(3), HeaderOwned::find_sync(b"xyzLOG1\x00\x00", 0));
+}
+
+#[test]
+fn test_resync_after_corruption() {
+    let mut bytes = Vec::new();
+    for sequence in 0..10 {
+        bytes.extend(frame(sequence, &[sequence; 3]));
+    }
+    let gap_start = bytes.len();
+    // A damaged region, including magic bytes preceded by an invalid record length.
+    let mut state = 0x1234_5678u32;
+    for i in 0..300 {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        if i % 50 == 10 {
+            bytes.extend([0x00, 0xA5, 0x5A]);
+        } else {
+            bytes.push((state >> 24) as u8);
+        }
+    }
+    // A byte whose record length is far too large, so parsing at the gap fails.
+    bytes[gap_start] = 0xFF;
+    let gap_end = bytes.len();
+    for sequence in 10..20 {
+        bytes.extend(frame(sequence, &[]));
+    }
+
+    let mut records = Vec::new();
+    let mut gaps = Vec::new();
+    for item in Resync::<Frame>::new(&bytes) {
+        match item {
+            Ok(frame) => records.push(frame.sequence()),
+            Err(skipped) => gaps.push(skipped),
+        }
+    }
+    assert_eq!((0..20).collect::<Vec<u8>>(), records);
+    assert_eq!(1, gaps.len());
+    assert_eq!(gap_start..gap_end, gaps[0].range);
+}
+
+#[test]
+fn test_resync_ignores_false_positives() {
+    // The body of the first frame contains the magic bytes and a plausible length.
+    let bytes = [
+        frame(1, &[4, 0xA5, 0x5A, 9]),
+        vec![0xEE, 0xEE],
+        frame(2, b"xy"),
+    ]
+    .concat();
+    let items: Vec<Result<u8, Skipped>> = Resync::<Frame>::new(&bytes)
+        .map(|item| item.map(|frame| frame.sequence()))
+        .collect();
+    assert_eq!(
+        vec![
+            Ok(1),
+            Err(Skipped {
+                range: 8..10,
+                error: ParseError::MagicMismatch {
+                    offset: 1,
+                    expected: b"\xA5\x5A",
+                    actual: MagicBytes::new(b"\xEE\x06"),
+                },
+            }),
+            Ok(2),
+        ],
+        items
+    );
+}
+
+#[test]
+fn test_resync_trailing_garbage() {
+    let bytes = [frame(1, b""), vec![3, 0xA5]].concat();
+    let mut records = Resync::<Frame>::new(&bytes);
+    assert_eq!(1, records.next().unwrap().unwrap().sequence());
+    let skipped = records.next().unwrap().unwrap_err();
+    assert_eq!(4..6, skipped.range);
+    assert!(records.next().is_none());
+    assert_eq!(6, records.offset());
+}
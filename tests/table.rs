@@ -0,0 +1,170 @@
+use byteview::Table;
+
+byteview::byteview_ref! {
+    pub struct IndexEntry {
+        pub key: u32be,
+        pub offset: u16le,
+    }
+}
+
+fn entries(keys: &[u32]) -> Vec<u8> {
+    keys.iter()
+        .enumerate()
+        .flat_map(|(i, key)| {
+            let mut entry = key.to_be_bytes().to_vec();
+            entry.extend((i as u16).to_le_bytes());
+            entry
+        })
+        .collect()
+}
+
+#[test]
+fn test_new() {
+    let bytes = entries(&[1, 2, 3]);
+    let table = Table::<IndexEntry>::new(&bytes).unwrap();
+    assert_eq!(3, table.len());
+    assert!(!table.is_empty());
+    assert_eq!(Some(2), table.get(1).map(|entry| entry.key()));
+    assert!(table.get(3).is_none());
+    assert_eq!(
+        vec![1, 2, 3],
+        table.iter().map(|entry| entry.key()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![2, 1, 0],
+        table
+            .into_iter()
+            .rev()
+            .map(|entry| entry.offset())
+            .collect::<Vec<_>>()
+    );
+
+    assert!(Table::<IndexEntry>::new(&bytes[1..]).is_none());
+    assert!(Table::<IndexEntry>::new(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_is_sorted_by_key() {
+    let sorted = entries(&[1, 4, 4, 9, 100]);
+    let table = Table::<IndexEntry>::new(&sorted).unwrap();
+    assert!(table.is_sorted_by_key(|entry| entry.key()));
+    assert!(!table.is_sorted_by_key(|entry| std::cmp::Reverse(entry.key())));
+
+    let unsorted = entries(&[1, 4, 3, 9]);
+    let table = Table::<IndexEntry>::new(&unsorted).unwrap();
+    assert!(!table.is_sorted_by_key(|entry| entry.key()));
+    assert!(table.is_sorted_by_key(|entry| entry.offset()));
+
+    let single = entries(&[7]);
+    let table = Table::<IndexEntry>::new(&single).unwrap();
+    assert!(table.is_sorted_by_key(|entry| entry.key()));
+    assert!(
+        Table::<IndexEntry>::new(&[])
+            .unwrap()
+            .is_sorted_by_key(|entry| entry.key())
+    );
+}
+
+#[test]
+fn test_partition_point() {
+    let keys = [1, 4, 4, 9, 100];
+    let bytes = entries(&keys);
+    let table = Table::<IndexEntry>::new(&bytes).unwrap();
+    for pivot in [0, 1, 2, 4, 5, 9, 50, 100, 101] {
+        assert_eq!(
+            keys.partition_point(|key| *key < pivot),
+            table.partition_point(|entry| entry.key() < pivot),
+            "pivot {pivot}"
+        );
+    }
+
+    let single = entries(&[7]);
+    let table = Table::<IndexEntry>::new(&single).unwrap();
+    assert_eq!(0, table.partition_point(|entry| entry.key() < 7));
+    assert_eq!(1, table.partition_point(|entry| entry.key() <= 7));
+}
+
+#[test]
+fn test_binary_search_by_key() {
+    let bytes = entries(&[1, 4, 9, 100]);
+    let table = Table::<IndexEntry>::new(&bytes).unwrap();
+    assert_eq!(Ok(0), table.binary_search_by_key(&1, |entry| entry.key()));
+    assert_eq!(Ok(2), table.binary_search_by_key(&9, |entry| entry.key()));
+    assert_eq!(Err(0), table.binary_search_by_key(&0, |entry| entry.key()));
+    assert_eq!(Err(3), table.binary_search_by_key(&10, |entry| entry.key()));
+    assert_eq!(
+        Err(4),
+        table.binary_search_by_key(&101, |entry| entry.key())
+    );
+}
+
+fn keys(table: Table<IndexEntry>) -> Vec<u32> {
+    table.iter().map(|entry| entry.key()).collect()
+}
+
+#[test]
+fn test_windows() {
+    let bytes = entries(&[1, 4, 9, 16, 25]);
+    let table = Table::<IndexEntry>::new(&bytes).unwrap();
+
+    let pairs = table.windows(2);
+    assert_eq!(4, pairs.len());
+    let differences: Vec<u32> = pairs
+        .map(|pair| pair.get(1).unwrap().key() - pair.get(0).unwrap().key())
+        .collect();
+    assert_eq!(vec![3, 5, 7, 9], differences);
+
+    let windows: Vec<Vec<u32>> = table.windows(4).map(keys).collect();
+    assert_eq!(vec![vec![1, 4, 9, 16], vec![4, 9, 16, 25]], windows);
+    assert_eq!(
+        vec![keys(table)],
+        table.windows(5).map(keys).collect::<Vec<_>>()
+    );
+    assert_eq!(0, table.windows(6).len());
+    assert_eq!(0, table.windows(6).count());
+    assert_eq!(5, table.windows(1).count());
+    assert_eq!(0, table.windows(usize::MAX).count());
+}
+
+#[test]
+fn test_chunks() {
+    let bytes = entries(&[1, 4, 9, 16, 25]);
+    let table = Table::<IndexEntry>::new(&bytes).unwrap();
+
+    let chunks = table.chunks(2);
+    assert_eq!(3, chunks.len());
+    let chunks: Vec<Vec<u32>> = chunks.map(keys).collect();
+    assert_eq!(vec![vec![1, 4], vec![9, 16], vec![25]], chunks);
+
+    let last = table.chunks(2).next_back().unwrap();
+    assert_eq!(1, last.len());
+    assert_eq!(&bytes[24..], last.as_bytes());
+
+    assert_eq!(
+        vec![keys(table)],
+        table.chunks(5).map(keys).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![keys(table)],
+        table.chunks(100).map(keys).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![keys(table)],
+        table.chunks(usize::MAX).map(keys).collect::<Vec<_>>()
+    );
+    assert_eq!(0, Table::<IndexEntry>::new(&[]).unwrap().chunks(3).count());
+}
+
+#[test]
+#[should_panic]
+fn test_windows_zero() {
+    let bytes = entries(&[1, 2]);
+    Table::<IndexEntry>::new(&bytes).unwrap().windows(0);
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_zero() {
+    let bytes = entries(&[1, 2]);
+    Table::<IndexEntry>::new(&bytes).unwrap().chunks(0);
+}
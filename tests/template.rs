@@ -0,0 +1,95 @@
+use byteview::Template;
+
+byteview::byteview_ref! {
+    pub struct EntryHeaderRef {
+        pub kind: u8,
+        _: u8,
+        pub index: u32be,
+        pub flags: u16le,
+    }
+}
+
+#[test]
+fn test_ignore_field() {
+    let known = [0x01, 0x00, 0x00, 0x00, 0x00, 0x07, 0x34, 0x12];
+    let template = Template::of(&EntryHeaderRef::from_array(&known)).ignore("index");
+    assert_eq!(known, template.pattern());
+    assert_eq!([0xFF, 0xFF, 0, 0, 0, 0, 0xFF, 0xFF], template.mask());
+
+    let same_but_index = [0x01, 0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x34, 0x12];
+    assert!(template.matches(&known));
+    assert!(template.matches(&same_but_index));
+    assert!(template.matches_view(&EntryHeaderRef::from_array(&same_but_index)));
+
+    let different_flags = [0x01, 0x00, 0x00, 0x00, 0x00, 0x07, 0x35, 0x12];
+    let different_padding = [0x01, 0x01, 0x00, 0x00, 0x00, 0x07, 0x34, 0x12];
+    assert!(!template.matches(&different_flags));
+    assert!(!template.matches(&different_padding));
+    assert!(!template.matches(&known[..7]));
+}
+
+byteview::byteview_ref! {
+    pub struct Reading {
+        pub sensor: u8,
+        #[byteview(optional)]
+        pub value: u16be,
+        #[byteview(optional)]
+        pub timestamp: u16be,
+    }
+}
+
+#[test]
+fn test_optional_fields() {
+    let (short, _) = Reading::split_slice(&[0x07]).unwrap();
+    let template = Template::of(&short).ignore("value").ignore("timestamp");
+    assert_eq!([0x07], template.pattern());
+    assert_eq!([0xFF], template.mask());
+    assert!(template.matches(&[0x07, 0x12, 0x34]));
+    assert!(!template.matches(&[0x08]));
+
+    let (partial, _) = Reading::split_slice(&[0x07, 0x00, 0x2A, 0x12]).unwrap();
+    let template = Template::of(&partial).ignore("timestamp");
+    assert_eq!([0x07, 0x00, 0x2A], template.pattern());
+    assert_eq!([0xFF, 0xFF, 0xFF], template.mask());
+    assert!(template.matches(&[0x07, 0x00, 0x2A, 0x56, 0x78]));
+    assert!(!template.matches(&[0x07, 0x00, 0x2B]));
+}
+
+#[test]
+fn test_find_in() {
+    let known = [0x01, 0x00, 0x00, 0x00, 0x00, 0x07, 0x34, 0x12];
+    let template = Template::of(&EntryHeaderRef::from_array(&known))
+        .ignore("index")
+        .ignore("flags");
+    let mut haystack = vec![0x01, 0x01, 0x02];
+    haystack.extend([0x01, 0x00, 0x09, 0x09, 0x09, 0x09, 0xAB, 0xCD]);
+    assert_eq!(Some(3), template.find_in(&haystack));
+    assert_eq!(None, template.find_in(&haystack[4..]));
+    assert_eq!(None, template.find_in(&[]));
+}
+
+#[test]
+fn test_new() {
+    let template = Template::new([0xA0, 0x00, 0x55], [0xF0, 0x00, 0xFF]).unwrap();
+    assert!(template.matches(&[0xAF, 0x12, 0x55, 0x99]));
+    assert!(!template.matches(&[0xAF, 0x12, 0x54]));
+    assert_eq!(Some(1), template.find_in(&[0x00, 0xA1, 0xFF, 0x55]));
+    assert_eq!(None, Template::new([0x00], []));
+
+    let empty = Template::new([], []).unwrap();
+    assert!(empty.matches(&[]));
+    assert_eq!(Some(0), empty.find_in(&[]));
+}
+
+#[test]
+#[should_panic(expected = "`EntryHeaderRef` has no field named `missing` to ignore")]
+fn test_ignore_missing_field() {
+    let bytes = [0; 8];
+    let _ = Template::of(&EntryHeaderRef::from_array(&bytes)).ignore("missing");
+}
+
+#[test]
+#[should_panic(expected = "fields can only be ignored in a template created from a struct")]
+fn test_ignore_without_layout() {
+    let _ = Template::new([0], [0xFF]).unwrap().ignore("kind");
+}
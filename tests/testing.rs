@@ -0,0 +1,59 @@
+#![cfg(feature = "testing")]
+
+use byteview::testing::{RoundTrip, check_roundtrip};
+
+byteview::byteview_ref! {
+    pub struct SampleRef {
+        pub index: u16le,
+        pub offset: i32be,
+        pub ratio: f64be,
+        pub name: [u8; 3],
+        _: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct SampleOwned {
+        pub index: u16le,
+        pub offset: i32be,
+        pub ratio: f64be,
+        pub name: [u8; 3],
+        _: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct Other {
+        pub index: u16be,
+    }
+}
+
+#[test]
+fn test_check() {
+    check_roundtrip::<SampleOwned>();
+    check_roundtrip::<Other>();
+    RoundTrip::<SampleOwned>::new()
+        .buffer(*b"\x01\x00\xFF\xFF\xFF\xFE\x3F\xF0\x00\x00\x00\x00\x00\x00abc\x00")
+        .skip_field("ratio")
+        .check_with_ref::<SampleRef>();
+}
+
+#[test]
+#[should_panic(expected = "`SampleOwned` has no field named `missing` to skip")]
+fn test_unknown_skipped_field() {
+    RoundTrip::<SampleOwned>::new()
+        .skip_field("missing")
+        .check();
+}
+
+#[test]
+#[should_panic(expected = "`Other` rejects")]
+fn test_short_buffer() {
+    RoundTrip::<Other>::new().buffer([0]).check();
+}
+
+#[test]
+#[should_panic(expected = "`SampleOwned` and `Other` have different layouts")]
+fn test_different_layouts() {
+    RoundTrip::<SampleOwned>::new().check_with_ref::<Other>();
+}
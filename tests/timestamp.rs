@@ -0,0 +1,50 @@
+#![cfg(feature = "chrono")]
+
+use chrono::{DateTime, Utc};
+
+byteview::byteview_ref! {
+    pub struct Archive {
+        pub created: timestamp32be,
+        pub modified: timestamp64le,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ArchiveOwned {
+        pub created: timestamp32be,
+        pub modified: timestamp64le,
+        pub kind: u8,
+    }
+}
+
+fn date(rfc3339: &str) -> Option<DateTime<Utc>> {
+    Some(DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc())
+}
+
+#[test]
+fn test_timestamp() {
+    assert_eq!(4 + 8 + 1, Archive::NUM_BYTES);
+    assert_eq!("u32be", Archive::LAYOUT.fields[0].ty);
+
+    let bytes = *b"\x65\x53\xF1\x00\x00\x00\x00\x80\x00\x00\x00\x00\x03";
+    let archive = Archive::from_array(&bytes);
+    assert_eq!(date("2023-11-14T22:13:20Z"), archive.created());
+    assert_eq!(0x6553_F100, archive.created_raw());
+    assert_eq!(date("2038-01-19T03:14:08Z"), archive.modified());
+    assert_eq!(0x8000_0000, archive.modified_raw());
+    assert_eq!(3, archive.kind());
+
+    let archive = ArchiveOwned::from_array([0; 13]);
+    assert_eq!(None, archive.created());
+    assert_eq!(None, archive.modified());
+
+    let mut bytes = [0xFF; 13];
+    bytes[4..12].copy_from_slice(&(-86_400i64).to_le_bytes());
+    let archive = ArchiveOwned::from_array(bytes);
+    assert_eq!(date("2106-02-07T06:28:15Z"), archive.created());
+    assert_eq!(date("1969-12-31T00:00:00Z"), archive.modified());
+
+    bytes[4..12].copy_from_slice(&i64::MAX.to_le_bytes());
+    assert_eq!(None, ArchiveOwned::from_array(bytes).modified());
+}
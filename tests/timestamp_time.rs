@@ -0,0 +1,57 @@
+#![cfg(feature = "time")]
+
+use byteview::time::OffsetDateTime;
+
+byteview::byteview_ref! {
+    pub struct Archive {
+        pub created: timestamp32be,
+        pub modified: timestamp64le,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct ArchiveOwned {
+        pub created: timestamp32be,
+        pub modified: timestamp64le,
+        pub kind: u8,
+    }
+}
+
+fn date(unix_timestamp: i64) -> Option<OffsetDateTime> {
+    Some(OffsetDateTime::from_unix_timestamp(unix_timestamp).unwrap())
+}
+
+#[test]
+fn test_timestamp() {
+    assert_eq!(4 + 8 + 1, Archive::NUM_BYTES);
+    assert_eq!("u32be", Archive::LAYOUT.fields[0].ty);
+
+    let bytes = *b"\x65\x53\xF1\x00\x00\x00\x00\x80\x00\x00\x00\x00\x03";
+    let archive = Archive::from_array(&bytes);
+    assert_eq!(date(0x6553_F100), archive.created());
+    assert_eq!(
+        "2023-11-14 22:13:20.0 +00:00:00",
+        archive.created().unwrap().to_string()
+    );
+    assert_eq!(0x6553_F100, archive.created_raw());
+    assert_eq!(date(0x8000_0000), archive.modified());
+    assert_eq!(0x8000_0000, archive.modified_raw());
+    assert_eq!(3, archive.kind());
+
+    let archive = ArchiveOwned::from_array([0; 13]);
+    assert_eq!(None, archive.created());
+    assert_eq!(None, archive.modified());
+
+    let mut bytes = [0xFF; 13];
+    bytes[4..12].copy_from_slice(&(-86_400i64).to_le_bytes());
+    let archive = ArchiveOwned::from_array(bytes);
+    assert_eq!(date(0xFFFF_FFFF), archive.created());
+    assert_eq!(
+        "1969-12-31 0:00:00.0 +00:00:00",
+        archive.modified().unwrap().to_string()
+    );
+
+    bytes[4..12].copy_from_slice(&i64::MAX.to_le_bytes());
+    assert_eq!(None, ArchiveOwned::from_array(bytes).modified());
+}
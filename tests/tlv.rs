@@ -0,0 +1,73 @@
+use byteview::{ParseError, TlvFormat, TlvWidth};
+
+const FORMAT: TlvFormat = TlvFormat::new(TlvWidth::U8, TlvWidth::U16Be);
+
+// Three TLVs (one with an empty value) followed by a truncated TLV.
+const BYTES: &[u8] = &[
+    0x01, 0x00, 0x03, b'a', b'b', b'c', //
+    0x02, 0x00, 0x00, //
+    0x03, 0x00, 0x01, 0xFF, //
+    0x04, 0x00, 0x05, b'x', b'y',
+];
+
+#[test]
+fn test_iter() {
+    let mut iter = FORMAT.iter(BYTES);
+    let tlv = iter.next().unwrap().unwrap();
+    assert_eq!((1, 3, &b"abc"[..]), (tlv.tag(), tlv.len(), tlv.value()));
+    let tlv = iter.next().unwrap().unwrap();
+    assert_eq!(2, tlv.tag());
+    assert!(tlv.is_empty());
+    let tlv = iter.next().unwrap().unwrap();
+    assert_eq!((3, &[0xFF][..]), (tlv.tag(), tlv.value()));
+    assert_eq!(
+        Some(Err(ParseError::LengthMismatch {
+            declared: 5,
+            available: 2
+        })),
+        iter.next()
+    );
+    assert_eq!(None, iter.next());
+    assert_eq!(&BYTES[13..], iter.remaining());
+}
+
+#[test]
+fn test_truncated_header() {
+    let format = TlvFormat::new(TlvWidth::U16Be, TlvWidth::U32Be);
+    assert_eq!(6, format.header_len());
+    assert_eq!(
+        Some(Err(ParseError::TooShort {
+            needed: 6,
+            available: 3
+        })),
+        format.iter(&[0, 1, 0]).next()
+    );
+}
+
+#[test]
+fn test_find_tag() {
+    let tlv = FORMAT.iter(BYTES).find_tag(3).unwrap().unwrap();
+    assert_eq!(&[0xFF], tlv.value());
+    assert_eq!(
+        Err(ParseError::LengthMismatch {
+            declared: 5,
+            available: 2
+        }),
+        FORMAT.iter(BYTES).find_tag(9)
+    );
+    assert_eq!(Ok(None), FORMAT.iter(&BYTES[..13]).find_tag(9));
+}
+
+#[test]
+fn test_nested() {
+    let format = TlvFormat::new(TlvWidth::U8, TlvWidth::U8);
+    let bytes = [0x10, 0x07, 0x01, 0x01, 0xAA, 0x02, 0x02, 0xBB, 0xCC];
+    let (outer, rest) = format.split_slice(&bytes).unwrap();
+    assert!(rest.is_empty());
+    let inner = outer
+        .value_as_tlvs()
+        .map(|tlv| tlv.map(|tlv| (tlv.tag(), tlv.value())))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(vec![(1, &[0xAA][..]), (2, &[0xBB, 0xCC][..])], inner);
+}
@@ -0,0 +1,53 @@
+use byteview::ByteView;
+
+byteview::byteview_ref! {
+    /// A CRC-32 checksum.
+    #[derive(Debug)]
+    pub struct Crc(pub u32be);
+
+    pub struct Pair(pub u16le, pub u8 as bool);
+}
+
+byteview::byteview_owned! {
+    pub struct Counter(#[byteview(counter)] pub u16be);
+}
+
+#[test]
+fn test_single_field() {
+    let crc = Crc::from_array(b"\xCB\xF4\x39\x26");
+    assert_eq!(0xCBF4_3926, crc.get());
+    assert_eq!(4, Crc::NUM_BYTES);
+    let (crc, rest) = <Crc as ByteView>::split_slice(b"\x00\x00\x00\x01\x02").unwrap();
+    assert_eq!(1, crc.get());
+    assert_eq!(b"\x02", rest);
+    assert_eq!("field_0", Crc::LAYOUT.fields[0].name);
+}
+
+#[test]
+fn test_single_field_names() {
+    // Only the "getter" is named `get`. Everything else uses the name in the layout.
+    assert_eq!(0, Crc::OFFSET_FIELD_0);
+    assert_eq!(4, Crc::SIZE_FIELD_0);
+    let field = Crc::LAYOUT.field("field_0").unwrap();
+    assert_eq!(
+        (Crc::OFFSET_FIELD_0, Crc::SIZE_FIELD_0),
+        (field.offset, field.size)
+    );
+    byteview::assert_offset!(Crc, field_0, 0);
+}
+
+#[test]
+fn test_multiple_fields() {
+    let pair = Pair::from_array(b"\x01\x02\x01");
+    assert_eq!(0x0201, pair.field_0());
+    assert_eq!(Ok(true), pair.field_1());
+    assert_eq!(3, Pair::NUM_BYTES);
+}
+
+#[test]
+fn test_owned() {
+    let mut counter = Counter::from_array([0, 1]);
+    assert_eq!(2, counter.increment_field_0_wrapping());
+    assert_eq!(2, counter.get());
+    assert_eq!(u16::MAX, counter.add_field_0_saturating(u16::MAX));
+}
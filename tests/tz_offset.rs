@@ -0,0 +1,83 @@
+#![cfg(feature = "chrono")]
+
+use chrono::FixedOffset;
+
+byteview::byteview_ref! {
+    pub struct LogEntry {
+        pub timestamp: u32be,
+        #[byteview(tz_offset_quarter_hours)]
+        pub zone: i8,
+        #[byteview(tz_offset_minutes)]
+        pub local_zone: i16be,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct LogEntryOwned {
+        pub timestamp: u32be,
+        #[byteview(tz_offset_quarter_hours)]
+        pub zone: i8,
+        #[byteview(tz_offset_minutes)]
+        pub local_zone: i16be,
+    }
+}
+
+fn hours_minutes(hours: i32, minutes: i32) -> Option<FixedOffset> {
+    FixedOffset::east_opt(hours * 3600 + hours.signum() * minutes * 60)
+}
+
+#[test]
+fn test_nepal() {
+    // +05:45 is 23 quarter-hours and 345 minutes.
+    let bytes = [0, 0, 0, 0, 23, 0x01, 0x59];
+    let entry = LogEntry::from_array(&bytes);
+    assert_eq!(hours_minutes(5, 45), entry.zone());
+    assert_eq!(hours_minutes(5, 45), entry.local_zone());
+    assert_eq!("+05:45", entry.zone().unwrap().to_string());
+    assert_eq!(23, entry.zone_raw());
+    assert_eq!(345, entry.local_zone_raw());
+
+    let entry = LogEntryOwned::from_array(bytes);
+    assert_eq!(hours_minutes(5, 45), entry.zone());
+    assert_eq!(hours_minutes(5, 45), entry.local_zone());
+}
+
+#[test]
+fn test_negative_offsets() {
+    // -03:30 is -14 quarter-hours and -210 minutes.
+    let [high, low] = (-210i16).to_be_bytes();
+    let bytes = [0, 0, 0, 0, -14i8 as u8, high, low];
+    let entry = LogEntry::from_array(&bytes);
+    assert_eq!(hours_minutes(-3, 30), entry.zone());
+    assert_eq!(hours_minutes(-3, 30), entry.local_zone());
+    assert_eq!(-14, entry.zone_raw());
+    assert_eq!(-210, entry.local_zone_raw());
+}
+
+#[test]
+fn test_limits() {
+    // Exactly 18 hours from UTC is valid.
+    let [high, low] = (-1080i16).to_be_bytes();
+    let bytes = [0, 0, 0, 0, 72, high, low];
+    let entry = LogEntry::from_array(&bytes);
+    assert_eq!(hours_minutes(18, 0), entry.zone());
+    assert_eq!(hours_minutes(-18, 0), entry.local_zone());
+}
+
+#[test]
+fn test_invalid() {
+    // 73 quarter-hours is 18:15 and 1081 minutes is 18:01, both more than 18 hours from UTC.
+    let [high, low] = 1081i16.to_be_bytes();
+    let bytes = [0, 0, 0, 0, 73, high, low];
+    let entry = LogEntry::from_array(&bytes);
+    assert_eq!(None, entry.zone());
+    assert_eq!(None, entry.local_zone());
+    assert_eq!(73, entry.zone_raw());
+    assert_eq!(1081, entry.local_zone_raw());
+
+    let [high, low] = i16::MIN.to_be_bytes();
+    let bytes = [0, 0, 0, 0, i8::MIN as u8, high, low];
+    let entry = LogEntry::from_array(&bytes);
+    assert_eq!(None, entry.zone());
+    assert_eq!(None, entry.local_zone());
+}
@@ -0,0 +1,5 @@
+#[test]
+fn test_type_errors() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
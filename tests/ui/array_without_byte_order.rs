@@ -0,0 +1,7 @@
+byteview::byteview! {
+    struct Foo {
+        a: [u16; 4],
+    }
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+byteview::byteview! {
+    struct Foo(u8,, u8);
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+byteview::byteview! {
+    struct Foo {
+        a: u8,
+        #[byteview(optional)]
+        b:
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+byteview::byteview! {
+    struct Foo {
+        pub a: ,
+        b: u8,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+byteview::byteview! {
+    struct Foo {
+        a: String,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+byteview::byteview! {
+    struct Foo {
+        a: u32eb,
+        b: u8,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+byteview::byteview! {
+    struct Foo {
+        a: u8,
+        b: usize,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,82 @@
+use byteview::{ByteOrder, LittleEndian};
+
+byteview::byteview! {
+    #[derive(Debug)]
+    pub struct EntryHeader {
+        pub index: u32be,
+        _: u8,
+        pub kind: u8,
+    }
+}
+
+byteview::byteview! {
+    #[derive(Debug)]
+    pub struct Message {
+        pub kind: u8,
+        #[byteview(optional)]
+        pub value: u16be,
+    }
+}
+
+byteview::byteview! {
+    #[derive(Debug)]
+    #[byteview(endian = "generic")]
+    pub struct Generic {
+        pub value: u16,
+    }
+}
+
+byteview::byteview! {
+    #[derive(Debug)]
+    #[byteview(endian = "runtime")]
+    pub struct Runtime {
+        pub value: u16,
+    }
+}
+
+#[test]
+fn test_equal_and_differing_bytes() {
+    let cached = EntryHeaderOwned::from_array(*b"\x00\x00\x00\x01\x00\x2A");
+    let parsed = EntryHeaderRef::from_array(b"\x00\x00\x00\x01\x00\x2A");
+    assert_eq!(parsed, cached);
+    assert_eq!(cached, parsed);
+
+    let other = EntryHeaderRef::from_array(b"\x00\x00\x00\x02\x00\x2A");
+    assert_ne!(other, cached);
+    assert_ne!(cached, other);
+}
+
+#[test]
+fn test_padding_bytes_are_compared() {
+    let cached = EntryHeaderOwned::from_array(*b"\x00\x00\x00\x01\x00\x2A");
+    let parsed = EntryHeaderRef::from_array(b"\x00\x00\x00\x01\xFF\x2A");
+    assert_eq!(cached.index(), parsed.index());
+    assert_eq!(cached.kind(), parsed.kind());
+    assert_ne!(parsed, cached);
+}
+
+#[test]
+fn test_variable_size() {
+    let cached = MessageOwned::from_exact_vec(vec![1, 0, 2]).unwrap();
+    assert_eq!(
+        MessageRef::from_exact_slice(b"\x01\x00\x02").unwrap(),
+        cached
+    );
+    assert_ne!(MessageRef::from_exact_slice(b"\x01").unwrap(), cached);
+}
+
+#[test]
+fn test_endianness() {
+    let cached = GenericOwned::<LittleEndian>::from_array(*b"\x01\x00");
+    assert_eq!(GenericRef::<LittleEndian>::from_array(b"\x01\x00"), cached);
+
+    let cached = RuntimeOwned::from_array_with(*b"\x01\x00", ByteOrder::Little);
+    assert_eq!(
+        RuntimeRef::from_array_with(b"\x01\x00", ByteOrder::Little),
+        cached
+    );
+    assert_ne!(
+        RuntimeRef::from_array_with(b"\x01\x00", ByteOrder::Big),
+        cached
+    );
+}
@@ -0,0 +1,122 @@
+use byteview::{ByteView, ParseError};
+
+byteview::byteview_ref! {
+    pub struct Header {
+        pub kind: u8,
+        pub version: u16le,
+        pub num_entries: varint,
+        pub num_strings: varint,
+    }
+}
+
+byteview::byteview_owned! {
+    pub struct HeaderOwned {
+        pub kind: u8,
+        pub version: u16le,
+        pub num_entries: varint,
+        pub num_strings: varint,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct Count {
+        pub count: varint,
+    }
+}
+
+const BYTES: &[u8] = b"\x07\x02\x00\xAC\x02\x05rest";
+
+#[test]
+fn test_constants() {
+    assert_eq!(3, Header::NUM_BYTES);
+    assert_eq!(5, Header::MIN_NUM_BYTES);
+    assert_eq!(23, Header::MAX_NUM_BYTES);
+    assert_eq!(2, Header::LAYOUT.fields.len());
+    assert_eq!(0, Count::NUM_BYTES);
+    assert_eq!(10, Count::MAX_NUM_BYTES);
+}
+
+#[test]
+fn test_split_slice() {
+    let (header, rest) = Header::split_slice(BYTES).unwrap();
+    assert_eq!(7, header.kind());
+    assert_eq!(2, header.version());
+    assert_eq!(300, header.num_entries());
+    assert_eq!(5, header.num_strings());
+    assert_eq!(6, header.byte_len());
+    assert_eq!(&BYTES[..6], header.as_bytes());
+    assert_eq!(b"rest", rest);
+
+    let (header, rest) = HeaderOwned::split_vec(BYTES.to_vec()).unwrap();
+    assert_eq!(300, header.num_entries());
+    assert_eq!(5, header.num_strings());
+    assert_eq!(b"rest", &rest[..]);
+
+    let header = Header::from_exact_slice(&BYTES[..6]).unwrap();
+    assert_eq!(300, header.num_entries());
+    assert!(Header::from_exact_slice(BYTES).is_none());
+
+    let (header, rest) = <HeaderOwned as ByteView>::split_slice(BYTES).unwrap();
+    assert_eq!(5, header.num_strings());
+    assert_eq!(b"rest", rest);
+}
+
+#[test]
+fn test_values() {
+    let cases: &[(&[u8], u64)] = &[
+        (b"\x00", 0),
+        (b"\x7F", 127),
+        (b"\x80\x01", 128),
+        (b"\xE5\x8E\x26", 624_485),
+        (b"\x80\x00", 0),
+        (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x01", u64::MAX),
+    ];
+    for &(bytes, value) in cases {
+        let count = Count::from_exact_slice(bytes).unwrap();
+        assert_eq!(value, count.count());
+    }
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Some(ParseError::TooShort {
+            needed: 5,
+            available: 2,
+        }),
+        Header::split_slice_checked(&BYTES[..2]).err()
+    );
+    assert_eq!(
+        Some(ParseError::TooShort {
+            needed: 5,
+            available: 4,
+        }),
+        Header::split_slice_checked(&BYTES[..4]).err()
+    );
+    assert_eq!(
+        Some(ParseError::TooShort {
+            needed: 6,
+            available: 5,
+        }),
+        Header::split_slice_checked(&BYTES[..5]).err()
+    );
+    assert!(Header::split_slice(&BYTES[..5]).is_none());
+
+    // The continuation bit never clears within 10 bytes.
+    let bytes = [0x80; 12];
+    assert_eq!(
+        Some(ParseError::InvalidVarint { offset: 0 }),
+        Count::split_slice_checked(&bytes).err()
+    );
+    // The tenth byte holds more than the most significant bit of a u64.
+    let bytes = b"\x07\x02\x00\x01\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x02";
+    assert_eq!(
+        Some(ParseError::InvalidVarint { offset: 4 }),
+        Header::split_slice_checked(bytes).err()
+    );
+    assert_eq!(
+        "the varint at offset 4 is longer than 10 bytes or does not fit in a u64",
+        ParseError::InvalidVarint { offset: 4 }.to_string()
+    );
+    assert!(HeaderOwned::split_vec(bytes.to_vec()).is_none());
+}
@@ -0,0 +1,134 @@
+use byteview::{ParseError, VersionError};
+
+byteview::byteview_ref! {
+    pub struct HeaderV1 {
+        pub magic: [u8; 4],
+        pub version: u8,
+        pub num_entries: u8,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct HeaderV2 {
+        pub magic: [u8; 4],
+        pub version: u8,
+        pub num_entries: u16be,
+    }
+}
+
+byteview::byteview_ref! {
+    pub struct HeaderV3 {
+        pub magic: [u8; 4],
+        pub version: u8,
+        pub num_entries: u32be,
+        pub flags: u8,
+    }
+}
+
+byteview::byteview_versioned! {
+    pub enum AnyHeader<'a> {
+        version_offset = 4;
+        common {
+            fn magic(&self) -> &[u8; 4];
+        }
+        1 => V1(HeaderV1<'a>),
+        2 => V2(HeaderV2<'a>),
+        3 => V3(HeaderV3<'a>),
+    }
+}
+
+byteview::byteview_owned! {
+    #[derive(Debug)]
+    pub struct FooterV1 {
+        pub checksum: u16be,
+        pub version: u8,
+    }
+}
+
+byteview::byteview_owned! {
+    #[derive(Debug)]
+    pub struct FooterV2 {
+        pub checksum: u32be,
+        pub version: u8,
+    }
+}
+
+byteview::byteview_versioned! {
+    #[derive(Debug)]
+    pub enum AnyFooter {
+        version_offset = FooterV1::NUM_BYTES - 1;
+        1 => V1(FooterV1),
+        2 => V2(FooterV2),
+    }
+}
+
+#[test]
+fn test_each_version() {
+    let bytes = b"HEAD\x01\x07rest";
+    let (header, rest) = AnyHeader::split_slice(bytes).unwrap();
+    let AnyHeader::V1(ref v1) = header else {
+        panic!("expected version 1");
+    };
+    assert_eq!(7, v1.num_entries());
+    assert_eq!(1, header.version());
+    assert_eq!(b"HEAD", header.magic());
+    assert_eq!(b"rest", rest);
+
+    let bytes = b"HEAD\x02\x01\x00";
+    let (header, rest) = AnyHeader::split_slice(bytes).unwrap();
+    let AnyHeader::V2(ref v2) = header else {
+        panic!("expected version 2");
+    };
+    assert_eq!(256, v2.num_entries());
+    assert_eq!(2, header.version());
+    assert!(rest.is_empty());
+
+    let bytes = b"HEAD\x03\x00\x00\x00\x09\x80";
+    let (header, _) = AnyHeader::split_slice(bytes).unwrap();
+    let AnyHeader::V3(ref v3) = header else {
+        panic!("expected version 3");
+    };
+    assert_eq!(9, v3.num_entries());
+    assert_eq!(0x80, v3.flags());
+    assert_eq!(3, header.version());
+}
+
+#[test]
+fn test_unknown_version() {
+    let bytes = b"HEAD\x04\x00\x00\x00\x09\x80";
+    assert_eq!(
+        Some(VersionError::UnknownVersion(4)),
+        AnyHeader::split_slice(bytes).err()
+    );
+}
+
+#[test]
+fn test_too_short() {
+    assert_eq!(
+        Some(VersionError::Parse(ParseError::TooShort {
+            needed: 5,
+            available: 4
+        })),
+        AnyHeader::split_slice(b"HEAD").err()
+    );
+    assert_eq!(
+        Some(VersionError::Parse(ParseError::TooShort {
+            needed: 10,
+            available: 7
+        })),
+        AnyHeader::split_slice(b"HEAD\x03\x00\x00").err()
+    );
+}
+
+#[test]
+fn test_owned() {
+    let (footer, _) = AnyFooter::split_slice(&[0x12, 0x34, 1]).unwrap();
+    let AnyFooter::V1(v1) = footer else {
+        panic!("expected version 1");
+    };
+    assert_eq!(0x1234, v1.checksum());
+    assert_eq!(
+        Some(VersionError::UnknownVersion(0)),
+        AnyFooter::split_slice(&[0x12, 0x34, 0, 0, 2]).err()
+    );
+}
@@ -0,0 +1,39 @@
+mod header {
+    byteview::byteview_ref! {
+        pub struct Header {
+            pub index: u32be,
+            pub(crate) kind: u8,
+            _reserved: u8,
+        }
+    }
+
+    impl Header<'_> {
+        pub fn is_reserved_zero(&self) -> bool {
+            self._reserved() == 0
+        }
+    }
+
+    byteview::byteview_owned! {
+        pub struct Counter {
+            #[byteview(counter)]
+            pub(crate) count: u16be,
+        }
+    }
+}
+
+use header::{Counter, Header};
+
+#[test]
+fn test_getters_have_field_visibility() {
+    let header = Header::from_array(b"\x00\x00\x00\x07\x2A\x00");
+    assert_eq!(7, header.index());
+    assert_eq!(42, header.kind());
+    assert!(header.is_reserved_zero());
+}
+
+#[test]
+fn test_other_methods_have_field_visibility() {
+    let mut counter = Counter::from_array([0, 1]);
+    assert_eq!(2, counter.increment_count_wrapping());
+    assert_eq!(2, counter.count());
+}
@@ -0,0 +1,112 @@
+use std::mem;
+
+byteview::byteview_owned! {
+    #[derive(Debug, Clone)]
+    #[byteview(zeroize_on_drop)]
+    pub struct Key {
+        pub id: u16be,
+        pub material: [u8; 16],
+    }
+}
+
+byteview::byteview_owned! {
+    #[byteview(zeroize_on_drop, endian = "runtime")]
+    pub struct Record {
+        pub count: u16,
+        pub kind: u8,
+        #[byteview(optional)]
+        pub extra: u32,
+    }
+}
+
+byteview::byteview! {
+    #[derive(Debug)]
+    #[byteview(zeroize_on_drop)]
+    pub struct Secret {
+        pub value: u64le,
+    }
+}
+
+byteview::byteview_owned! {
+    #[derive(Debug, Clone, Copy)]
+    pub struct Plain {
+        pub value: u32be,
+    }
+}
+
+const KEY_BYTES: [u8; 18] = [0, 7, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+
+#[test]
+fn test_zeroize_on_drop() {
+    assert!(mem::needs_drop::<Key>());
+    assert!(mem::needs_drop::<Record>());
+    assert!(mem::needs_drop::<SecretOwned>());
+    assert!(!mem::needs_drop::<SecretRef<'_>>());
+    assert!(!mem::needs_drop::<Plain>());
+
+    // The struct can still be constructed, read, and cloned as usual.
+    let key = Key::from_array(KEY_BYTES);
+    let copy = key.clone();
+    assert_eq!(7, copy.id());
+    assert_eq!(KEY_BYTES[2..], copy.material()[..]);
+    drop(key);
+
+    let record = Record::from_exact_vec_with(vec![3, 0, 9], byteview::ByteOrder::Little).unwrap();
+    assert_eq!(3, record.count());
+    assert_eq!(9, record.kind());
+    assert_eq!(None, record.extra());
+
+    let secret = SecretRef::from_array(b"\x01\x00\x00\x00\x00\x00\x00\x00").to_owned();
+    assert_eq!(1, secret.value());
+}
+
+#[test]
+fn test_wipe() {
+    let mut bytes = [1, 2, 3];
+    byteview::__private::wipe(&mut bytes);
+    assert_eq!([0, 0, 0], bytes);
+
+    let mut bytes = vec![1, 2, 3, 4];
+    bytes.truncate(2);
+    byteview::__private::wipe_vec(&mut bytes);
+    assert_eq!(vec![0, 0], bytes);
+    // The bytes past the length are wiped too.
+    // SAFETY: the vector has a capacity of at least 4, and the bytes past its length were initialized above.
+    unsafe { bytes.set_len(4) };
+    assert_eq!(vec![0, 0, 0, 0], bytes);
+}
+
+#[cfg(feature = "zeroize")]
+mod zeroize {
+    use super::*;
+    use byteview::zeroize::{Zeroize, ZeroizeOnDrop};
+
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+    #[test]
+    fn test_zeroize() {
+        let mut key = Key::from_array(KEY_BYTES);
+        key.zeroize();
+        assert_eq!([0; 18], key.as_bytes());
+        assert_eq!(0, key.id());
+
+        let mut plain = Plain::from_array(*b"\x00\x00\x00\x2A");
+        plain.zeroize();
+        assert_eq!([0; 4], plain.as_bytes());
+
+        let mut record =
+            Record::from_exact_vec_with(vec![3, 0, 9, 1, 2, 3, 4], byteview::ByteOrder::Big)
+                .unwrap();
+        record.zeroize();
+        assert_eq!([0; 7], record.as_bytes());
+        assert_eq!(Some(0), record.extra());
+
+        let mut secret = SecretRef::from_array(b"\x01\x00\x00\x00\x00\x00\x00\x00").to_owned();
+        secret.zeroize();
+        assert_eq!(0, secret.value());
+
+        assert_zeroize_on_drop::<Key>();
+        assert_zeroize_on_drop::<Record>();
+        assert_zeroize_on_drop::<SecretOwned>();
+    }
+}